@@ -75,7 +75,11 @@ class CbzPageController extends ChangeNotifier {
         _resolvedFile = resolved;
         _archivePath = resolved.path;
         
-        final names = await cbz_api.getCbzPageNames(path: resolved.path);
+        final pages = await cbz_api.getCbzPageNames(
+          path: resolved.path,
+          skipPatterns: const [],
+        );
+        final names = pages.names;
         
         _pageNames = names;
         _pageCount = names.length;
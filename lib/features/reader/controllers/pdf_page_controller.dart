@@ -6,6 +6,7 @@ import 'package:flutter/scheduler.dart';
 import 'package:reader_app/src/rust/api/pdf.dart' as pdf_api;
 import 'package:reader_app/src/rust/api/pdf.dart' show PdfTextRect;
 import 'package:reader_app/src/rust/api/crop.dart';
+import 'package:reader_app/src/rust/api/error.dart';
 import 'package:reader_app/core/models/book.dart';
 import 'package:reader_app/data/repositories/book_repository.dart';
 import 'package:reader_app/data/services/book_file_resolver.dart';
@@ -466,34 +467,33 @@ class PdfPageController extends ChangeNotifier {
     if (book.sourceType != BookSourceType.linked || book.sourceUri == null) {
       return false;
     }
-    final message = error.toString();
-    return message.contains('PDF_OPEN_ERROR::FORMAT') ||
-        message.contains('PDF_OPEN_ERROR::HEADER') ||
-        message.contains('PDF_OPEN_ERROR::EMPTY') ||
-        message.contains('PDF_OPEN_ERROR::FILE') ||
-        message.contains('FormatError');
+    return switch (error) {
+      FerrousError_Corrupt() ||
+      FerrousError_BadHeader() ||
+      FerrousError_Empty() ||
+      FerrousError_FileMissing() ||
+      FerrousError_Io() =>
+        true,
+      _ => false,
+    };
   }
 
   String _formatPdfError(Object error, {String? context}) {
-    final message = error.toString();
-    if (message.contains('PDF_OPEN_ERROR::HEADER')) {
-      return 'This file does not appear to be a valid PDF.';
-    }
-    if (message.contains('PDF_OPEN_ERROR::EMPTY')) {
-      return 'This PDF appears to be empty or still syncing.';
-    }
-    if (message.contains('PDF_OPEN_ERROR::FORMAT') || message.contains('FormatError')) {
-      return 'This PDF appears to be corrupted or invalid.';
-    }
-    if (message.contains('PDF_OPEN_ERROR::PASSWORD')) {
-      return 'This PDF is password-protected and cannot be opened.';
-    }
-    if (message.contains('PDF_OPEN_ERROR::SECURITY')) {
-      return 'This PDF cannot be opened due to its security settings.';
-    }
-    if (message.contains('PDF_OPEN_ERROR::FILE')) {
-      return 'Unable to access the PDF file. Try re-linking the folder.';
+    switch (error) {
+      case FerrousError_BadHeader():
+        return 'This file does not appear to be a valid PDF.';
+      case FerrousError_Empty():
+        return 'This PDF appears to be empty or still syncing.';
+      case FerrousError_Corrupt():
+        return 'This PDF appears to be corrupted or invalid.';
+      case FerrousError_PasswordRequired():
+        return 'This PDF is password-protected and cannot be opened.';
+      case FerrousError_Security():
+        return 'This PDF cannot be opened due to its security settings.';
+      case FerrousError_FileMissing() || FerrousError_Io():
+        return 'Unable to access the PDF file. Try re-linking the folder.';
     }
+    final message = error.toString();
     if (context != null) {
       return '$context Error: $message';
     }
@@ -66,7 +66,7 @@ Future<void> main() async {
         final safService = SafService();
         final nativeLibDir = await safService.getNativeLibraryDir();
         if (nativeLibDir != null) {
-          await initPdfium(path: nativeLibDir);
+          await initPdfium(libraryPath: nativeLibDir);
         }
       } catch (e) {
         debugPrint('Failed to initialize PDFium path: $e');
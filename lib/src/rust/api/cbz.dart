@@ -4,30 +4,116 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'covers.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'cbz.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_image_entries`, `is_image_file`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These functions are ignored because they are not marked as `pub`: `decode_page`, `get_image_entries`, `glob_match`, `is_image_file`, `load_cbz_page_rgba`, `lock_page_cache`, `matches_skip_pattern`, `natural_cmp`, `open_comic_archive`, `parse_comic_info`, `rar_entry_name`, `read_entry_dimensions`, `rgba_to_page_data`, `sample_border_color`, `scaled_page_dimensions`, `sort_page_entries`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `default`, `entry_checksum`, `eq`, `file_entries`, `file_entries`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `image_entries`, `image_entries`, `read_entry_prefix`, `read_entry`, `read_entry`
 
-/// Get total number of image pages in a CBZ archive
+/// Get total number of image pages in a CBZ/CBR archive
 Future<int> getCbzPageCount({required String path}) =>
     RustLib.instance.api.crateApiCbzGetCbzPageCount(path: path);
 
-/// Get list of page names (sorted) for chapter detection etc
-Future<List<String>> getCbzPageNames({required String path}) =>
-    RustLib.instance.api.crateApiCbzGetCbzPageNames(path: path);
+/// Get list of page names (sorted) for chapter detection etc, leaving out
+/// pages whose file name matches any of `skip_patterns` (e.g. `credits*`,
+/// `*_ad.*`). Filtering happens after sorting, so positions in `names` are
+/// stable; load filtered pages with `get_cbz_page_by_name`, since the
+/// index-based page APIs count every page. No patterns lists every page.
+Future<CbzPageNames> getCbzPageNames(
+        {required String path, required List<String> skipPatterns}) =>
+    RustLib.instance.api
+        .crateApiCbzGetCbzPageNames(path: path, skipPatterns: skipPatterns);
+
+/// Drop every decoded page cached by `get_cbz_page_by_name`.
+Future<void> clearCbzCache() => RustLib.instance.api.crateApiCbzClearCbzCache();
+
+/// How many decoded pages `get_cbz_page_by_name` keeps (default 0, off).
+/// Each holds a full RGBA buffer, so keep this small; 0 disables the cache.
+Future<void> setCbzCacheCapacity({required int capacity}) =>
+    RustLib.instance.api.crateApiCbzSetCbzCacheCapacity(capacity: capacity);
 
+/// Decode a page by entry name. Zip entries with the same CRC-32 and size
+/// as a page decoded recently are served from the page cache instead (see
+/// `set_cbz_cache_capacity`); RAR pages are always decoded.
 Future<CbzPageData> getCbzPageByName(
         {required String path, required String entryName, int? maxWidth}) =>
     RustLib.instance.api.crateApiCbzGetCbzPageByName(
         path: path, entryName: entryName, maxWidth: maxWidth);
 
+/// Like `get_cbz_page_by_name`, but encoded as PNG, JPEG or WebP instead of
+/// raw RGBA. A JPEG of a photographic scan is a fraction of the RGBA buffer,
+/// so prefer this unless the pixels go straight into a texture.
+Future<EncodedImage> getCbzPageEncoded(
+        {required String path,
+        required String entryName,
+        int? maxWidth,
+        required CoverFormat format}) =>
+    RustLib.instance.api.crateApiCbzGetCbzPageEncoded(
+        path: path, entryName: entryName, maxWidth: maxWidth, format: format);
+
+/// Small JPEG of one page whose longest edge fits in `max_dim`, for page
+/// grids. Resized like book covers, so aspect ratio is preserved and pages
+/// are never upscaled.
+Future<EncodedImage> getCbzThumbnail(
+        {required String path,
+        required String entryName,
+        required int maxDim}) =>
+    RustLib.instance.api.crateApiCbzGetCbzThumbnail(
+        path: path, entryName: entryName, maxDim: maxDim);
+
 /// Extract and optionally resize a single page by index.
 Future<CbzPageData> getCbzPage(
         {required String path, required int index, int? maxWidth}) =>
     RustLib.instance.api
         .crateApiCbzGetCbzPage(path: path, index: index, maxWidth: maxWidth);
 
+/// Like `get_cbz_page`, but also return the page's average border color so the
+/// reader can letterbox with a matching fill.
+Future<CbzPageWithBackground> getCbzPageWithBackground(
+        {required String path, required int index, int? maxWidth}) =>
+    RustLib.instance.api.crateApiCbzGetCbzPageWithBackground(
+        path: path, index: index, maxWidth: maxWidth);
+
+/// Decode every page in reading order, emitting each one as soon as it's
+/// ready so the first page can be shown while the rest are still decoding.
+/// Stops early when the Dart side stops listening.
+Stream<CbzPageEvent> streamCbzPages({required String path, int? maxWidth}) =>
+    RustLib.instance.api
+        .crateApiCbzStreamCbzPages(path: path, maxWidth: maxWidth);
+
+/// Thorough integrity check: read every image entry through the archive's
+/// CRC-32 check without decoding it. Reads the whole archive, so only call it
+/// when the user asks for a verification (e.g. at import), not on open.
+Future<List<(String, bool)>> verifyCbzIntegrity({required String path}) =>
+    RustLib.instance.api.crateApiCbzVerifyCbzIntegrity(path: path);
+
+/// Common page size if every page has the same dimensions, else `None`.
+/// Only image headers are read, so this is cheap enough to call on open.
+Future<(int, int)?> getCbzUniformSize({required String path}) =>
+    RustLib.instance.api.crateApiCbzGetCbzUniformSize(path: path);
+
+/// Read `ComicInfo.xml` from the archive root. Returns `Ok(None)` when the
+/// archive has no such file, and an error when it exists but can't be parsed.
+Future<ComicInfo?> getCbzMetadata({required String path}) =>
+    RustLib.instance.api.crateApiCbzGetCbzMetadata(path: path);
+
+/// Dimensions and spread hint for one page, from its image header only, so
+/// it's cheap enough to call for every page.
+Future<CbzPageInfo> getCbzPageInfo(
+        {required String path, required String entryName}) =>
+    RustLib.instance.api
+        .crateApiCbzGetCbzPageInfo(path: path, entryName: entryName);
+
+/// Predicted RGBA size in bytes of `get_cbz_page(path, index, max_width)`,
+/// from the image header alone. Lets the caller refuse a page or lower
+/// `max_width` before a large decode.
+Future<BigInt> estimateCbzPageBytes(
+        {required String path, required int index, int? maxWidth}) =>
+    RustLib.instance.api.crateApiCbzEstimateCbzPageBytes(
+        path: path, index: index, maxWidth: maxWidth);
+
 /// Struct to hold extracted page data
 class CbzPageData {
   final int width;
@@ -52,3 +138,159 @@ class CbzPageData {
           height == other.height &&
           rgbaBytes == other.rgbaBytes;
 }
+
+@freezed
+sealed class CbzPageEvent with _$CbzPageEvent {
+  const CbzPageEvent._();
+
+  /// One decoded page; pages arrive in reading order
+  const factory CbzPageEvent.page({
+    required int index,
+    required CbzPageData page,
+  }) = CbzPageEvent_Page;
+  /// Every page was handled. `failed` lists pages that couldn't be read or
+  /// decoded and were skipped.
+  const factory CbzPageEvent.done({
+    required int pageCount,
+    required Int32List failed,
+  }) = CbzPageEvent_Done;
+}
+
+/// Page dimensions read from the image header. `is_wide` marks a likely
+/// two-page spread.
+class CbzPageInfo {
+  final int width;
+  final int height;
+  final bool isWide;
+
+  const CbzPageInfo({
+    required this.width,
+    required this.height,
+    required this.isWide,
+  });
+
+  @override
+  int get hashCode => width.hashCode ^ height.hashCode ^ isWide.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CbzPageInfo &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          isWide == other.isWide;
+}
+
+/// Page names left after `skip_patterns`, and the ones filtered out
+class CbzPageNames {
+  final List<String> names;
+  final int pageCount;
+  final List<String> skipped;
+
+  const CbzPageNames({
+    required this.names,
+    required this.pageCount,
+    required this.skipped,
+  });
+
+  @override
+  int get hashCode => names.hashCode ^ pageCount.hashCode ^ skipped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CbzPageNames &&
+          runtimeType == other.runtimeType &&
+          names == other.names &&
+          pageCount == other.pageCount &&
+          skipped == other.skipped;
+}
+
+/// Page data plus the letterbox color sampled from the page border (0xAARRGGBB)
+class CbzPageWithBackground {
+  final CbzPageData page;
+  final int backgroundColor;
+
+  const CbzPageWithBackground({
+    required this.page,
+    required this.backgroundColor,
+  });
+
+  @override
+  int get hashCode => page.hashCode ^ backgroundColor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CbzPageWithBackground &&
+          runtimeType == other.runtimeType &&
+          page == other.page &&
+          backgroundColor == other.backgroundColor;
+}
+
+/// Metadata from a comic's `ComicInfo.xml`
+class ComicInfo {
+  final String? series;
+  final String? number;
+  final String? title;
+  final String? writer;
+  final int? pageCount;
+  /// Pages are read right-to-left (`Manga` is `Yes` or `YesAndRightToLeft`)
+  final bool manga;
+
+  const ComicInfo({
+    this.series,
+    this.number,
+    this.title,
+    this.writer,
+    this.pageCount,
+    required this.manga,
+  });
+
+  @override
+  int get hashCode =>
+      series.hashCode ^
+      number.hashCode ^
+      title.hashCode ^
+      writer.hashCode ^
+      pageCount.hashCode ^
+      manga.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComicInfo &&
+          runtimeType == other.runtimeType &&
+          series == other.series &&
+          number == other.number &&
+          title == other.title &&
+          writer == other.writer &&
+          pageCount == other.pageCount &&
+          manga == other.manga;
+}
+
+/// An encoded page image and its pixel size
+class EncodedImage {
+  final int width;
+  final int height;
+  final Uint8List bytes;
+
+  const EncodedImage({
+    required this.width,
+    required this.height,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode => width.hashCode ^ height.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncodedImage &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          bytes == other.bytes;
+}
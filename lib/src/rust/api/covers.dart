@@ -5,10 +5,86 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'covers.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_cbz_cover`, `extract_epub_cover_from_opf`, `extract_epub_cover`, `extract_first_image_ref_from_html`, `extract_pdf_cover`, `find_zip_entry_case_insensitive`, `is_supported_image_path`, `normalize_zip_path`, `percent_decode_to_string`, `read_zip_bytes`, `read_zip_string`, `resolve_epub_href`, `save_cover_thumbnail`, `strip_fragment_and_query`
+// These functions are ignored because they are not marked as `pub`: `book_format`, `decode_cover_image`, `draw_centered_line`, `drawable_text`, `encode_cover`, `encode_png`, `extract_cover_encoded`, `extract_first_image_ref_from_html`, `find_cbz_cover_bytes`, `find_cover_source_bytes`, `find_epub_cover_bytes_from_opf`, `find_epub_cover_bytes`, `find_epub_opf_path`, `find_fb2_cover_bytes`, `find_mobi_cover_bytes`, `find_zip_entry_by_basename`, `find_zip_entry_case_insensitive`, `fit_title`, `fnv1a_64`, `is_supported_image_path`, `is_svg`, `looks_like_svg`, `normalize_zip_path`, `normalized_basename`, `percent_decode_to_string`, `placeholder_color`, `rasterize_svg`, `read_cover_zip_bytes`, `read_single_file_zip`, `read_zip_bytes`, `read_zip_entry`, `read_zip_string`, `render_pdf_cover`, `resize_to_fit`, `resolve_epub_href`, `strip_fragment_and_query`, `text_width`, `wrap_text`, `write_cover_file`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `eq`, `fmt`, `fmt`
 
 Future<String> extractCover(
         {required String bookPath, required String savePath}) =>
     RustLib.instance.api
         .crateApiCoversExtractCover(bookPath: bookPath, savePath: savePath);
+
+/// Like `extract_cover`, but with an explicit thumbnail size and encoding,
+/// e.g. JPEG to keep a large library's cover cache small.
+Future<String> extractCoverWithOptions(
+        {required String bookPath,
+        required String savePath,
+        required CoverOptions options}) =>
+    RustLib.instance.api.crateApiCoversExtractCoverWithOptions(
+        bookPath: bookPath, savePath: savePath, options: options);
+
+/// Render a plain cover card for books without one: a solid background
+/// derived from the title (or `seed`, when given) with the title and author
+/// in white, saved as PNG to `save_path`. The same title always gets the
+/// same color, across runs and releases. The bundled font has no CJK
+/// glyphs; characters it can't draw are skipped, so a CJK-only title gives
+/// a plain colored card.
+Future<String> generatePlaceholderCover(
+        {required String title,
+        required String author,
+        required String savePath,
+        BigInt? seed}) =>
+    RustLib.instance.api.crateApiCoversGeneratePlaceholderCover(
+        title: title, author: author, savePath: savePath, seed: seed);
+
+/// Extract the cover as encoded PNG bytes without touching the disk. `max_dim`
+/// bounds the longest edge (default 360px, or a 300px-wide render for PDFs).
+/// Embedded covers the image decoder can't read are an error, so the bytes
+/// returned are always in the requested format.
+Future<Uint8List> extractCoverBytes({required String bookPath, int? maxDim}) =>
+    RustLib.instance.api
+        .crateApiCoversExtractCoverBytes(bookPath: bookPath, maxDim: maxDim);
+
+/// Decode the book's cover once and encode it as PNG at each requested size
+/// (longest edge), sorted ascending by size.
+Future<List<(int, Uint8List)>> extractCoverMulti(
+        {required String bookPath, required Uint32List sizes}) =>
+    RustLib.instance.api
+        .crateApiCoversExtractCoverMulti(bookPath: bookPath, sizes: sizes);
+
+/// Encoded output format for cover thumbnails
+@freezed
+sealed class CoverFormat with _$CoverFormat {
+  const CoverFormat._();
+
+  const factory CoverFormat.png() = CoverFormat_Png;
+  const factory CoverFormat.jpeg({
+    required int quality,
+  }) = CoverFormat_Jpeg;
+  const factory CoverFormat.webp() = CoverFormat_Webp;
+}
+
+/// Size and encoding of an extracted cover. The default (360px PNG) matches
+/// what `extract_cover` writes.
+class CoverOptions {
+  final int maxDim;
+  final CoverFormat format;
+
+  const CoverOptions({
+    required this.maxDim,
+    required this.format,
+  });
+
+  @override
+  int get hashCode => maxDim.hashCode ^ format.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoverOptions &&
+          runtimeType == other.runtimeType &&
+          maxDim == other.maxDim &&
+          format == other.format;
+}
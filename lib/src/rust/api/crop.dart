@@ -6,13 +6,74 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `corners_are_dark`, `crop_render_config`, `image_orientation`, `median`, `page_margins`, `profile_peakiness`, `std_dev`, `text_orientation`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
 
+/// Content box of the page, as margins relative to its size, on a white
+/// background with the default threshold.
 Future<CropMargins> detectPdfWhitespace(
         {required String path, required int pageIndex}) =>
     RustLib.instance.api
         .crateApiCropDetectPdfWhitespace(path: path, pageIndex: pageIndex);
 
+/// `detect_pdf_whitespace` with a chosen background. A pixel is background
+/// when all channels are within `threshold` of white (or of black for dark
+/// backgrounds).
+Future<CropMargins> detectPdfWhitespaceWithOptions(
+        {required String path,
+        required int pageIndex,
+        required BackgroundKind background,
+        required int threshold}) =>
+    RustLib.instance.api.crateApiCropDetectPdfWhitespaceWithOptions(
+        path: path,
+        pageIndex: pageIndex,
+        background: background,
+        threshold: threshold);
+
+/// `detect_pdf_whitespace_with_options` for pages `start..end` (clamped to the
+/// document), opening the document once.
+Future<List<CropMargins>> detectPdfWhitespaceBatch(
+        {required String path,
+        required int start,
+        required int end,
+        required BackgroundKind background,
+        required int threshold}) =>
+    RustLib.instance.api.crateApiCropDetectPdfWhitespaceBatch(
+        path: path,
+        start: start,
+        end: end,
+        background: background,
+        threshold: threshold);
+
+/// One crop box for the whole document: the margins of every
+/// `sample_every`-th page are detected and the smallest inset per edge
+/// taken, so content on any sampled page stays visible. Outlier pages are
+/// dropped first by comparing each edge against its median.
+Future<CropMargins> detectPdfUniformCrop(
+        {required String path, required int sampleEvery}) =>
+    RustLib.instance.api
+        .crateApiCropDetectPdfUniformCrop(path: path, sampleEvery: sampleEvery);
+
+/// Suggested clockwise rotation (0, 90, 180 or 270 degrees) to show a
+/// PDF or comic page upright. PDFs with a text layer are judged by glyph
+/// angles, image pages by projection profiles. 0 means the page looks
+/// upright or the evidence is too weak to say. This is a separate, opt-in
+/// analysis: it renders or decodes the page.
+Future<int> detectPageOrientation(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiCropDetectPageOrientation(path: path, pageIndex: pageIndex);
+
+/// Which end of the brightness range counts as page background when
+/// detecting margins.
+enum BackgroundKind {
+  light,
+  dark,
+  /// Decided per page from its corners
+  auto,
+  ;
+}
+
 class CropMargins {
   final double top;
   final double bottom;
@@ -5,8 +5,84 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'docx.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `escape_html`, `extract_docx_media`, `parse_docx_relationships`, `parse_paragraph_to_html`, `parse_table_to_html`
+// These functions are ignored because they are not marked as `pub`: `cell_merge_properties`, `close_lists`, `collect_plain_text`, `debug_string_val`, `escape_html`, `extract_docx_media`, `get_grid_span_regex`, `heading_level`, `highlight_css_color`, `ordered_list_levels`, `paragraph_plain_text`, `parse_docx_relationships`, `parse_paragraph_children_to_html`, `parse_paragraph_to_html`, `parse_table_to_html`, `read_docx_headings`, `read_docx_media`, `read_docx_paragraphs`, `render_docx_html`, `src_for`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `fmt`, `fmt`
 
+/// Convert a DOCX to HTML. Images are extracted next to the book (into
+/// `<path>_media`) and referenced by absolute `file://` URLs.
 Future<String> readDocxToHtml({required String path}) =>
     RustLib.instance.api.crateApiDocxReadDocxToHtml(path: path);
+
+/// Convert a DOCX to HTML with embedded images as data URIs or as files in
+/// a caller-chosen assets directory.
+Future<String> readDocxToHtmlWithImages(
+        {required String path, required DocxImageMode imageMode}) =>
+    RustLib.instance.api
+        .crateApiDocxReadDocxToHtmlWithImages(path: path, imageMode: imageMode);
+
+/// Read the core properties (title, author, dates, ...) of a DOCX. A
+/// document without `docProps/core.xml` gets all-empty metadata.
+Future<DocxMetadata> getDocxMetadata({required String path}) =>
+    RustLib.instance.api.crateApiDocxGetDocxMetadata(path: path);
+
+/// How `read_docx_to_html_with_images` emits embedded images
+@freezed
+sealed class DocxImageMode with _$DocxImageMode {
+  const DocxImageMode._();
+
+  /// Inline `data:` URIs; nothing is written to disk
+  const factory DocxImageMode.dataUri() = DocxImageMode_DataUri;
+  /// Extract media under `assets_dir` and reference it by relative path
+  /// (e.g. `word/media/image1.png`), for HTML loaded with that base URL
+  const factory DocxImageMode.assetsDir({
+    required String assetsDir,
+  }) = DocxImageMode_AssetsDir;
+}
+
+/// Document properties from `docProps/core.xml`; missing ones are empty
+class DocxMetadata {
+  final String title;
+  final String creator;
+  final String subject;
+  final String description;
+  final String lastModifiedBy;
+  /// W3CDTF timestamps as stored, e.g. "2024-03-01T10:00:00Z"
+  final String created;
+  final String modified;
+
+  const DocxMetadata({
+    required this.title,
+    required this.creator,
+    required this.subject,
+    required this.description,
+    required this.lastModifiedBy,
+    required this.created,
+    required this.modified,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      creator.hashCode ^
+      subject.hashCode ^
+      description.hashCode ^
+      lastModifiedBy.hashCode ^
+      created.hashCode ^
+      modified.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DocxMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          creator == other.creator &&
+          subject == other.subject &&
+          description == other.description &&
+          lastModifiedBy == other.lastModifiedBy &&
+          created == other.created &&
+          modified == other.modified;
+}
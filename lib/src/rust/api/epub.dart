@@ -0,0 +1,314 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `collapse_label`, `collect_toc_titles`, `from_property`, `get_resource_link_regex`, `media_type_from_extension`, `nav_list_entries`, `ncx_point_entries`, `open_epub_archive`, `parse_smil_clips`, `parse_smil_clock`, `read_epub_package`, `read_epub_toc_titles`, `read_nav_toc`, `read_ncx_toc`, `resolve_toc_href`, `rewrite_chapter_links`, `toc_nav`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Read the `dc:*` children of the OPF `<metadata>` element. Single-valued
+/// fields keep their first non-blank occurrence.
+Future<EpubMetadata> getEpubMetadata({required String path}) =>
+    RustLib.instance.api.crateApiEpubGetEpubMetadata(path: path);
+
+/// Detect whether the book (and each spine item) is reflowable or fixed-layout.
+/// Books that don't declare `rendition:layout` are reflowable.
+Future<EpubRendition> getEpubRendition({required String path}) =>
+    RustLib.instance.api.crateApiEpubGetEpubRendition(path: path);
+
+/// Hierarchical table of contents from the EPUB3 nav document, falling back
+/// to the EPUB2 NCX when there is no nav document or its TOC is empty.
+Future<List<TocEntry>> getEpubToc({required String path}) =>
+    RustLib.instance.api.crateApiEpubGetEpubToc(path: path);
+
+/// Reading order of the book from the OPF spine, titled from the nav
+/// document (or NCX). Untitled spine items get an empty title.
+Future<List<EpubChapter>> getEpubSpine({required String path}) =>
+    RustLib.instance.api.crateApiEpubGetEpubSpine(path: path);
+
+/// XHTML of one chapter, with relative resource links rewritten to archive paths.
+Future<String> getEpubChapterHtml(
+        {required String path, required String href}) =>
+    RustLib.instance.api.crateApiEpubGetEpubChapterHtml(path: path, href: href);
+
+/// Every match of `query` in the book's text, in spine order. Each
+/// chapter's body is reduced to the normalized text TTS highlighting uses
+/// and matched like `search_pdf` does. Chapters that fail to read or parse
+/// are skipped.
+Future<List<EpubSearchHit>> searchEpub(
+        {required String path,
+        required String query,
+        required bool caseSensitive}) =>
+    RustLib.instance.api.crateApiEpubSearchEpub(
+        path: path, query: query, caseSensitive: caseSensitive);
+
+/// Read a file a chapter references, resolving `href` against `base_href`
+/// (the chapter's archive path; empty when `href` is already an archive
+/// path). The media type comes from the OPF manifest, or the extension for
+/// files the manifest doesn't list.
+Future<ResourceBytes> readEpubResource(
+        {required String path,
+        required String href,
+        required String baseHref}) =>
+    RustLib.instance.api.crateApiEpubReadEpubResource(
+        path: path, href: href, baseHref: baseHref);
+
+/// Read the media overlay (SMIL) clips for a content document. `href` is the
+/// archive path of the chapter; returns an empty list when it has no overlay.
+Future<List<MediaClip>> getEpubMediaOverlay(
+        {required String path, required String href}) =>
+    RustLib.instance.api
+        .crateApiEpubGetEpubMediaOverlay(path: path, href: href);
+
+/// One reading-order entry of an EPUB
+class EpubChapter {
+  final String id;
+  final String href;
+  final String title;
+  final int order;
+
+  const EpubChapter({
+    required this.id,
+    required this.href,
+    required this.title,
+    required this.order,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ href.hashCode ^ title.hashCode ^ order.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubChapter &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          href == other.href &&
+          title == other.title &&
+          order == other.order;
+}
+
+/// EPUB3 `rendition:layout`: reflowable text or fixed-layout pages
+enum EpubLayout {
+  reflowable,
+  prePaginated,
+  ;
+}
+
+/// Dublin Core metadata of an EPUB; missing single-valued fields are `None`
+class EpubMetadata {
+  final String? title;
+  final List<String> creators;
+  final String? language;
+  final String? identifier;
+  final String? publisher;
+  final String? date;
+  final String? description;
+  final List<String> subjects;
+
+  const EpubMetadata({
+    this.title,
+    required this.creators,
+    this.language,
+    this.identifier,
+    this.publisher,
+    this.date,
+    this.description,
+    required this.subjects,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      creators.hashCode ^
+      language.hashCode ^
+      identifier.hashCode ^
+      publisher.hashCode ^
+      date.hashCode ^
+      description.hashCode ^
+      subjects.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          creators == other.creators &&
+          language == other.language &&
+          identifier == other.identifier &&
+          publisher == other.publisher &&
+          date == other.date &&
+          description == other.description &&
+          subjects == other.subjects;
+}
+
+/// Book-wide rendition layout plus the effective layout of every spine item
+class EpubRendition {
+  final EpubLayout layout;
+  final List<EpubSpineLayout> spine;
+
+  const EpubRendition({
+    required this.layout,
+    required this.spine,
+  });
+
+  @override
+  int get hashCode => layout.hashCode ^ spine.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubRendition &&
+          runtimeType == other.runtimeType &&
+          layout == other.layout &&
+          spine == other.spine;
+}
+
+/// One match of an EPUB text search
+class EpubSearchHit {
+  final String chapterHref;
+  /// Char range of the match in the chapter's normalized text, the same
+  /// offsets `insert_html_highlight` takes
+  final int charStart;
+  final int charEnd;
+  /// The match with up to `SEARCH_SNIPPET_CHARS` characters either side
+  final String snippet;
+
+  const EpubSearchHit({
+    required this.chapterHref,
+    required this.charStart,
+    required this.charEnd,
+    required this.snippet,
+  });
+
+  @override
+  int get hashCode =>
+      chapterHref.hashCode ^
+      charStart.hashCode ^
+      charEnd.hashCode ^
+      snippet.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubSearchHit &&
+          runtimeType == other.runtimeType &&
+          chapterHref == other.chapterHref &&
+          charStart == other.charStart &&
+          charEnd == other.charEnd &&
+          snippet == other.snippet;
+}
+
+/// Effective layout of one spine item
+class EpubSpineLayout {
+  final String href;
+  final EpubLayout layout;
+
+  const EpubSpineLayout({
+    required this.href,
+    required this.layout,
+  });
+
+  @override
+  int get hashCode => href.hashCode ^ layout.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubSpineLayout &&
+          runtimeType == other.runtimeType &&
+          href == other.href &&
+          layout == other.layout;
+}
+
+/// One audio clip of an EPUB3 media overlay, synced to a text fragment
+class MediaClip {
+  final String textFragmentId;
+  final String audioHref;
+  final double clipBegin;
+  /// `None` when the SMIL has no `clipEnd`: the clip plays until the next
+  /// clip begins or the audio file ends
+  final double? clipEnd;
+
+  const MediaClip({
+    required this.textFragmentId,
+    required this.audioHref,
+    required this.clipBegin,
+    this.clipEnd,
+  });
+
+  @override
+  int get hashCode =>
+      textFragmentId.hashCode ^
+      audioHref.hashCode ^
+      clipBegin.hashCode ^
+      clipEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaClip &&
+          runtimeType == other.runtimeType &&
+          textFragmentId == other.textFragmentId &&
+          audioHref == other.audioHref &&
+          clipBegin == other.clipBegin &&
+          clipEnd == other.clipEnd;
+}
+
+/// Raw bytes of an archive entry and its media type
+class ResourceBytes {
+  final Uint8List bytes;
+  final String mediaType;
+
+  const ResourceBytes({
+    required this.bytes,
+    required this.mediaType,
+  });
+
+  @override
+  int get hashCode => bytes.hashCode ^ mediaType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResourceBytes &&
+          runtimeType == other.runtimeType &&
+          bytes == other.bytes &&
+          mediaType == other.mediaType;
+}
+
+/// A table of contents entry. `href` is an archive path, with the fragment
+/// kept when the entry points inside a chapter.
+class TocEntry {
+  final String label;
+  final String href;
+  /// Nesting level, 0 for top-level entries
+  final int depth;
+  final List<TocEntry> children;
+
+  const TocEntry({
+    required this.label,
+    required this.href,
+    required this.depth,
+    required this.children,
+  });
+
+  @override
+  int get hashCode =>
+      label.hashCode ^ href.hashCode ^ depth.hashCode ^ children.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TocEntry &&
+          runtimeType == other.runtimeType &&
+          label == other.label &&
+          href == other.href &&
+          depth == other.depth &&
+          children == other.children;
+}
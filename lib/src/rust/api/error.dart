@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`, `fmt`, `from`, `from`, `from`, `from`
+
+/// Typed failure of the PDF API, surfaced to Dart as a sealed class so
+/// callers match on variants instead of message prefixes. `Display` keeps
+/// the `CATEGORY::DETAIL:` prefixes the string errors used, for logs.
+/// Converts into `anyhow::Error` like any other error.
+@freezed
+sealed class FerrousError with _$FerrousError implements FrbException {
+  const FerrousError._();
+
+  /// The path of the file, for the message
+  const factory FerrousError.fileMissing(
+    String field0,
+  ) = FerrousError_FileMissing;
+  const factory FerrousError.empty(
+    String field0,
+  ) = FerrousError_Empty;
+  const factory FerrousError.badHeader(
+    String field0,
+  ) = FerrousError_BadHeader;
+  const factory FerrousError.passwordRequired(
+    String field0,
+  ) = FerrousError_PasswordRequired;
+  const factory FerrousError.corrupt(
+    String field0,
+  ) = FerrousError_Corrupt;
+  /// Opening is refused by the document's security handler
+  const factory FerrousError.security(
+    String field0,
+  ) = FerrousError_Security;
+  /// pdfium failed on a page while opening the document
+  const factory FerrousError.pageError(
+    String field0,
+  ) = FerrousError_PageError;
+  const factory FerrousError.pageOutOfRange() = FerrousError_PageOutOfRange;
+  /// pdfium could not load a page of an already open document
+  const factory FerrousError.pageLoad({
+    required int pageIndex,
+    required String detail,
+  }) = FerrousError_PageLoad;
+  /// The document's owner password forbids copying its text; the path
+  const factory FerrousError.permissionDenied(
+    String field0,
+  ) = FerrousError_PermissionDenied;
+  const factory FerrousError.unsupported(
+    String field0,
+  ) = FerrousError_Unsupported;
+  /// A request the function can't serve, e.g. a zero tile size
+  const factory FerrousError.invalidArgument(
+    String field0,
+  ) = FerrousError_InvalidArgument;
+  /// Stopped through `cancel_operation`
+  const factory FerrousError.cancelled() = FerrousError_Cancelled;
+  /// Any other pdfium failure: rendering, text, annotations, saving
+  const factory FerrousError.pdfium(
+    String field0,
+  ) = FerrousError_Pdfium;
+  /// Encoding a rendered page or an embedded image failed
+  const factory FerrousError.image(
+    String field0,
+  ) = FerrousError_Image;
+  const factory FerrousError.io(
+    String field0,
+  ) = FerrousError_Io;
+}
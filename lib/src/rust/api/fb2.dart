@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `children`, `close`, `declared_encoding`, `escape_html`, `href`, `is_image_content_type`, `node`, `open`, `read_fb2_bytes`, `read_fb2_xml`, `wrap`
+
+/// Convert an FB2 book (plain or `.fb2.zip`) to HTML: sections, headings,
+/// paragraphs and inline emphasis map to their semantic HTML counterparts,
+/// and `<binary>` images are inlined as data URIs. Legacy 8-bit encodings
+/// are decoded as declared (see `read_fb2_xml`).
+Future<String> readFb2ToHtml({required String path}) =>
+    RustLib.instance.api.crateApiFb2ReadFb2ToHtml(path: path);
+
+/// Cover image bytes of an FB2 book, from its `<coverpage>` reference.
+Future<Uint8List> extractFb2Cover({required String path}) =>
+    RustLib.instance.api.crateApiFb2ExtractFb2Cover(path: path);
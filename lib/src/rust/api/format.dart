@@ -0,0 +1,171 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'covers.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'format.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `comic_chapters_from_page_names`, `fixed_layout_epub_page_bytes`, `from_extension`, `markup_plain_text`, `sniff_zip_format`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `UnpagedFormatError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Identify a book by its content rather than trusting the extension. Falls
+/// back to the extension for formats without a signature (plain text).
+Future<BookFormat> detectBookFormat({required String path}) =>
+    RustLib.instance.api.crateApiFormatDetectBookFormat(path: path);
+
+/// Number of pages (or reading-order sections) of any paged book: PDF pages,
+/// comic pages, EPUB spine items or MOBI sections. Valid page indices are
+/// `0..count`. Formats without pages fail with `UnpagedFormatError`.
+Future<int> getPageCount({required String path}) =>
+    RustLib.instance.api.crateApiFormatGetPageCount(path: path);
+
+/// Table of contents of any supported book, normalized across formats: the
+/// PDF outline, titled EPUB spine items, MOBI sections, DOCX headings or comic
+/// folders. Formats without chapter structure yield an empty list.
+Future<List<ChapterEntry>> getChapters({required String path}) =>
+    RustLib.instance.api.crateApiFormatGetChapters(path: path);
+
+/// Plain text of any book for TTS, split into `unit`s. `Page` and
+/// `Chapter` both give the format's natural unit: PDF pages, EPUB spine
+/// chapters or MOBI sections; DOCX, FB2 and TXT come back whole either way.
+/// `Whole` joins everything with blank lines. Comics have no text.
+Future<List<String>> extractPlainText(
+        {required String path, required TextUnit unit}) =>
+    RustLib.instance.api.crateApiFormatExtractPlainText(path: path, unit: unit);
+
+/// Render one page of any fixed-layout book (PDF page, comic page or
+/// pre-paginated EPUB page) fitted within `width` x `height` and encoded per
+/// `options`. The format-specific renderers stay available for finer control.
+Future<RenderedPage> renderPage(
+        {required String path,
+        required int pageIndex,
+        required int width,
+        required int height,
+        required RenderOptions options}) =>
+    RustLib.instance.api.crateApiFormatRenderPage(
+        path: path,
+        pageIndex: pageIndex,
+        width: width,
+        height: height,
+        options: options);
+
+/// Book container formats the reader understands
+enum BookFormat {
+  pdf,
+  epub,
+  cbz,
+  cbr,
+  docx,
+  mobi,
+  fb2,
+  txt,
+  unknown,
+  ;
+}
+
+/// One entry of a book's table of contents
+class ChapterEntry {
+  final String title;
+  /// Nesting level, 0 for top-level entries
+  final int level;
+  final ChapterLocation location;
+
+  const ChapterEntry({
+    required this.title,
+    required this.level,
+    required this.location,
+  });
+
+  @override
+  int get hashCode => title.hashCode ^ level.hashCode ^ location.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterEntry &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          level == other.level &&
+          location == other.location;
+}
+
+/// Where a chapter starts, in the terms of the format's own reader APIs.
+@freezed
+sealed class ChapterLocation with _$ChapterLocation {
+  const ChapterLocation._();
+
+  /// Page index of a fixed-layout book (PDF, comics)
+  const factory ChapterLocation.page({
+    required int index,
+  }) = ChapterLocation_Page;
+  /// Section of a reflowable book (EPUB spine item, MOBI section, 0 for a
+  /// whole DOCX) and a position inside it. For DOCX, `offset` is the
+  /// heading's ordinal among the h1–h6 elements of `read_docx_to_html`;
+  /// elsewhere it is 0, the start of the section.
+  const factory ChapterLocation.section({
+    required int index,
+    required int offset,
+  }) = ChapterLocation_Section;
+}
+
+/// Options for `render_page`. Pages are encoded like covers; the default is
+/// JPEG at quality 85.
+class RenderOptions {
+  final CoverFormat format;
+
+  const RenderOptions({
+    required this.format,
+  });
+
+  @override
+  int get hashCode => format.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RenderOptions &&
+          runtimeType == other.runtimeType &&
+          format == other.format;
+}
+
+/// Encoding and size of a page from `render_page`
+class RenderedPage {
+  final Uint8List bytes;
+  final int width;
+  final int height;
+  final CoverFormat format;
+
+  const RenderedPage({
+    required this.bytes,
+    required this.width,
+    required this.height,
+    required this.format,
+  });
+
+  @override
+  int get hashCode =>
+      bytes.hashCode ^ width.hashCode ^ height.hashCode ^ format.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RenderedPage &&
+          runtimeType == other.runtimeType &&
+          bytes == other.bytes &&
+          width == other.width &&
+          height == other.height &&
+          format == other.format;
+}
+
+/// Granularity of `extract_plain_text`
+enum TextUnit {
+  page,
+  chapter,
+  whole,
+  ;
+}
@@ -0,0 +1,19 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `script_of`
+
+/// Dominant Unicode script of `text` ("Latin", "Cyrillic", "Han", "Arabic",
+/// ...), by counting letters per script. Returns "Common" when the text has
+/// no letters at all and "Unknown" when its letters are in unlisted scripts.
+Future<String> detectScript({required String text}) =>
+    RustLib.instance.api.crateApiLanguageDetectScript(text: text);
+
+/// Whether a script returned by `detect_script` is written right-to-left.
+Future<bool> isRtlScript({required String script}) =>
+    RustLib.instance.api.crateApiLanguageIsRtlScript(script: script);
@@ -5,23 +5,111 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'library.freezed.dart';
 
-Future<List<BookMetadata>> scanLibrary({required String rootPath}) =>
-    RustLib.instance.api.crateApiLibraryScanLibrary(rootPath: rootPath);
+// These functions are ignored because they are not marked as `pub`: `apply_embedded_metadata`, `book_metadata_from_path`, `collapse_whitespace`, `cover_file_name`, `html_paragraphs`, `is_hidden`, `is_supported_book`, `is_synopsis_candidate`, `modified_epoch`, `pdf_text_paragraphs`, `plain_text_paragraphs`, `read_embedded_metadata`, `synopsis_sections`, `truncate_synopsis`, `walk_library`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `default`
 
+/// Walk `root_path` for supported books. With `read_metadata`, titles and
+/// authors come from the files themselves (slower on large libraries);
+/// otherwise, or when a file has none, the file name is used.
+///
+/// Unreadable entries (permission denied, broken links, ...) are reported in
+/// `skipped`; only an unreadable `root_path` fails the whole scan.
+///
+/// `options` default to every supported format, unlimited depth, hidden
+/// files included and symlinked folders not descended into, which matches
+/// the scan before `ScanOptions` existed.
+Future<ScanReport> scanLibrary(
+        {required String rootPath,
+        required bool readMetadata,
+        ScanOptions? options}) =>
+    RustLib.instance.api.crateApiLibraryScanLibrary(
+        rootPath: rootPath, readMetadata: readMetadata, options: options);
+
+/// Rescan `root_path` against the books found last time. Files whose size
+/// and modification time match their `known` entry are left out; embedded
+/// metadata is only read for added and modified books. `options` work as in
+/// `scan_library`; known books they leave out count as removed.
+Future<ScanDelta> scanLibraryIncremental(
+        {required String rootPath,
+        required List<KnownFile> known,
+        ScanOptions? options}) =>
+    RustLib.instance.api.crateApiLibraryScanLibraryIncremental(
+        rootPath: rootPath, known: known, options: options);
+
+/// `scan_library` that reports its progress every few dozen entries while
+/// walking, then emits the full report as the final event. Stops early when
+/// the Dart side stops listening.
+Stream<ScanEvent> scanLibraryWithProgress(
+        {required String rootPath,
+        required bool readMetadata,
+        ScanOptions? options}) =>
+    RustLib.instance.api.crateApiLibraryScanLibraryWithProgress(
+        rootPath: rootPath, readMetadata: readMetadata, options: options);
+
+/// Read metadata for a single book and extract its cover into `cover_dir`.
+/// Title and author come from the book when it has them, as with a
+/// `read_metadata` scan. A missing cover or unreadable metadata is not an
+/// error; the book is still imported with what could be read.
+Future<ImportedBook> importBook(
+        {required String path, required String coverDir}) =>
+    RustLib.instance.api
+        .crateApiLibraryImportBook(path: path, coverDir: coverDir);
+
+/// Request cancellation of a running `import_library_stream`. Returns whether
+/// an import with that id was running. Same as `cancel_operation`.
+Future<bool> cancelLibraryImport({required BigInt importId}) =>
+    RustLib.instance.api.crateApiLibraryCancelLibraryImport(importId: importId);
+
+/// Walk `root_path` and import every supported book, emitting each one as soon
+/// as its metadata and cover are ready, followed by a final summary event.
+/// `import_id` is an operation id (see `create_operation`); cancel with
+/// `cancel_library_import(import_id)` or `cancel_operation`.
+Stream<ImportEvent> importLibraryStream(
+        {required String rootPath,
+        required String coverDir,
+        required BigInt importId}) =>
+    RustLib.instance.api.crateApiLibraryImportLibraryStream(
+        rootPath: rootPath, coverDir: coverDir, importId: importId);
+
+/// Synthesize a description from the first substantial paragraph of body
+/// text, truncated to `max_chars`. Returns an empty string when nothing
+/// suitable is found rather than front matter or garbage.
+Future<String> getBookSynopsis({required String path, required int maxChars}) =>
+    RustLib.instance.api
+        .crateApiLibraryGetBookSynopsis(path: path, maxChars: maxChars);
+
+/// `size_bytes`, `modified_epoch` and `format` were added after the first
+/// three fields; existing callers can ignore them.
 class BookMetadata {
   final String title;
   final String author;
   final String path;
+  final BigInt sizeBytes;
+  /// Modification time in seconds since the Unix epoch
+  final PlatformInt64 modifiedEpoch;
+  /// Lowercased extension, `fb2.zip` for zipped FB2
+  final String format;
 
   const BookMetadata({
     required this.title,
     required this.author,
     required this.path,
+    required this.sizeBytes,
+    required this.modifiedEpoch,
+    required this.format,
   });
 
   @override
-  int get hashCode => title.hashCode ^ author.hashCode ^ path.hashCode;
+  int get hashCode =>
+      title.hashCode ^
+      author.hashCode ^
+      path.hashCode ^
+      sizeBytes.hashCode ^
+      modifiedEpoch.hashCode ^
+      format.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -30,5 +118,263 @@ class BookMetadata {
           runtimeType == other.runtimeType &&
           title == other.title &&
           author == other.author &&
-          path == other.path;
+          path == other.path &&
+          sizeBytes == other.sizeBytes &&
+          modifiedEpoch == other.modifiedEpoch &&
+          format == other.format;
+}
+
+@freezed
+sealed class ImportEvent with _$ImportEvent {
+  const ImportEvent._();
+
+  const factory ImportEvent.book(
+    ImportedBook field0,
+  ) = ImportEvent_Book;
+  const factory ImportEvent.finished(
+    ImportSummary field0,
+  ) = ImportEvent_Finished;
+}
+
+class ImportFailure {
+  final String path;
+  final String reason;
+
+  const ImportFailure({
+    required this.path,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportFailure &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          reason == other.reason;
+}
+
+class ImportSummary {
+  final int imported;
+  final List<ImportFailure> failed;
+  final bool cancelled;
+
+  const ImportSummary({
+    required this.imported,
+    required this.failed,
+    required this.cancelled,
+  });
+
+  @override
+  int get hashCode => imported.hashCode ^ failed.hashCode ^ cancelled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportSummary &&
+          runtimeType == other.runtimeType &&
+          imported == other.imported &&
+          failed == other.failed &&
+          cancelled == other.cancelled;
+}
+
+class ImportedBook {
+  final BookMetadata metadata;
+  final String? coverPath;
+
+  const ImportedBook({
+    required this.metadata,
+    this.coverPath,
+  });
+
+  @override
+  int get hashCode => metadata.hashCode ^ coverPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportedBook &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          coverPath == other.coverPath;
+}
+
+/// A book from a previous scan, as the app stored it
+class KnownFile {
+  final String path;
+  /// Modification time in seconds since the Unix epoch
+  final PlatformInt64 modifiedEpoch;
+  final BigInt size;
+
+  const KnownFile({
+    required this.path,
+    required this.modifiedEpoch,
+    required this.size,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ modifiedEpoch.hashCode ^ size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KnownFile &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          modifiedEpoch == other.modifiedEpoch &&
+          size == other.size;
+}
+
+class ScanDelta {
+  final List<BookMetadata> added;
+  final List<BookMetadata> modified;
+  /// Paths of known books that are no longer there
+  final List<String> removed;
+  /// Entries the rescan could not read, as in `ScanReport::skipped`
+  final List<ScanError> skipped;
+
+  const ScanDelta({
+    required this.added,
+    required this.modified,
+    required this.removed,
+    required this.skipped,
+  });
+
+  @override
+  int get hashCode =>
+      added.hashCode ^ modified.hashCode ^ removed.hashCode ^ skipped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanDelta &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          modified == other.modified &&
+          removed == other.removed &&
+          skipped == other.skipped;
+}
+
+/// A file or directory the scan could not read
+class ScanError {
+  final String path;
+  final String reason;
+
+  const ScanError({
+    required this.path,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanError &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          reason == other.reason;
+}
+
+@freezed
+sealed class ScanEvent with _$ScanEvent {
+  const ScanEvent._();
+
+  const factory ScanEvent.progress(
+    ScanProgress field0,
+  ) = ScanEvent_Progress;
+  const factory ScanEvent.finished(
+    ScanReport field0,
+  ) = ScanEvent_Finished;
+}
+
+/// What `scan_library` walks and which files it picks up
+class ScanOptions {
+  /// Book extensions without the dot, matched case-insensitively;
+  /// `fb2` also matches `.fb2.zip`
+  final List<String> extensions;
+  /// How deep to walk, counted like `WalkDir::max_depth`: 1 picks up only
+  /// the files directly in `root_path`, 2 also those one folder down, and
+  /// so on; `None` for no limit
+  final BigInt? maxDepth;
+  /// Whether to descend into symlinked folders. Symlinked book files are
+  /// picked up either way.
+  final bool followSymlinks;
+  /// Whether to pick up dotfiles and descend into dot-folders
+  final bool includeHidden;
+
+  const ScanOptions({
+    required this.extensions,
+    this.maxDepth,
+    required this.followSymlinks,
+    required this.includeHidden,
+  });
+
+  @override
+  int get hashCode =>
+      extensions.hashCode ^
+      maxDepth.hashCode ^
+      followSymlinks.hashCode ^
+      includeHidden.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanOptions &&
+          runtimeType == other.runtimeType &&
+          extensions == other.extensions &&
+          maxDepth == other.maxDepth &&
+          followSymlinks == other.followSymlinks &&
+          includeHidden == other.includeHidden;
+}
+
+class ScanProgress {
+  /// Files and folders walked so far
+  final int scanned;
+  /// Supported books found so far
+  final int found;
+  final String currentPath;
+
+  const ScanProgress({
+    required this.scanned,
+    required this.found,
+    required this.currentPath,
+  });
+
+  @override
+  int get hashCode => scanned.hashCode ^ found.hashCode ^ currentPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanProgress &&
+          runtimeType == other.runtimeType &&
+          scanned == other.scanned &&
+          found == other.found &&
+          currentPath == other.currentPath;
+}
+
+class ScanReport {
+  final List<BookMetadata> books;
+  final List<ScanError> skipped;
+
+  const ScanReport({
+    required this.books,
+    required this.skipped,
+  });
+
+  @override
+  int get hashCode => books.hashCode ^ skipped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanReport &&
+          runtimeType == other.runtimeType &&
+          books == other.books &&
+          skipped == other.skipped;
 }
@@ -6,20 +6,34 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_title`, `prepare_mobi_content`, `split_large_html`
+// These functions are ignored because they are not marked as `pub`: `decode`, `extract_title`, `floor_boundary`, `html`, `kf8_content`, `kf8_header_index`, `mobi_language_code`, `mobi_toc_entries`, `palmdoc_decompress`, `prepare_mobi_content`, `read_book_text`, `read_u16`, `read_u32`, `read`, `rewrite_mobi_image_refs`, `split_large_html`, `split_mobi_chapters`, `split_mobi_sections`, `trailing_entries_size`, `write_mobi_images`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `fmt`
 
+/// Book HTML with image references pointing at extracted files. AZW3 files
+/// are read from their KF8 part when it can be decoded.
 Future<String> getMobiContent({required String path}) =>
     RustLib.instance.api.crateApiMobiGetMobiContent(path: path);
 
+/// Chapters of the book, split via its TOC page, headings or page breaks
+/// (see `split_mobi_chapters`), with image references resolved.
 Future<List<MobiChapter>> getMobiChapters({required String path}) =>
     RustLib.instance.api.crateApiMobiGetMobiChapters(path: path);
 
+/// Raw bytes of the image an `<img recindex="N">` in the book text refers
+/// to. `recindex` is 1-based from the first image record, as in the markup.
+Future<Uint8List> getMobiImage({required String path, required int recindex}) =>
+    RustLib.instance.api
+        .crateApiMobiGetMobiImage(path: path, recindex: recindex);
+
 Future<String> getMobiTitle({required String path}) =>
     RustLib.instance.api.crateApiMobiGetMobiTitle(path: path);
 
 Future<String> getMobiAuthor({required String path}) =>
     RustLib.instance.api.crateApiMobiGetMobiAuthor(path: path);
 
+Future<MobiMetadata> getMobiMetadata({required String path}) =>
+    RustLib.instance.api.crateApiMobiGetMobiMetadata(path: path);
+
 class MobiChapter {
   final String title;
   final String htmlContent;
@@ -40,3 +54,48 @@ class MobiChapter {
           title == other.title &&
           htmlContent == other.htmlContent;
 }
+
+/// Catalog metadata from the MOBI header and its EXTH records
+class MobiMetadata {
+  final String title;
+  final String? author;
+  final String? publisher;
+  /// BCP-47 code ("en", "ru", ...) of the header locale
+  final String? language;
+  final String? isbn;
+  final String? published;
+  final String? description;
+
+  const MobiMetadata({
+    required this.title,
+    this.author,
+    this.publisher,
+    this.language,
+    this.isbn,
+    this.published,
+    this.description,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      author.hashCode ^
+      publisher.hashCode ^
+      language.hashCode ^
+      isbn.hashCode ^
+      published.hashCode ^
+      description.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MobiMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          author == other.author &&
+          publisher == other.publisher &&
+          language == other.language &&
+          isbn == other.isbn &&
+          published == other.published &&
+          description == other.description;
+}
@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_cancelled`, `flag`, `lock_operations`, `new`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CancelledError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`, `fmt`
+
+/// A fresh id to pass as `operation_id` to cancellable calls. Nothing is
+/// registered until a call starts with it, so unused ids cost nothing.
+Future<BigInt> createOperation() =>
+    RustLib.instance.api.crateApiOperationCreateOperation();
+
+/// Ask the calls running under `id` to stop; they fail with
+/// `CancelledError` at their next check. Returns whether one was running.
+/// An id from `create_operation` whose call hasn't started yet is still
+/// cancelled, so the call stops as soon as it begins (within `TOMBSTONE_TTL`).
+Future<bool> cancelOperation({required BigInt id}) =>
+    RustLib.instance.api.crateApiOperationCancelOperation(id: id);
@@ -4,20 +4,57 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'pdf.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `ensure_pdf_header`, `get_pdfium`, `get_pool`, `load_pdf_document`, `map_pdfium_load_error`
+// These functions are ignored because they are not marked as `pub`: `annotation_kind`, `apply_layer_overrides`, `bind_pdfium`, `blend_highlight_rects`, `bookmark_page_index`, `catalog`, `cff_index_first`, `char_range_rects`, `collect_layer_names`, `collect_page_lines`, `column_paragraphs`, `decode_pdf_text`, `default_hidden_layers`, `dehyphenate_text`, `edge_line_keys`, `embedded_image_bytes`, `encode_page_image`, `encode_page_jpeg`, `ensure_pdf_header`, `ensure_text_extraction_allowed`, `escape_reflow_text`, `find_text_matches`, `flatten_bookmarks`, `font_type_from_name`, `get_object_header_regex`, `get_pdf_page`, `get_pdfium`, `get_pool`, `get_root_reference_regex`, `get`, `group_names`, `is_cid_keyed_cff`, `is_empty_text_rect`, `is_form_object`, `is_pdf_delimiter`, `is_pdf_regular`, `last_load_error`, `link_kind`, `literal_string`, `load_pdf_document`, `lock_pool`, `map_pdfium_load_error`, `new`, `normalized_page_rect`, `object`, `objects`, `open`, `optional_content_mark`, `pack_color`, `page_chars`, `parse`, `pdf_font_info`, `pdf_font_name`, `pdf_password`, `pick_word_start_near_point`, `read_object_streams`, `read_wide_string`, `regular_token`, `remove`, `render_page_region`, `render_pdf_page_image`, `resolve`, `rest`, `running_head_key`, `skip_whitespace`, `sniff_font_type`, `snippet`, `split_page_columns`, `stream_data`, `user_space_rect`, `with_raw_document`, `word_start_in_rects`
 // These functions are ignored because they have generic arguments: `with_document`, `with_pdfium`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-Future<void> initPdfium({required String path}) =>
-    RustLib.instance.api.crateApiPdfInitPdfium(path: path);
+/// Load pdfium from `library_path` (see `bind_pdfium`) before the first PDF
+/// call; without it the library is looked up by its platform name. Returns
+/// `false` without rebinding when pdfium was already initialized, and
+/// `FerrousError::Unsupported` when it can't be loaded.
+Future<bool> initPdfium({String? libraryPath}) =>
+    RustLib.instance.api.crateApiPdfInitPdfium(libraryPath: libraryPath);
+
+/// Whether the pdfium library could be loaded. When it can't, every PDF
+/// function fails with `FerrousError::Unsupported` instead of crashing, and
+/// the app can hide its PDF features.
+Future<bool> isPdfSupported() =>
+    RustLib.instance.api.crateApiPdfIsPdfSupported();
+
+/// Open a password-protected PDF with `password` and remember it for `path`,
+/// so every other PDF function opens the file transparently afterwards. A
+/// wrong password fails with `FerrousError::PasswordRequired` and
+/// is not stored.
+Future<void> unlockPdf({required String path, required String password}) =>
+    RustLib.instance.api.crateApiPdfUnlockPdf(path: path, password: password);
+
+/// Forget the password stored for `path` and drop its pooled document.
+Future<void> lockPdf({required String path}) =>
+    RustLib.instance.api.crateApiPdfLockPdf(path: path);
+
+/// Drop the pooled document for `path` so the next call reloads it from
+/// disk. Call after the file was replaced or deleted. Returns whether a
+/// document was cached.
+Future<bool> evictPdfDocument({required String path}) =>
+    RustLib.instance.api.crateApiPdfEvictPdfDocument(path: path);
+
+/// Drop every pooled PDF document.
+Future<void> clearPdfCache() => RustLib.instance.api.crateApiPdfClearPdfCache();
 
 /// Get the page count of a PDF file
 Future<int> getPdfPageCount({required String path}) =>
     RustLib.instance.api.crateApiPdfGetPdfPageCount(path: path);
 
-/// Render a specific page of a PDF to PNG bytes with actual dimensions.
+/// Sizes of every page, so layouts can be reserved before rendering.
+Future<List<PageSize>> getPdfPageSizes({required String path}) =>
+    RustLib.instance.api.crateApiPdfGetPdfPageSizes(path: path);
+
+/// Render a specific page of a PDF to JPEG bytes with actual dimensions.
 Future<PdfPageRenderResult> renderPdfPage(
         {required String path,
         required int pageIndex,
@@ -26,12 +63,159 @@ Future<PdfPageRenderResult> renderPdfPage(
     RustLib.instance.api.crateApiPdfRenderPdfPage(
         path: path, pageIndex: pageIndex, width: width, height: height);
 
+/// Render a specific page of a PDF in the given output format.
+/// `rotation_override` turns the result clockwise by that many degrees
+/// (rounded to a quarter turn) on top of the page's own `/Rotate`; the
+/// rotated image still fits `width` x `height`. A cancelled `operation_id`
+/// stops it before rendering.
+Future<PdfPageRenderResult> renderPdfPageFmt(
+        {required String path,
+        required int pageIndex,
+        required int width,
+        required int height,
+        required PdfImageFormat format,
+        int? rotationOverride,
+        BigInt? operationId}) =>
+    RustLib.instance.api.crateApiPdfRenderPdfPageFmt(
+        path: path,
+        pageIndex: pageIndex,
+        width: width,
+        height: height,
+        format: format,
+        rotationOverride: rotationOverride,
+        operationId: operationId);
+
+/// `render_pdf_page` with translucent highlight rectangles baked into the
+/// JPEG, e.g. search hits on a result thumbnail. `rects` are normalized
+/// top-left coordinates (as from the text bounds APIs) and `color` is
+/// 0xAARRGGBB, its alpha setting how strongly the page shows through.
+Future<PdfPageRenderResult> renderPdfPageWithHighlights(
+        {required String path,
+        required int pageIndex,
+        required int width,
+        required int height,
+        required List<PdfTextRect> rects,
+        required int color}) =>
+    RustLib.instance.api.crateApiPdfRenderPdfPageWithHighlights(
+        path: path,
+        pageIndex: pageIndex,
+        width: width,
+        height: height,
+        rects: rects,
+        color: color);
+
+/// JPEG thumbnails of every page (or of `page_range`, start inclusive, end
+/// exclusive) at `max_width` pixels wide, rendered from one open document.
+/// Cancelling `operation_id` stops it between pages.
+Future<List<PdfPageRenderResult>> renderPdfThumbnails(
+        {required String path,
+        required int maxWidth,
+        (int, int)? pageRange,
+        BigInt? operationId}) =>
+    RustLib.instance.api.crateApiPdfRenderPdfThumbnails(
+        path: path,
+        maxWidth: maxWidth,
+        pageRange: pageRange,
+        operationId: operationId);
+
+/// Optional content groups ("layers") used on the document's pages,
+/// including inside form XObjects, in first-use order. `visible` is the
+/// state from the document's default configuration, which plain renders
+/// follow until `render_pdf_page_with_layers` overrides it. Documents
+/// without layers yield an empty list.
+Future<List<PdfLayer>> listPdfLayers({required String path}) =>
+    RustLib.instance.api.crateApiPdfListPdfLayers(path: path);
+
+/// Like `render_pdf_page`, but with layer visibility overridden by name.
+/// Layers not listed in `layers` keep the document's default state.
+Future<PdfPageRenderResult> renderPdfPageWithLayers(
+        {required String path,
+        required int pageIndex,
+        required int width,
+        required int height,
+        required List<PdfLayer> layers}) =>
+    RustLib.instance.api.crateApiPdfRenderPdfPageWithLayers(
+        path: path,
+        pageIndex: pageIndex,
+        width: width,
+        height: height,
+        layers: layers);
+
+/// Render the page at `scale` (pixels per PDF point) and return the
+/// `tile_size`-square tiles intersecting `viewport`, a normalized top-left
+/// rect of the page. Only the area covered by those tiles is rasterized.
+/// At most `MAX_TILES_PER_CALL` tiles are returned, row by row from the
+/// viewport's top-left corner.
+Future<List<PdfTile>> renderPdfPageTiles(
+        {required String path,
+        required int pageIndex,
+        required double scale,
+        required int tileSize,
+        required PdfTextRect viewport}) =>
+    RustLib.instance.api.crateApiPdfRenderPdfPageTiles(
+        path: path,
+        pageIndex: pageIndex,
+        scale: scale,
+        tileSize: tileSize,
+        viewport: viewport);
+
+/// Rasterize only `region` (a normalized top-left rect of the page) into an
+/// `output_width` x `output_height` JPEG. The page is translated and scaled
+/// so the region fills the bitmap, so pdfium never draws the rest of the
+/// page and memory stays proportional to the output size.
+Future<PdfPageRenderResult> renderPdfPageRegion(
+        {required String path,
+        required int pageIndex,
+        required PdfTextRect region,
+        required int outputWidth,
+        required int outputHeight}) =>
+    RustLib.instance.api.crateApiPdfRenderPdfPageRegion(
+        path: path,
+        pageIndex: pageIndex,
+        region: region,
+        outputWidth: outputWidth,
+        outputHeight: outputHeight);
+
+/// Read the permission bits of a PDF. Bits pdfium can't interpret (unknown
+/// security handler revisions) are reported as allowed.
+Future<PdfPermissions> getPdfPermissions({required String path}) =>
+    RustLib.instance.api.crateApiPdfGetPdfPermissions(path: path);
+
+/// Choose whether text-extraction APIs refuse documents whose owner password
+/// forbids copying. When enforced they fail with
+/// `FerrousError::PermissionDenied` instead of returning text.
+Future<void> setEnforcePdfPermissions({required bool enforce}) =>
+    RustLib.instance.api.crateApiPdfSetEnforcePdfPermissions(enforce: enforce);
+
 /// Extract the text of a specific page of a PDF file.
 Future<String> extractPdfPageText(
         {required String path, required int pageIndex}) =>
     RustLib.instance.api
         .crateApiPdfExtractPdfPageText(path: path, pageIndex: pageIndex);
 
+/// `extract_pdf_page_text` with words hyphenated across line breaks
+/// rejoined (see `dehyphenate_text`), for TTS and search.
+Future<String> extractPdfPageTextDehyphenated(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api.crateApiPdfExtractPdfPageTextDehyphenated(
+        path: path, pageIndex: pageIndex);
+
+/// Extract page text with running headers/footers removed. A short line near
+/// the top or bottom edge is only dropped when the same line (ignoring page
+/// numbers) sits at an edge of at least two neighbouring pages, so body text
+/// is kept whenever in doubt.
+Future<String> extractPdfPageBodyText(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiPdfExtractPdfPageBodyText(path: path, pageIndex: pageIndex);
+
+/// Page text reflowed into `<p>` paragraphs for a resizable text view. Lines
+/// come from the text layer (including OCR layers of scanned PDFs), columns
+/// are read left to right, and paragraphs break on vertical gaps.
+Future<String> reflowPdfPage({required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiPdfReflowPdfPage(path: path, pageIndex: pageIndex);
+
 /// Extract page text starting near a normalized point on the rendered page.
 Future<String> extractPdfPageTextFromPoint(
         {required String path,
@@ -41,6 +225,63 @@ Future<String> extractPdfPageTextFromPoint(
     RustLib.instance.api.crateApiPdfExtractPdfPageTextFromPoint(
         path: path, pageIndex: pageIndex, xNorm: xNorm, yNorm: yNorm);
 
+/// Text of the characters whose box center lies inside a normalized top-left
+/// rect, in text-layer order. Skipped text between selected characters
+/// collapses to a newline when it spans a line break and to a space
+/// otherwise, so a dragged column reads line by line.
+Future<String> extractPdfTextInRect(
+        {required String path,
+        required int pageIndex,
+        required double left,
+        required double top,
+        required double right,
+        required double bottom}) =>
+    RustLib.instance.api.crateApiPdfExtractPdfTextInRect(
+        path: path,
+        pageIndex: pageIndex,
+        left: left,
+        top: top,
+        right: right,
+        bottom: bottom);
+
+/// Like `extract_pdf_page_text_from_point`, but return the character index of
+/// the word start so TTS can begin from it. `None` when the page has no text
+/// near the point.
+Future<int?> getPdfTextIndexFromPoint(
+        {required String path,
+        required int pageIndex,
+        required double xNorm,
+        required double yNorm}) =>
+    RustLib.instance.api.crateApiPdfGetPdfTextIndexFromPoint(
+        path: path, pageIndex: pageIndex, xNorm: xNorm, yNorm: yNorm);
+
+/// Word-level counterpart of `extract_all_page_character_bounds`: one rect
+/// per word, in text order. Words are split on whitespace like
+/// `find_word_index_at_point` does; characters without bounds still count
+/// towards the word's text.
+Future<List<PdfWordRect>> extractAllPageWordBounds(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiPdfExtractAllPageWordBounds(path: path, pageIndex: pageIndex);
+
+/// Character index of the word under a normalized point, using the rects from
+/// `extract_all_page_character_bounds`. Falls back to the nearest word when
+/// nothing is directly under the point.
+Future<int?> findWordIndexAtPoint(
+        {required List<PdfTextRect> rects,
+        required double xNorm,
+        required double yNorm}) =>
+    RustLib.instance.api.crateApiPdfFindWordIndexAtPoint(
+        rects: rects, xNorm: xNorm, yNorm: yNorm);
+
+/// Character index of the first word whose line starts at or below a
+/// normalized scroll offset, using the rects from
+/// `extract_all_page_character_bounds`. Falls back to the last word on the page.
+Future<int?> findFirstVisibleWordIndex(
+        {required List<PdfTextRect> rects, required double yNorm}) =>
+    RustLib.instance.api
+        .crateApiPdfFindFirstVisibleWordIndex(rects: rects, yNorm: yNorm);
+
 /// Extract normalized character bounding boxes for a text range on the page.
 Future<List<PdfTextRect>> extractPdfPageTextBounds(
         {required String path,
@@ -59,9 +300,408 @@ Future<List<PdfTextRect>> extractAllPageCharacterBounds(
     RustLib.instance.api.crateApiPdfExtractAllPageCharacterBounds(
         path: path, pageIndex: pageIndex);
 
+/// List the fonts used by text across the whole document, de-duplicated by name.
+Future<List<PdfFontInfo>> listPdfFonts({required String path}) =>
+    RustLib.instance.api.crateApiPdfListPdfFonts(path: path);
+
+/// Read the document information dictionary. Missing entries come back as
+/// empty strings.
+Future<PdfMetadata> getPdfMetadata({required String path}) =>
+    RustLib.instance.api.crateApiPdfGetPdfMetadata(path: path);
+
+/// Images placed directly on the page at their native resolution (masks and
+/// transforms are not applied), with where each one is drawn. Images inside
+/// form XObjects are not listed.
+Future<List<PdfImage>> extractPdfPageImages(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiPdfExtractPdfPageImages(path: path, pageIndex: pageIndex);
+
+/// Markup annotations (highlights, notes, ...) of a page in document order.
+/// Read-only: the annotations stay in the file untouched.
+Future<List<PdfAnnotation>> getPdfAnnotations(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiPdfGetPdfAnnotations(path: path, pageIndex: pageIndex);
+
+/// Add one highlight annotation covering `rects` (normalized top-left, e.g.
+/// the rects of a text selection) in `color` (0xAARRGGBB) and save the file.
+/// The pooled copy of the document is evicted so later reads see the
+/// highlight.
+Future<void> addPdfHighlight(
+        {required String path,
+        required int pageIndex,
+        required List<PdfTextRect> rects,
+        required int color}) =>
+    RustLib.instance.api.crateApiPdfAddPdfHighlight(
+        path: path, pageIndex: pageIndex, rects: rects, color: color);
+
+/// Current values of the document's fillable form fields, page by page.
+/// Push buttons and signatures carry no value and are left out.
+Future<List<PdfFormField>> getPdfFormFields({required String path}) =>
+    RustLib.instance.api.crateApiPdfGetPdfFormFields(path: path);
+
+/// Flattened bookmark tree in document order. PDFs without an outline yield
+/// an empty list.
+Future<List<PdfOutlineItem>> getPdfOutline({required String path}) =>
+    RustLib.instance.api.crateApiPdfGetPdfOutline(path: path);
+
+/// Every match of `query` in the document's text layer, in page order.
+/// Matching is done on the page text by character index (pdfium's own
+/// search only reports rects), so hits line up with
+/// `extract_pdf_page_text_bounds` and `stream_pdf_search`. Cancelling
+/// `operation_id` stops it between pages.
+Future<List<PdfSearchHit>> searchPdf(
+        {required String path,
+        required String query,
+        required bool caseSensitive,
+        BigInt? operationId}) =>
+    RustLib.instance.api.crateApiPdfSearchPdf(
+        path: path,
+        query: query,
+        caseSensitive: caseSensitive,
+        operationId: operationId);
+
+/// Search the text layer page by page, streaming each hit with its context
+/// as soon as its page is scanned. The scan starts at `start_page` and wraps
+/// around to the pages before it, so "find next" after navigating continues
+/// from the current page instead of restarting at page 0. Stops early when
+/// the listener goes away, or with `FerrousError::Cancelled` when
+/// `operation_id` is cancelled.
+Stream<PdfSearchEvent> streamPdfSearch(
+        {required String path,
+        required String query,
+        required bool caseSensitive,
+        required int startPage,
+        BigInt? operationId}) =>
+    RustLib.instance.api.crateApiPdfStreamPdfSearch(
+        path: path,
+        query: query,
+        caseSensitive: caseSensitive,
+        startPage: startPage,
+        operationId: operationId);
+
+/// Link annotations of a page. Links whose target can't be resolved (e.g.
+/// launch or JavaScript actions) are skipped.
+Future<List<PdfLink>> getPdfPageLinks(
+        {required String path, required int pageIndex}) =>
+    RustLib.instance.api
+        .crateApiPdfGetPdfPageLinks(path: path, pageIndex: pageIndex);
+
 Future<String> testPdfModule() =>
     RustLib.instance.api.crateApiPdfTestPdfModule();
 
+/// Page size in PDF points (1/72 inch), as displayed.
+class PageSize {
+  final double widthPts;
+  final double heightPts;
+  /// The page's `/Rotate` (0, 90, 180 or 270, clockwise), already applied
+  /// to the width and height above.
+  final int rotationDegrees;
+
+  const PageSize({
+    required this.widthPts,
+    required this.heightPts,
+    required this.rotationDegrees,
+  });
+
+  @override
+  int get hashCode =>
+      widthPts.hashCode ^ heightPts.hashCode ^ rotationDegrees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PageSize &&
+          runtimeType == other.runtimeType &&
+          widthPts == other.widthPts &&
+          heightPts == other.heightPts &&
+          rotationDegrees == other.rotationDegrees;
+}
+
+class PdfAnnotation {
+  final PdfAnnotationKind kind;
+  /// Normalized top-left like the text rects
+  final PdfTextRect rect;
+  final String contents;
+  /// 0xAARRGGBB
+  final int? color;
+
+  const PdfAnnotation({
+    required this.kind,
+    required this.rect,
+    required this.contents,
+    this.color,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ rect.hashCode ^ contents.hashCode ^ color.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfAnnotation &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          rect == other.rect &&
+          contents == other.contents &&
+          color == other.color;
+}
+
+/// Annotation subtypes surfaced by `get_pdf_annotations`
+enum PdfAnnotationKind {
+  highlight,
+  underline,
+  squiggly,
+  strikeOut,
+  /// Sticky note
+  text,
+  freeText,
+  ink,
+  other,
+  ;
+}
+
+class PdfFontInfo {
+  final String name;
+  final String fontType;
+  final bool isEmbedded;
+
+  const PdfFontInfo({
+    required this.name,
+    required this.fontType,
+    required this.isEmbedded,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ fontType.hashCode ^ isEmbedded.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfFontInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          fontType == other.fontType &&
+          isEmbedded == other.isEmbedded;
+}
+
+/// One widget of a form field. Radio groups list each button separately,
+/// under the group's name.
+class PdfFormField {
+  final String name;
+  final PdfFormFieldKind kind;
+  /// Text and choice fields: the current value (empty when unset).
+  /// Checkboxes and radio buttons: `"true"` when checked, else `"false"`.
+  final String value;
+  final int pageIndex;
+  /// Normalized top-left like the text rects
+  final PdfTextRect rect;
+
+  const PdfFormField({
+    required this.name,
+    required this.kind,
+    required this.value,
+    required this.pageIndex,
+    required this.rect,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      kind.hashCode ^
+      value.hashCode ^
+      pageIndex.hashCode ^
+      rect.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfFormField &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          kind == other.kind &&
+          value == other.value &&
+          pageIndex == other.pageIndex &&
+          rect == other.rect;
+}
+
+enum PdfFormFieldKind {
+  text,
+  checkbox,
+  radio,
+  /// Combo or list box
+  choice,
+  ;
+}
+
+/// An image embedded in a page
+class PdfImage {
+  final Uint8List bytes;
+  /// `jpeg` when the stream is a plain JPEG passed through as stored,
+  /// otherwise `png`
+  final String format;
+  /// Placement on the page, normalized top-left like the text rects
+  final PdfTextRect rect;
+
+  const PdfImage({
+    required this.bytes,
+    required this.format,
+    required this.rect,
+  });
+
+  @override
+  int get hashCode => bytes.hashCode ^ format.hashCode ^ rect.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfImage &&
+          runtimeType == other.runtimeType &&
+          bytes == other.bytes &&
+          format == other.format &&
+          rect == other.rect;
+}
+
+/// Encoding of a rendered page. `RawRgba` skips encoding entirely and
+/// returns `width * height * 4` bytes, like the CBZ page loaders.
+@freezed
+sealed class PdfImageFormat with _$PdfImageFormat {
+  const PdfImageFormat._();
+
+  const factory PdfImageFormat.png() = PdfImageFormat_Png;
+  const factory PdfImageFormat.jpeg({
+    required int quality,
+  }) = PdfImageFormat_Jpeg;
+  const factory PdfImageFormat.rawRgba() = PdfImageFormat_RawRgba;
+}
+
+/// An optional content group ("layer") and whether a render should show it.
+class PdfLayer {
+  final String name;
+  final bool visible;
+
+  const PdfLayer({
+    required this.name,
+    required this.visible,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ visible.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfLayer &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          visible == other.visible;
+}
+
+/// A clickable area of a page, normalized top-left like the text rects.
+class PdfLink {
+  final PdfTextRect rect;
+  final PdfLinkKind kind;
+
+  const PdfLink({
+    required this.rect,
+    required this.kind,
+  });
+
+  @override
+  int get hashCode => rect.hashCode ^ kind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfLink &&
+          runtimeType == other.runtimeType &&
+          rect == other.rect &&
+          kind == other.kind;
+}
+
+/// Where a link points
+@freezed
+sealed class PdfLinkKind with _$PdfLinkKind {
+  const PdfLinkKind._();
+
+  const factory PdfLinkKind.uri(
+    String field0,
+  ) = PdfLinkKind_Uri;
+  const factory PdfLinkKind.page(
+    int field0,
+  ) = PdfLinkKind_Page;
+}
+
+class PdfMetadata {
+  final String title;
+  final String author;
+  final String subject;
+  final String keywords;
+  final String creator;
+  final String producer;
+  final int pageCount;
+
+  const PdfMetadata({
+    required this.title,
+    required this.author,
+    required this.subject,
+    required this.keywords,
+    required this.creator,
+    required this.producer,
+    required this.pageCount,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      author.hashCode ^
+      subject.hashCode ^
+      keywords.hashCode ^
+      creator.hashCode ^
+      producer.hashCode ^
+      pageCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          author == other.author &&
+          subject == other.subject &&
+          keywords == other.keywords &&
+          creator == other.creator &&
+          producer == other.producer &&
+          pageCount == other.pageCount;
+}
+
+class PdfOutlineItem {
+  final String title;
+  final int pageIndex;
+  /// Nesting level, 0 for top-level entries
+  final int depth;
+
+  const PdfOutlineItem({
+    required this.title,
+    required this.pageIndex,
+    required this.depth,
+  });
+
+  @override
+  int get hashCode => title.hashCode ^ pageIndex.hashCode ^ depth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfOutlineItem &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          pageIndex == other.pageIndex &&
+          depth == other.depth;
+}
+
 class PdfPageRenderResult {
   final Uint8List data;
   final int width;
@@ -86,6 +726,95 @@ class PdfPageRenderResult {
           height == other.height;
 }
 
+/// Owner-password restrictions of a PDF. Unprotected documents allow everything.
+class PdfPermissions {
+  final bool canPrint;
+  final bool canCopy;
+  final bool canModify;
+  final bool canAnnotate;
+  final bool canFillForms;
+
+  const PdfPermissions({
+    required this.canPrint,
+    required this.canCopy,
+    required this.canModify,
+    required this.canAnnotate,
+    required this.canFillForms,
+  });
+
+  @override
+  int get hashCode =>
+      canPrint.hashCode ^
+      canCopy.hashCode ^
+      canModify.hashCode ^
+      canAnnotate.hashCode ^
+      canFillForms.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfPermissions &&
+          runtimeType == other.runtimeType &&
+          canPrint == other.canPrint &&
+          canCopy == other.canCopy &&
+          canModify == other.canModify &&
+          canAnnotate == other.canAnnotate &&
+          canFillForms == other.canFillForms;
+}
+
+/// Events of `stream_pdf_search`
+@freezed
+sealed class PdfSearchEvent with _$PdfSearchEvent {
+  const PdfSearchEvent._();
+
+  const factory PdfSearchEvent.hit({
+    required PdfSearchHit hit,
+    /// Up to `SEARCH_SNIPPET_CHARS` characters either side of the match
+    required String contextBefore,
+    required String contextAfter,
+    /// Matches found so far in this scan, including this one
+    required int totalMatches,
+  }) = PdfSearchEvent_Hit;
+  /// Every page has been scanned
+  const factory PdfSearchEvent.finished({
+    required int totalMatches,
+  }) = PdfSearchEvent_Finished;
+}
+
+/// One search match. `char_start..char_end` are pdfium character indices
+/// on the page, as taken by `extract_pdf_page_text_bounds`.
+class PdfSearchHit {
+  final int pageIndex;
+  final int charStart;
+  final int charEnd;
+  /// Normalized top-left rects of the matched characters
+  final List<PdfTextRect> rects;
+
+  const PdfSearchHit({
+    required this.pageIndex,
+    required this.charStart,
+    required this.charEnd,
+    required this.rects,
+  });
+
+  @override
+  int get hashCode =>
+      pageIndex.hashCode ^
+      charStart.hashCode ^
+      charEnd.hashCode ^
+      rects.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfSearchHit &&
+          runtimeType == other.runtimeType &&
+          pageIndex == other.pageIndex &&
+          charStart == other.charStart &&
+          charEnd == other.charEnd &&
+          rects == other.rects;
+}
+
 class PdfTextRect {
   final double left;
   final double top;
@@ -113,3 +842,85 @@ class PdfTextRect {
           right == other.right &&
           bottom == other.bottom;
 }
+
+/// One JPEG tile of a zoomed page render. `x`/`y` are the tile's pixel
+/// offset in the full page render; edge tiles may be smaller than the tile size.
+class PdfTile {
+  final int row;
+  final int col;
+  final int x;
+  final int y;
+  final int width;
+  final int height;
+  final Uint8List bytes;
+
+  const PdfTile({
+    required this.row,
+    required this.col,
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode =>
+      row.hashCode ^
+      col.hashCode ^
+      x.hashCode ^
+      y.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfTile &&
+          runtimeType == other.runtimeType &&
+          row == other.row &&
+          col == other.col &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height &&
+          bytes == other.bytes;
+}
+
+/// A whitespace-delimited word of the text layer and the union of its
+/// character boxes, normalized top-left.
+class PdfWordRect {
+  final String text;
+  final double left;
+  final double top;
+  final double right;
+  final double bottom;
+
+  const PdfWordRect({
+    required this.text,
+    required this.left,
+    required this.top,
+    required this.right,
+    required this.bottom,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      left.hashCode ^
+      top.hashCode ^
+      right.hashCode ^
+      bottom.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfWordRect &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          left == other.left &&
+          top == other.top &&
+          right == other.right &&
+          bottom == other.bottom;
+}
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `record_timed_sample`, `timed_threshold_ms`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Set how slow (in ms) a `timed!` block must be to get logged; 10 by default.
+Future<void> setTimedThresholdMs({required BigInt thresholdMs}) =>
+    RustLib.instance.api
+        .crateApiProfilingSetTimedThresholdMs(thresholdMs: thresholdMs);
+
+/// Start or stop recording every `timed!` block. Off by default; turning it
+/// off keeps the samples recorded so far.
+Future<void> setProfilingEnabled({required bool enabled}) =>
+    RustLib.instance.api.crateApiProfilingSetProfilingEnabled(enabled: enabled);
+
+/// Samples recorded since the last call, oldest first.
+Future<List<TimedSample>> takeProfilingSamples() =>
+    RustLib.instance.api.crateApiProfilingTakeProfilingSamples();
+
+/// Duration of one `timed!` block
+class TimedSample {
+  final String name;
+  final double millis;
+
+  const TimedSample({
+    required this.name,
+    required this.millis,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ millis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimedSample &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          millis == other.millis;
+}
@@ -6,17 +6,55 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_text_from_html`, `get_whitespace_regex`, `normalize_text`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `escape_ssml`, `follows_street_name`, `get_abbreviation_regex`, `get_number_regex`, `get_paragraph_break_regex`, `is_standalone_number`, `join_text_nodes`, `normalize_text_with_offsets`, `number_words`, `ordinal_words`, `paragraph_spans`, `parse_body_text_nodes`, `place_node`, `push_below_thousand`, `text_node_at`, `year_words`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Translate a normalized char range `[start, end)` of `data` into the
+/// matching char range of the text it was computed from.
+Future<(int, int)> normalizedRangeToRaw(
+        {required TextHighlightData data,
+        required int start,
+        required int end}) =>
+    RustLib.instance.api.crateApiTtsTextNormalizedRangeToRaw(
+        data: data, start: start, end: end);
 
 Future<TextHighlightData> precomputeTextHighlights({required String text}) =>
     RustLib.instance.api.crateApiTtsTextPrecomputeTextHighlights(text: text);
 
+/// Word count and reading time, rounded up to whole minutes, at
+/// `words_per_minute` (0 for the default speed). Words are the
+/// word-boundary segments `precompute_text_highlights` yields, minus
+/// punctuation-only ones.
+Future<ReadingTime> estimateReadingTime(
+        {required String text, required int wordsPerMinute}) =>
+    RustLib.instance.api.crateApiTtsTextEstimateReadingTime(
+        text: text, wordsPerMinute: wordsPerMinute);
+
+/// BCP-47 code ("en", "ru", "zh", ...) of the language `text` is written
+/// in, detected with whatlang over the normalized text. `None` when the
+/// text is too short or mixed to tell with reasonable confidence, so the
+/// caller can fall back to the user's default voice.
+Future<String?> detectTextLanguage({required String text}) =>
+    RustLib.instance.api.crateApiTtsTextDetectTextLanguage(text: text);
+
 Future<SentenceSpan?> findSentenceForOffset(
         {required List<SentenceSpan> sentences, required int offset}) =>
     RustLib.instance.api.crateApiTtsTextFindSentenceForOffset(
         sentences: sentences, offset: offset);
 
+/// SSML for the normalized text: one `<s>` per sentence and a
+/// `<mark name="w{index}"/>` before each word, `index` being the word's
+/// position in `data.words`, so engines report word timings back.
+Future<String> toSsml({required TextHighlightData data, String? voice}) =>
+    RustLib.instance.api.crateApiTtsTextToSsml(data: data, voice: voice);
+
+/// Wrap the characters `[highlight_start, highlight_end)` of the normalized
+/// text (the offsets `precompute_text_highlights` produces) in `<tag_name>`.
+/// The body's text nodes are normalized together and the range mapped back
+/// through `normalize_text_with_offsets`; every text node it touches is
+/// split and its part wrapped separately, so ranges crossing
+/// element boundaries still give well-formed HTML. Input without
+/// `<html>`/`<body>` is treated as a fragment and returned as one.
 Future<String> insertHtmlHighlight(
         {required String html,
         required int highlightStart,
@@ -28,10 +66,193 @@ Future<String> insertHtmlHighlight(
         highlightEnd: highlightEnd,
         tagName: tagName);
 
+/// Like `insert_html_highlight` with several ranges at once, e.g. the
+/// current word inside the current sentence. Ranges are applied in one
+/// pass: a range inside another is wrapped inside its element (an equal
+/// range nests in the one listed first), while ranges that cross each
+/// other are rejected since their elements could not nest.
+Future<String> insertHtmlHighlights(
+        {required String html, required List<HighlightRange> ranges}) =>
+    RustLib.instance.api
+        .crateApiTtsTextInsertHtmlHighlights(html: html, ranges: ranges);
+
+/// Strip `html` to the normalized text of its body, the same text and
+/// offsets `insert_html_highlight` works on, and locate every word in the
+/// DOM by text node index and offset. Text nodes are counted in document
+/// order, including whitespace-only ones, so a renderer walking the same
+/// DOM can find a word without searching the HTML for it.
+Future<HtmlHighlightData> htmlToHighlightData({required String html}) =>
+    RustLib.instance.api.crateApiTtsTextHtmlToHighlightData(html: html);
+
+/// Set the largest number `expand_for_speech` spells out in words.
+Future<void> setSpelledNumberMax({required BigInt max}) =>
+    RustLib.instance.api.crateApiTtsTextSetSpelledNumberMax(max: max);
+
+/// Rewrite `text` the way it should be spoken, for the synthesizer only:
+/// common abbreviations are expanded ("Dr. Smith" to "Doctor Smith", "Elm
+/// St." to "Elm Street"), standalone numbers up to the limit set with
+/// `set_spelled_number_max` are spelled out, four-digit numbers from 1100
+/// to 1999 read as years and "21st" as "twenty-first". Decimals, times and
+/// numbers inside words are left alone. Offsets change, so highlighting
+/// keeps using `precompute_text_highlights` on the on-screen text. Only
+/// English (`locale` "en", "en-US", ... or empty) is expanded; text in
+/// other locales is returned unchanged.
+Future<String> expandForSpeech(
+        {required String text, required String locale}) =>
+    RustLib.instance.api
+        .crateApiTtsTextExpandForSpeech(text: text, locale: locale);
+
 /// Test function for TTS text module
 Future<String> testTtsTextModule() =>
     RustLib.instance.api.crateApiTtsTextTestTtsTextModule();
 
+/// A normalized char range `[start, end)` to wrap in `<tag_name>`
+class HighlightRange {
+  final int start;
+  final int end;
+  final String tagName;
+
+  const HighlightRange({
+    required this.start,
+    required this.end,
+    required this.tagName,
+  });
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode ^ tagName.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HighlightRange &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          tagName == other.tagName;
+}
+
+/// `TextHighlightData` for the body text of an HTML chapter, with each
+/// word mapped back to its text node
+class HtmlHighlightData {
+  final List<HtmlWordSpan> words;
+  final List<SentenceSpan> sentences;
+  final List<ParagraphSpan> paragraphs;
+  final String normalizedText;
+  /// Number of body text nodes `node_index` counts through
+  final int textNodeCount;
+
+  const HtmlHighlightData({
+    required this.words,
+    required this.sentences,
+    required this.paragraphs,
+    required this.normalizedText,
+    required this.textNodeCount,
+  });
+
+  @override
+  int get hashCode =>
+      words.hashCode ^
+      sentences.hashCode ^
+      paragraphs.hashCode ^
+      normalizedText.hashCode ^
+      textNodeCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HtmlHighlightData &&
+          runtimeType == other.runtimeType &&
+          words == other.words &&
+          sentences == other.sentences &&
+          paragraphs == other.paragraphs &&
+          normalizedText == other.normalizedText &&
+          textNodeCount == other.textNodeCount;
+}
+
+/// A word of `HtmlHighlightData`, located both in the normalized text and
+/// in the HTML it came from
+class HtmlWordSpan {
+  final int start;
+  final int end;
+  final String text;
+  /// Index of the text node the word starts in, counting the body's text
+  /// nodes in document order
+  final int nodeIndex;
+  /// Char offset of the word's first char within that text node
+  final int nodeOffset;
+
+  const HtmlWordSpan({
+    required this.start,
+    required this.end,
+    required this.text,
+    required this.nodeIndex,
+    required this.nodeOffset,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^
+      end.hashCode ^
+      text.hashCode ^
+      nodeIndex.hashCode ^
+      nodeOffset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HtmlWordSpan &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          text == other.text &&
+          nodeIndex == other.nodeIndex &&
+          nodeOffset == other.nodeOffset;
+}
+
+/// A paragraph span with character offsets into the normalized text
+class ParagraphSpan {
+  final int start;
+  final int end;
+
+  const ParagraphSpan({
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParagraphSpan &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end;
+}
+
+/// Estimated reading time of a text
+class ReadingTime {
+  final int wordCount;
+  final int minutes;
+
+  const ReadingTime({
+    required this.wordCount,
+    required this.minutes,
+  });
+
+  @override
+  int get hashCode => wordCount.hashCode ^ minutes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadingTime &&
+          runtimeType == other.runtimeType &&
+          wordCount == other.wordCount &&
+          minutes == other.minutes;
+}
+
 /// A sentence span with character offsets
 class SentenceSpan {
   final int start;
@@ -58,17 +279,27 @@ class SentenceSpan {
 class TextHighlightData {
   final List<WordSpan> words;
   final List<SentenceSpan> sentences;
+  /// Paragraphs as found in the source text before whitespace collapsing
+  final List<ParagraphSpan> paragraphs;
   final String normalizedText;
+  /// Char offset in the source text of each char of `normalized_text`
+  final Uint32List normalizedToRaw;
 
   const TextHighlightData({
     required this.words,
     required this.sentences,
+    required this.paragraphs,
     required this.normalizedText,
+    required this.normalizedToRaw,
   });
 
   @override
   int get hashCode =>
-      words.hashCode ^ sentences.hashCode ^ normalizedText.hashCode;
+      words.hashCode ^
+      sentences.hashCode ^
+      paragraphs.hashCode ^
+      normalizedText.hashCode ^
+      normalizedToRaw.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -77,7 +308,9 @@ class TextHighlightData {
           runtimeType == other.runtimeType &&
           words == other.words &&
           sentences == other.sentences &&
-          normalizedText == other.normalizedText;
+          paragraphs == other.paragraphs &&
+          normalizedText == other.normalizedText &&
+          normalizedToRaw == other.normalizedToRaw;
 }
 
 /// A word span with character offsets
@@ -8,9 +8,16 @@ import 'api/cbz.dart';
 import 'api/covers.dart';
 import 'api/crop.dart';
 import 'api/docx.dart';
+import 'api/epub.dart';
+import 'api/error.dart';
+import 'api/fb2.dart';
+import 'api/format.dart';
+import 'api/language.dart';
 import 'api/library.dart';
 import 'api/mobi.dart';
+import 'api/operation.dart';
 import 'api/pdf.dart';
+import 'api/profiling.dart';
 import 'api/tts_text.dart';
 import 'api/txt.dart';
 import 'dart:async';
@@ -77,7 +84,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1736096077;
+  int get rustContentHash => -953460240;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -88,15 +95,89 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<void> crateApiPdfAddPdfHighlight(
+      {required String path,
+      required int pageIndex,
+      required List<PdfTextRect> rects,
+      required int color});
+
+  Future<bool> crateApiLibraryCancelLibraryImport({required BigInt importId});
+
+  Future<bool> crateApiOperationCancelOperation({required BigInt id});
+
+  Future<void> crateApiCbzClearCbzCache();
+
+  Future<void> crateApiPdfClearPdfCache();
+
+  Future<BigInt> crateApiOperationCreateOperation();
+
+  Future<BookFormat> crateApiFormatDetectBookFormat({required String path});
+
+  Future<int> crateApiCropDetectPageOrientation(
+      {required String path, required int pageIndex});
+
+  Future<CropMargins> crateApiCropDetectPdfUniformCrop(
+      {required String path, required int sampleEvery});
+
   Future<CropMargins> crateApiCropDetectPdfWhitespace(
       {required String path, required int pageIndex});
 
+  Future<List<CropMargins>> crateApiCropDetectPdfWhitespaceBatch(
+      {required String path,
+      required int start,
+      required int end,
+      required BackgroundKind background,
+      required int threshold});
+
+  Future<CropMargins> crateApiCropDetectPdfWhitespaceWithOptions(
+      {required String path,
+      required int pageIndex,
+      required BackgroundKind background,
+      required int threshold});
+
+  Future<String> crateApiLanguageDetectScript({required String text});
+
+  Future<String?> crateApiTtsTextDetectTextLanguage({required String text});
+
+  Future<BigInt> crateApiCbzEstimateCbzPageBytes(
+      {required String path, required int index, int? maxWidth});
+
+  Future<ReadingTime> crateApiTtsTextEstimateReadingTime(
+      {required String text, required int wordsPerMinute});
+
+  Future<bool> crateApiPdfEvictPdfDocument({required String path});
+
+  Future<String> crateApiTtsTextExpandForSpeech(
+      {required String text, required String locale});
+
   Future<List<PdfTextRect>> crateApiPdfExtractAllPageCharacterBounds(
       {required String path, required int pageIndex});
 
+  Future<List<PdfWordRect>> crateApiPdfExtractAllPageWordBounds(
+      {required String path, required int pageIndex});
+
   Future<String> crateApiCoversExtractCover(
       {required String bookPath, required String savePath});
 
+  Future<Uint8List> crateApiCoversExtractCoverBytes(
+      {required String bookPath, int? maxDim});
+
+  Future<List<(int, Uint8List)>> crateApiCoversExtractCoverMulti(
+      {required String bookPath, required Uint32List sizes});
+
+  Future<String> crateApiCoversExtractCoverWithOptions(
+      {required String bookPath,
+      required String savePath,
+      required CoverOptions options});
+
+  Future<Uint8List> crateApiFb2ExtractFb2Cover({required String path});
+
+  Future<String> crateApiPdfExtractPdfPageBodyText(
+      {required String path, required int pageIndex});
+
+  Future<List<PdfImage>> crateApiPdfExtractPdfPageImages(
+      {required String path, required int pageIndex});
+
   Future<String> crateApiPdfExtractPdfPageText(
       {required String path, required int pageIndex});
 
@@ -106,15 +187,48 @@ abstract class RustLibApi extends BaseApi {
       required int startIndex,
       required int endIndex});
 
+  Future<String> crateApiPdfExtractPdfPageTextDehyphenated(
+      {required String path, required int pageIndex});
+
   Future<String> crateApiPdfExtractPdfPageTextFromPoint(
       {required String path,
       required int pageIndex,
       required double xNorm,
       required double yNorm});
 
+  Future<String> crateApiPdfExtractPdfTextInRect(
+      {required String path,
+      required int pageIndex,
+      required double left,
+      required double top,
+      required double right,
+      required double bottom});
+
+  Future<List<String>> crateApiFormatExtractPlainText(
+      {required String path, required TextUnit unit});
+
+  Future<int?> crateApiPdfFindFirstVisibleWordIndex(
+      {required List<PdfTextRect> rects, required double yNorm});
+
   Future<SentenceSpan?> crateApiTtsTextFindSentenceForOffset(
       {required List<SentenceSpan> sentences, required int offset});
 
+  Future<int?> crateApiPdfFindWordIndexAtPoint(
+      {required List<PdfTextRect> rects,
+      required double xNorm,
+      required double yNorm});
+
+  Future<String> crateApiCoversGeneratePlaceholderCover(
+      {required String title,
+      required String author,
+      required String savePath,
+      BigInt? seed});
+
+  Future<String> crateApiLibraryGetBookSynopsis(
+      {required String path, required int maxChars});
+
+  Future<ComicInfo?> crateApiCbzGetCbzMetadata({required String path});
+
   Future<CbzPageData> crateApiCbzGetCbzPage(
       {required String path, required int index, int? maxWidth});
 
@@ -123,7 +237,43 @@ abstract class RustLibApi extends BaseApi {
 
   Future<int> crateApiCbzGetCbzPageCount({required String path});
 
-  Future<List<String>> crateApiCbzGetCbzPageNames({required String path});
+  Future<EncodedImage> crateApiCbzGetCbzPageEncoded(
+      {required String path,
+      required String entryName,
+      int? maxWidth,
+      required CoverFormat format});
+
+  Future<CbzPageInfo> crateApiCbzGetCbzPageInfo(
+      {required String path, required String entryName});
+
+  Future<CbzPageNames> crateApiCbzGetCbzPageNames(
+      {required String path, required List<String> skipPatterns});
+
+  Future<CbzPageWithBackground> crateApiCbzGetCbzPageWithBackground(
+      {required String path, required int index, int? maxWidth});
+
+  Future<EncodedImage> crateApiCbzGetCbzThumbnail(
+      {required String path, required String entryName, required int maxDim});
+
+  Future<(int, int)?> crateApiCbzGetCbzUniformSize({required String path});
+
+  Future<List<ChapterEntry>> crateApiFormatGetChapters({required String path});
+
+  Future<DocxMetadata> crateApiDocxGetDocxMetadata({required String path});
+
+  Future<String> crateApiEpubGetEpubChapterHtml(
+      {required String path, required String href});
+
+  Future<List<MediaClip>> crateApiEpubGetEpubMediaOverlay(
+      {required String path, required String href});
+
+  Future<EpubMetadata> crateApiEpubGetEpubMetadata({required String path});
+
+  Future<EpubRendition> crateApiEpubGetEpubRendition({required String path});
+
+  Future<List<EpubChapter>> crateApiEpubGetEpubSpine({required String path});
+
+  Future<List<TocEntry>> crateApiEpubGetEpubToc({required String path});
 
   Future<String> crateApiMobiGetMobiAuthor({required String path});
 
@@ -131,13 +281,54 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiMobiGetMobiContent({required String path});
 
+  Future<Uint8List> crateApiMobiGetMobiImage(
+      {required String path, required int recindex});
+
+  Future<MobiMetadata> crateApiMobiGetMobiMetadata({required String path});
+
   Future<String> crateApiMobiGetMobiTitle({required String path});
 
+  Future<int> crateApiFormatGetPageCount({required String path});
+
+  Future<List<PdfAnnotation>> crateApiPdfGetPdfAnnotations(
+      {required String path, required int pageIndex});
+
+  Future<List<PdfFormField>> crateApiPdfGetPdfFormFields(
+      {required String path});
+
+  Future<PdfMetadata> crateApiPdfGetPdfMetadata({required String path});
+
+  Future<List<PdfOutlineItem>> crateApiPdfGetPdfOutline({required String path});
+
   Future<int> crateApiPdfGetPdfPageCount({required String path});
 
+  Future<List<PdfLink>> crateApiPdfGetPdfPageLinks(
+      {required String path, required int pageIndex});
+
+  Future<List<PageSize>> crateApiPdfGetPdfPageSizes({required String path});
+
+  Future<PdfPermissions> crateApiPdfGetPdfPermissions({required String path});
+
+  Future<int?> crateApiPdfGetPdfTextIndexFromPoint(
+      {required String path,
+      required int pageIndex,
+      required double xNorm,
+      required double yNorm});
+
   Future<String> crateApiHelloWorld();
 
-  Future<void> crateApiPdfInitPdfium({required String path});
+  Future<HtmlHighlightData> crateApiTtsTextHtmlToHighlightData(
+      {required String html});
+
+  Future<ImportedBook> crateApiLibraryImportBook(
+      {required String path, required String coverDir});
+
+  Stream<ImportEvent> crateApiLibraryImportLibraryStream(
+      {required String rootPath,
+      required String coverDir,
+      required BigInt importId});
+
+  Future<bool> crateApiPdfInitPdfium({String? libraryPath});
 
   Future<String> crateApiTtsTextInsertHtmlHighlight(
       {required String html,
@@ -145,25 +336,158 @@ abstract class RustLibApi extends BaseApi {
       required int highlightEnd,
       required String tagName});
 
+  Future<String> crateApiTtsTextInsertHtmlHighlights(
+      {required String html, required List<HighlightRange> ranges});
+
+  Future<bool> crateApiPdfIsPdfSupported();
+
+  Future<bool> crateApiLanguageIsRtlScript({required String script});
+
+  Future<List<PdfFontInfo>> crateApiPdfListPdfFonts({required String path});
+
+  Future<List<PdfLayer>> crateApiPdfListPdfLayers({required String path});
+
+  Future<void> crateApiPdfLockPdf({required String path});
+
+  Future<(int, int)> crateApiTtsTextNormalizedRangeToRaw(
+      {required TextHighlightData data, required int start, required int end});
+
   Future<TextHighlightData> crateApiTtsTextPrecomputeTextHighlights(
       {required String text});
 
   Future<String> crateApiDocxReadDocxToHtml({required String path});
 
+  Future<String> crateApiDocxReadDocxToHtmlWithImages(
+      {required String path, required DocxImageMode imageMode});
+
+  Future<ResourceBytes> crateApiEpubReadEpubResource(
+      {required String path, required String href, required String baseHref});
+
+  Future<String> crateApiFb2ReadFb2ToHtml({required String path});
+
   Future<String> crateApiTxtReadTxtToHtml({required String path});
 
+  Future<String> crateApiPdfReflowPdfPage(
+      {required String path, required int pageIndex});
+
+  Future<RenderedPage> crateApiFormatRenderPage(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required RenderOptions options});
+
   Future<PdfPageRenderResult> crateApiPdfRenderPdfPage(
       {required String path,
       required int pageIndex,
       required int width,
       required int height});
 
-  Future<List<BookMetadata>> crateApiLibraryScanLibrary(
-      {required String rootPath});
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageFmt(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required PdfImageFormat format,
+      int? rotationOverride,
+      BigInt? operationId});
+
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageRegion(
+      {required String path,
+      required int pageIndex,
+      required PdfTextRect region,
+      required int outputWidth,
+      required int outputHeight});
+
+  Future<List<PdfTile>> crateApiPdfRenderPdfPageTiles(
+      {required String path,
+      required int pageIndex,
+      required double scale,
+      required int tileSize,
+      required PdfTextRect viewport});
+
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageWithHighlights(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required List<PdfTextRect> rects,
+      required int color});
+
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageWithLayers(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required List<PdfLayer> layers});
+
+  Future<List<PdfPageRenderResult>> crateApiPdfRenderPdfThumbnails(
+      {required String path,
+      required int maxWidth,
+      (int, int)? pageRange,
+      BigInt? operationId});
+
+  Future<ScanReport> crateApiLibraryScanLibrary(
+      {required String rootPath,
+      required bool readMetadata,
+      ScanOptions? options});
+
+  Future<ScanDelta> crateApiLibraryScanLibraryIncremental(
+      {required String rootPath,
+      required List<KnownFile> known,
+      ScanOptions? options});
+
+  Stream<ScanEvent> crateApiLibraryScanLibraryWithProgress(
+      {required String rootPath,
+      required bool readMetadata,
+      ScanOptions? options});
+
+  Future<List<EpubSearchHit>> crateApiEpubSearchEpub(
+      {required String path,
+      required String query,
+      required bool caseSensitive});
+
+  Future<List<PdfSearchHit>> crateApiPdfSearchPdf(
+      {required String path,
+      required String query,
+      required bool caseSensitive,
+      BigInt? operationId});
+
+  Future<void> crateApiCbzSetCbzCacheCapacity({required int capacity});
+
+  Future<void> crateApiPdfSetEnforcePdfPermissions({required bool enforce});
+
+  Future<void> crateApiProfilingSetProfilingEnabled({required bool enabled});
+
+  Future<void> crateApiTtsTextSetSpelledNumberMax({required BigInt max});
+
+  Future<void> crateApiProfilingSetTimedThresholdMs(
+      {required BigInt thresholdMs});
+
+  Stream<CbzPageEvent> crateApiCbzStreamCbzPages(
+      {required String path, int? maxWidth});
+
+  Stream<PdfSearchEvent> crateApiPdfStreamPdfSearch(
+      {required String path,
+      required String query,
+      required bool caseSensitive,
+      required int startPage,
+      BigInt? operationId});
+
+  Future<List<TimedSample>> crateApiProfilingTakeProfilingSamples();
 
   Future<String> crateApiPdfTestPdfModule();
 
   Future<String> crateApiTtsTextTestTtsTextModule();
+
+  Future<String> crateApiTtsTextToSsml(
+      {required TextHighlightData data, String? voice});
+
+  Future<void> crateApiPdfUnlockPdf(
+      {required String path, required String password});
+
+  Future<List<(String, bool)>> crateApiCbzVerifyCbzIntegrity(
+      {required String path});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -175,539 +499,547 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<CropMargins> crateApiCropDetectPdfWhitespace(
-      {required String path, required int pageIndex}) {
+  Future<void> crateApiPdfAddPdfHighlight(
+      {required String path,
+      required int pageIndex,
+      required List<PdfTextRect> rects,
+      required int color}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
         sse_encode_u_32(pageIndex, serializer);
+        sse_encode_list_pdf_text_rect(rects, serializer);
+        sse_encode_u_32(color, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_crop_margins,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_ferrous_error,
       ),
-      constMeta: kCrateApiCropDetectPdfWhitespaceConstMeta,
-      argValues: [path, pageIndex],
+      constMeta: kCrateApiPdfAddPdfHighlightConstMeta,
+      argValues: [path, pageIndex, rects, color],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCropDetectPdfWhitespaceConstMeta =>
-      const TaskConstMeta(
-        debugName: "detect_pdf_whitespace",
-        argNames: ["path", "pageIndex"],
+  TaskConstMeta get kCrateApiPdfAddPdfHighlightConstMeta => const TaskConstMeta(
+        debugName: "add_pdf_highlight",
+        argNames: ["path", "pageIndex", "rects", "color"],
       );
 
   @override
-  Future<List<PdfTextRect>> crateApiPdfExtractAllPageCharacterBounds(
-      {required String path, required int pageIndex}) {
+  Future<bool> crateApiLibraryCancelLibraryImport({required BigInt importId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_64(importId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_pdf_text_rect,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPdfExtractAllPageCharacterBoundsConstMeta,
-      argValues: [path, pageIndex],
+      constMeta: kCrateApiLibraryCancelLibraryImportConstMeta,
+      argValues: [importId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfExtractAllPageCharacterBoundsConstMeta =>
+  TaskConstMeta get kCrateApiLibraryCancelLibraryImportConstMeta =>
       const TaskConstMeta(
-        debugName: "extract_all_page_character_bounds",
-        argNames: ["path", "pageIndex"],
+        debugName: "cancel_library_import",
+        argNames: ["importId"],
       );
 
   @override
-  Future<String> crateApiCoversExtractCover(
-      {required String bookPath, required String savePath}) {
+  Future<bool> crateApiOperationCancelOperation({required BigInt id}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(bookPath, serializer);
-        sse_encode_String(savePath, serializer);
+        sse_encode_u_64(id, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiCoversExtractCoverConstMeta,
-      argValues: [bookPath, savePath],
+      constMeta: kCrateApiOperationCancelOperationConstMeta,
+      argValues: [id],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoversExtractCoverConstMeta => const TaskConstMeta(
-        debugName: "extract_cover",
-        argNames: ["bookPath", "savePath"],
+  TaskConstMeta get kCrateApiOperationCancelOperationConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_operation",
+        argNames: ["id"],
       );
 
   @override
-  Future<String> crateApiPdfExtractPdfPageText(
-      {required String path, required int pageIndex}) {
+  Future<void> crateApiCbzClearCbzCache() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(pageIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 4, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPdfExtractPdfPageTextConstMeta,
-      argValues: [path, pageIndex],
+      constMeta: kCrateApiCbzClearCbzCacheConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfExtractPdfPageTextConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_pdf_page_text",
-        argNames: ["path", "pageIndex"],
+  TaskConstMeta get kCrateApiCbzClearCbzCacheConstMeta => const TaskConstMeta(
+        debugName: "clear_cbz_cache",
+        argNames: [],
       );
 
   @override
-  Future<List<PdfTextRect>> crateApiPdfExtractPdfPageTextBounds(
-      {required String path,
-      required int pageIndex,
-      required int startIndex,
-      required int endIndex}) {
+  Future<void> crateApiPdfClearPdfCache() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(pageIndex, serializer);
-        sse_encode_u_32(startIndex, serializer);
-        sse_encode_u_32(endIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_pdf_text_rect,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPdfExtractPdfPageTextBoundsConstMeta,
-      argValues: [path, pageIndex, startIndex, endIndex],
+      constMeta: kCrateApiPdfClearPdfCacheConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfExtractPdfPageTextBoundsConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_pdf_page_text_bounds",
-        argNames: ["path", "pageIndex", "startIndex", "endIndex"],
+  TaskConstMeta get kCrateApiPdfClearPdfCacheConstMeta => const TaskConstMeta(
+        debugName: "clear_pdf_cache",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiPdfExtractPdfPageTextFromPoint(
-      {required String path,
-      required int pageIndex,
-      required double xNorm,
-      required double yNorm}) {
+  Future<BigInt> crateApiOperationCreateOperation() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(pageIndex, serializer);
-        sse_encode_f_64(xNorm, serializer);
-        sse_encode_f_64(yNorm, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 6, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPdfExtractPdfPageTextFromPointConstMeta,
-      argValues: [path, pageIndex, xNorm, yNorm],
+      constMeta: kCrateApiOperationCreateOperationConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfExtractPdfPageTextFromPointConstMeta =>
+  TaskConstMeta get kCrateApiOperationCreateOperationConstMeta =>
       const TaskConstMeta(
-        debugName: "extract_pdf_page_text_from_point",
-        argNames: ["path", "pageIndex", "xNorm", "yNorm"],
+        debugName: "create_operation",
+        argNames: [],
       );
 
   @override
-  Future<SentenceSpan?> crateApiTtsTextFindSentenceForOffset(
-      {required List<SentenceSpan> sentences, required int offset}) {
+  Future<BookFormat> crateApiFormatDetectBookFormat({required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_sentence_span(sentences, serializer);
-        sse_encode_u_32(offset, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_sentence_span,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_book_format,
+        decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiTtsTextFindSentenceForOffsetConstMeta,
-      argValues: [sentences, offset],
+      constMeta: kCrateApiFormatDetectBookFormatConstMeta,
+      argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTtsTextFindSentenceForOffsetConstMeta =>
+  TaskConstMeta get kCrateApiFormatDetectBookFormatConstMeta =>
       const TaskConstMeta(
-        debugName: "find_sentence_for_offset",
-        argNames: ["sentences", "offset"],
+        debugName: "detect_book_format",
+        argNames: ["path"],
       );
 
   @override
-  Future<CbzPageData> crateApiCbzGetCbzPage(
-      {required String path, required int index, int? maxWidth}) {
+  Future<int> crateApiCropDetectPageOrientation(
+      {required String path, required int pageIndex}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
-        sse_encode_i_32(index, serializer);
-        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        sse_encode_u_32(pageIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 8, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_cbz_page_data,
+        decodeSuccessData: sse_decode_u_16,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCbzGetCbzPageConstMeta,
-      argValues: [path, index, maxWidth],
+      constMeta: kCrateApiCropDetectPageOrientationConstMeta,
+      argValues: [path, pageIndex],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCbzGetCbzPageConstMeta => const TaskConstMeta(
-        debugName: "get_cbz_page",
-        argNames: ["path", "index", "maxWidth"],
+  TaskConstMeta get kCrateApiCropDetectPageOrientationConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_page_orientation",
+        argNames: ["path", "pageIndex"],
       );
 
   @override
-  Future<CbzPageData> crateApiCbzGetCbzPageByName(
-      {required String path, required String entryName, int? maxWidth}) {
+  Future<CropMargins> crateApiCropDetectPdfUniformCrop(
+      {required String path, required int sampleEvery}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
-        sse_encode_String(entryName, serializer);
-        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        sse_encode_u_32(sampleEvery, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_cbz_page_data,
+        decodeSuccessData: sse_decode_crop_margins,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCbzGetCbzPageByNameConstMeta,
-      argValues: [path, entryName, maxWidth],
+      constMeta: kCrateApiCropDetectPdfUniformCropConstMeta,
+      argValues: [path, sampleEvery],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCbzGetCbzPageByNameConstMeta =>
+  TaskConstMeta get kCrateApiCropDetectPdfUniformCropConstMeta =>
       const TaskConstMeta(
-        debugName: "get_cbz_page_by_name",
-        argNames: ["path", "entryName", "maxWidth"],
+        debugName: "detect_pdf_uniform_crop",
+        argNames: ["path", "sampleEvery"],
       );
 
   @override
-  Future<int> crateApiCbzGetCbzPageCount({required String path}) {
+  Future<CropMargins> crateApiCropDetectPdfWhitespace(
+      {required String path, required int pageIndex}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_i_32,
+        decodeSuccessData: sse_decode_crop_margins,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCbzGetCbzPageCountConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCropDetectPdfWhitespaceConstMeta,
+      argValues: [path, pageIndex],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCbzGetCbzPageCountConstMeta => const TaskConstMeta(
-        debugName: "get_cbz_page_count",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiCropDetectPdfWhitespaceConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_pdf_whitespace",
+        argNames: ["path", "pageIndex"],
       );
 
   @override
-  Future<List<String>> crateApiCbzGetCbzPageNames({required String path}) {
+  Future<List<CropMargins>> crateApiCropDetectPdfWhitespaceBatch(
+      {required String path,
+      required int start,
+      required int end,
+      required BackgroundKind background,
+      required int threshold}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
+        sse_encode_u_32(start, serializer);
+        sse_encode_u_32(end, serializer);
+        sse_encode_background_kind(background, serializer);
+        sse_encode_u_8(threshold, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_list_crop_margins,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCbzGetCbzPageNamesConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCropDetectPdfWhitespaceBatchConstMeta,
+      argValues: [path, start, end, background, threshold],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCbzGetCbzPageNamesConstMeta => const TaskConstMeta(
-        debugName: "get_cbz_page_names",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiCropDetectPdfWhitespaceBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_pdf_whitespace_batch",
+        argNames: ["path", "start", "end", "background", "threshold"],
       );
 
   @override
-  Future<String> crateApiMobiGetMobiAuthor({required String path}) {
+  Future<CropMargins> crateApiCropDetectPdfWhitespaceWithOptions(
+      {required String path,
+      required int pageIndex,
+      required BackgroundKind background,
+      required int threshold}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_background_kind(background, serializer);
+        sse_encode_u_8(threshold, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_crop_margins,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiMobiGetMobiAuthorConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCropDetectPdfWhitespaceWithOptionsConstMeta,
+      argValues: [path, pageIndex, background, threshold],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMobiGetMobiAuthorConstMeta => const TaskConstMeta(
-        debugName: "get_mobi_author",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiCropDetectPdfWhitespaceWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_pdf_whitespace_with_options",
+        argNames: ["path", "pageIndex", "background", "threshold"],
       );
 
   @override
-  Future<List<MobiChapter>> crateApiMobiGetMobiChapters(
-      {required String path}) {
+  Future<String> crateApiLanguageDetectScript({required String text}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(text, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 13, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_mobi_chapter,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMobiGetMobiChaptersConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiLanguageDetectScriptConstMeta,
+      argValues: [text],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMobiGetMobiChaptersConstMeta =>
+  TaskConstMeta get kCrateApiLanguageDetectScriptConstMeta =>
       const TaskConstMeta(
-        debugName: "get_mobi_chapters",
-        argNames: ["path"],
+        debugName: "detect_script",
+        argNames: ["text"],
       );
 
   @override
-  Future<String> crateApiMobiGetMobiContent({required String path}) {
+  Future<String?> crateApiTtsTextDetectTextLanguage({required String text}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(text, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 14, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMobiGetMobiContentConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiTtsTextDetectTextLanguageConstMeta,
+      argValues: [text],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMobiGetMobiContentConstMeta => const TaskConstMeta(
-        debugName: "get_mobi_content",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiTtsTextDetectTextLanguageConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_text_language",
+        argNames: ["text"],
       );
 
   @override
-  Future<String> crateApiMobiGetMobiTitle({required String path}) {
+  Future<BigInt> crateApiCbzEstimateCbzPageBytes(
+      {required String path, required int index, int? maxWidth}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
+        sse_encode_i_32(index, serializer);
+        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 15, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_u_64,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiMobiGetMobiTitleConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCbzEstimateCbzPageBytesConstMeta,
+      argValues: [path, index, maxWidth],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMobiGetMobiTitleConstMeta => const TaskConstMeta(
-        debugName: "get_mobi_title",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiCbzEstimateCbzPageBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "estimate_cbz_page_bytes",
+        argNames: ["path", "index", "maxWidth"],
       );
 
   @override
-  Future<int> crateApiPdfGetPdfPageCount({required String path}) {
+  Future<ReadingTime> crateApiTtsTextEstimateReadingTime(
+      {required String text, required int wordsPerMinute}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(text, serializer);
+        sse_encode_u_32(wordsPerMinute, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_32,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_reading_time,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPdfGetPdfPageCountConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiTtsTextEstimateReadingTimeConstMeta,
+      argValues: [text, wordsPerMinute],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfGetPdfPageCountConstMeta => const TaskConstMeta(
-        debugName: "get_pdf_page_count",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiTtsTextEstimateReadingTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "estimate_reading_time",
+        argNames: ["text", "wordsPerMinute"],
       );
 
   @override
-  Future<String> crateApiHelloWorld() {
+  Future<bool> crateApiPdfEvictPdfDocument({required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_ferrous_error,
       ),
-      constMeta: kCrateApiHelloWorldConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPdfEvictPdfDocumentConstMeta,
+      argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiHelloWorldConstMeta => const TaskConstMeta(
-        debugName: "hello_world",
-        argNames: [],
+  TaskConstMeta get kCrateApiPdfEvictPdfDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "evict_pdf_document",
+        argNames: ["path"],
       );
 
   @override
-  Future<void> crateApiPdfInitPdfium({required String path}) {
+  Future<String> crateApiTtsTextExpandForSpeech(
+      {required String text, required String locale}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(text, serializer);
+        sse_encode_String(locale, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 18, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPdfInitPdfiumConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiTtsTextExpandForSpeechConstMeta,
+      argValues: [text, locale],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfInitPdfiumConstMeta => const TaskConstMeta(
-        debugName: "init_pdfium",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiTtsTextExpandForSpeechConstMeta =>
+      const TaskConstMeta(
+        debugName: "expand_for_speech",
+        argNames: ["text", "locale"],
       );
 
   @override
-  Future<String> crateApiTtsTextInsertHtmlHighlight(
-      {required String html,
-      required int highlightStart,
-      required int highlightEnd,
-      required String tagName}) {
+  Future<List<PdfTextRect>> crateApiPdfExtractAllPageCharacterBounds(
+      {required String path, required int pageIndex}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(html, serializer);
-        sse_encode_u_32(highlightStart, serializer);
-        sse_encode_u_32(highlightEnd, serializer);
-        sse_encode_String(tagName, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 19, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_pdf_text_rect,
+        decodeErrorData: sse_decode_ferrous_error,
       ),
-      constMeta: kCrateApiTtsTextInsertHtmlHighlightConstMeta,
-      argValues: [html, highlightStart, highlightEnd, tagName],
+      constMeta: kCrateApiPdfExtractAllPageCharacterBoundsConstMeta,
+      argValues: [path, pageIndex],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTtsTextInsertHtmlHighlightConstMeta =>
+  TaskConstMeta get kCrateApiPdfExtractAllPageCharacterBoundsConstMeta =>
       const TaskConstMeta(
-        debugName: "insert_html_highlight",
-        argNames: ["html", "highlightStart", "highlightEnd", "tagName"],
+        debugName: "extract_all_page_character_bounds",
+        argNames: ["path", "pageIndex"],
       );
 
   @override
-  Future<TextHighlightData> crateApiTtsTextPrecomputeTextHighlights(
-      {required String text}) {
+  Future<List<PdfWordRect>> crateApiPdfExtractAllPageWordBounds(
+      {required String path, required int pageIndex}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(text, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 20, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_text_highlight_data,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_list_pdf_word_rect,
+        decodeErrorData: sse_decode_ferrous_error,
       ),
-      constMeta: kCrateApiTtsTextPrecomputeTextHighlightsConstMeta,
-      argValues: [text],
+      constMeta: kCrateApiPdfExtractAllPageWordBoundsConstMeta,
+      argValues: [path, pageIndex],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTtsTextPrecomputeTextHighlightsConstMeta =>
+  TaskConstMeta get kCrateApiPdfExtractAllPageWordBoundsConstMeta =>
       const TaskConstMeta(
-        debugName: "precompute_text_highlights",
-        argNames: ["text"],
+        debugName: "extract_all_page_word_bounds",
+        argNames: ["path", "pageIndex"],
       );
 
   @override
-  Future<String> crateApiDocxReadDocxToHtml({required String path}) {
+  Future<String> crateApiCoversExtractCover(
+      {required String bookPath, required String savePath}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(bookPath, serializer);
+        sse_encode_String(savePath, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 21, port: port_);
       },
@@ -715,818 +1047,7429 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiDocxReadDocxToHtmlConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCoversExtractCoverConstMeta,
+      argValues: [bookPath, savePath],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiDocxReadDocxToHtmlConstMeta => const TaskConstMeta(
-        debugName: "read_docx_to_html",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiCoversExtractCoverConstMeta => const TaskConstMeta(
+        debugName: "extract_cover",
+        argNames: ["bookPath", "savePath"],
       );
 
   @override
-  Future<String> crateApiTxtReadTxtToHtml({required String path}) {
+  Future<Uint8List> crateApiCoversExtractCoverBytes(
+      {required String bookPath, int? maxDim}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(bookPath, serializer);
+        sse_encode_opt_box_autoadd_u_32(maxDim, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 22, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiTxtReadTxtToHtmlConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCoversExtractCoverBytesConstMeta,
+      argValues: [bookPath, maxDim],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTxtReadTxtToHtmlConstMeta => const TaskConstMeta(
-        debugName: "read_txt_to_html",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiCoversExtractCoverBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_cover_bytes",
+        argNames: ["bookPath", "maxDim"],
       );
 
   @override
-  Future<PdfPageRenderResult> crateApiPdfRenderPdfPage(
-      {required String path,
-      required int pageIndex,
-      required int width,
-      required int height}) {
+  Future<List<(int, Uint8List)>> crateApiCoversExtractCoverMulti(
+      {required String bookPath, required Uint32List sizes}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(pageIndex, serializer);
-        sse_encode_u_32(width, serializer);
-        sse_encode_u_32(height, serializer);
+        sse_encode_String(bookPath, serializer);
+        sse_encode_list_prim_u_32_strict(sizes, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 23, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_pdf_page_render_result,
+        decodeSuccessData: sse_decode_list_record_u_32_list_prim_u_8_strict,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiPdfRenderPdfPageConstMeta,
-      argValues: [path, pageIndex, width, height],
+      constMeta: kCrateApiCoversExtractCoverMultiConstMeta,
+      argValues: [bookPath, sizes],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfRenderPdfPageConstMeta => const TaskConstMeta(
-        debugName: "render_pdf_page",
-        argNames: ["path", "pageIndex", "width", "height"],
+  TaskConstMeta get kCrateApiCoversExtractCoverMultiConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_cover_multi",
+        argNames: ["bookPath", "sizes"],
       );
 
   @override
-  Future<List<BookMetadata>> crateApiLibraryScanLibrary(
-      {required String rootPath}) {
+  Future<String> crateApiCoversExtractCoverWithOptions(
+      {required String bookPath,
+      required String savePath,
+      required CoverOptions options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(rootPath, serializer);
+        sse_encode_String(bookPath, serializer);
+        sse_encode_String(savePath, serializer);
+        sse_encode_box_autoadd_cover_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 24, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_book_metadata,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiLibraryScanLibraryConstMeta,
-      argValues: [rootPath],
+      constMeta: kCrateApiCoversExtractCoverWithOptionsConstMeta,
+      argValues: [bookPath, savePath, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiLibraryScanLibraryConstMeta => const TaskConstMeta(
-        debugName: "scan_library",
-        argNames: ["rootPath"],
+  TaskConstMeta get kCrateApiCoversExtractCoverWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_cover_with_options",
+        argNames: ["bookPath", "savePath", "options"],
       );
 
   @override
-  Future<String> crateApiPdfTestPdfModule() {
+  Future<Uint8List> crateApiFb2ExtractFb2Cover({required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 25, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiPdfTestPdfModuleConstMeta,
-      argValues: [],
+      constMeta: kCrateApiFb2ExtractFb2CoverConstMeta,
+      argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPdfTestPdfModuleConstMeta => const TaskConstMeta(
-        debugName: "test_pdf_module",
-        argNames: [],
+  TaskConstMeta get kCrateApiFb2ExtractFb2CoverConstMeta => const TaskConstMeta(
+        debugName: "extract_fb2_cover",
+        argNames: ["path"],
       );
 
   @override
-  Future<String> crateApiTtsTextTestTtsTextModule() {
+  Future<String> crateApiPdfExtractPdfPageBodyText(
+      {required String path, required int pageIndex}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 26, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_ferrous_error,
       ),
-      constMeta: kCrateApiTtsTextTestTtsTextModuleConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPdfExtractPdfPageBodyTextConstMeta,
+      argValues: [path, pageIndex],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTtsTextTestTtsTextModuleConstMeta =>
+  TaskConstMeta get kCrateApiPdfExtractPdfPageBodyTextConstMeta =>
       const TaskConstMeta(
-        debugName: "test_tts_text_module",
-        argNames: [],
+        debugName: "extract_pdf_page_body_text",
+        argNames: ["path", "pageIndex"],
       );
 
+  @override
+  Future<List<PdfImage>> crateApiPdfExtractPdfPageImages(
+      {required String path, required int pageIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_image,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfExtractPdfPageImagesConstMeta,
+      argValues: [path, pageIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfExtractPdfPageImagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_pdf_page_images",
+        argNames: ["path", "pageIndex"],
+      );
+
+  @override
+  Future<String> crateApiPdfExtractPdfPageText(
+      {required String path, required int pageIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfExtractPdfPageTextConstMeta,
+      argValues: [path, pageIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfExtractPdfPageTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_pdf_page_text",
+        argNames: ["path", "pageIndex"],
+      );
+
+  @override
+  Future<List<PdfTextRect>> crateApiPdfExtractPdfPageTextBounds(
+      {required String path,
+      required int pageIndex,
+      required int startIndex,
+      required int endIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_32(startIndex, serializer);
+        sse_encode_u_32(endIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_text_rect,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfExtractPdfPageTextBoundsConstMeta,
+      argValues: [path, pageIndex, startIndex, endIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfExtractPdfPageTextBoundsConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_pdf_page_text_bounds",
+        argNames: ["path", "pageIndex", "startIndex", "endIndex"],
+      );
+
+  @override
+  Future<String> crateApiPdfExtractPdfPageTextDehyphenated(
+      {required String path, required int pageIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfExtractPdfPageTextDehyphenatedConstMeta,
+      argValues: [path, pageIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfExtractPdfPageTextDehyphenatedConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_pdf_page_text_dehyphenated",
+        argNames: ["path", "pageIndex"],
+      );
+
+  @override
+  Future<String> crateApiPdfExtractPdfPageTextFromPoint(
+      {required String path,
+      required int pageIndex,
+      required double xNorm,
+      required double yNorm}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_f_64(xNorm, serializer);
+        sse_encode_f_64(yNorm, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfExtractPdfPageTextFromPointConstMeta,
+      argValues: [path, pageIndex, xNorm, yNorm],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfExtractPdfPageTextFromPointConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_pdf_page_text_from_point",
+        argNames: ["path", "pageIndex", "xNorm", "yNorm"],
+      );
+
+  @override
+  Future<String> crateApiPdfExtractPdfTextInRect(
+      {required String path,
+      required int pageIndex,
+      required double left,
+      required double top,
+      required double right,
+      required double bottom}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_f_32(left, serializer);
+        sse_encode_f_32(top, serializer);
+        sse_encode_f_32(right, serializer);
+        sse_encode_f_32(bottom, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfExtractPdfTextInRectConstMeta,
+      argValues: [path, pageIndex, left, top, right, bottom],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfExtractPdfTextInRectConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_pdf_text_in_rect",
+        argNames: ["path", "pageIndex", "left", "top", "right", "bottom"],
+      );
+
+  @override
+  Future<List<String>> crateApiFormatExtractPlainText(
+      {required String path, required TextUnit unit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_text_unit(unit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiFormatExtractPlainTextConstMeta,
+      argValues: [path, unit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFormatExtractPlainTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_plain_text",
+        argNames: ["path", "unit"],
+      );
+
+  @override
+  Future<int?> crateApiPdfFindFirstVisibleWordIndex(
+      {required List<PdfTextRect> rects, required double yNorm}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_pdf_text_rect(rects, serializer);
+        sse_encode_f_32(yNorm, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPdfFindFirstVisibleWordIndexConstMeta,
+      argValues: [rects, yNorm],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfFindFirstVisibleWordIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_first_visible_word_index",
+        argNames: ["rects", "yNorm"],
+      );
+
+  @override
+  Future<SentenceSpan?> crateApiTtsTextFindSentenceForOffset(
+      {required List<SentenceSpan> sentences, required int offset}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_sentence_span(sentences, serializer);
+        sse_encode_u_32(offset, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_sentence_span,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTtsTextFindSentenceForOffsetConstMeta,
+      argValues: [sentences, offset],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextFindSentenceForOffsetConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_sentence_for_offset",
+        argNames: ["sentences", "offset"],
+      );
+
+  @override
+  Future<int?> crateApiPdfFindWordIndexAtPoint(
+      {required List<PdfTextRect> rects,
+      required double xNorm,
+      required double yNorm}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_pdf_text_rect(rects, serializer);
+        sse_encode_f_32(xNorm, serializer);
+        sse_encode_f_32(yNorm, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPdfFindWordIndexAtPointConstMeta,
+      argValues: [rects, xNorm, yNorm],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfFindWordIndexAtPointConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_word_index_at_point",
+        argNames: ["rects", "xNorm", "yNorm"],
+      );
+
+  @override
+  Future<String> crateApiCoversGeneratePlaceholderCover(
+      {required String title,
+      required String author,
+      required String savePath,
+      BigInt? seed}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(title, serializer);
+        sse_encode_String(author, serializer);
+        sse_encode_String(savePath, serializer);
+        sse_encode_opt_box_autoadd_u_64(seed, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoversGeneratePlaceholderCoverConstMeta,
+      argValues: [title, author, savePath, seed],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoversGeneratePlaceholderCoverConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_placeholder_cover",
+        argNames: ["title", "author", "savePath", "seed"],
+      );
+
+  @override
+  Future<String> crateApiLibraryGetBookSynopsis(
+      {required String path, required int maxChars}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(maxChars, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiLibraryGetBookSynopsisConstMeta,
+      argValues: [path, maxChars],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLibraryGetBookSynopsisConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_book_synopsis",
+        argNames: ["path", "maxChars"],
+      );
+
+  @override
+  Future<ComicInfo?> crateApiCbzGetCbzMetadata({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_comic_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzMetadataConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzMetadataConstMeta => const TaskConstMeta(
+        debugName: "get_cbz_metadata",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<CbzPageData> crateApiCbzGetCbzPage(
+      {required String path, required int index, int? maxWidth}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_i_32(index, serializer);
+        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cbz_page_data,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageConstMeta,
+      argValues: [path, index, maxWidth],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageConstMeta => const TaskConstMeta(
+        debugName: "get_cbz_page",
+        argNames: ["path", "index", "maxWidth"],
+      );
+
+  @override
+  Future<CbzPageData> crateApiCbzGetCbzPageByName(
+      {required String path, required String entryName, int? maxWidth}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(entryName, serializer);
+        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cbz_page_data,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageByNameConstMeta,
+      argValues: [path, entryName, maxWidth],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageByNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cbz_page_by_name",
+        argNames: ["path", "entryName", "maxWidth"],
+      );
+
+  @override
+  Future<int> crateApiCbzGetCbzPageCount({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageCountConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageCountConstMeta => const TaskConstMeta(
+        debugName: "get_cbz_page_count",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<EncodedImage> crateApiCbzGetCbzPageEncoded(
+      {required String path,
+      required String entryName,
+      int? maxWidth,
+      required CoverFormat format}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(entryName, serializer);
+        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        sse_encode_box_autoadd_cover_format(format, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_encoded_image,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageEncodedConstMeta,
+      argValues: [path, entryName, maxWidth, format],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageEncodedConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cbz_page_encoded",
+        argNames: ["path", "entryName", "maxWidth", "format"],
+      );
+
+  @override
+  Future<CbzPageInfo> crateApiCbzGetCbzPageInfo(
+      {required String path, required String entryName}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(entryName, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cbz_page_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageInfoConstMeta,
+      argValues: [path, entryName],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageInfoConstMeta => const TaskConstMeta(
+        debugName: "get_cbz_page_info",
+        argNames: ["path", "entryName"],
+      );
+
+  @override
+  Future<CbzPageNames> crateApiCbzGetCbzPageNames(
+      {required String path, required List<String> skipPatterns}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_list_String(skipPatterns, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cbz_page_names,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageNamesConstMeta,
+      argValues: [path, skipPatterns],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageNamesConstMeta => const TaskConstMeta(
+        debugName: "get_cbz_page_names",
+        argNames: ["path", "skipPatterns"],
+      );
+
+  @override
+  Future<CbzPageWithBackground> crateApiCbzGetCbzPageWithBackground(
+      {required String path, required int index, int? maxWidth}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_i_32(index, serializer);
+        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cbz_page_with_background,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzPageWithBackgroundConstMeta,
+      argValues: [path, index, maxWidth],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzPageWithBackgroundConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cbz_page_with_background",
+        argNames: ["path", "index", "maxWidth"],
+      );
+
+  @override
+  Future<EncodedImage> crateApiCbzGetCbzThumbnail(
+      {required String path, required String entryName, required int maxDim}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(entryName, serializer);
+        sse_encode_u_32(maxDim, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_encoded_image,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzThumbnailConstMeta,
+      argValues: [path, entryName, maxDim],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzThumbnailConstMeta => const TaskConstMeta(
+        debugName: "get_cbz_thumbnail",
+        argNames: ["path", "entryName", "maxDim"],
+      );
+
+  @override
+  Future<(int, int)?> crateApiCbzGetCbzUniformSize({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_record_u_32_u_32,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzGetCbzUniformSizeConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzGetCbzUniformSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cbz_uniform_size",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<ChapterEntry>> crateApiFormatGetChapters({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_chapter_entry,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiFormatGetChaptersConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFormatGetChaptersConstMeta => const TaskConstMeta(
+        debugName: "get_chapters",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<DocxMetadata> crateApiDocxGetDocxMetadata({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_docx_metadata,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiDocxGetDocxMetadataConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDocxGetDocxMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_docx_metadata",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiEpubGetEpubChapterHtml(
+      {required String path, required String href}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(href, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubGetEpubChapterHtmlConstMeta,
+      argValues: [path, href],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubGetEpubChapterHtmlConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_epub_chapter_html",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<List<MediaClip>> crateApiEpubGetEpubMediaOverlay(
+      {required String path, required String href}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(href, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_media_clip,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubGetEpubMediaOverlayConstMeta,
+      argValues: [path, href],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubGetEpubMediaOverlayConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_epub_media_overlay",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<EpubMetadata> crateApiEpubGetEpubMetadata({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_epub_metadata,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubGetEpubMetadataConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubGetEpubMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_epub_metadata",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<EpubRendition> crateApiEpubGetEpubRendition({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_epub_rendition,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubGetEpubRenditionConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubGetEpubRenditionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_epub_rendition",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<EpubChapter>> crateApiEpubGetEpubSpine({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_epub_chapter,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubGetEpubSpineConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubGetEpubSpineConstMeta => const TaskConstMeta(
+        debugName: "get_epub_spine",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<TocEntry>> crateApiEpubGetEpubToc({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_toc_entry,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubGetEpubTocConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubGetEpubTocConstMeta => const TaskConstMeta(
+        debugName: "get_epub_toc",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiMobiGetMobiAuthor({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMobiGetMobiAuthorConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMobiGetMobiAuthorConstMeta => const TaskConstMeta(
+        debugName: "get_mobi_author",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<MobiChapter>> crateApiMobiGetMobiChapters(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_mobi_chapter,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMobiGetMobiChaptersConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMobiGetMobiChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_mobi_chapters",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiMobiGetMobiContent({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMobiGetMobiContentConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMobiGetMobiContentConstMeta => const TaskConstMeta(
+        debugName: "get_mobi_content",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Uint8List> crateApiMobiGetMobiImage(
+      {required String path, required int recindex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(recindex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMobiGetMobiImageConstMeta,
+      argValues: [path, recindex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMobiGetMobiImageConstMeta => const TaskConstMeta(
+        debugName: "get_mobi_image",
+        argNames: ["path", "recindex"],
+      );
+
+  @override
+  Future<MobiMetadata> crateApiMobiGetMobiMetadata({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_mobi_metadata,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMobiGetMobiMetadataConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMobiGetMobiMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_mobi_metadata",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiMobiGetMobiTitle({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMobiGetMobiTitleConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMobiGetMobiTitleConstMeta => const TaskConstMeta(
+        debugName: "get_mobi_title",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<int> crateApiFormatGetPageCount({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiFormatGetPageCountConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFormatGetPageCountConstMeta => const TaskConstMeta(
+        debugName: "get_page_count",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<PdfAnnotation>> crateApiPdfGetPdfAnnotations(
+      {required String path, required int pageIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_annotation,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfAnnotationsConstMeta,
+      argValues: [path, pageIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfAnnotationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pdf_annotations",
+        argNames: ["path", "pageIndex"],
+      );
+
+  @override
+  Future<List<PdfFormField>> crateApiPdfGetPdfFormFields(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_form_field,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfFormFieldsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfFormFieldsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pdf_form_fields",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<PdfMetadata> crateApiPdfGetPdfMetadata({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 66, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_metadata,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfMetadataConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfMetadataConstMeta => const TaskConstMeta(
+        debugName: "get_pdf_metadata",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<PdfOutlineItem>> crateApiPdfGetPdfOutline(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_outline_item,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfOutlineConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfOutlineConstMeta => const TaskConstMeta(
+        debugName: "get_pdf_outline",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<int> crateApiPdfGetPdfPageCount({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 68, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfPageCountConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfPageCountConstMeta => const TaskConstMeta(
+        debugName: "get_pdf_page_count",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<PdfLink>> crateApiPdfGetPdfPageLinks(
+      {required String path, required int pageIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 69, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_link,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfPageLinksConstMeta,
+      argValues: [path, pageIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfPageLinksConstMeta => const TaskConstMeta(
+        debugName: "get_pdf_page_links",
+        argNames: ["path", "pageIndex"],
+      );
+
+  @override
+  Future<List<PageSize>> crateApiPdfGetPdfPageSizes({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 70, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_page_size,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfPageSizesConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfPageSizesConstMeta => const TaskConstMeta(
+        debugName: "get_pdf_page_sizes",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<PdfPermissions> crateApiPdfGetPdfPermissions({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 71, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_permissions,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfPermissionsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfPermissionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pdf_permissions",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<int?> crateApiPdfGetPdfTextIndexFromPoint(
+      {required String path,
+      required int pageIndex,
+      required double xNorm,
+      required double yNorm}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_f_64(xNorm, serializer);
+        sse_encode_f_64(yNorm, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 72, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfGetPdfTextIndexFromPointConstMeta,
+      argValues: [path, pageIndex, xNorm, yNorm],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfGetPdfTextIndexFromPointConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pdf_text_index_from_point",
+        argNames: ["path", "pageIndex", "xNorm", "yNorm"],
+      );
+
+  @override
+  Future<String> crateApiHelloWorld() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 73, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiHelloWorldConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiHelloWorldConstMeta => const TaskConstMeta(
+        debugName: "hello_world",
+        argNames: [],
+      );
+
+  @override
+  Future<HtmlHighlightData> crateApiTtsTextHtmlToHighlightData(
+      {required String html}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(html, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 74, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_html_highlight_data,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTtsTextHtmlToHighlightDataConstMeta,
+      argValues: [html],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextHtmlToHighlightDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "html_to_highlight_data",
+        argNames: ["html"],
+      );
+
+  @override
+  Future<ImportedBook> crateApiLibraryImportBook(
+      {required String path, required String coverDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(coverDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 75, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_imported_book,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiLibraryImportBookConstMeta,
+      argValues: [path, coverDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLibraryImportBookConstMeta => const TaskConstMeta(
+        debugName: "import_book",
+        argNames: ["path", "coverDir"],
+      );
+
+  @override
+  Stream<ImportEvent> crateApiLibraryImportLibraryStream(
+      {required String rootPath,
+      required String coverDir,
+      required BigInt importId}) {
+    final sink = RustStreamSink<ImportEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootPath, serializer);
+        sse_encode_String(coverDir, serializer);
+        sse_encode_u_64(importId, serializer);
+        sse_encode_StreamSink_import_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 76, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiLibraryImportLibraryStreamConstMeta,
+      argValues: [rootPath, coverDir, importId, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLibraryImportLibraryStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_library_stream",
+        argNames: ["rootPath", "coverDir", "importId", "sink"],
+      );
+
+  @override
+  Future<bool> crateApiPdfInitPdfium({String? libraryPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_String(libraryPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 77, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfInitPdfiumConstMeta,
+      argValues: [libraryPath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfInitPdfiumConstMeta => const TaskConstMeta(
+        debugName: "init_pdfium",
+        argNames: ["libraryPath"],
+      );
+
+  @override
+  Future<String> crateApiTtsTextInsertHtmlHighlight(
+      {required String html,
+      required int highlightStart,
+      required int highlightEnd,
+      required String tagName}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(html, serializer);
+        sse_encode_u_32(highlightStart, serializer);
+        sse_encode_u_32(highlightEnd, serializer);
+        sse_encode_String(tagName, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 78, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTtsTextInsertHtmlHighlightConstMeta,
+      argValues: [html, highlightStart, highlightEnd, tagName],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextInsertHtmlHighlightConstMeta =>
+      const TaskConstMeta(
+        debugName: "insert_html_highlight",
+        argNames: ["html", "highlightStart", "highlightEnd", "tagName"],
+      );
+
+  @override
+  Future<String> crateApiTtsTextInsertHtmlHighlights(
+      {required String html, required List<HighlightRange> ranges}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(html, serializer);
+        sse_encode_list_highlight_range(ranges, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 79, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTtsTextInsertHtmlHighlightsConstMeta,
+      argValues: [html, ranges],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextInsertHtmlHighlightsConstMeta =>
+      const TaskConstMeta(
+        debugName: "insert_html_highlights",
+        argNames: ["html", "ranges"],
+      );
+
+  @override
+  Future<bool> crateApiPdfIsPdfSupported() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 80, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPdfIsPdfSupportedConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfIsPdfSupportedConstMeta => const TaskConstMeta(
+        debugName: "is_pdf_supported",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiLanguageIsRtlScript({required String script}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(script, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 81, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiLanguageIsRtlScriptConstMeta,
+      argValues: [script],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLanguageIsRtlScriptConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_rtl_script",
+        argNames: ["script"],
+      );
+
+  @override
+  Future<List<PdfFontInfo>> crateApiPdfListPdfFonts({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 82, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_font_info,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfListPdfFontsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfListPdfFontsConstMeta => const TaskConstMeta(
+        debugName: "list_pdf_fonts",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<PdfLayer>> crateApiPdfListPdfLayers({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 83, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_layer,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfListPdfLayersConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfListPdfLayersConstMeta => const TaskConstMeta(
+        debugName: "list_pdf_layers",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiPdfLockPdf({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 84, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPdfLockPdfConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfLockPdfConstMeta => const TaskConstMeta(
+        debugName: "lock_pdf",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<(int, int)> crateApiTtsTextNormalizedRangeToRaw(
+      {required TextHighlightData data, required int start, required int end}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_text_highlight_data(data, serializer);
+        sse_encode_u_32(start, serializer);
+        sse_encode_u_32(end, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 85, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_u_32_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTtsTextNormalizedRangeToRawConstMeta,
+      argValues: [data, start, end],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextNormalizedRangeToRawConstMeta =>
+      const TaskConstMeta(
+        debugName: "normalized_range_to_raw",
+        argNames: ["data", "start", "end"],
+      );
+
+  @override
+  Future<TextHighlightData> crateApiTtsTextPrecomputeTextHighlights(
+      {required String text}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(text, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 86, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_text_highlight_data,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTtsTextPrecomputeTextHighlightsConstMeta,
+      argValues: [text],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextPrecomputeTextHighlightsConstMeta =>
+      const TaskConstMeta(
+        debugName: "precompute_text_highlights",
+        argNames: ["text"],
+      );
+
+  @override
+  Future<String> crateApiDocxReadDocxToHtml({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 87, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiDocxReadDocxToHtmlConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDocxReadDocxToHtmlConstMeta => const TaskConstMeta(
+        debugName: "read_docx_to_html",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiDocxReadDocxToHtmlWithImages(
+      {required String path, required DocxImageMode imageMode}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_box_autoadd_docx_image_mode(imageMode, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 88, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiDocxReadDocxToHtmlWithImagesConstMeta,
+      argValues: [path, imageMode],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDocxReadDocxToHtmlWithImagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_docx_to_html_with_images",
+        argNames: ["path", "imageMode"],
+      );
+
+  @override
+  Future<ResourceBytes> crateApiEpubReadEpubResource(
+      {required String path, required String href, required String baseHref}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(href, serializer);
+        sse_encode_String(baseHref, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 89, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_resource_bytes,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubReadEpubResourceConstMeta,
+      argValues: [path, href, baseHref],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubReadEpubResourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_epub_resource",
+        argNames: ["path", "href", "baseHref"],
+      );
+
+  @override
+  Future<String> crateApiFb2ReadFb2ToHtml({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 90, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiFb2ReadFb2ToHtmlConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFb2ReadFb2ToHtmlConstMeta => const TaskConstMeta(
+        debugName: "read_fb2_to_html",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiTxtReadTxtToHtml({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 91, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTxtReadTxtToHtmlConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTxtReadTxtToHtmlConstMeta => const TaskConstMeta(
+        debugName: "read_txt_to_html",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiPdfReflowPdfPage(
+      {required String path, required int pageIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 92, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfReflowPdfPageConstMeta,
+      argValues: [path, pageIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfReflowPdfPageConstMeta => const TaskConstMeta(
+        debugName: "reflow_pdf_page",
+        argNames: ["path", "pageIndex"],
+      );
+
+  @override
+  Future<RenderedPage> crateApiFormatRenderPage(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required RenderOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_box_autoadd_render_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 93, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_rendered_page,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiFormatRenderPageConstMeta,
+      argValues: [path, pageIndex, width, height, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFormatRenderPageConstMeta => const TaskConstMeta(
+        debugName: "render_page",
+        argNames: ["path", "pageIndex", "width", "height", "options"],
+      );
+
+  @override
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPage(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 94, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_page_render_result,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfPageConstMeta,
+      argValues: [path, pageIndex, width, height],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfPageConstMeta => const TaskConstMeta(
+        debugName: "render_pdf_page",
+        argNames: ["path", "pageIndex", "width", "height"],
+      );
+
+  @override
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageFmt(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required PdfImageFormat format,
+      int? rotationOverride,
+      BigInt? operationId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_box_autoadd_pdf_image_format(format, serializer);
+        sse_encode_opt_box_autoadd_i_32(rotationOverride, serializer);
+        sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 95, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_page_render_result,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfPageFmtConstMeta,
+      argValues: [
+        path,
+        pageIndex,
+        width,
+        height,
+        format,
+        rotationOverride,
+        operationId
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfPageFmtConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_page_fmt",
+        argNames: [
+          "path",
+          "pageIndex",
+          "width",
+          "height",
+          "format",
+          "rotationOverride",
+          "operationId"
+        ],
+      );
+
+  @override
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageRegion(
+      {required String path,
+      required int pageIndex,
+      required PdfTextRect region,
+      required int outputWidth,
+      required int outputHeight}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_box_autoadd_pdf_text_rect(region, serializer);
+        sse_encode_u_32(outputWidth, serializer);
+        sse_encode_u_32(outputHeight, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 96, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_page_render_result,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfPageRegionConstMeta,
+      argValues: [path, pageIndex, region, outputWidth, outputHeight],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfPageRegionConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_page_region",
+        argNames: [
+          "path",
+          "pageIndex",
+          "region",
+          "outputWidth",
+          "outputHeight"
+        ],
+      );
+
+  @override
+  Future<List<PdfTile>> crateApiPdfRenderPdfPageTiles(
+      {required String path,
+      required int pageIndex,
+      required double scale,
+      required int tileSize,
+      required PdfTextRect viewport}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_f_32(scale, serializer);
+        sse_encode_u_32(tileSize, serializer);
+        sse_encode_box_autoadd_pdf_text_rect(viewport, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 97, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_tile,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfPageTilesConstMeta,
+      argValues: [path, pageIndex, scale, tileSize, viewport],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfPageTilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_page_tiles",
+        argNames: ["path", "pageIndex", "scale", "tileSize", "viewport"],
+      );
+
+  @override
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageWithHighlights(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required List<PdfTextRect> rects,
+      required int color}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_list_pdf_text_rect(rects, serializer);
+        sse_encode_u_32(color, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 98, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_page_render_result,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfPageWithHighlightsConstMeta,
+      argValues: [path, pageIndex, width, height, rects, color],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfPageWithHighlightsConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_page_with_highlights",
+        argNames: ["path", "pageIndex", "width", "height", "rects", "color"],
+      );
+
+  @override
+  Future<PdfPageRenderResult> crateApiPdfRenderPdfPageWithLayers(
+      {required String path,
+      required int pageIndex,
+      required int width,
+      required int height,
+      required List<PdfLayer> layers}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_list_pdf_layer(layers, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 99, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pdf_page_render_result,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfPageWithLayersConstMeta,
+      argValues: [path, pageIndex, width, height, layers],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfPageWithLayersConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_page_with_layers",
+        argNames: ["path", "pageIndex", "width", "height", "layers"],
+      );
+
+  @override
+  Future<List<PdfPageRenderResult>> crateApiPdfRenderPdfThumbnails(
+      {required String path,
+      required int maxWidth,
+      (int, int)? pageRange,
+      BigInt? operationId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(maxWidth, serializer);
+        sse_encode_opt_box_autoadd_record_u_32_u_32(pageRange, serializer);
+        sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 100, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_page_render_result,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfRenderPdfThumbnailsConstMeta,
+      argValues: [path, maxWidth, pageRange, operationId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfRenderPdfThumbnailsConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_thumbnails",
+        argNames: ["path", "maxWidth", "pageRange", "operationId"],
+      );
+
+  @override
+  Future<ScanReport> crateApiLibraryScanLibrary(
+      {required String rootPath,
+      required bool readMetadata,
+      ScanOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootPath, serializer);
+        sse_encode_bool(readMetadata, serializer);
+        sse_encode_opt_box_autoadd_scan_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 101, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_report,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiLibraryScanLibraryConstMeta,
+      argValues: [rootPath, readMetadata, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLibraryScanLibraryConstMeta => const TaskConstMeta(
+        debugName: "scan_library",
+        argNames: ["rootPath", "readMetadata", "options"],
+      );
+
+  @override
+  Future<ScanDelta> crateApiLibraryScanLibraryIncremental(
+      {required String rootPath,
+      required List<KnownFile> known,
+      ScanOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootPath, serializer);
+        sse_encode_list_known_file(known, serializer);
+        sse_encode_opt_box_autoadd_scan_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 102, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_delta,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiLibraryScanLibraryIncrementalConstMeta,
+      argValues: [rootPath, known, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLibraryScanLibraryIncrementalConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_library_incremental",
+        argNames: ["rootPath", "known", "options"],
+      );
+
+  @override
+  Stream<ScanEvent> crateApiLibraryScanLibraryWithProgress(
+      {required String rootPath,
+      required bool readMetadata,
+      ScanOptions? options}) {
+    final sink = RustStreamSink<ScanEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootPath, serializer);
+        sse_encode_bool(readMetadata, serializer);
+        sse_encode_opt_box_autoadd_scan_options(options, serializer);
+        sse_encode_StreamSink_scan_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 103, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiLibraryScanLibraryWithProgressConstMeta,
+      argValues: [rootPath, readMetadata, options, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLibraryScanLibraryWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_library_with_progress",
+        argNames: ["rootPath", "readMetadata", "options", "sink"],
+      );
+
+  @override
+  Future<List<EpubSearchHit>> crateApiEpubSearchEpub(
+      {required String path,
+      required String query,
+      required bool caseSensitive}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_bool(caseSensitive, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 104, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_epub_search_hit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiEpubSearchEpubConstMeta,
+      argValues: [path, query, caseSensitive],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEpubSearchEpubConstMeta => const TaskConstMeta(
+        debugName: "search_epub",
+        argNames: ["path", "query", "caseSensitive"],
+      );
+
+  @override
+  Future<List<PdfSearchHit>> crateApiPdfSearchPdf(
+      {required String path,
+      required String query,
+      required bool caseSensitive,
+      BigInt? operationId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_bool(caseSensitive, serializer);
+        sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 105, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pdf_search_hit,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfSearchPdfConstMeta,
+      argValues: [path, query, caseSensitive, operationId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfSearchPdfConstMeta => const TaskConstMeta(
+        debugName: "search_pdf",
+        argNames: ["path", "query", "caseSensitive", "operationId"],
+      );
+
+  @override
+  Future<void> crateApiCbzSetCbzCacheCapacity({required int capacity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(capacity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 106, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCbzSetCbzCacheCapacityConstMeta,
+      argValues: [capacity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzSetCbzCacheCapacityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cbz_cache_capacity",
+        argNames: ["capacity"],
+      );
+
+  @override
+  Future<void> crateApiPdfSetEnforcePdfPermissions({required bool enforce}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enforce, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 107, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPdfSetEnforcePdfPermissionsConstMeta,
+      argValues: [enforce],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfSetEnforcePdfPermissionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_enforce_pdf_permissions",
+        argNames: ["enforce"],
+      );
+
+  @override
+  Future<void> crateApiProfilingSetProfilingEnabled({required bool enabled}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 108, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiProfilingSetProfilingEnabledConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiProfilingSetProfilingEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_profiling_enabled",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiTtsTextSetSpelledNumberMax({required BigInt max}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(max, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 109, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTtsTextSetSpelledNumberMaxConstMeta,
+      argValues: [max],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextSetSpelledNumberMaxConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_spelled_number_max",
+        argNames: ["max"],
+      );
+
+  @override
+  Future<void> crateApiProfilingSetTimedThresholdMs(
+      {required BigInt thresholdMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(thresholdMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 110, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiProfilingSetTimedThresholdMsConstMeta,
+      argValues: [thresholdMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiProfilingSetTimedThresholdMsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_timed_threshold_ms",
+        argNames: ["thresholdMs"],
+      );
+
+  @override
+  Stream<CbzPageEvent> crateApiCbzStreamCbzPages(
+      {required String path, int? maxWidth}) {
+    final sink = RustStreamSink<CbzPageEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_opt_box_autoadd_i_32(maxWidth, serializer);
+        sse_encode_StreamSink_cbz_page_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 111, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzStreamCbzPagesConstMeta,
+      argValues: [path, maxWidth, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCbzStreamCbzPagesConstMeta => const TaskConstMeta(
+        debugName: "stream_cbz_pages",
+        argNames: ["path", "maxWidth", "sink"],
+      );
+
+  @override
+  Stream<PdfSearchEvent> crateApiPdfStreamPdfSearch(
+      {required String path,
+      required String query,
+      required bool caseSensitive,
+      required int startPage,
+      BigInt? operationId}) {
+    final sink = RustStreamSink<PdfSearchEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_bool(caseSensitive, serializer);
+        sse_encode_u_32(startPage, serializer);
+        sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+        sse_encode_StreamSink_pdf_search_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 112, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfStreamPdfSearchConstMeta,
+      argValues: [path, query, caseSensitive, startPage, operationId, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiPdfStreamPdfSearchConstMeta => const TaskConstMeta(
+        debugName: "stream_pdf_search",
+        argNames: [
+          "path",
+          "query",
+          "caseSensitive",
+          "startPage",
+          "operationId",
+          "sink"
+        ],
+      );
+
+  @override
+  Future<List<TimedSample>> crateApiProfilingTakeProfilingSamples() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 113, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_timed_sample,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiProfilingTakeProfilingSamplesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiProfilingTakeProfilingSamplesConstMeta =>
+      const TaskConstMeta(
+        debugName: "take_profiling_samples",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiPdfTestPdfModule() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 114, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPdfTestPdfModuleConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfTestPdfModuleConstMeta => const TaskConstMeta(
+        debugName: "test_pdf_module",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiTtsTextTestTtsTextModule() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 115, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTtsTextTestTtsTextModuleConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextTestTtsTextModuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_tts_text_module",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiTtsTextToSsml(
+      {required TextHighlightData data, String? voice}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_text_highlight_data(data, serializer);
+        sse_encode_opt_String(voice, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 116, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTtsTextToSsmlConstMeta,
+      argValues: [data, voice],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTtsTextToSsmlConstMeta => const TaskConstMeta(
+        debugName: "to_ssml",
+        argNames: ["data", "voice"],
+      );
+
+  @override
+  Future<void> crateApiPdfUnlockPdf(
+      {required String path, required String password}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(password, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 117, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_ferrous_error,
+      ),
+      constMeta: kCrateApiPdfUnlockPdfConstMeta,
+      argValues: [path, password],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPdfUnlockPdfConstMeta => const TaskConstMeta(
+        debugName: "unlock_pdf",
+        argNames: ["path", "password"],
+      );
+
+  @override
+  Future<List<(String, bool)>> crateApiCbzVerifyCbzIntegrity(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 118, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_record_string_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCbzVerifyCbzIntegrityConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCbzVerifyCbzIntegrityConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_cbz_integrity",
+        argNames: ["path"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<CbzPageEvent> dco_decode_StreamSink_cbz_page_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ImportEvent> dco_decode_StreamSink_import_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PdfSearchEvent> dco_decode_StreamSink_pdf_search_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  BackgroundKind dco_decode_background_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BackgroundKind.values[raw as int];
+  }
+
+  @protected
+  BookFormat dco_decode_book_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BookFormat.values[raw as int];
+  }
+
+  @protected
+  BookMetadata dco_decode_book_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return BookMetadata(
+      title: dco_decode_String(arr[0]),
+      author: dco_decode_String(arr[1]),
+      path: dco_decode_String(arr[2]),
+      sizeBytes: dco_decode_u_64(arr[3]),
+      modifiedEpoch: dco_decode_i_64(arr[4]),
+      format: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CbzPageData dco_decode_box_autoadd_cbz_page_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cbz_page_data(raw);
+  }
+
+  @protected
+  ComicInfo dco_decode_box_autoadd_comic_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_comic_info(raw);
+  }
+
+  @protected
+  CoverFormat dco_decode_box_autoadd_cover_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cover_format(raw);
+  }
+
+  @protected
+  CoverOptions dco_decode_box_autoadd_cover_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cover_options(raw);
+  }
+
+  @protected
+  DocxImageMode dco_decode_box_autoadd_docx_image_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_docx_image_mode(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  ImportSummary dco_decode_box_autoadd_import_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_summary(raw);
+  }
+
+  @protected
+  ImportedBook dco_decode_box_autoadd_imported_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_imported_book(raw);
+  }
+
+  @protected
+  PdfImageFormat dco_decode_box_autoadd_pdf_image_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pdf_image_format(raw);
+  }
+
+  @protected
+  PdfSearchHit dco_decode_box_autoadd_pdf_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pdf_search_hit(raw);
+  }
+
+  @protected
+  PdfTextRect dco_decode_box_autoadd_pdf_text_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pdf_text_rect(raw);
+  }
+
+  @protected
+  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_record_u_32_u_32(raw);
+  }
+
+  @protected
+  RenderOptions dco_decode_box_autoadd_render_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_render_options(raw);
+  }
+
+  @protected
+  ScanOptions dco_decode_box_autoadd_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_options(raw);
+  }
+
+  @protected
+  ScanProgress dco_decode_box_autoadd_scan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_progress(raw);
+  }
+
+  @protected
+  ScanReport dco_decode_box_autoadd_scan_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_report(raw);
+  }
+
+  @protected
+  SentenceSpan dco_decode_box_autoadd_sentence_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sentence_span(raw);
+  }
+
+  @protected
+  TextHighlightData dco_decode_box_autoadd_text_highlight_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_text_highlight_data(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  CbzPageData dco_decode_cbz_page_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CbzPageData(
+      width: dco_decode_i_32(arr[0]),
+      height: dco_decode_i_32(arr[1]),
+      rgbaBytes: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  CbzPageEvent dco_decode_cbz_page_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CbzPageEvent_Page(
+          index: dco_decode_i_32(raw[1]),
+          page: dco_decode_box_autoadd_cbz_page_data(raw[2]),
+        );
+      case 1:
+        return CbzPageEvent_Done(
+          pageCount: dco_decode_i_32(raw[1]),
+          failed: dco_decode_list_prim_i_32_strict(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CbzPageInfo dco_decode_cbz_page_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CbzPageInfo(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      isWide: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  CbzPageNames dco_decode_cbz_page_names(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CbzPageNames(
+      names: dco_decode_list_String(arr[0]),
+      pageCount: dco_decode_i_32(arr[1]),
+      skipped: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  CbzPageWithBackground dco_decode_cbz_page_with_background(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CbzPageWithBackground(
+      page: dco_decode_cbz_page_data(arr[0]),
+      backgroundColor: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ChapterEntry dco_decode_chapter_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChapterEntry(
+      title: dco_decode_String(arr[0]),
+      level: dco_decode_u_32(arr[1]),
+      location: dco_decode_chapter_location(arr[2]),
+    );
+  }
+
+  @protected
+  ChapterLocation dco_decode_chapter_location(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ChapterLocation_Page(
+          index: dco_decode_u_32(raw[1]),
+        );
+      case 1:
+        return ChapterLocation_Section(
+          index: dco_decode_u_32(raw[1]),
+          offset: dco_decode_u_32(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ComicInfo dco_decode_comic_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ComicInfo(
+      series: dco_decode_opt_String(arr[0]),
+      number: dco_decode_opt_String(arr[1]),
+      title: dco_decode_opt_String(arr[2]),
+      writer: dco_decode_opt_String(arr[3]),
+      pageCount: dco_decode_opt_box_autoadd_i_32(arr[4]),
+      manga: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  CoverFormat dco_decode_cover_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CoverFormat_Png();
+      case 1:
+        return CoverFormat_Jpeg(
+          quality: dco_decode_u_8(raw[1]),
+        );
+      case 2:
+        return CoverFormat_Webp();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CoverOptions dco_decode_cover_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CoverOptions(
+      maxDim: dco_decode_u_32(arr[0]),
+      format: dco_decode_cover_format(arr[1]),
+    );
+  }
+
+  @protected
+  CropMargins dco_decode_crop_margins(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CropMargins(
+      top: dco_decode_f_32(arr[0]),
+      bottom: dco_decode_f_32(arr[1]),
+      left: dco_decode_f_32(arr[2]),
+      right: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  DocxImageMode dco_decode_docx_image_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DocxImageMode_DataUri();
+      case 1:
+        return DocxImageMode_AssetsDir(
+          assetsDir: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DocxMetadata dco_decode_docx_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DocxMetadata(
+      title: dco_decode_String(arr[0]),
+      creator: dco_decode_String(arr[1]),
+      subject: dco_decode_String(arr[2]),
+      description: dco_decode_String(arr[3]),
+      lastModifiedBy: dco_decode_String(arr[4]),
+      created: dco_decode_String(arr[5]),
+      modified: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  EncodedImage dco_decode_encoded_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EncodedImage(
+      width: dco_decode_i_32(arr[0]),
+      height: dco_decode_i_32(arr[1]),
+      bytes: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  EpubChapter dco_decode_epub_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EpubChapter(
+      id: dco_decode_String(arr[0]),
+      href: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      order: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  EpubLayout dco_decode_epub_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EpubLayout.values[raw as int];
+  }
+
+  @protected
+  EpubMetadata dco_decode_epub_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return EpubMetadata(
+      title: dco_decode_opt_String(arr[0]),
+      creators: dco_decode_list_String(arr[1]),
+      language: dco_decode_opt_String(arr[2]),
+      identifier: dco_decode_opt_String(arr[3]),
+      publisher: dco_decode_opt_String(arr[4]),
+      date: dco_decode_opt_String(arr[5]),
+      description: dco_decode_opt_String(arr[6]),
+      subjects: dco_decode_list_String(arr[7]),
+    );
+  }
+
+  @protected
+  EpubRendition dco_decode_epub_rendition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpubRendition(
+      layout: dco_decode_epub_layout(arr[0]),
+      spine: dco_decode_list_epub_spine_layout(arr[1]),
+    );
+  }
+
+  @protected
+  EpubSearchHit dco_decode_epub_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EpubSearchHit(
+      chapterHref: dco_decode_String(arr[0]),
+      charStart: dco_decode_u_32(arr[1]),
+      charEnd: dco_decode_u_32(arr[2]),
+      snippet: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  EpubSpineLayout dco_decode_epub_spine_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpubSpineLayout(
+      href: dco_decode_String(arr[0]),
+      layout: dco_decode_epub_layout(arr[1]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FerrousError dco_decode_ferrous_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FerrousError_FileMissing(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return FerrousError_Empty(
+          dco_decode_String(raw[1]),
+        );
+      case 2:
+        return FerrousError_BadHeader(
+          dco_decode_String(raw[1]),
+        );
+      case 3:
+        return FerrousError_PasswordRequired(
+          dco_decode_String(raw[1]),
+        );
+      case 4:
+        return FerrousError_Corrupt(
+          dco_decode_String(raw[1]),
+        );
+      case 5:
+        return FerrousError_Security(
+          dco_decode_String(raw[1]),
+        );
+      case 6:
+        return FerrousError_PageError(
+          dco_decode_String(raw[1]),
+        );
+      case 7:
+        return FerrousError_PageOutOfRange();
+      case 8:
+        return FerrousError_PageLoad(
+          pageIndex: dco_decode_u_32(raw[1]),
+          detail: dco_decode_String(raw[2]),
+        );
+      case 9:
+        return FerrousError_PermissionDenied(
+          dco_decode_String(raw[1]),
+        );
+      case 10:
+        return FerrousError_Unsupported(
+          dco_decode_String(raw[1]),
+        );
+      case 11:
+        return FerrousError_InvalidArgument(
+          dco_decode_String(raw[1]),
+        );
+      case 12:
+        return FerrousError_Cancelled();
+      case 13:
+        return FerrousError_Pdfium(
+          dco_decode_String(raw[1]),
+        );
+      case 14:
+        return FerrousError_Image(
+          dco_decode_String(raw[1]),
+        );
+      case 15:
+        return FerrousError_Io(
+          dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  HighlightRange dco_decode_highlight_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HighlightRange(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      tagName: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  HtmlHighlightData dco_decode_html_highlight_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HtmlHighlightData(
+      words: dco_decode_list_html_word_span(arr[0]),
+      sentences: dco_decode_list_sentence_span(arr[1]),
+      paragraphs: dco_decode_list_paragraph_span(arr[2]),
+      normalizedText: dco_decode_String(arr[3]),
+      textNodeCount: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  HtmlWordSpan dco_decode_html_word_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HtmlWordSpan(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      text: dco_decode_String(arr[2]),
+      nodeIndex: dco_decode_u_32(arr[3]),
+      nodeOffset: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImportEvent dco_decode_import_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ImportEvent_Book(
+          dco_decode_box_autoadd_imported_book(raw[1]),
+        );
+      case 1:
+        return ImportEvent_Finished(
+          dco_decode_box_autoadd_import_summary(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ImportFailure dco_decode_import_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportFailure(
+      path: dco_decode_String(arr[0]),
+      reason: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ImportSummary dco_decode_import_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ImportSummary(
+      imported: dco_decode_u_32(arr[0]),
+      failed: dco_decode_list_import_failure(arr[1]),
+      cancelled: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ImportedBook dco_decode_imported_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportedBook(
+      metadata: dco_decode_book_metadata(arr[0]),
+      coverPath: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  KnownFile dco_decode_known_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return KnownFile(
+      path: dco_decode_String(arr[0]),
+      modifiedEpoch: dco_decode_i_64(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BookMetadata> dco_decode_list_book_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_metadata).toList();
+  }
+
+  @protected
+  List<ChapterEntry> dco_decode_list_chapter_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter_entry).toList();
+  }
+
+  @protected
+  List<CropMargins> dco_decode_list_crop_margins(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_crop_margins).toList();
+  }
+
+  @protected
+  List<EpubChapter> dco_decode_list_epub_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_chapter).toList();
+  }
+
+  @protected
+  List<EpubSearchHit> dco_decode_list_epub_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_search_hit).toList();
+  }
+
+  @protected
+  List<EpubSpineLayout> dco_decode_list_epub_spine_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_spine_layout).toList();
+  }
+
+  @protected
+  List<HighlightRange> dco_decode_list_highlight_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_highlight_range).toList();
+  }
+
+  @protected
+  List<HtmlWordSpan> dco_decode_list_html_word_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_html_word_span).toList();
+  }
+
+  @protected
+  List<ImportFailure> dco_decode_list_import_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_import_failure).toList();
+  }
+
+  @protected
+  List<KnownFile> dco_decode_list_known_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_known_file).toList();
+  }
+
+  @protected
+  List<MediaClip> dco_decode_list_media_clip(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_clip).toList();
+  }
+
+  @protected
+  List<MobiChapter> dco_decode_list_mobi_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mobi_chapter).toList();
+  }
+
+  @protected
+  List<PageSize> dco_decode_list_page_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_page_size).toList();
+  }
+
+  @protected
+  List<ParagraphSpan> dco_decode_list_paragraph_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_paragraph_span).toList();
+  }
+
+  @protected
+  List<PdfAnnotation> dco_decode_list_pdf_annotation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_annotation).toList();
+  }
+
+  @protected
+  List<PdfFontInfo> dco_decode_list_pdf_font_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_font_info).toList();
+  }
+
+  @protected
+  List<PdfFormField> dco_decode_list_pdf_form_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_form_field).toList();
+  }
+
+  @protected
+  List<PdfImage> dco_decode_list_pdf_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_image).toList();
+  }
+
+  @protected
+  List<PdfLayer> dco_decode_list_pdf_layer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_layer).toList();
+  }
+
+  @protected
+  List<PdfLink> dco_decode_list_pdf_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_link).toList();
+  }
+
+  @protected
+  List<PdfOutlineItem> dco_decode_list_pdf_outline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_outline_item).toList();
+  }
+
+  @protected
+  List<PdfPageRenderResult> dco_decode_list_pdf_page_render_result(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_page_render_result).toList();
+  }
+
+  @protected
+  List<PdfSearchHit> dco_decode_list_pdf_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_search_hit).toList();
+  }
+
+  @protected
+  List<PdfTextRect> dco_decode_list_pdf_text_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_text_rect).toList();
+  }
+
+  @protected
+  List<PdfTile> dco_decode_list_pdf_tile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_tile).toList();
+  }
+
+  @protected
+  List<PdfWordRect> dco_decode_list_pdf_word_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_word_rect).toList();
+  }
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int32List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, bool)> dco_decode_list_record_string_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_bool).toList();
+  }
+
+  @protected
+  List<(int, Uint8List)> dco_decode_list_record_u_32_list_prim_u_8_strict(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_u_32_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  List<ScanError> dco_decode_list_scan_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scan_error).toList();
+  }
+
+  @protected
+  List<SentenceSpan> dco_decode_list_sentence_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sentence_span).toList();
+  }
+
+  @protected
+  List<TimedSample> dco_decode_list_timed_sample(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_timed_sample).toList();
+  }
+
+  @protected
+  List<TocEntry> dco_decode_list_toc_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_toc_entry).toList();
+  }
+
+  @protected
+  List<WordSpan> dco_decode_list_word_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_word_span).toList();
+  }
+
+  @protected
+  MediaClip dco_decode_media_clip(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MediaClip(
+      textFragmentId: dco_decode_String(arr[0]),
+      audioHref: dco_decode_String(arr[1]),
+      clipBegin: dco_decode_f_64(arr[2]),
+      clipEnd: dco_decode_opt_box_autoadd_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  MobiChapter dco_decode_mobi_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MobiChapter(
+      title: dco_decode_String(arr[0]),
+      htmlContent: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  MobiMetadata dco_decode_mobi_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MobiMetadata(
+      title: dco_decode_String(arr[0]),
+      author: dco_decode_opt_String(arr[1]),
+      publisher: dco_decode_opt_String(arr[2]),
+      language: dco_decode_opt_String(arr[3]),
+      isbn: dco_decode_opt_String(arr[4]),
+      published: dco_decode_opt_String(arr[5]),
+      description: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ComicInfo? dco_decode_opt_box_autoadd_comic_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_comic_info(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  (int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_record_u_32_u_32(raw);
+  }
+
+  @protected
+  ScanOptions? dco_decode_opt_box_autoadd_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_scan_options(raw);
+  }
+
+  @protected
+  SentenceSpan? dco_decode_opt_box_autoadd_sentence_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sentence_span(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  PageSize dco_decode_page_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PageSize(
+      widthPts: dco_decode_f_32(arr[0]),
+      heightPts: dco_decode_f_32(arr[1]),
+      rotationDegrees: dco_decode_u_16(arr[2]),
+    );
+  }
+
+  @protected
+  ParagraphSpan dco_decode_paragraph_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ParagraphSpan(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  PdfAnnotation dco_decode_pdf_annotation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PdfAnnotation(
+      kind: dco_decode_pdf_annotation_kind(arr[0]),
+      rect: dco_decode_pdf_text_rect(arr[1]),
+      contents: dco_decode_String(arr[2]),
+      color: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  PdfAnnotationKind dco_decode_pdf_annotation_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PdfAnnotationKind.values[raw as int];
+  }
+
+  @protected
+  PdfFontInfo dco_decode_pdf_font_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PdfFontInfo(
+      name: dco_decode_String(arr[0]),
+      fontType: dco_decode_String(arr[1]),
+      isEmbedded: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  PdfFormField dco_decode_pdf_form_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PdfFormField(
+      name: dco_decode_String(arr[0]),
+      kind: dco_decode_pdf_form_field_kind(arr[1]),
+      value: dco_decode_String(arr[2]),
+      pageIndex: dco_decode_u_32(arr[3]),
+      rect: dco_decode_pdf_text_rect(arr[4]),
+    );
+  }
+
+  @protected
+  PdfFormFieldKind dco_decode_pdf_form_field_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PdfFormFieldKind.values[raw as int];
+  }
+
+  @protected
+  PdfImage dco_decode_pdf_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PdfImage(
+      bytes: dco_decode_list_prim_u_8_strict(arr[0]),
+      format: dco_decode_String(arr[1]),
+      rect: dco_decode_pdf_text_rect(arr[2]),
+    );
+  }
+
+  @protected
+  PdfImageFormat dco_decode_pdf_image_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PdfImageFormat_Png();
+      case 1:
+        return PdfImageFormat_Jpeg(
+          quality: dco_decode_u_8(raw[1]),
+        );
+      case 2:
+        return PdfImageFormat_RawRgba();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PdfLayer dco_decode_pdf_layer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PdfLayer(
+      name: dco_decode_String(arr[0]),
+      visible: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  PdfLink dco_decode_pdf_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PdfLink(
+      rect: dco_decode_pdf_text_rect(arr[0]),
+      kind: dco_decode_pdf_link_kind(arr[1]),
+    );
+  }
+
+  @protected
+  PdfLinkKind dco_decode_pdf_link_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PdfLinkKind_Uri(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return PdfLinkKind_Page(
+          dco_decode_u_32(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PdfMetadata dco_decode_pdf_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PdfMetadata(
+      title: dco_decode_String(arr[0]),
+      author: dco_decode_String(arr[1]),
+      subject: dco_decode_String(arr[2]),
+      keywords: dco_decode_String(arr[3]),
+      creator: dco_decode_String(arr[4]),
+      producer: dco_decode_String(arr[5]),
+      pageCount: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  PdfOutlineItem dco_decode_pdf_outline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PdfOutlineItem(
+      title: dco_decode_String(arr[0]),
+      pageIndex: dco_decode_u_32(arr[1]),
+      depth: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  PdfPageRenderResult dco_decode_pdf_page_render_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PdfPageRenderResult(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  PdfPermissions dco_decode_pdf_permissions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PdfPermissions(
+      canPrint: dco_decode_bool(arr[0]),
+      canCopy: dco_decode_bool(arr[1]),
+      canModify: dco_decode_bool(arr[2]),
+      canAnnotate: dco_decode_bool(arr[3]),
+      canFillForms: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  PdfSearchEvent dco_decode_pdf_search_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PdfSearchEvent_Hit(
+          hit: dco_decode_box_autoadd_pdf_search_hit(raw[1]),
+          contextBefore: dco_decode_String(raw[2]),
+          contextAfter: dco_decode_String(raw[3]),
+          totalMatches: dco_decode_u_32(raw[4]),
+        );
+      case 1:
+        return PdfSearchEvent_Finished(
+          totalMatches: dco_decode_u_32(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PdfSearchHit dco_decode_pdf_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PdfSearchHit(
+      pageIndex: dco_decode_u_32(arr[0]),
+      charStart: dco_decode_u_32(arr[1]),
+      charEnd: dco_decode_u_32(arr[2]),
+      rects: dco_decode_list_pdf_text_rect(arr[3]),
+    );
+  }
+
+  @protected
+  PdfTextRect dco_decode_pdf_text_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PdfTextRect(
+      left: dco_decode_f_32(arr[0]),
+      top: dco_decode_f_32(arr[1]),
+      right: dco_decode_f_32(arr[2]),
+      bottom: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  PdfTile dco_decode_pdf_tile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PdfTile(
+      row: dco_decode_u_32(arr[0]),
+      col: dco_decode_u_32(arr[1]),
+      x: dco_decode_u_32(arr[2]),
+      y: dco_decode_u_32(arr[3]),
+      width: dco_decode_u_32(arr[4]),
+      height: dco_decode_u_32(arr[5]),
+      bytes: dco_decode_list_prim_u_8_strict(arr[6]),
+    );
+  }
+
+  @protected
+  PdfWordRect dco_decode_pdf_word_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PdfWordRect(
+      text: dco_decode_String(arr[0]),
+      left: dco_decode_f_32(arr[1]),
+      top: dco_decode_f_32(arr[2]),
+      right: dco_decode_f_32(arr[3]),
+      bottom: dco_decode_f_32(arr[4]),
+    );
+  }
+
+  @protected
+  ReadingTime dco_decode_reading_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReadingTime(
+      wordCount: dco_decode_u_32(arr[0]),
+      minutes: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  (String, bool) dco_decode_record_string_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  (int, Uint8List) dco_decode_record_u_32_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_u_32(arr[0]),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (int, int) dco_decode_record_u_32_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_u_32(arr[0]),
+      dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  RenderOptions dco_decode_render_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return RenderOptions(
+      format: dco_decode_cover_format(arr[0]),
+    );
+  }
+
+  @protected
+  RenderedPage dco_decode_rendered_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RenderedPage(
+      bytes: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      format: dco_decode_cover_format(arr[3]),
+    );
+  }
+
+  @protected
+  ResourceBytes dco_decode_resource_bytes(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ResourceBytes(
+      bytes: dco_decode_list_prim_u_8_strict(arr[0]),
+      mediaType: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ScanDelta dco_decode_scan_delta(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanDelta(
+      added: dco_decode_list_book_metadata(arr[0]),
+      modified: dco_decode_list_book_metadata(arr[1]),
+      removed: dco_decode_list_String(arr[2]),
+      skipped: dco_decode_list_scan_error(arr[3]),
+    );
+  }
+
+  @protected
+  ScanError dco_decode_scan_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScanError(
+      path: dco_decode_String(arr[0]),
+      reason: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ScanEvent_Progress(
+          dco_decode_box_autoadd_scan_progress(raw[1]),
+        );
+      case 1:
+        return ScanEvent_Finished(
+          dco_decode_box_autoadd_scan_report(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ScanOptions dco_decode_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanOptions(
+      extensions: dco_decode_list_String(arr[0]),
+      maxDepth: dco_decode_opt_box_autoadd_usize(arr[1]),
+      followSymlinks: dco_decode_bool(arr[2]),
+      includeHidden: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  ScanProgress dco_decode_scan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ScanProgress(
+      scanned: dco_decode_u_32(arr[0]),
+      found: dco_decode_u_32(arr[1]),
+      currentPath: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ScanReport dco_decode_scan_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScanReport(
+      books: dco_decode_list_book_metadata(arr[0]),
+      skipped: dco_decode_list_scan_error(arr[1]),
+    );
+  }
+
+  @protected
+  SentenceSpan dco_decode_sentence_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SentenceSpan(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  TextHighlightData dco_decode_text_highlight_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TextHighlightData(
+      words: dco_decode_list_word_span(arr[0]),
+      sentences: dco_decode_list_sentence_span(arr[1]),
+      paragraphs: dco_decode_list_paragraph_span(arr[2]),
+      normalizedText: dco_decode_String(arr[3]),
+      normalizedToRaw: dco_decode_list_prim_u_32_strict(arr[4]),
+    );
+  }
+
+  @protected
+  TextUnit dco_decode_text_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TextUnit.values[raw as int];
+  }
+
+  @protected
+  TimedSample dco_decode_timed_sample(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TimedSample(
+      name: dco_decode_String(arr[0]),
+      millis: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  TocEntry dco_decode_toc_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TocEntry(
+      label: dco_decode_String(arr[0]),
+      href: dco_decode_String(arr[1]),
+      depth: dco_decode_u_32(arr[2]),
+      children: dco_decode_list_toc_entry(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WordSpan dco_decode_word_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WordSpan(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      text: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<CbzPageEvent> sse_decode_StreamSink_cbz_page_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ImportEvent> sse_decode_StreamSink_import_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PdfSearchEvent> sse_decode_StreamSink_pdf_search_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  BackgroundKind sse_decode_background_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BackgroundKind.values[inner];
+  }
+
+  @protected
+  BookFormat sse_decode_book_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BookFormat.values[inner];
+  }
+
+  @protected
+  BookMetadata sse_decode_book_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    var var_modifiedEpoch = sse_decode_i_64(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    return BookMetadata(
+        title: var_title,
+        author: var_author,
+        path: var_path,
+        sizeBytes: var_sizeBytes,
+        modifiedEpoch: var_modifiedEpoch,
+        format: var_format);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  CbzPageData sse_decode_box_autoadd_cbz_page_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cbz_page_data(deserializer));
+  }
+
+  @protected
+  ComicInfo sse_decode_box_autoadd_comic_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_comic_info(deserializer));
+  }
+
+  @protected
+  CoverFormat sse_decode_box_autoadd_cover_format(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cover_format(deserializer));
+  }
+
+  @protected
+  CoverOptions sse_decode_box_autoadd_cover_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cover_options(deserializer));
+  }
+
+  @protected
+  DocxImageMode sse_decode_box_autoadd_docx_image_mode(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_docx_image_mode(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ImportSummary sse_decode_box_autoadd_import_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_summary(deserializer));
+  }
+
+  @protected
+  ImportedBook sse_decode_box_autoadd_imported_book(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_imported_book(deserializer));
+  }
+
+  @protected
+  PdfImageFormat sse_decode_box_autoadd_pdf_image_format(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pdf_image_format(deserializer));
+  }
+
+  @protected
+  PdfSearchHit sse_decode_box_autoadd_pdf_search_hit(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pdf_search_hit(deserializer));
+  }
+
+  @protected
+  PdfTextRect sse_decode_box_autoadd_pdf_text_rect(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pdf_text_rect(deserializer));
+  }
+
+  @protected
+  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_record_u_32_u_32(deserializer));
+  }
+
+  @protected
+  RenderOptions sse_decode_box_autoadd_render_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_render_options(deserializer));
+  }
+
+  @protected
+  ScanOptions sse_decode_box_autoadd_scan_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_options(deserializer));
+  }
+
+  @protected
+  ScanProgress sse_decode_box_autoadd_scan_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_progress(deserializer));
+  }
+
+  @protected
+  ScanReport sse_decode_box_autoadd_scan_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_report(deserializer));
+  }
+
+  @protected
+  SentenceSpan sse_decode_box_autoadd_sentence_span(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sentence_span(deserializer));
+  }
+
+  @protected
+  TextHighlightData sse_decode_box_autoadd_text_highlight_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_text_highlight_data(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  CbzPageData sse_decode_cbz_page_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_i_32(deserializer);
+    var var_height = sse_decode_i_32(deserializer);
+    var var_rgbaBytes = sse_decode_list_prim_u_8_strict(deserializer);
+    return CbzPageData(
+        width: var_width, height: var_height, rgbaBytes: var_rgbaBytes);
+  }
+
+  @protected
+  CbzPageEvent sse_decode_cbz_page_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_index = sse_decode_i_32(deserializer);
+        var var_page = sse_decode_box_autoadd_cbz_page_data(deserializer);
+        return CbzPageEvent_Page(index: var_index, page: var_page);
+      case 1:
+        var var_pageCount = sse_decode_i_32(deserializer);
+        var var_failed = sse_decode_list_prim_i_32_strict(deserializer);
+        return CbzPageEvent_Done(pageCount: var_pageCount, failed: var_failed);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CbzPageInfo sse_decode_cbz_page_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_isWide = sse_decode_bool(deserializer);
+    return CbzPageInfo(
+        width: var_width, height: var_height, isWide: var_isWide);
+  }
+
+  @protected
+  CbzPageNames sse_decode_cbz_page_names(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_names = sse_decode_list_String(deserializer);
+    var var_pageCount = sse_decode_i_32(deserializer);
+    var var_skipped = sse_decode_list_String(deserializer);
+    return CbzPageNames(
+        names: var_names, pageCount: var_pageCount, skipped: var_skipped);
+  }
+
+  @protected
+  CbzPageWithBackground sse_decode_cbz_page_with_background(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_page = sse_decode_cbz_page_data(deserializer);
+    var var_backgroundColor = sse_decode_u_32(deserializer);
+    return CbzPageWithBackground(
+        page: var_page, backgroundColor: var_backgroundColor);
+  }
+
+  @protected
+  ChapterEntry sse_decode_chapter_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_level = sse_decode_u_32(deserializer);
+    var var_location = sse_decode_chapter_location(deserializer);
+    return ChapterEntry(
+        title: var_title, level: var_level, location: var_location);
+  }
+
+  @protected
+  ChapterLocation sse_decode_chapter_location(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_index = sse_decode_u_32(deserializer);
+        return ChapterLocation_Page(index: var_index);
+      case 1:
+        var var_index = sse_decode_u_32(deserializer);
+        var var_offset = sse_decode_u_32(deserializer);
+        return ChapterLocation_Section(index: var_index, offset: var_offset);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ComicInfo sse_decode_comic_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_series = sse_decode_opt_String(deserializer);
+    var var_number = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_writer = sse_decode_opt_String(deserializer);
+    var var_pageCount = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_manga = sse_decode_bool(deserializer);
+    return ComicInfo(
+        series: var_series,
+        number: var_number,
+        title: var_title,
+        writer: var_writer,
+        pageCount: var_pageCount,
+        manga: var_manga);
+  }
+
+  @protected
+  CoverFormat sse_decode_cover_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return CoverFormat_Png();
+      case 1:
+        var var_quality = sse_decode_u_8(deserializer);
+        return CoverFormat_Jpeg(quality: var_quality);
+      case 2:
+        return CoverFormat_Webp();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CoverOptions sse_decode_cover_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxDim = sse_decode_u_32(deserializer);
+    var var_format = sse_decode_cover_format(deserializer);
+    return CoverOptions(maxDim: var_maxDim, format: var_format);
+  }
+
+  @protected
+  CropMargins sse_decode_crop_margins(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_top = sse_decode_f_32(deserializer);
+    var var_bottom = sse_decode_f_32(deserializer);
+    var var_left = sse_decode_f_32(deserializer);
+    var var_right = sse_decode_f_32(deserializer);
+    return CropMargins(
+        top: var_top, bottom: var_bottom, left: var_left, right: var_right);
+  }
+
+  @protected
+  DocxImageMode sse_decode_docx_image_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return DocxImageMode_DataUri();
+      case 1:
+        var var_assetsDir = sse_decode_String(deserializer);
+        return DocxImageMode_AssetsDir(assetsDir: var_assetsDir);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DocxMetadata sse_decode_docx_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_creator = sse_decode_String(deserializer);
+    var var_subject = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_lastModifiedBy = sse_decode_String(deserializer);
+    var var_created = sse_decode_String(deserializer);
+    var var_modified = sse_decode_String(deserializer);
+    return DocxMetadata(
+        title: var_title,
+        creator: var_creator,
+        subject: var_subject,
+        description: var_description,
+        lastModifiedBy: var_lastModifiedBy,
+        created: var_created,
+        modified: var_modified);
+  }
+
+  @protected
+  EncodedImage sse_decode_encoded_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_i_32(deserializer);
+    var var_height = sse_decode_i_32(deserializer);
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    return EncodedImage(width: var_width, height: var_height, bytes: var_bytes);
+  }
+
+  @protected
+  EpubChapter sse_decode_epub_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_href = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_order = sse_decode_u_32(deserializer);
+    return EpubChapter(
+        id: var_id, href: var_href, title: var_title, order: var_order);
+  }
+
+  @protected
+  EpubLayout sse_decode_epub_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EpubLayout.values[inner];
+  }
+
+  @protected
+  EpubMetadata sse_decode_epub_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_creators = sse_decode_list_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_identifier = sse_decode_opt_String(deserializer);
+    var var_publisher = sse_decode_opt_String(deserializer);
+    var var_date = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_subjects = sse_decode_list_String(deserializer);
+    return EpubMetadata(
+        title: var_title,
+        creators: var_creators,
+        language: var_language,
+        identifier: var_identifier,
+        publisher: var_publisher,
+        date: var_date,
+        description: var_description,
+        subjects: var_subjects);
+  }
+
+  @protected
+  EpubRendition sse_decode_epub_rendition(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_layout = sse_decode_epub_layout(deserializer);
+    var var_spine = sse_decode_list_epub_spine_layout(deserializer);
+    return EpubRendition(layout: var_layout, spine: var_spine);
+  }
+
+  @protected
+  EpubSearchHit sse_decode_epub_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterHref = sse_decode_String(deserializer);
+    var var_charStart = sse_decode_u_32(deserializer);
+    var var_charEnd = sse_decode_u_32(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    return EpubSearchHit(
+        chapterHref: var_chapterHref,
+        charStart: var_charStart,
+        charEnd: var_charEnd,
+        snippet: var_snippet);
+  }
+
+  @protected
+  EpubSpineLayout sse_decode_epub_spine_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_href = sse_decode_String(deserializer);
+    var var_layout = sse_decode_epub_layout(deserializer);
+    return EpubSpineLayout(href: var_href, layout: var_layout);
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FerrousError sse_decode_ferrous_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_FileMissing(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Empty(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_BadHeader(var_field0);
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_PasswordRequired(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Corrupt(var_field0);
+      case 5:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Security(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_PageError(var_field0);
+      case 7:
+        return FerrousError_PageOutOfRange();
+      case 8:
+        var var_pageIndex = sse_decode_u_32(deserializer);
+        var var_detail = sse_decode_String(deserializer);
+        return FerrousError_PageLoad(
+            pageIndex: var_pageIndex, detail: var_detail);
+      case 9:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_PermissionDenied(var_field0);
+      case 10:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Unsupported(var_field0);
+      case 11:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_InvalidArgument(var_field0);
+      case 12:
+        return FerrousError_Cancelled();
+      case 13:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Pdfium(var_field0);
+      case 14:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Image(var_field0);
+      case 15:
+        var var_field0 = sse_decode_String(deserializer);
+        return FerrousError_Io(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  HighlightRange sse_decode_highlight_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_tagName = sse_decode_String(deserializer);
+    return HighlightRange(start: var_start, end: var_end, tagName: var_tagName);
+  }
+
+  @protected
+  HtmlHighlightData sse_decode_html_highlight_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_words = sse_decode_list_html_word_span(deserializer);
+    var var_sentences = sse_decode_list_sentence_span(deserializer);
+    var var_paragraphs = sse_decode_list_paragraph_span(deserializer);
+    var var_normalizedText = sse_decode_String(deserializer);
+    var var_textNodeCount = sse_decode_u_32(deserializer);
+    return HtmlHighlightData(
+        words: var_words,
+        sentences: var_sentences,
+        paragraphs: var_paragraphs,
+        normalizedText: var_normalizedText,
+        textNodeCount: var_textNodeCount);
+  }
+
+  @protected
+  HtmlWordSpan sse_decode_html_word_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_nodeIndex = sse_decode_u_32(deserializer);
+    var var_nodeOffset = sse_decode_u_32(deserializer);
+    return HtmlWordSpan(
+        start: var_start,
+        end: var_end,
+        text: var_text,
+        nodeIndex: var_nodeIndex,
+        nodeOffset: var_nodeOffset);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImportEvent sse_decode_import_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_imported_book(deserializer);
+        return ImportEvent_Book(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_import_summary(deserializer);
+        return ImportEvent_Finished(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ImportFailure sse_decode_import_failure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return ImportFailure(path: var_path, reason: var_reason);
+  }
+
+  @protected
+  ImportSummary sse_decode_import_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imported = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_list_import_failure(deserializer);
+    var var_cancelled = sse_decode_bool(deserializer);
+    return ImportSummary(
+        imported: var_imported, failed: var_failed, cancelled: var_cancelled);
+  }
+
+  @protected
+  ImportedBook sse_decode_imported_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_book_metadata(deserializer);
+    var var_coverPath = sse_decode_opt_String(deserializer);
+    return ImportedBook(metadata: var_metadata, coverPath: var_coverPath);
+  }
+
+  @protected
+  KnownFile sse_decode_known_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_modifiedEpoch = sse_decode_i_64(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    return KnownFile(
+        path: var_path, modifiedEpoch: var_modifiedEpoch, size: var_size);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookMetadata> sse_decode_list_book_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChapterEntry> sse_decode_list_chapter_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChapterEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CropMargins> sse_decode_list_crop_margins(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CropMargins>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_crop_margins(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubChapter> sse_decode_list_epub_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubSearchHit> sse_decode_list_epub_search_hit(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubSearchHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_search_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubSpineLayout> sse_decode_list_epub_spine_layout(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubSpineLayout>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_spine_layout(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HighlightRange> sse_decode_list_highlight_range(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HighlightRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_highlight_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HtmlWordSpan> sse_decode_list_html_word_span(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HtmlWordSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_html_word_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImportFailure> sse_decode_list_import_failure(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImportFailure>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_import_failure(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KnownFile> sse_decode_list_known_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KnownFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_known_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaClip> sse_decode_list_media_clip(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaClip>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_clip(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MobiChapter> sse_decode_list_mobi_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MobiChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mobi_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PageSize> sse_decode_list_page_size(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PageSize>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_page_size(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ParagraphSpan> sse_decode_list_paragraph_span(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ParagraphSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_paragraph_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfAnnotation> sse_decode_list_pdf_annotation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfAnnotation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_annotation(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfFontInfo> sse_decode_list_pdf_font_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfFontInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_font_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfFormField> sse_decode_list_pdf_form_field(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfFormField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_form_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfImage> sse_decode_list_pdf_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfImage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_image(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfLayer> sse_decode_list_pdf_layer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfLayer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_layer(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfLink> sse_decode_list_pdf_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfLink>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_link(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfOutlineItem> sse_decode_list_pdf_outline_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfOutlineItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_outline_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfPageRenderResult> sse_decode_list_pdf_page_render_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfPageRenderResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_page_render_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfSearchHit> sse_decode_list_pdf_search_hit(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfSearchHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_search_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfTextRect> sse_decode_list_pdf_text_rect(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfTextRect>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_text_rect(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfTile> sse_decode_list_pdf_tile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfTile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_tile(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfWordRect> sse_decode_list_pdf_word_rect(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfWordRect>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_word_rect(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, bool)> sse_decode_list_record_string_bool(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, bool)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_bool(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(int, Uint8List)> sse_decode_list_record_u_32_list_prim_u_8_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(int, Uint8List)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_u_32_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScanError> sse_decode_list_scan_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScanError>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scan_error(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SentenceSpan> sse_decode_list_sentence_span(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SentenceSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sentence_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimedSample> sse_decode_list_timed_sample(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimedSample>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_timed_sample(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TocEntry> sse_decode_list_toc_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TocEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_toc_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WordSpan> sse_decode_list_word_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WordSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_word_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MediaClip sse_decode_media_clip(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_textFragmentId = sse_decode_String(deserializer);
+    var var_audioHref = sse_decode_String(deserializer);
+    var var_clipBegin = sse_decode_f_64(deserializer);
+    var var_clipEnd = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return MediaClip(
+        textFragmentId: var_textFragmentId,
+        audioHref: var_audioHref,
+        clipBegin: var_clipBegin,
+        clipEnd: var_clipEnd);
+  }
+
+  @protected
+  MobiChapter sse_decode_mobi_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_htmlContent = sse_decode_String(deserializer);
+    return MobiChapter(title: var_title, htmlContent: var_htmlContent);
+  }
+
+  @protected
+  MobiMetadata sse_decode_mobi_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_publisher = sse_decode_opt_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_isbn = sse_decode_opt_String(deserializer);
+    var var_published = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    return MobiMetadata(
+        title: var_title,
+        author: var_author,
+        publisher: var_publisher,
+        language: var_language,
+        isbn: var_isbn,
+        published: var_published,
+        description: var_description);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ComicInfo? sse_decode_opt_box_autoadd_comic_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_comic_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  (int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_record_u_32_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ScanOptions? sse_decode_opt_box_autoadd_scan_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_scan_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SentenceSpan? sse_decode_opt_box_autoadd_sentence_span(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sentence_span(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PageSize sse_decode_page_size(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_widthPts = sse_decode_f_32(deserializer);
+    var var_heightPts = sse_decode_f_32(deserializer);
+    var var_rotationDegrees = sse_decode_u_16(deserializer);
+    return PageSize(
+        widthPts: var_widthPts,
+        heightPts: var_heightPts,
+        rotationDegrees: var_rotationDegrees);
+  }
+
+  @protected
+  ParagraphSpan sse_decode_paragraph_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return ParagraphSpan(start: var_start, end: var_end);
+  }
+
+  @protected
+  PdfAnnotation sse_decode_pdf_annotation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_pdf_annotation_kind(deserializer);
+    var var_rect = sse_decode_pdf_text_rect(deserializer);
+    var var_contents = sse_decode_String(deserializer);
+    var var_color = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return PdfAnnotation(
+        kind: var_kind,
+        rect: var_rect,
+        contents: var_contents,
+        color: var_color);
+  }
+
+  @protected
+  PdfAnnotationKind sse_decode_pdf_annotation_kind(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PdfAnnotationKind.values[inner];
+  }
+
+  @protected
+  PdfFontInfo sse_decode_pdf_font_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_fontType = sse_decode_String(deserializer);
+    var var_isEmbedded = sse_decode_bool(deserializer);
+    return PdfFontInfo(
+        name: var_name, fontType: var_fontType, isEmbedded: var_isEmbedded);
+  }
+
+  @protected
+  PdfFormField sse_decode_pdf_form_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_pdf_form_field_kind(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_pageIndex = sse_decode_u_32(deserializer);
+    var var_rect = sse_decode_pdf_text_rect(deserializer);
+    return PdfFormField(
+        name: var_name,
+        kind: var_kind,
+        value: var_value,
+        pageIndex: var_pageIndex,
+        rect: var_rect);
+  }
+
+  @protected
+  PdfFormFieldKind sse_decode_pdf_form_field_kind(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PdfFormFieldKind.values[inner];
+  }
+
+  @protected
+  PdfImage sse_decode_pdf_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    var var_rect = sse_decode_pdf_text_rect(deserializer);
+    return PdfImage(bytes: var_bytes, format: var_format, rect: var_rect);
+  }
+
+  @protected
+  PdfImageFormat sse_decode_pdf_image_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return PdfImageFormat_Png();
+      case 1:
+        var var_quality = sse_decode_u_8(deserializer);
+        return PdfImageFormat_Jpeg(quality: var_quality);
+      case 2:
+        return PdfImageFormat_RawRgba();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PdfLayer sse_decode_pdf_layer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_visible = sse_decode_bool(deserializer);
+    return PdfLayer(name: var_name, visible: var_visible);
+  }
+
+  @protected
+  PdfLink sse_decode_pdf_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rect = sse_decode_pdf_text_rect(deserializer);
+    var var_kind = sse_decode_pdf_link_kind(deserializer);
+    return PdfLink(rect: var_rect, kind: var_kind);
+  }
+
+  @protected
+  PdfLinkKind sse_decode_pdf_link_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return PdfLinkKind_Uri(var_field0);
+      case 1:
+        var var_field0 = sse_decode_u_32(deserializer);
+        return PdfLinkKind_Page(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PdfMetadata sse_decode_pdf_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_subject = sse_decode_String(deserializer);
+    var var_keywords = sse_decode_String(deserializer);
+    var var_creator = sse_decode_String(deserializer);
+    var var_producer = sse_decode_String(deserializer);
+    var var_pageCount = sse_decode_u_32(deserializer);
+    return PdfMetadata(
+        title: var_title,
+        author: var_author,
+        subject: var_subject,
+        keywords: var_keywords,
+        creator: var_creator,
+        producer: var_producer,
+        pageCount: var_pageCount);
+  }
+
+  @protected
+  PdfOutlineItem sse_decode_pdf_outline_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_pageIndex = sse_decode_u_32(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    return PdfOutlineItem(
+        title: var_title, pageIndex: var_pageIndex, depth: var_depth);
+  }
+
+  @protected
+  PdfPageRenderResult sse_decode_pdf_page_render_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return PdfPageRenderResult(
+        data: var_data, width: var_width, height: var_height);
+  }
+
+  @protected
+  PdfPermissions sse_decode_pdf_permissions(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_canPrint = sse_decode_bool(deserializer);
+    var var_canCopy = sse_decode_bool(deserializer);
+    var var_canModify = sse_decode_bool(deserializer);
+    var var_canAnnotate = sse_decode_bool(deserializer);
+    var var_canFillForms = sse_decode_bool(deserializer);
+    return PdfPermissions(
+        canPrint: var_canPrint,
+        canCopy: var_canCopy,
+        canModify: var_canModify,
+        canAnnotate: var_canAnnotate,
+        canFillForms: var_canFillForms);
+  }
+
+  @protected
+  PdfSearchEvent sse_decode_pdf_search_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_hit = sse_decode_box_autoadd_pdf_search_hit(deserializer);
+        var var_contextBefore = sse_decode_String(deserializer);
+        var var_contextAfter = sse_decode_String(deserializer);
+        var var_totalMatches = sse_decode_u_32(deserializer);
+        return PdfSearchEvent_Hit(
+            hit: var_hit,
+            contextBefore: var_contextBefore,
+            contextAfter: var_contextAfter,
+            totalMatches: var_totalMatches);
+      case 1:
+        var var_totalMatches = sse_decode_u_32(deserializer);
+        return PdfSearchEvent_Finished(totalMatches: var_totalMatches);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PdfSearchHit sse_decode_pdf_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageIndex = sse_decode_u_32(deserializer);
+    var var_charStart = sse_decode_u_32(deserializer);
+    var var_charEnd = sse_decode_u_32(deserializer);
+    var var_rects = sse_decode_list_pdf_text_rect(deserializer);
+    return PdfSearchHit(
+        pageIndex: var_pageIndex,
+        charStart: var_charStart,
+        charEnd: var_charEnd,
+        rects: var_rects);
+  }
+
+  @protected
+  PdfTextRect sse_decode_pdf_text_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_left = sse_decode_f_32(deserializer);
+    var var_top = sse_decode_f_32(deserializer);
+    var var_right = sse_decode_f_32(deserializer);
+    var var_bottom = sse_decode_f_32(deserializer);
+    return PdfTextRect(
+        left: var_left, top: var_top, right: var_right, bottom: var_bottom);
+  }
+
+  @protected
+  PdfTile sse_decode_pdf_tile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_row = sse_decode_u_32(deserializer);
+    var var_col = sse_decode_u_32(deserializer);
+    var var_x = sse_decode_u_32(deserializer);
+    var var_y = sse_decode_u_32(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    return PdfTile(
+        row: var_row,
+        col: var_col,
+        x: var_x,
+        y: var_y,
+        width: var_width,
+        height: var_height,
+        bytes: var_bytes);
+  }
+
+  @protected
+  PdfWordRect sse_decode_pdf_word_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_left = sse_decode_f_32(deserializer);
+    var var_top = sse_decode_f_32(deserializer);
+    var var_right = sse_decode_f_32(deserializer);
+    var var_bottom = sse_decode_f_32(deserializer);
+    return PdfWordRect(
+        text: var_text,
+        left: var_left,
+        top: var_top,
+        right: var_right,
+        bottom: var_bottom);
+  }
+
+  @protected
+  ReadingTime sse_decode_reading_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_wordCount = sse_decode_u_32(deserializer);
+    var var_minutes = sse_decode_u_32(deserializer);
+    return ReadingTime(wordCount: var_wordCount, minutes: var_minutes);
+  }
+
+  @protected
+  (String, bool) sse_decode_record_string_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_bool(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (int, Uint8List) sse_decode_record_u_32_list_prim_u_8_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_32(deserializer);
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_32(deserializer);
+    var var_field1 = sse_decode_u_32(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RenderOptions sse_decode_render_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_cover_format(deserializer);
+    return RenderOptions(format: var_format);
+  }
+
+  @protected
+  RenderedPage sse_decode_rendered_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_format = sse_decode_cover_format(deserializer);
+    return RenderedPage(
+        bytes: var_bytes,
+        width: var_width,
+        height: var_height,
+        format: var_format);
+  }
+
+  @protected
+  ResourceBytes sse_decode_resource_bytes(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_mediaType = sse_decode_String(deserializer);
+    return ResourceBytes(bytes: var_bytes, mediaType: var_mediaType);
+  }
+
+  @protected
+  ScanDelta sse_decode_scan_delta(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_list_book_metadata(deserializer);
+    var var_modified = sse_decode_list_book_metadata(deserializer);
+    var var_removed = sse_decode_list_String(deserializer);
+    var var_skipped = sse_decode_list_scan_error(deserializer);
+    return ScanDelta(
+        added: var_added,
+        modified: var_modified,
+        removed: var_removed,
+        skipped: var_skipped);
+  }
+
+  @protected
+  ScanError sse_decode_scan_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return ScanError(path: var_path, reason: var_reason);
+  }
+
+  @protected
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_scan_progress(deserializer);
+        return ScanEvent_Progress(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_scan_report(deserializer);
+        return ScanEvent_Finished(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ScanOptions sse_decode_scan_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_extensions = sse_decode_list_String(deserializer);
+    var var_maxDepth = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_followSymlinks = sse_decode_bool(deserializer);
+    var var_includeHidden = sse_decode_bool(deserializer);
+    return ScanOptions(
+        extensions: var_extensions,
+        maxDepth: var_maxDepth,
+        followSymlinks: var_followSymlinks,
+        includeHidden: var_includeHidden);
+  }
+
+  @protected
+  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_scanned = sse_decode_u_32(deserializer);
+    var var_found = sse_decode_u_32(deserializer);
+    var var_currentPath = sse_decode_String(deserializer);
+    return ScanProgress(
+        scanned: var_scanned, found: var_found, currentPath: var_currentPath);
+  }
+
+  @protected
+  ScanReport sse_decode_scan_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_books = sse_decode_list_book_metadata(deserializer);
+    var var_skipped = sse_decode_list_scan_error(deserializer);
+    return ScanReport(books: var_books, skipped: var_skipped);
+  }
+
+  @protected
+  SentenceSpan sse_decode_sentence_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return SentenceSpan(start: var_start, end: var_end);
+  }
+
+  @protected
+  TextHighlightData sse_decode_text_highlight_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_words = sse_decode_list_word_span(deserializer);
+    var var_sentences = sse_decode_list_sentence_span(deserializer);
+    var var_paragraphs = sse_decode_list_paragraph_span(deserializer);
+    var var_normalizedText = sse_decode_String(deserializer);
+    var var_normalizedToRaw = sse_decode_list_prim_u_32_strict(deserializer);
+    return TextHighlightData(
+        words: var_words,
+        sentences: var_sentences,
+        paragraphs: var_paragraphs,
+        normalizedText: var_normalizedText,
+        normalizedToRaw: var_normalizedToRaw);
+  }
+
+  @protected
+  TextUnit sse_decode_text_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TextUnit.values[inner];
+  }
+
+  @protected
+  TimedSample sse_decode_timed_sample(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_millis = sse_decode_f_64(deserializer);
+    return TimedSample(name: var_name, millis: var_millis);
+  }
+
+  @protected
+  TocEntry sse_decode_toc_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_label = sse_decode_String(deserializer);
+    var var_href = sse_decode_String(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    var var_children = sse_decode_list_toc_entry(deserializer);
+    return TocEntry(
+        label: var_label,
+        href: var_href,
+        depth: var_depth,
+        children: var_children);
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WordSpan sse_decode_word_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return WordSpan(start: var_start, end: var_end, text: var_text);
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_cbz_page_event_Sse(
+      RustStreamSink<CbzPageEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_cbz_page_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_import_event_Sse(
+      RustStreamSink<ImportEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_import_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_pdf_search_event_Sse(
+      RustStreamSink<PdfSearchEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_pdf_search_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_scan_event_Sse(
+      RustStreamSink<ScanEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_background_kind(
+      BackgroundKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_book_format(BookFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_book_metadata(BookMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+    sse_encode_i_64(self.modifiedEpoch, serializer);
+    sse_encode_String(self.format, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cbz_page_data(
+      CbzPageData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cbz_page_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_comic_info(
+      ComicInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_comic_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cover_format(
+      CoverFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cover_format(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cover_options(
+      CoverOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cover_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_docx_image_mode(
+      DocxImageMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_docx_image_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_import_summary(
+      ImportSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_summary(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_imported_book(
+      ImportedBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_imported_book(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pdf_image_format(
+      PdfImageFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pdf_image_format(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pdf_search_hit(
+      PdfSearchHit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pdf_search_hit(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pdf_text_rect(
+      PdfTextRect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pdf_text_rect(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_record_u_32_u_32(
+      (int, int) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_record_u_32_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_render_options(
+      RenderOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_render_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_options(
+      ScanOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_progress(
+      ScanProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_progress(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_report(
+      ScanReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sentence_span(
+      SentenceSpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sentence_span(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_text_highlight_data(
+      TextHighlightData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_highlight_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self, serializer);
+  }
+
+  @protected
+  void sse_encode_cbz_page_data(CbzPageData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.width, serializer);
+    sse_encode_i_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.rgbaBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_cbz_page_event(CbzPageEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case CbzPageEvent_Page(index: final index, page: final page):
+        sse_encode_i_32(0, serializer);
+        sse_encode_i_32(index, serializer);
+        sse_encode_box_autoadd_cbz_page_data(page, serializer);
+      case CbzPageEvent_Done(pageCount: final pageCount, failed: final failed):
+        sse_encode_i_32(1, serializer);
+        sse_encode_i_32(pageCount, serializer);
+        sse_encode_list_prim_i_32_strict(failed, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_cbz_page_info(CbzPageInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_bool(self.isWide, serializer);
+  }
+
+  @protected
+  void sse_encode_cbz_page_names(CbzPageNames self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.names, serializer);
+    sse_encode_i_32(self.pageCount, serializer);
+    sse_encode_list_String(self.skipped, serializer);
+  }
+
+  @protected
+  void sse_encode_cbz_page_with_background(
+      CbzPageWithBackground self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cbz_page_data(self.page, serializer);
+    sse_encode_u_32(self.backgroundColor, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_entry(ChapterEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_32(self.level, serializer);
+    sse_encode_chapter_location(self.location, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_location(
+      ChapterLocation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ChapterLocation_Page(index: final index):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(index, serializer);
+      case ChapterLocation_Section(index: final index, offset: final offset):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(index, serializer);
+        sse_encode_u_32(offset, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_comic_info(ComicInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.series, serializer);
+    sse_encode_opt_String(self.number, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.writer, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.pageCount, serializer);
+    sse_encode_bool(self.manga, serializer);
+  }
+
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  BookMetadata dco_decode_book_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return BookMetadata(
-      title: dco_decode_String(arr[0]),
-      author: dco_decode_String(arr[1]),
-      path: dco_decode_String(arr[2]),
-    );
+  void sse_encode_cover_format(CoverFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case CoverFormat_Png():
+        sse_encode_i_32(0, serializer);
+      case CoverFormat_Jpeg(quality: final quality):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_8(quality, serializer);
+      case CoverFormat_Webp():
+        sse_encode_i_32(2, serializer);
+    }
   }
 
   @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_cover_options(CoverOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxDim, serializer);
+    sse_encode_cover_format(self.format, serializer);
   }
 
   @protected
-  SentenceSpan dco_decode_box_autoadd_sentence_span(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_sentence_span(raw);
+  void sse_encode_crop_margins(CropMargins self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.top, serializer);
+    sse_encode_f_32(self.bottom, serializer);
+    sse_encode_f_32(self.left, serializer);
+    sse_encode_f_32(self.right, serializer);
   }
 
   @protected
-  CbzPageData dco_decode_cbz_page_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return CbzPageData(
-      width: dco_decode_i_32(arr[0]),
-      height: dco_decode_i_32(arr[1]),
-      rgbaBytes: dco_decode_list_prim_u_8_strict(arr[2]),
-    );
+  void sse_encode_docx_image_mode(
+      DocxImageMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DocxImageMode_DataUri():
+        sse_encode_i_32(0, serializer);
+      case DocxImageMode_AssetsDir(assetsDir: final assetsDir):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(assetsDir, serializer);
+    }
   }
 
   @protected
-  CropMargins dco_decode_crop_margins(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return CropMargins(
-      top: dco_decode_f_32(arr[0]),
-      bottom: dco_decode_f_32(arr[1]),
-      left: dco_decode_f_32(arr[2]),
-      right: dco_decode_f_32(arr[3]),
-    );
+  void sse_encode_docx_metadata(DocxMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.creator, serializer);
+    sse_encode_String(self.subject, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.lastModifiedBy, serializer);
+    sse_encode_String(self.created, serializer);
+    sse_encode_String(self.modified, serializer);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_encoded_image(EncodedImage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.width, serializer);
+    sse_encode_i_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_epub_chapter(EpubChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.href, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_32(self.order, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_epub_layout(EpubLayout self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_metadata(EpubMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_list_String(self.creators, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.identifier, serializer);
+    sse_encode_opt_String(self.publisher, serializer);
+    sse_encode_opt_String(self.date, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_list_String(self.subjects, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_rendition(EpubRendition self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_epub_layout(self.layout, serializer);
+    sse_encode_list_epub_spine_layout(self.spine, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_search_hit(
+      EpubSearchHit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.chapterHref, serializer);
+    sse_encode_u_32(self.charStart, serializer);
+    sse_encode_u_32(self.charEnd, serializer);
+    sse_encode_String(self.snippet, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_spine_layout(
+      EpubSpineLayout self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.href, serializer);
+    sse_encode_epub_layout(self.layout, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_ferrous_error(FerrousError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FerrousError_FileMissing(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Empty(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_BadHeader(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_PasswordRequired(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Corrupt(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Security(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_PageError(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_PageOutOfRange():
+        sse_encode_i_32(7, serializer);
+      case FerrousError_PageLoad(pageIndex: final pageIndex, detail: final detail):
+        sse_encode_i_32(8, serializer);
+        sse_encode_u_32(pageIndex, serializer);
+        sse_encode_String(detail, serializer);
+      case FerrousError_PermissionDenied(field0: final field0):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Unsupported(field0: final field0):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_InvalidArgument(field0: final field0):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Cancelled():
+        sse_encode_i_32(12, serializer);
+      case FerrousError_Pdfium(field0: final field0):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Image(field0: final field0):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(field0, serializer);
+      case FerrousError_Io(field0: final field0):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_highlight_range(
+      HighlightRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_String(self.tagName, serializer);
+  }
+
+  @protected
+  void sse_encode_html_highlight_data(
+      HtmlHighlightData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_html_word_span(self.words, serializer);
+    sse_encode_list_sentence_span(self.sentences, serializer);
+    sse_encode_list_paragraph_span(self.paragraphs, serializer);
+    sse_encode_String(self.normalizedText, serializer);
+    sse_encode_u_32(self.textNodeCount, serializer);
+  }
+
+  @protected
+  void sse_encode_html_word_span(HtmlWordSpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.nodeIndex, serializer);
+    sse_encode_u_32(self.nodeOffset, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_import_event(ImportEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ImportEvent_Book(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_imported_book(field0, serializer);
+      case ImportEvent_Finished(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_import_summary(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_import_failure(ImportFailure self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_import_summary(ImportSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.imported, serializer);
+    sse_encode_list_import_failure(self.failed, serializer);
+    sse_encode_bool(self.cancelled, serializer);
+  }
+
+  @protected
+  void sse_encode_imported_book(ImportedBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_metadata(self.metadata, serializer);
+    sse_encode_opt_String(self.coverPath, serializer);
+  }
+
+  @protected
+  void sse_encode_known_file(KnownFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_i_64(self.modifiedEpoch, serializer);
+    sse_encode_u_64(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_metadata(
+      List<BookMetadata> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_metadata(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_chapter_entry(
+      List<ChapterEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_crop_margins(
+      List<CropMargins> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_crop_margins(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_chapter(
+      List<EpubChapter> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_search_hit(
+      List<EpubSearchHit> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_search_hit(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_spine_layout(
+      List<EpubSpineLayout> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_spine_layout(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_highlight_range(
+      List<HighlightRange> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_highlight_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_html_word_span(
+      List<HtmlWordSpan> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_html_word_span(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_import_failure(
+      List<ImportFailure> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_import_failure(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_known_file(
+      List<KnownFile> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_known_file(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_media_clip(
+      List<MediaClip> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_clip(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_mobi_chapter(
+      List<MobiChapter> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mobi_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_page_size(
+      List<PageSize> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_page_size(item, serializer);
+    }
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_list_paragraph_span(
+      List<ParagraphSpan> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_paragraph_span(item, serializer);
+    }
   }
 
   @protected
-  List<BookMetadata> dco_decode_list_book_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_book_metadata).toList();
+  void sse_encode_list_pdf_annotation(
+      List<PdfAnnotation> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_annotation(item, serializer);
+    }
   }
 
   @protected
-  List<MobiChapter> dco_decode_list_mobi_chapter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_mobi_chapter).toList();
+  void sse_encode_list_pdf_font_info(
+      List<PdfFontInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_font_info(item, serializer);
+    }
   }
 
   @protected
-  List<PdfTextRect> dco_decode_list_pdf_text_rect(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_pdf_text_rect).toList();
+  void sse_encode_list_pdf_form_field(
+      List<PdfFormField> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_form_field(item, serializer);
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_list_pdf_image(
+      List<PdfImage> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_image(item, serializer);
+    }
   }
 
   @protected
-  List<SentenceSpan> dco_decode_list_sentence_span(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_sentence_span).toList();
+  void sse_encode_list_pdf_layer(
+      List<PdfLayer> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_layer(item, serializer);
+    }
   }
 
   @protected
-  List<WordSpan> dco_decode_list_word_span(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_word_span).toList();
+  void sse_encode_list_pdf_link(List<PdfLink> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_link(item, serializer);
+    }
   }
 
   @protected
-  MobiChapter dco_decode_mobi_chapter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return MobiChapter(
-      title: dco_decode_String(arr[0]),
-      htmlContent: dco_decode_String(arr[1]),
-    );
+  void sse_encode_list_pdf_outline_item(
+      List<PdfOutlineItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_outline_item(item, serializer);
+    }
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  void sse_encode_list_pdf_page_render_result(
+      List<PdfPageRenderResult> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_page_render_result(item, serializer);
+    }
   }
 
   @protected
-  SentenceSpan? dco_decode_opt_box_autoadd_sentence_span(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_sentence_span(raw);
+  void sse_encode_list_pdf_search_hit(
+      List<PdfSearchHit> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_search_hit(item, serializer);
+    }
   }
 
   @protected
-  PdfPageRenderResult dco_decode_pdf_page_render_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return PdfPageRenderResult(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      width: dco_decode_u_32(arr[1]),
-      height: dco_decode_u_32(arr[2]),
-    );
+  void sse_encode_list_pdf_text_rect(
+      List<PdfTextRect> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_text_rect(item, serializer);
+    }
   }
 
   @protected
-  PdfTextRect dco_decode_pdf_text_rect(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PdfTextRect(
-      left: dco_decode_f_32(arr[0]),
-      top: dco_decode_f_32(arr[1]),
-      right: dco_decode_f_32(arr[2]),
-      bottom: dco_decode_f_32(arr[3]),
-    );
+  void sse_encode_list_pdf_tile(List<PdfTile> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_tile(item, serializer);
+    }
   }
 
   @protected
-  SentenceSpan dco_decode_sentence_span(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SentenceSpan(
-      start: dco_decode_u_32(arr[0]),
-      end: dco_decode_u_32(arr[1]),
-    );
+  void sse_encode_list_pdf_word_rect(
+      List<PdfWordRect> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_word_rect(item, serializer);
+    }
   }
 
   @protected
-  TextHighlightData dco_decode_text_highlight_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return TextHighlightData(
-      words: dco_decode_list_word_span(arr[0]),
-      sentences: dco_decode_list_sentence_span(arr[1]),
-      normalizedText: dco_decode_String(arr[2]),
-    );
+  void sse_encode_list_prim_i_32_strict(
+      Int32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt32List(self);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_prim_u_32_strict(
+      Uint32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_record_string_bool(
+      List<(String, bool)> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_bool(item, serializer);
+    }
   }
 
   @protected
-  WordSpan dco_decode_word_span(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WordSpan(
-      start: dco_decode_u_32(arr[0]),
-      end: dco_decode_u_32(arr[1]),
-      text: dco_decode_String(arr[2]),
-    );
+  void sse_encode_list_record_u_32_list_prim_u_8_strict(
+      List<(int, Uint8List)> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_u_32_list_prim_u_8_strict(item, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_list_scan_error(
+      List<ScanError> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_scan_error(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_sentence_span(
+      List<SentenceSpan> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sentence_span(item, serializer);
+    }
   }
 
   @protected
-  BookMetadata sse_decode_book_metadata(SseDeserializer deserializer) {
+  void sse_encode_list_timed_sample(
+      List<TimedSample> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_String(deserializer);
-    var var_author = sse_decode_String(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    return BookMetadata(title: var_title, author: var_author, path: var_path);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_timed_sample(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_toc_entry(
+      List<TocEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_toc_entry(item, serializer);
+    }
   }
 
   @protected
-  SentenceSpan sse_decode_box_autoadd_sentence_span(
-      SseDeserializer deserializer) {
+  void sse_encode_list_word_span(
+      List<WordSpan> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_sentence_span(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_word_span(item, serializer);
+    }
   }
 
   @protected
-  CbzPageData sse_decode_cbz_page_data(SseDeserializer deserializer) {
+  void sse_encode_media_clip(MediaClip self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_width = sse_decode_i_32(deserializer);
-    var var_height = sse_decode_i_32(deserializer);
-    var var_rgbaBytes = sse_decode_list_prim_u_8_strict(deserializer);
-    return CbzPageData(
-        width: var_width, height: var_height, rgbaBytes: var_rgbaBytes);
+    sse_encode_String(self.textFragmentId, serializer);
+    sse_encode_String(self.audioHref, serializer);
+    sse_encode_f_64(self.clipBegin, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.clipEnd, serializer);
   }
 
   @protected
-  CropMargins sse_decode_crop_margins(SseDeserializer deserializer) {
+  void sse_encode_mobi_chapter(MobiChapter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_top = sse_decode_f_32(deserializer);
-    var var_bottom = sse_decode_f_32(deserializer);
-    var var_left = sse_decode_f_32(deserializer);
-    var var_right = sse_decode_f_32(deserializer);
-    return CropMargins(
-        top: var_top, bottom: var_bottom, left: var_left, right: var_right);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.htmlContent, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_mobi_metadata(MobiMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_opt_String(self.publisher, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.isbn, serializer);
+    sse_encode_opt_String(self.published, serializer);
+    sse_encode_opt_String(self.description, serializer);
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_comic_info(
+      ComicInfo? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_comic_info(self, serializer);
+    }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<BookMetadata> sse_decode_list_book_metadata(
-      SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BookMetadata>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_book_metadata(deserializer));
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<MobiChapter> sse_decode_list_mobi_chapter(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_record_u_32_u_32(
+      (int, int)? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MobiChapter>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_mobi_chapter(deserializer));
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_record_u_32_u_32(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<PdfTextRect> sse_decode_list_pdf_text_rect(
-      SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_scan_options(
+      ScanOptions? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PdfTextRect>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_pdf_text_rect(deserializer));
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_scan_options(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_sentence_span(
+      SentenceSpan? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sentence_span(self, serializer);
+    }
   }
 
   @protected
-  List<SentenceSpan> sse_decode_list_sentence_span(
-      SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SentenceSpan>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sentence_span(deserializer));
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<WordSpan> sse_decode_list_word_span(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WordSpan>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_word_span(deserializer));
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  MobiChapter sse_decode_mobi_chapter(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_usize(
+      BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_String(deserializer);
-    var var_htmlContent = sse_decode_String(deserializer);
-    return MobiChapter(title: var_title, htmlContent: var_htmlContent);
+    
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_usize(self, serializer);
+    }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+  void sse_encode_page_size(PageSize self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_f_32(self.widthPts, serializer);
+    sse_encode_f_32(self.heightPts, serializer);
+    sse_encode_u_16(self.rotationDegrees, serializer);
   }
 
   @protected
-  SentenceSpan? sse_decode_opt_box_autoadd_sentence_span(
-      SseDeserializer deserializer) {
+  void sse_encode_paragraph_span(ParagraphSpan self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_sentence_span(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
   }
 
   @protected
-  PdfPageRenderResult sse_decode_pdf_page_render_result(
-      SseDeserializer deserializer) {
+  void sse_encode_pdf_annotation(PdfAnnotation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    return PdfPageRenderResult(
-        data: var_data, width: var_width, height: var_height);
+    sse_encode_pdf_annotation_kind(self.kind, serializer);
+    sse_encode_pdf_text_rect(self.rect, serializer);
+    sse_encode_String(self.contents, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.color, serializer);
   }
 
   @protected
-  PdfTextRect sse_decode_pdf_text_rect(SseDeserializer deserializer) {
+  void sse_encode_pdf_annotation_kind(
+      PdfAnnotationKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_left = sse_decode_f_32(deserializer);
-    var var_top = sse_decode_f_32(deserializer);
-    var var_right = sse_decode_f_32(deserializer);
-    var var_bottom = sse_decode_f_32(deserializer);
-    return PdfTextRect(
-        left: var_left, top: var_top, right: var_right, bottom: var_bottom);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  SentenceSpan sse_decode_sentence_span(SseDeserializer deserializer) {
+  void sse_encode_pdf_font_info(PdfFontInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_start = sse_decode_u_32(deserializer);
-    var var_end = sse_decode_u_32(deserializer);
-    return SentenceSpan(start: var_start, end: var_end);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.fontType, serializer);
+    sse_encode_bool(self.isEmbedded, serializer);
   }
 
   @protected
-  TextHighlightData sse_decode_text_highlight_data(
-      SseDeserializer deserializer) {
+  void sse_encode_pdf_form_field(PdfFormField self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_words = sse_decode_list_word_span(deserializer);
-    var var_sentences = sse_decode_list_sentence_span(deserializer);
-    var var_normalizedText = sse_decode_String(deserializer);
-    return TextHighlightData(
-        words: var_words,
-        sentences: var_sentences,
-        normalizedText: var_normalizedText);
+    sse_encode_String(self.name, serializer);
+    sse_encode_pdf_form_field_kind(self.kind, serializer);
+    sse_encode_String(self.value, serializer);
+    sse_encode_u_32(self.pageIndex, serializer);
+    sse_encode_pdf_text_rect(self.rect, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_pdf_form_field_kind(
+      PdfFormFieldKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_pdf_image(PdfImage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_pdf_text_rect(self.rect, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_pdf_image_format(
+      PdfImageFormat self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case PdfImageFormat_Png():
+        sse_encode_i_32(0, serializer);
+      case PdfImageFormat_Jpeg(quality: final quality):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_8(quality, serializer);
+      case PdfImageFormat_RawRgba():
+        sse_encode_i_32(2, serializer);
+    }
   }
 
   @protected
-  WordSpan sse_decode_word_span(SseDeserializer deserializer) {
+  void sse_encode_pdf_layer(PdfLayer self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_start = sse_decode_u_32(deserializer);
-    var var_end = sse_decode_u_32(deserializer);
-    var var_text = sse_decode_String(deserializer);
-    return WordSpan(start: var_start, end: var_end, text: var_text);
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.visible, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_pdf_link(PdfLink self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_pdf_text_rect(self.rect, serializer);
+    sse_encode_pdf_link_kind(self.kind, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_pdf_link_kind(PdfLinkKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    switch (self) {
+      case PdfLinkKind_Uri(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case PdfLinkKind_Page(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(field0, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_pdf_metadata(PdfMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_String(self.subject, serializer);
+    sse_encode_String(self.keywords, serializer);
+    sse_encode_String(self.creator, serializer);
+    sse_encode_String(self.producer, serializer);
+    sse_encode_u_32(self.pageCount, serializer);
   }
 
   @protected
-  void sse_encode_book_metadata(BookMetadata self, SseSerializer serializer) {
+  void sse_encode_pdf_outline_item(
+      PdfOutlineItem self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.title, serializer);
-    sse_encode_String(self.author, serializer);
-    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.pageIndex, serializer);
+    sse_encode_u_32(self.depth, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+  void sse_encode_pdf_page_render_result(
+      PdfPageRenderResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_sentence_span(
-      SentenceSpan self, SseSerializer serializer) {
+  void sse_encode_pdf_permissions(
+      PdfPermissions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_sentence_span(self, serializer);
+    sse_encode_bool(self.canPrint, serializer);
+    sse_encode_bool(self.canCopy, serializer);
+    sse_encode_bool(self.canModify, serializer);
+    sse_encode_bool(self.canAnnotate, serializer);
+    sse_encode_bool(self.canFillForms, serializer);
   }
 
   @protected
-  void sse_encode_cbz_page_data(CbzPageData self, SseSerializer serializer) {
+  void sse_encode_pdf_search_event(
+      PdfSearchEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.width, serializer);
-    sse_encode_i_32(self.height, serializer);
-    sse_encode_list_prim_u_8_strict(self.rgbaBytes, serializer);
+    switch (self) {
+      case PdfSearchEvent_Hit(hit: final hit, contextBefore: final contextBefore, contextAfter: final contextAfter, totalMatches: final totalMatches):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_pdf_search_hit(hit, serializer);
+        sse_encode_String(contextBefore, serializer);
+        sse_encode_String(contextAfter, serializer);
+        sse_encode_u_32(totalMatches, serializer);
+      case PdfSearchEvent_Finished(totalMatches: final totalMatches):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(totalMatches, serializer);
+    }
   }
 
   @protected
-  void sse_encode_crop_margins(CropMargins self, SseSerializer serializer) {
+  void sse_encode_pdf_search_hit(PdfSearchHit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.pageIndex, serializer);
+    sse_encode_u_32(self.charStart, serializer);
+    sse_encode_u_32(self.charEnd, serializer);
+    sse_encode_list_pdf_text_rect(self.rects, serializer);
+  }
+
+  @protected
+  void sse_encode_pdf_text_rect(PdfTextRect self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.top, serializer);
-    sse_encode_f_32(self.bottom, serializer);
     sse_encode_f_32(self.left, serializer);
+    sse_encode_f_32(self.top, serializer);
     sse_encode_f_32(self.right, serializer);
+    sse_encode_f_32(self.bottom, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_pdf_tile(PdfTile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_u_32(self.row, serializer);
+    sse_encode_u_32(self.col, serializer);
+    sse_encode_u_32(self.x, serializer);
+    sse_encode_u_32(self.y, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_pdf_word_rect(PdfWordRect self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_32(self.left, serializer);
+    sse_encode_f_32(self.top, serializer);
+    sse_encode_f_32(self.right, serializer);
+    sse_encode_f_32(self.bottom, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_reading_time(ReadingTime self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_u_32(self.wordCount, serializer);
+    sse_encode_u_32(self.minutes, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_record_string_bool(
+      (String, bool) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_String(self.$1, serializer);
+    sse_encode_bool(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_list_book_metadata(
-      List<BookMetadata> self, SseSerializer serializer) {
+  void sse_encode_record_u_32_list_prim_u_8_strict(
+      (int, Uint8List) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_book_metadata(item, serializer);
-    }
+    sse_encode_u_32(self.$1, serializer);
+    sse_encode_list_prim_u_8_strict(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_list_mobi_chapter(
-      List<MobiChapter> self, SseSerializer serializer) {
+  void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_mobi_chapter(item, serializer);
-    }
+    sse_encode_u_32(self.$1, serializer);
+    sse_encode_u_32(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_list_pdf_text_rect(
-      List<PdfTextRect> self, SseSerializer serializer) {
+  void sse_encode_render_options(RenderOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_pdf_text_rect(item, serializer);
-    }
+    sse_encode_cover_format(self.format, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_rendered_page(RenderedPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_cover_format(self.format, serializer);
   }
 
   @protected
-  void sse_encode_list_sentence_span(
-      List<SentenceSpan> self, SseSerializer serializer) {
+  void sse_encode_resource_bytes(ResourceBytes self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sentence_span(item, serializer);
-    }
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+    sse_encode_String(self.mediaType, serializer);
   }
 
   @protected
-  void sse_encode_list_word_span(
-      List<WordSpan> self, SseSerializer serializer) {
+  void sse_encode_scan_delta(ScanDelta self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_word_span(item, serializer);
-    }
+    sse_encode_list_book_metadata(self.added, serializer);
+    sse_encode_list_book_metadata(self.modified, serializer);
+    sse_encode_list_String(self.removed, serializer);
+    sse_encode_list_scan_error(self.skipped, serializer);
   }
 
   @protected
-  void sse_encode_mobi_chapter(MobiChapter self, SseSerializer serializer) {
+  void sse_encode_scan_error(ScanError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.htmlContent, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.reason, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
+    switch (self) {
+      case ScanEvent_Progress(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_scan_progress(field0, serializer);
+      case ScanEvent_Finished(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_scan_report(field0, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_sentence_span(
-      SentenceSpan? self, SseSerializer serializer) {
+  void sse_encode_scan_options(ScanOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_sentence_span(self, serializer);
-    }
+    sse_encode_list_String(self.extensions, serializer);
+    sse_encode_opt_box_autoadd_usize(self.maxDepth, serializer);
+    sse_encode_bool(self.followSymlinks, serializer);
+    sse_encode_bool(self.includeHidden, serializer);
   }
 
   @protected
-  void sse_encode_pdf_page_render_result(
-      PdfPageRenderResult self, SseSerializer serializer) {
+  void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.scanned, serializer);
+    sse_encode_u_32(self.found, serializer);
+    sse_encode_String(self.currentPath, serializer);
   }
 
   @protected
-  void sse_encode_pdf_text_rect(PdfTextRect self, SseSerializer serializer) {
+  void sse_encode_scan_report(ScanReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.left, serializer);
-    sse_encode_f_32(self.top, serializer);
-    sse_encode_f_32(self.right, serializer);
-    sse_encode_f_32(self.bottom, serializer);
+    sse_encode_list_book_metadata(self.books, serializer);
+    sse_encode_list_scan_error(self.skipped, serializer);
   }
 
   @protected
@@ -1542,7 +8485,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_word_span(self.words, serializer);
     sse_encode_list_sentence_span(self.sentences, serializer);
+    sse_encode_list_paragraph_span(self.paragraphs, serializer);
     sse_encode_String(self.normalizedText, serializer);
+    sse_encode_list_prim_u_32_strict(self.normalizedToRaw, serializer);
+  }
+
+  @protected
+  void sse_encode_text_unit(TextUnit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_timed_sample(TimedSample self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_f_64(self.millis, serializer);
+  }
+
+  @protected
+  void sse_encode_toc_entry(TocEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.href, serializer);
+    sse_encode_u_32(self.depth, serializer);
+    sse_encode_list_toc_entry(self.children, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
   }
 
   @protected
@@ -1551,6 +8524,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1563,16 +8542,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_word_span(WordSpan self, SseSerializer serializer) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.start, serializer);
-    sse_encode_u_32(self.end, serializer);
-    sse_encode_String(self.text, serializer);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_word_span(WordSpan self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_String(self.text, serializer);
   }
 }
@@ -11,9 +11,16 @@ import 'api/cbz.dart';
 import 'api/covers.dart';
 import 'api/crop.dart';
 import 'api/docx.dart';
+import 'api/epub.dart';
+import 'api/error.dart';
+import 'api/fb2.dart';
+import 'api/format.dart';
+import 'api/language.dart';
 import 'api/library.dart';
 import 'api/mobi.dart';
+import 'api/operation.dart';
 import 'api/pdf.dart';
+import 'api/profiling.dart';
 import 'api/tts_text.dart';
 import 'api/txt.dart';
 import 'dart:async';
@@ -32,211 +39,1238 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<CbzPageEvent> dco_decode_StreamSink_cbz_page_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<ImportEvent> dco_decode_StreamSink_import_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<PdfSearchEvent> dco_decode_StreamSink_pdf_search_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
-  BookMetadata dco_decode_book_metadata(dynamic raw);
+  BackgroundKind dco_decode_background_kind(dynamic raw);
+
+  @protected
+  BookFormat dco_decode_book_format(dynamic raw);
+
+  @protected
+  BookMetadata dco_decode_book_metadata(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  CbzPageData dco_decode_box_autoadd_cbz_page_data(dynamic raw);
+
+  @protected
+  ComicInfo dco_decode_box_autoadd_comic_info(dynamic raw);
+
+  @protected
+  CoverFormat dco_decode_box_autoadd_cover_format(dynamic raw);
+
+  @protected
+  CoverOptions dco_decode_box_autoadd_cover_options(dynamic raw);
+
+  @protected
+  DocxImageMode dco_decode_box_autoadd_docx_image_mode(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  ImportSummary dco_decode_box_autoadd_import_summary(dynamic raw);
+
+  @protected
+  ImportedBook dco_decode_box_autoadd_imported_book(dynamic raw);
+
+  @protected
+  PdfImageFormat dco_decode_box_autoadd_pdf_image_format(dynamic raw);
+
+  @protected
+  PdfSearchHit dco_decode_box_autoadd_pdf_search_hit(dynamic raw);
+
+  @protected
+  PdfTextRect dco_decode_box_autoadd_pdf_text_rect(dynamic raw);
+
+  @protected
+  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw);
+
+  @protected
+  RenderOptions dco_decode_box_autoadd_render_options(dynamic raw);
+
+  @protected
+  ScanOptions dco_decode_box_autoadd_scan_options(dynamic raw);
+
+  @protected
+  ScanProgress dco_decode_box_autoadd_scan_progress(dynamic raw);
+
+  @protected
+  ScanReport dco_decode_box_autoadd_scan_report(dynamic raw);
+
+  @protected
+  SentenceSpan dco_decode_box_autoadd_sentence_span(dynamic raw);
+
+  @protected
+  TextHighlightData dco_decode_box_autoadd_text_highlight_data(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+  @protected
+  CbzPageData dco_decode_cbz_page_data(dynamic raw);
+
+  @protected
+  CbzPageEvent dco_decode_cbz_page_event(dynamic raw);
+
+  @protected
+  CbzPageInfo dco_decode_cbz_page_info(dynamic raw);
+
+  @protected
+  CbzPageNames dco_decode_cbz_page_names(dynamic raw);
+
+  @protected
+  CbzPageWithBackground dco_decode_cbz_page_with_background(dynamic raw);
+
+  @protected
+  ChapterEntry dco_decode_chapter_entry(dynamic raw);
+
+  @protected
+  ChapterLocation dco_decode_chapter_location(dynamic raw);
+
+  @protected
+  ComicInfo dco_decode_comic_info(dynamic raw);
+
+  @protected
+  CoverFormat dco_decode_cover_format(dynamic raw);
+
+  @protected
+  CoverOptions dco_decode_cover_options(dynamic raw);
+
+  @protected
+  CropMargins dco_decode_crop_margins(dynamic raw);
+
+  @protected
+  DocxImageMode dco_decode_docx_image_mode(dynamic raw);
+
+  @protected
+  DocxMetadata dco_decode_docx_metadata(dynamic raw);
+
+  @protected
+  EncodedImage dco_decode_encoded_image(dynamic raw);
+
+  @protected
+  EpubChapter dco_decode_epub_chapter(dynamic raw);
+
+  @protected
+  EpubLayout dco_decode_epub_layout(dynamic raw);
+
+  @protected
+  EpubMetadata dco_decode_epub_metadata(dynamic raw);
+
+  @protected
+  EpubRendition dco_decode_epub_rendition(dynamic raw);
+
+  @protected
+  EpubSearchHit dco_decode_epub_search_hit(dynamic raw);
+
+  @protected
+  EpubSpineLayout dco_decode_epub_spine_layout(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FerrousError dco_decode_ferrous_error(dynamic raw);
+
+  @protected
+  HighlightRange dco_decode_highlight_range(dynamic raw);
+
+  @protected
+  HtmlHighlightData dco_decode_html_highlight_data(dynamic raw);
+
+  @protected
+  HtmlWordSpan dco_decode_html_word_span(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  ImportEvent dco_decode_import_event(dynamic raw);
+
+  @protected
+  ImportFailure dco_decode_import_failure(dynamic raw);
+
+  @protected
+  ImportSummary dco_decode_import_summary(dynamic raw);
+
+  @protected
+  ImportedBook dco_decode_imported_book(dynamic raw);
+
+  @protected
+  KnownFile dco_decode_known_file(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<BookMetadata> dco_decode_list_book_metadata(dynamic raw);
+
+  @protected
+  List<ChapterEntry> dco_decode_list_chapter_entry(dynamic raw);
+
+  @protected
+  List<CropMargins> dco_decode_list_crop_margins(dynamic raw);
+
+  @protected
+  List<EpubChapter> dco_decode_list_epub_chapter(dynamic raw);
+
+  @protected
+  List<EpubSearchHit> dco_decode_list_epub_search_hit(dynamic raw);
+
+  @protected
+  List<EpubSpineLayout> dco_decode_list_epub_spine_layout(dynamic raw);
+
+  @protected
+  List<HighlightRange> dco_decode_list_highlight_range(dynamic raw);
+
+  @protected
+  List<HtmlWordSpan> dco_decode_list_html_word_span(dynamic raw);
+
+  @protected
+  List<ImportFailure> dco_decode_list_import_failure(dynamic raw);
+
+  @protected
+  List<KnownFile> dco_decode_list_known_file(dynamic raw);
+
+  @protected
+  List<MediaClip> dco_decode_list_media_clip(dynamic raw);
+
+  @protected
+  List<MobiChapter> dco_decode_list_mobi_chapter(dynamic raw);
+
+  @protected
+  List<PageSize> dco_decode_list_page_size(dynamic raw);
+
+  @protected
+  List<ParagraphSpan> dco_decode_list_paragraph_span(dynamic raw);
+
+  @protected
+  List<PdfAnnotation> dco_decode_list_pdf_annotation(dynamic raw);
+
+  @protected
+  List<PdfFontInfo> dco_decode_list_pdf_font_info(dynamic raw);
+
+  @protected
+  List<PdfFormField> dco_decode_list_pdf_form_field(dynamic raw);
+
+  @protected
+  List<PdfImage> dco_decode_list_pdf_image(dynamic raw);
+
+  @protected
+  List<PdfLayer> dco_decode_list_pdf_layer(dynamic raw);
+
+  @protected
+  List<PdfLink> dco_decode_list_pdf_link(dynamic raw);
+
+  @protected
+  List<PdfOutlineItem> dco_decode_list_pdf_outline_item(dynamic raw);
+
+  @protected
+  List<PdfPageRenderResult> dco_decode_list_pdf_page_render_result(dynamic raw);
+
+  @protected
+  List<PdfSearchHit> dco_decode_list_pdf_search_hit(dynamic raw);
+
+  @protected
+  List<PdfTextRect> dco_decode_list_pdf_text_rect(dynamic raw);
+
+  @protected
+  List<PdfTile> dco_decode_list_pdf_tile(dynamic raw);
+
+  @protected
+  List<PdfWordRect> dco_decode_list_pdf_word_rect(dynamic raw);
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<(String, bool)> dco_decode_list_record_string_bool(dynamic raw);
+
+  @protected
+  List<(int, Uint8List)> dco_decode_list_record_u_32_list_prim_u_8_strict(
+      dynamic raw);
+
+  @protected
+  List<ScanError> dco_decode_list_scan_error(dynamic raw);
+
+  @protected
+  List<SentenceSpan> dco_decode_list_sentence_span(dynamic raw);
+
+  @protected
+  List<TimedSample> dco_decode_list_timed_sample(dynamic raw);
+
+  @protected
+  List<TocEntry> dco_decode_list_toc_entry(dynamic raw);
+
+  @protected
+  List<WordSpan> dco_decode_list_word_span(dynamic raw);
+
+  @protected
+  MediaClip dco_decode_media_clip(dynamic raw);
+
+  @protected
+  MobiChapter dco_decode_mobi_chapter(dynamic raw);
+
+  @protected
+  MobiMetadata dco_decode_mobi_metadata(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  ComicInfo? dco_decode_opt_box_autoadd_comic_info(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  (int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32(dynamic raw);
+
+  @protected
+  ScanOptions? dco_decode_opt_box_autoadd_scan_options(dynamic raw);
+
+  @protected
+  SentenceSpan? dco_decode_opt_box_autoadd_sentence_span(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+  @protected
+  PageSize dco_decode_page_size(dynamic raw);
+
+  @protected
+  ParagraphSpan dco_decode_paragraph_span(dynamic raw);
+
+  @protected
+  PdfAnnotation dco_decode_pdf_annotation(dynamic raw);
+
+  @protected
+  PdfAnnotationKind dco_decode_pdf_annotation_kind(dynamic raw);
+
+  @protected
+  PdfFontInfo dco_decode_pdf_font_info(dynamic raw);
+
+  @protected
+  PdfFormField dco_decode_pdf_form_field(dynamic raw);
+
+  @protected
+  PdfFormFieldKind dco_decode_pdf_form_field_kind(dynamic raw);
+
+  @protected
+  PdfImage dco_decode_pdf_image(dynamic raw);
+
+  @protected
+  PdfImageFormat dco_decode_pdf_image_format(dynamic raw);
+
+  @protected
+  PdfLayer dco_decode_pdf_layer(dynamic raw);
+
+  @protected
+  PdfLink dco_decode_pdf_link(dynamic raw);
+
+  @protected
+  PdfLinkKind dco_decode_pdf_link_kind(dynamic raw);
+
+  @protected
+  PdfMetadata dco_decode_pdf_metadata(dynamic raw);
+
+  @protected
+  PdfOutlineItem dco_decode_pdf_outline_item(dynamic raw);
+
+  @protected
+  PdfPageRenderResult dco_decode_pdf_page_render_result(dynamic raw);
+
+  @protected
+  PdfPermissions dco_decode_pdf_permissions(dynamic raw);
+
+  @protected
+  PdfSearchEvent dco_decode_pdf_search_event(dynamic raw);
+
+  @protected
+  PdfSearchHit dco_decode_pdf_search_hit(dynamic raw);
+
+  @protected
+  PdfTextRect dco_decode_pdf_text_rect(dynamic raw);
+
+  @protected
+  PdfTile dco_decode_pdf_tile(dynamic raw);
+
+  @protected
+  PdfWordRect dco_decode_pdf_word_rect(dynamic raw);
+
+  @protected
+  ReadingTime dco_decode_reading_time(dynamic raw);
+
+  @protected
+  (String, bool) dco_decode_record_string_bool(dynamic raw);
+
+  @protected
+  (int, Uint8List) dco_decode_record_u_32_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  (int, int) dco_decode_record_u_32_u_32(dynamic raw);
+
+  @protected
+  RenderOptions dco_decode_render_options(dynamic raw);
+
+  @protected
+  RenderedPage dco_decode_rendered_page(dynamic raw);
+
+  @protected
+  ResourceBytes dco_decode_resource_bytes(dynamic raw);
+
+  @protected
+  ScanDelta dco_decode_scan_delta(dynamic raw);
+
+  @protected
+  ScanError dco_decode_scan_error(dynamic raw);
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw);
+
+  @protected
+  ScanOptions dco_decode_scan_options(dynamic raw);
+
+  @protected
+  ScanProgress dco_decode_scan_progress(dynamic raw);
+
+  @protected
+  ScanReport dco_decode_scan_report(dynamic raw);
+
+  @protected
+  SentenceSpan dco_decode_sentence_span(dynamic raw);
+
+  @protected
+  TextHighlightData dco_decode_text_highlight_data(dynamic raw);
+
+  @protected
+  TextUnit dco_decode_text_unit(dynamic raw);
+
+  @protected
+  TimedSample dco_decode_timed_sample(dynamic raw);
+
+  @protected
+  TocEntry dco_decode_toc_entry(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WordSpan dco_decode_word_span(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<CbzPageEvent> sse_decode_StreamSink_cbz_page_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ImportEvent> sse_decode_StreamSink_import_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<PdfSearchEvent> sse_decode_StreamSink_pdf_search_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  BackgroundKind sse_decode_background_kind(SseDeserializer deserializer);
+
+  @protected
+  BookFormat sse_decode_book_format(SseDeserializer deserializer);
+
+  @protected
+  BookMetadata sse_decode_book_metadata(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  CbzPageData sse_decode_box_autoadd_cbz_page_data(
+      SseDeserializer deserializer);
+
+  @protected
+  ComicInfo sse_decode_box_autoadd_comic_info(SseDeserializer deserializer);
+
+  @protected
+  CoverFormat sse_decode_box_autoadd_cover_format(SseDeserializer deserializer);
+
+  @protected
+  CoverOptions sse_decode_box_autoadd_cover_options(
+      SseDeserializer deserializer);
+
+  @protected
+  DocxImageMode sse_decode_box_autoadd_docx_image_mode(
+      SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  ImportSummary sse_decode_box_autoadd_import_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  ImportedBook sse_decode_box_autoadd_imported_book(
+      SseDeserializer deserializer);
+
+  @protected
+  PdfImageFormat sse_decode_box_autoadd_pdf_image_format(
+      SseDeserializer deserializer);
+
+  @protected
+  PdfSearchHit sse_decode_box_autoadd_pdf_search_hit(
+      SseDeserializer deserializer);
+
+  @protected
+  PdfTextRect sse_decode_box_autoadd_pdf_text_rect(
+      SseDeserializer deserializer);
+
+  @protected
+  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
+      SseDeserializer deserializer);
+
+  @protected
+  RenderOptions sse_decode_box_autoadd_render_options(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanOptions sse_decode_box_autoadd_scan_options(SseDeserializer deserializer);
+
+  @protected
+  ScanProgress sse_decode_box_autoadd_scan_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanReport sse_decode_box_autoadd_scan_report(SseDeserializer deserializer);
+
+  @protected
+  SentenceSpan sse_decode_box_autoadd_sentence_span(
+      SseDeserializer deserializer);
+
+  @protected
+  TextHighlightData sse_decode_box_autoadd_text_highlight_data(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  CbzPageData sse_decode_cbz_page_data(SseDeserializer deserializer);
+
+  @protected
+  CbzPageEvent sse_decode_cbz_page_event(SseDeserializer deserializer);
+
+  @protected
+  CbzPageInfo sse_decode_cbz_page_info(SseDeserializer deserializer);
+
+  @protected
+  CbzPageNames sse_decode_cbz_page_names(SseDeserializer deserializer);
+
+  @protected
+  CbzPageWithBackground sse_decode_cbz_page_with_background(
+      SseDeserializer deserializer);
+
+  @protected
+  ChapterEntry sse_decode_chapter_entry(SseDeserializer deserializer);
+
+  @protected
+  ChapterLocation sse_decode_chapter_location(SseDeserializer deserializer);
+
+  @protected
+  ComicInfo sse_decode_comic_info(SseDeserializer deserializer);
+
+  @protected
+  CoverFormat sse_decode_cover_format(SseDeserializer deserializer);
+
+  @protected
+  CoverOptions sse_decode_cover_options(SseDeserializer deserializer);
+
+  @protected
+  CropMargins sse_decode_crop_margins(SseDeserializer deserializer);
+
+  @protected
+  DocxImageMode sse_decode_docx_image_mode(SseDeserializer deserializer);
+
+  @protected
+  DocxMetadata sse_decode_docx_metadata(SseDeserializer deserializer);
+
+  @protected
+  EncodedImage sse_decode_encoded_image(SseDeserializer deserializer);
+
+  @protected
+  EpubChapter sse_decode_epub_chapter(SseDeserializer deserializer);
+
+  @protected
+  EpubLayout sse_decode_epub_layout(SseDeserializer deserializer);
+
+  @protected
+  EpubMetadata sse_decode_epub_metadata(SseDeserializer deserializer);
+
+  @protected
+  EpubRendition sse_decode_epub_rendition(SseDeserializer deserializer);
+
+  @protected
+  EpubSearchHit sse_decode_epub_search_hit(SseDeserializer deserializer);
+
+  @protected
+  EpubSpineLayout sse_decode_epub_spine_layout(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FerrousError sse_decode_ferrous_error(SseDeserializer deserializer);
+
+  @protected
+  HighlightRange sse_decode_highlight_range(SseDeserializer deserializer);
+
+  @protected
+  HtmlHighlightData sse_decode_html_highlight_data(
+      SseDeserializer deserializer);
+
+  @protected
+  HtmlWordSpan sse_decode_html_word_span(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportEvent sse_decode_import_event(SseDeserializer deserializer);
+
+  @protected
+  ImportFailure sse_decode_import_failure(SseDeserializer deserializer);
+
+  @protected
+  ImportSummary sse_decode_import_summary(SseDeserializer deserializer);
+
+  @protected
+  ImportedBook sse_decode_imported_book(SseDeserializer deserializer);
+
+  @protected
+  KnownFile sse_decode_known_file(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<BookMetadata> sse_decode_list_book_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ChapterEntry> sse_decode_list_chapter_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  List<CropMargins> sse_decode_list_crop_margins(SseDeserializer deserializer);
+
+  @protected
+  List<EpubChapter> sse_decode_list_epub_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<EpubSearchHit> sse_decode_list_epub_search_hit(
+      SseDeserializer deserializer);
+
+  @protected
+  List<EpubSpineLayout> sse_decode_list_epub_spine_layout(
+      SseDeserializer deserializer);
+
+  @protected
+  List<HighlightRange> sse_decode_list_highlight_range(
+      SseDeserializer deserializer);
+
+  @protected
+  List<HtmlWordSpan> sse_decode_list_html_word_span(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ImportFailure> sse_decode_list_import_failure(
+      SseDeserializer deserializer);
+
+  @protected
+  List<KnownFile> sse_decode_list_known_file(SseDeserializer deserializer);
+
+  @protected
+  List<MediaClip> sse_decode_list_media_clip(SseDeserializer deserializer);
+
+  @protected
+  List<MobiChapter> sse_decode_list_mobi_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<PageSize> sse_decode_list_page_size(SseDeserializer deserializer);
+
+  @protected
+  List<ParagraphSpan> sse_decode_list_paragraph_span(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PdfAnnotation> sse_decode_list_pdf_annotation(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PdfFontInfo> sse_decode_list_pdf_font_info(SseDeserializer deserializer);
+
+  @protected
+  List<PdfFormField> sse_decode_list_pdf_form_field(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PdfImage> sse_decode_list_pdf_image(SseDeserializer deserializer);
+
+  @protected
+  List<PdfLayer> sse_decode_list_pdf_layer(SseDeserializer deserializer);
+
+  @protected
+  List<PdfLink> sse_decode_list_pdf_link(SseDeserializer deserializer);
+
+  @protected
+  List<PdfOutlineItem> sse_decode_list_pdf_outline_item(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PdfPageRenderResult> sse_decode_list_pdf_page_render_result(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PdfSearchHit> sse_decode_list_pdf_search_hit(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PdfTextRect> sse_decode_list_pdf_text_rect(SseDeserializer deserializer);
+
+  @protected
+  List<PdfTile> sse_decode_list_pdf_tile(SseDeserializer deserializer);
+
+  @protected
+  List<PdfWordRect> sse_decode_list_pdf_word_rect(SseDeserializer deserializer);
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, bool)> sse_decode_list_record_string_bool(
+      SseDeserializer deserializer);
+
+  @protected
+  List<(int, Uint8List)> sse_decode_list_record_u_32_list_prim_u_8_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ScanError> sse_decode_list_scan_error(SseDeserializer deserializer);
+
+  @protected
+  List<SentenceSpan> sse_decode_list_sentence_span(
+      SseDeserializer deserializer);
+
+  @protected
+  List<TimedSample> sse_decode_list_timed_sample(SseDeserializer deserializer);
+
+  @protected
+  List<TocEntry> sse_decode_list_toc_entry(SseDeserializer deserializer);
+
+  @protected
+  List<WordSpan> sse_decode_list_word_span(SseDeserializer deserializer);
+
+  @protected
+  MediaClip sse_decode_media_clip(SseDeserializer deserializer);
+
+  @protected
+  MobiChapter sse_decode_mobi_chapter(SseDeserializer deserializer);
+
+  @protected
+  MobiMetadata sse_decode_mobi_metadata(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ComicInfo? sse_decode_opt_box_autoadd_comic_info(
+      SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  (int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanOptions? sse_decode_opt_box_autoadd_scan_options(
+      SseDeserializer deserializer);
+
+  @protected
+  SentenceSpan? sse_decode_opt_box_autoadd_sentence_span(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  PageSize sse_decode_page_size(SseDeserializer deserializer);
+
+  @protected
+  ParagraphSpan sse_decode_paragraph_span(SseDeserializer deserializer);
+
+  @protected
+  PdfAnnotation sse_decode_pdf_annotation(SseDeserializer deserializer);
+
+  @protected
+  PdfAnnotationKind sse_decode_pdf_annotation_kind(
+      SseDeserializer deserializer);
+
+  @protected
+  PdfFontInfo sse_decode_pdf_font_info(SseDeserializer deserializer);
+
+  @protected
+  PdfFormField sse_decode_pdf_form_field(SseDeserializer deserializer);
+
+  @protected
+  PdfFormFieldKind sse_decode_pdf_form_field_kind(SseDeserializer deserializer);
+
+  @protected
+  PdfImage sse_decode_pdf_image(SseDeserializer deserializer);
+
+  @protected
+  PdfImageFormat sse_decode_pdf_image_format(SseDeserializer deserializer);
+
+  @protected
+  PdfLayer sse_decode_pdf_layer(SseDeserializer deserializer);
+
+  @protected
+  PdfLink sse_decode_pdf_link(SseDeserializer deserializer);
+
+  @protected
+  PdfLinkKind sse_decode_pdf_link_kind(SseDeserializer deserializer);
+
+  @protected
+  PdfMetadata sse_decode_pdf_metadata(SseDeserializer deserializer);
+
+  @protected
+  PdfOutlineItem sse_decode_pdf_outline_item(SseDeserializer deserializer);
+
+  @protected
+  PdfPageRenderResult sse_decode_pdf_page_render_result(
+      SseDeserializer deserializer);
+
+  @protected
+  PdfPermissions sse_decode_pdf_permissions(SseDeserializer deserializer);
+
+  @protected
+  PdfSearchEvent sse_decode_pdf_search_event(SseDeserializer deserializer);
+
+  @protected
+  PdfSearchHit sse_decode_pdf_search_hit(SseDeserializer deserializer);
+
+  @protected
+  PdfTextRect sse_decode_pdf_text_rect(SseDeserializer deserializer);
+
+  @protected
+  PdfTile sse_decode_pdf_tile(SseDeserializer deserializer);
+
+  @protected
+  PdfWordRect sse_decode_pdf_word_rect(SseDeserializer deserializer);
+
+  @protected
+  ReadingTime sse_decode_reading_time(SseDeserializer deserializer);
+
+  @protected
+  (String, bool) sse_decode_record_string_bool(SseDeserializer deserializer);
+
+  @protected
+  (int, Uint8List) sse_decode_record_u_32_list_prim_u_8_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer);
+
+  @protected
+  RenderOptions sse_decode_render_options(SseDeserializer deserializer);
+
+  @protected
+  RenderedPage sse_decode_rendered_page(SseDeserializer deserializer);
+
+  @protected
+  ResourceBytes sse_decode_resource_bytes(SseDeserializer deserializer);
+
+  @protected
+  ScanDelta sse_decode_scan_delta(SseDeserializer deserializer);
+
+  @protected
+  ScanError sse_decode_scan_error(SseDeserializer deserializer);
+
+  @protected
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer);
+
+  @protected
+  ScanOptions sse_decode_scan_options(SseDeserializer deserializer);
+
+  @protected
+  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer);
+
+  @protected
+  ScanReport sse_decode_scan_report(SseDeserializer deserializer);
+
+  @protected
+  SentenceSpan sse_decode_sentence_span(SseDeserializer deserializer);
+
+  @protected
+  TextHighlightData sse_decode_text_highlight_data(
+      SseDeserializer deserializer);
+
+  @protected
+  TextUnit sse_decode_text_unit(SseDeserializer deserializer);
+
+  @protected
+  TimedSample sse_decode_timed_sample(SseDeserializer deserializer);
+
+  @protected
+  TocEntry sse_decode_toc_entry(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw);
+  void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  SentenceSpan dco_decode_box_autoadd_sentence_span(dynamic raw);
+  BigInt sse_decode_usize(SseDeserializer deserializer);
 
   @protected
-  CbzPageData dco_decode_cbz_page_data(dynamic raw);
+  WordSpan sse_decode_word_span(SseDeserializer deserializer);
 
   @protected
-  CropMargins dco_decode_crop_margins(dynamic raw);
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  void sse_encode_StreamSink_cbz_page_event_Sse(
+      RustStreamSink<CbzPageEvent> self, SseSerializer serializer);
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  void sse_encode_StreamSink_import_event_Sse(
+      RustStreamSink<ImportEvent> self, SseSerializer serializer);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  void sse_encode_StreamSink_pdf_search_event_Sse(
+      RustStreamSink<PdfSearchEvent> self, SseSerializer serializer);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  void sse_encode_StreamSink_scan_event_Sse(
+      RustStreamSink<ScanEvent> self, SseSerializer serializer);
 
   @protected
-  List<BookMetadata> dco_decode_list_book_metadata(dynamic raw);
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  List<MobiChapter> dco_decode_list_mobi_chapter(dynamic raw);
+  void sse_encode_background_kind(
+      BackgroundKind self, SseSerializer serializer);
 
   @protected
-  List<PdfTextRect> dco_decode_list_pdf_text_rect(dynamic raw);
+  void sse_encode_book_format(BookFormat self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_book_metadata(BookMetadata self, SseSerializer serializer);
 
   @protected
-  List<SentenceSpan> dco_decode_list_sentence_span(dynamic raw);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  List<WordSpan> dco_decode_list_word_span(dynamic raw);
+  void sse_encode_box_autoadd_cbz_page_data(
+      CbzPageData self, SseSerializer serializer);
 
   @protected
-  MobiChapter dco_decode_mobi_chapter(dynamic raw);
+  void sse_encode_box_autoadd_comic_info(
+      ComicInfo self, SseSerializer serializer);
 
   @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+  void sse_encode_box_autoadd_cover_format(
+      CoverFormat self, SseSerializer serializer);
 
   @protected
-  SentenceSpan? dco_decode_opt_box_autoadd_sentence_span(dynamic raw);
+  void sse_encode_box_autoadd_cover_options(
+      CoverOptions self, SseSerializer serializer);
 
   @protected
-  PdfPageRenderResult dco_decode_pdf_page_render_result(dynamic raw);
+  void sse_encode_box_autoadd_docx_image_mode(
+      DocxImageMode self, SseSerializer serializer);
 
   @protected
-  PdfTextRect dco_decode_pdf_text_rect(dynamic raw);
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
   @protected
-  SentenceSpan dco_decode_sentence_span(dynamic raw);
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
   @protected
-  TextHighlightData dco_decode_text_highlight_data(dynamic raw);
+  void sse_encode_box_autoadd_import_summary(
+      ImportSummary self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_box_autoadd_imported_book(
+      ImportedBook self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_box_autoadd_pdf_image_format(
+      PdfImageFormat self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_box_autoadd_pdf_search_hit(
+      PdfSearchHit self, SseSerializer serializer);
 
   @protected
-  WordSpan dco_decode_word_span(dynamic raw);
+  void sse_encode_box_autoadd_pdf_text_rect(
+      PdfTextRect self, SseSerializer serializer);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_record_u_32_u_32(
+      (int, int) self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_render_options(
+      RenderOptions self, SseSerializer serializer);
 
   @protected
-  BookMetadata sse_decode_book_metadata(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_scan_options(
+      ScanOptions self, SseSerializer serializer);
 
   @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_scan_progress(
+      ScanProgress self, SseSerializer serializer);
 
   @protected
-  SentenceSpan sse_decode_box_autoadd_sentence_span(
-      SseDeserializer deserializer);
+  void sse_encode_box_autoadd_scan_report(
+      ScanReport self, SseSerializer serializer);
 
   @protected
-  CbzPageData sse_decode_cbz_page_data(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_sentence_span(
+      SentenceSpan self, SseSerializer serializer);
 
   @protected
-  CropMargins sse_decode_crop_margins(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_text_highlight_data(
+      TextHighlightData self, SseSerializer serializer);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_cbz_page_data(CbzPageData self, SseSerializer serializer);
 
   @protected
-  List<BookMetadata> sse_decode_list_book_metadata(
-      SseDeserializer deserializer);
+  void sse_encode_cbz_page_event(CbzPageEvent self, SseSerializer serializer);
 
   @protected
-  List<MobiChapter> sse_decode_list_mobi_chapter(SseDeserializer deserializer);
+  void sse_encode_cbz_page_info(CbzPageInfo self, SseSerializer serializer);
 
   @protected
-  List<PdfTextRect> sse_decode_list_pdf_text_rect(SseDeserializer deserializer);
+  void sse_encode_cbz_page_names(CbzPageNames self, SseSerializer serializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_cbz_page_with_background(
+      CbzPageWithBackground self, SseSerializer serializer);
 
   @protected
-  List<SentenceSpan> sse_decode_list_sentence_span(
-      SseDeserializer deserializer);
+  void sse_encode_chapter_entry(ChapterEntry self, SseSerializer serializer);
 
   @protected
-  List<WordSpan> sse_decode_list_word_span(SseDeserializer deserializer);
+  void sse_encode_chapter_location(
+      ChapterLocation self, SseSerializer serializer);
 
   @protected
-  MobiChapter sse_decode_mobi_chapter(SseDeserializer deserializer);
+  void sse_encode_comic_info(ComicInfo self, SseSerializer serializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+  void sse_encode_cover_format(CoverFormat self, SseSerializer serializer);
 
   @protected
-  SentenceSpan? sse_decode_opt_box_autoadd_sentence_span(
-      SseDeserializer deserializer);
+  void sse_encode_cover_options(CoverOptions self, SseSerializer serializer);
 
   @protected
-  PdfPageRenderResult sse_decode_pdf_page_render_result(
-      SseDeserializer deserializer);
+  void sse_encode_crop_margins(CropMargins self, SseSerializer serializer);
 
   @protected
-  PdfTextRect sse_decode_pdf_text_rect(SseDeserializer deserializer);
+  void sse_encode_docx_image_mode(DocxImageMode self, SseSerializer serializer);
 
   @protected
-  SentenceSpan sse_decode_sentence_span(SseDeserializer deserializer);
+  void sse_encode_docx_metadata(DocxMetadata self, SseSerializer serializer);
 
   @protected
-  TextHighlightData sse_decode_text_highlight_data(
-      SseDeserializer deserializer);
+  void sse_encode_encoded_image(EncodedImage self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_epub_chapter(EpubChapter self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_epub_layout(EpubLayout self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_epub_metadata(EpubMetadata self, SseSerializer serializer);
 
   @protected
-  WordSpan sse_decode_word_span(SseDeserializer deserializer);
+  void sse_encode_epub_rendition(EpubRendition self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_epub_search_hit(EpubSearchHit self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+  void sse_encode_epub_spine_layout(
+      EpubSpineLayout self, SseSerializer serializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_book_metadata(BookMetadata self, SseSerializer serializer);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+  void sse_encode_ferrous_error(FerrousError self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_sentence_span(
-      SentenceSpan self, SseSerializer serializer);
+  void sse_encode_highlight_range(
+      HighlightRange self, SseSerializer serializer);
 
   @protected
-  void sse_encode_cbz_page_data(CbzPageData self, SseSerializer serializer);
+  void sse_encode_html_highlight_data(
+      HtmlHighlightData self, SseSerializer serializer);
 
   @protected
-  void sse_encode_crop_margins(CropMargins self, SseSerializer serializer);
+  void sse_encode_html_word_span(HtmlWordSpan self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_import_event(ImportEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_failure(ImportFailure self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_summary(ImportSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_imported_book(ImportedBook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_known_file(KnownFile self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
@@ -245,42 +1279,293 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_book_metadata(
       List<BookMetadata> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_chapter_entry(
+      List<ChapterEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_crop_margins(
+      List<CropMargins> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_epub_chapter(
+      List<EpubChapter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_epub_search_hit(
+      List<EpubSearchHit> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_epub_spine_layout(
+      List<EpubSpineLayout> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_highlight_range(
+      List<HighlightRange> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_html_word_span(
+      List<HtmlWordSpan> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_import_failure(
+      List<ImportFailure> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_known_file(
+      List<KnownFile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_media_clip(
+      List<MediaClip> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_mobi_chapter(
       List<MobiChapter> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_page_size(List<PageSize> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_paragraph_span(
+      List<ParagraphSpan> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_annotation(
+      List<PdfAnnotation> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_font_info(
+      List<PdfFontInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_form_field(
+      List<PdfFormField> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_image(List<PdfImage> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_layer(List<PdfLayer> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_link(List<PdfLink> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_outline_item(
+      List<PdfOutlineItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_page_render_result(
+      List<PdfPageRenderResult> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_search_hit(
+      List<PdfSearchHit> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_pdf_text_rect(
       List<PdfTextRect> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_pdf_tile(List<PdfTile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pdf_word_rect(
+      List<PdfWordRect> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_i_32_strict(
+      Int32List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+      Uint32List self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_record_string_bool(
+      List<(String, bool)> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_record_u_32_list_prim_u_8_strict(
+      List<(int, Uint8List)> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_scan_error(
+      List<ScanError> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_sentence_span(
       List<SentenceSpan> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_timed_sample(
+      List<TimedSample> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_toc_entry(List<TocEntry> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_word_span(List<WordSpan> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_media_clip(MediaClip self, SseSerializer serializer);
+
   @protected
   void sse_encode_mobi_chapter(MobiChapter self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_mobi_metadata(MobiMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_comic_info(
+      ComicInfo? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_record_u_32_u_32(
+      (int, int)? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_scan_options(
+      ScanOptions? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_sentence_span(
       SentenceSpan? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_page_size(PageSize self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_paragraph_span(ParagraphSpan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_annotation(PdfAnnotation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_annotation_kind(
+      PdfAnnotationKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_font_info(PdfFontInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_form_field(PdfFormField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_form_field_kind(
+      PdfFormFieldKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_image(PdfImage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_image_format(
+      PdfImageFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_layer(PdfLayer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_link(PdfLink self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_link_kind(PdfLinkKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_metadata(PdfMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_outline_item(
+      PdfOutlineItem self, SseSerializer serializer);
+
   @protected
   void sse_encode_pdf_page_render_result(
       PdfPageRenderResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_pdf_permissions(
+      PdfPermissions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_search_event(
+      PdfSearchEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_search_hit(PdfSearchHit self, SseSerializer serializer);
+
   @protected
   void sse_encode_pdf_text_rect(PdfTextRect self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_pdf_tile(PdfTile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_word_rect(PdfWordRect self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reading_time(ReadingTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_bool(
+      (String, bool) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_u_32_list_prim_u_8_strict(
+      (int, Uint8List) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_render_options(RenderOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rendered_page(RenderedPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resource_bytes(ResourceBytes self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_delta(ScanDelta self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_error(ScanError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_options(ScanOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_report(ScanReport self, SseSerializer serializer);
+
   @protected
   void sse_encode_sentence_span(SentenceSpan self, SseSerializer serializer);
 
@@ -288,9 +1573,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_text_highlight_data(
       TextHighlightData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_text_unit(TextUnit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_timed_sample(TimedSample self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_toc_entry(TocEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -298,10 +1598,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_word_span(WordSpan self, SseSerializer serializer);
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_word_span(WordSpan self, SseSerializer serializer);
 }
 
 // Section: wire_class
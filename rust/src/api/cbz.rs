@@ -1,9 +1,16 @@
 use std::fs::File;
+use std::cmp::Ordering;
 use std::io::{Read, BufReader};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
 use crate::timed;
+use lru::LruCache;
 use zip::ZipArchive;
-use image::GenericImageView;
+use image::{GenericImageView, RgbaImage};
 use anyhow::{Result, Context, anyhow};
+use crate::api::covers::{encode_cover, resize_to_fit, CoverFormat};
+use crate::frb_generated::StreamSink;
 
 /// Struct to hold extracted page data
 #[derive(Debug)]
@@ -13,6 +20,13 @@ pub struct CbzPageData {
     pub rgba_bytes: Vec<u8>,
 }
 
+/// Page data plus the letterbox color sampled from the page border (0xAARRGGBB)
+#[derive(Debug)]
+pub struct CbzPageWithBackground {
+    pub page: CbzPageData,
+    pub background_color: u32,
+}
+
 /// Check if a filename is a supported image format
 fn is_image_file(name: &str) -> bool {
     let lower = name.to_lowercase();
@@ -23,7 +37,90 @@ fn is_image_file(name: &str) -> bool {
     lower.ends_with(".webp")
 }
 
-/// Get sorted list of image entries from archive
+/// Compare names so embedded integer runs sort numerically ("p2" < "p10").
+/// Names that compare equal that way (e.g. "p01" vs "p1") fall back to
+/// plain string order, so the sort is total and deterministic.
+pub(crate) fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let mut a_num = String::new();
+                while let Some(c) = a_chars.next_if(|c| c.is_ascii_digit()) {
+                    a_num.push(c);
+                }
+                let mut b_num = String::new();
+                while let Some(c) = b_chars.next_if(|c| c.is_ascii_digit()) {
+                    b_num.push(c);
+                }
+                // Compare by magnitude without parsing, so long runs can't overflow
+                let a_trim = a_num.trim_start_matches('0');
+                let b_trim = b_num.trim_start_matches('0');
+                let ordering = a_trim.len().cmp(&b_trim.len()).then_with(|| a_trim.cmp(b_trim));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+/// Reading order of page entries:
+/// 1. by folder depth, so top-level pages come before any subfolder;
+/// 2. by folder path, component by component in natural order, so
+///    `chapter2/` precedes `chapter10/` and each folder's pages stay together;
+/// 3. by file name in natural order within a folder.
+///
+/// A top-level entry named `cover.*` is then moved to the front; covers of
+/// chapters in subfolders stay with their chapter.
+fn sort_page_entries(entries: &mut [String]) {
+    fn folders(name: &str) -> Vec<&str> {
+        let mut components: Vec<&str> = name.split('/').filter(|c| !c.is_empty()).collect();
+        components.pop();
+        components
+    }
+    fn file_name(name: &str) -> &str {
+        name.rsplit('/').next().unwrap_or(name)
+    }
+
+    entries.sort_by(|a, b| {
+        let (a_folders, b_folders) = (folders(a), folders(b));
+        a_folders
+            .len()
+            .cmp(&b_folders.len())
+            .then_with(|| {
+                a_folders
+                    .iter()
+                    .zip(&b_folders)
+                    .map(|(x, y)| natural_cmp(x, y))
+                    .find(|ordering| *ordering != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            })
+            .then_with(|| natural_cmp(file_name(a), file_name(b)))
+    });
+
+    let is_cover = |name: &str| {
+        let file_name = file_name(name).to_lowercase();
+        folders(name).is_empty() && file_name.rsplit_once('.').is_some_and(|(stem, _)| stem == "cover")
+    };
+    if let Some(cover) = entries.iter().position(|name| is_cover(name)) {
+        entries[..=cover].rotate_right(1);
+    }
+}
+
+/// Get sorted list of image entries from archive, see `sort_page_entries`
 fn get_image_entries(archive: &mut ZipArchive<BufReader<File>>) -> Vec<String> {
     let mut entries: Vec<String> = (0..archive.len())
         .filter_map(|i| {
@@ -37,43 +134,293 @@ fn get_image_entries(archive: &mut ZipArchive<BufReader<File>>) -> Vec<String> {
             })
         })
         .collect();
-    entries.sort();
+    sort_page_entries(&mut entries);
     entries
 }
 
-/// Get total number of image pages in a CBZ archive
-#[flutter_rust_bridge::frb]
-pub fn get_cbz_page_count(path: String) -> Result<i32> {
-    let file = File::open(&path)
+/// Page dimensions read from the image header. `is_wide` marks a likely
+/// two-page spread.
+#[derive(Debug, Clone, Copy)]
+pub struct CbzPageInfo {
+    pub width: u32,
+    pub height: u32,
+    pub is_wide: bool,
+}
+
+/// Metadata from a comic's `ComicInfo.xml`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComicInfo {
+    pub series: Option<String>,
+    pub number: Option<String>,
+    pub title: Option<String>,
+    pub writer: Option<String>,
+    pub page_count: Option<i32>,
+    /// Pages are read right-to-left (`Manga` is `Yes` or `YesAndRightToLeft`)
+    pub manga: bool,
+}
+
+/// Backend-agnostic access to a comic archive, so CBZ (zip) and CBR (rar)
+/// share the page APIs below.
+pub(crate) trait ComicArchive {
+    /// Image entry names in reading order (see `sort_page_entries`)
+    fn image_entries(&mut self) -> Result<Vec<String>>;
+
+    /// Names of all file entries, in archive order
+    fn file_entries(&mut self) -> Result<Vec<String>>;
+
+    /// Raw bytes of one entry
+    fn read_entry(&mut self, name: &str) -> Result<Vec<u8>>;
+
+    /// Up to `limit` leading bytes of one entry. Backends that can't stream
+    /// an entry return it whole.
+    fn read_entry_prefix(&mut self, name: &str, _limit: u64) -> Result<Vec<u8>> {
+        self.read_entry(name)
+    }
+
+    /// CRC-32 and uncompressed size of one entry, read from its header
+    /// without decompressing. `None` when the backend doesn't expose them.
+    fn entry_checksum(&mut self, _name: &str) -> Option<(u32, u64)> {
+        None
+    }
+}
+
+struct ZipComic {
+    archive: ZipArchive<BufReader<File>>,
+}
+
+impl ComicArchive for ZipComic {
+    fn image_entries(&mut self) -> Result<Vec<String>> {
+        Ok(get_image_entries(&mut self.archive))
+    }
+
+    fn file_entries(&mut self) -> Result<Vec<String>> {
+        Ok((0..self.archive.len())
+            .filter_map(|i| self.archive.by_index(i).ok())
+            .filter(|entry| !entry.is_dir())
+            .map(|entry| entry.name().to_string())
+            .collect())
+    }
+
+    fn read_entry(&mut self, name: &str) -> Result<Vec<u8>> {
+        let mut entry = self.archive.by_name(name)
+            .with_context(|| format!("Failed to read entry: {}", name))?;
+        let mut buffer = Vec::new();
+        // The zip reader validates the CRC once the entry is read to the end
+        entry.read_to_end(&mut buffer)
+            .with_context(|| "Failed to read image data")?;
+        Ok(buffer)
+    }
+
+    fn read_entry_prefix(&mut self, name: &str, limit: u64) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.archive.by_name(name)
+            .with_context(|| format!("Failed to read entry: {}", name))?
+            .take(limit)
+            .read_to_end(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn entry_checksum(&mut self, name: &str) -> Option<(u32, u64)> {
+        let entry = self.archive.by_name(name).ok()?;
+        Some((entry.crc32(), entry.size()))
+    }
+}
+
+/// RAR archives are read sequentially, so every lookup walks the headers
+/// from the start.
+struct RarComic {
+    path: String,
+}
+
+fn rar_entry_name(header: &unrar::FileHeader) -> String {
+    header.filename.to_string_lossy().replace('\\', "/")
+}
+
+impl ComicArchive for RarComic {
+    fn image_entries(&mut self) -> Result<Vec<String>> {
+        let listing = unrar::Archive::new(&self.path)
+            .open_for_listing()
+            .with_context(|| "Failed to read RAR archive")?;
+
+        let mut entries = Vec::new();
+        for header in listing {
+            let header = header?;
+            let name = rar_entry_name(&header);
+            if header.is_file() && is_image_file(&name) {
+                entries.push(name);
+            }
+        }
+        sort_page_entries(&mut entries);
+        Ok(entries)
+    }
+
+    fn file_entries(&mut self) -> Result<Vec<String>> {
+        let listing = unrar::Archive::new(&self.path)
+            .open_for_listing()
+            .with_context(|| "Failed to read RAR archive")?;
+
+        let mut entries = Vec::new();
+        for header in listing {
+            let header = header?;
+            if header.is_file() {
+                entries.push(rar_entry_name(&header));
+            }
+        }
+        Ok(entries)
+    }
+
+    fn read_entry(&mut self, name: &str) -> Result<Vec<u8>> {
+        let mut archive = unrar::Archive::new(&self.path)
+            .open_for_processing()
+            .with_context(|| "Failed to read RAR archive")?;
+
+        while let Some(header) = archive.read_header()? {
+            if rar_entry_name(header.entry()) == name {
+                let (data, _) = header.read()
+                    .with_context(|| format!("Failed to read entry: {}", name))?;
+                return Ok(data);
+            }
+            archive = header.skip()?;
+        }
+
+        Err(anyhow!("Failed to read entry: {}", name))
+    }
+}
+
+/// Open a comic archive, picking the backend from the magic bytes rather
+/// than the extension (plenty of .cbr files are really zips and vice versa).
+pub(crate) fn open_comic_archive(path: &str) -> Result<Box<dyn ComicArchive>> {
+    let mut file = File::open(path)
         .with_context(|| format!("Failed to open CBZ file: {}", path))?;
-    let reader = BufReader::new(file);
-    let mut archive = ZipArchive::new(reader)
+    let mut magic = Vec::with_capacity(7);
+    file.by_ref().take(7).read_to_end(&mut magic)?;
+
+    if magic.starts_with(b"Rar!\x1a\x07") {
+        return Ok(Box::new(RarComic { path: path.to_string() }));
+    }
+
+    let archive = ZipArchive::new(BufReader::new(File::open(path)?))
         .with_context(|| "Failed to read ZIP archive")?;
-    
-    let _entries = get_image_entries(&mut archive);
+    Ok(Box::new(ZipComic { archive }))
+}
+
+/// Get total number of image pages in a CBZ/CBR archive
+#[flutter_rust_bridge::frb]
+pub fn get_cbz_page_count(path: String) -> Result<i32> {
     timed!("get_cbz_page_count", {
-        let file = File::open(&path)?;
-        let reader = BufReader::new(file);
-        let mut archive = ZipArchive::new(reader)?;
+        let mut archive = open_comic_archive(&path)?;
         
-        let entries = get_image_entries(&mut archive);
+        let entries = archive.image_entries()?;
         Ok(entries.len() as i32)
     })
 }
 
-/// Get list of page names (sorted) for chapter detection etc
+/// Page names left after `skip_patterns`, and the ones filtered out
+#[derive(Debug, Clone)]
+pub struct CbzPageNames {
+    pub names: Vec<String>,
+    pub page_count: i32,
+    pub skipped: Vec<String>,
+}
+
+/// Case-insensitive glob match supporting `*` and `?`.
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| glob_match(rest, &text[skip..])),
+        Some((&p, rest)) => text.split_first().is_some_and(|(&t, text_rest)| {
+            (p == '?' || p == t) && glob_match(rest, text_rest)
+        }),
+    }
+}
+
+/// Whether the entry's file name matches a skip pattern: a glob when the
+/// pattern has `*` or `?`, otherwise a substring, both case-insensitive.
+fn matches_skip_pattern(name: &str, patterns: &[String]) -> bool {
+    let file_name = name.rsplit('/').next().unwrap_or(name).to_lowercase();
+    let file_chars: Vec<char> = file_name.chars().collect();
+    patterns.iter().map(|p| p.trim().to_lowercase()).filter(|p| !p.is_empty()).any(|pattern| {
+        if pattern.contains(['*', '?']) {
+            glob_match(&pattern.chars().collect::<Vec<_>>(), &file_chars)
+        } else {
+            file_name.contains(&pattern)
+        }
+    })
+}
+
+/// Get list of page names (sorted) for chapter detection etc, leaving out
+/// pages whose file name matches any of `skip_patterns` (e.g. `credits*`,
+/// `*_ad.*`). Filtering happens after sorting, so positions in `names` are
+/// stable; load filtered pages with `get_cbz_page_by_name`, since the
+/// index-based page APIs count every page. No patterns lists every page.
 #[flutter_rust_bridge::frb]
 #[hotpath::measure]
-pub fn get_cbz_page_names(path: String) -> Result<Vec<String>> {
+pub fn get_cbz_page_names(path: String, skip_patterns: Vec<String>) -> Result<CbzPageNames> {
     timed!("get_cbz_page_names", {
-        let file = File::open(&path)?;
-        let reader = BufReader::new(file);
-        let mut archive = ZipArchive::new(reader)?;
-        
-        Ok(get_image_entries(&mut archive))
+        let mut archive = open_comic_archive(&path)?;
+
+        let (skipped, names): (Vec<String>, Vec<String>) = archive
+            .image_entries()?
+            .into_iter()
+            .partition(|name| matches_skip_pattern(name, &skip_patterns));
+        Ok(CbzPageNames {
+            page_count: names.len() as i32,
+            names,
+            skipped,
+        })
     })
 }
 
+/// Decoded pages are cached by content: the same image stored under several
+/// entries of one archive (chapter-break pages in omnibuses) has the same
+/// CRC and size, so it is decoded once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PageCacheKey {
+    path: String,
+    crc: u32,
+    size: u64,
+    max_width: Option<i32>,
+}
+
+/// Off until the app opts in: each cached page is a full RGBA buffer, tens
+/// of megabytes for a large spread.
+const DEFAULT_PAGE_CACHE_CAPACITY: usize = 0;
+
+static PAGE_CACHE_CAPACITY: AtomicUsize = AtomicUsize::new(DEFAULT_PAGE_CACHE_CAPACITY);
+static PAGE_CACHE: OnceLock<Mutex<LruCache<PageCacheKey, Arc<CbzPageData>>>> = OnceLock::new();
+
+fn lock_page_cache() -> MutexGuard<'static, LruCache<PageCacheKey, Arc<CbzPageData>>> {
+    let cache = PAGE_CACHE.get_or_init(|| {
+        // Unused while the capacity is 0; `set_cbz_cache_capacity` resizes it
+        let capacity = NonZeroUsize::new(DEFAULT_PAGE_CACHE_CAPACITY).unwrap_or(NonZeroUsize::MIN);
+        Mutex::new(LruCache::new(capacity))
+    });
+    match cache.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Drop every decoded page cached by `get_cbz_page_by_name`.
+pub fn clear_cbz_cache() {
+    lock_page_cache().clear();
+}
+
+/// How many decoded pages `get_cbz_page_by_name` keeps (default 0, off).
+/// Each holds a full RGBA buffer, so keep this small; 0 disables the cache.
+pub fn set_cbz_cache_capacity(capacity: u32) {
+    let capacity = capacity as usize;
+    PAGE_CACHE_CAPACITY.store(capacity, AtomicOrdering::Relaxed);
+    let mut cache = lock_page_cache();
+    match NonZeroUsize::new(capacity) {
+        Some(capacity) => cache.resize(capacity),
+        None => cache.clear(),
+    }
+}
+
+/// Decode a page by entry name. Zip entries with the same CRC-32 and size
+/// as a page decoded recently are served from the page cache instead (see
+/// `set_cbz_cache_capacity`); RAR pages are always decoded.
 #[flutter_rust_bridge::frb]
 #[hotpath::measure]
 pub fn get_cbz_page_by_name(
@@ -82,48 +429,188 @@ pub fn get_cbz_page_by_name(
     max_width: Option<i32>,
 ) -> Result<CbzPageData> {
     timed!("get_cbz_page_by_name", {
-        let file = File::open(&path)?;
-        let reader = BufReader::new(file);
-        let mut archive = ZipArchive::new(reader)?;
-
-        // Read the image data directly by name
-        let mut entry = archive.by_name(&entry_name)
-            .with_context(|| format!("Failed to read entry: {}", entry_name))?;
-
-        let mut buffer = Vec::new();
-        entry.read_to_end(&mut buffer)
-            .with_context(|| "Failed to read image data")?;
-
-        // Decode the image
-        let img = image::load_from_memory(&buffer)
-            .with_context(|| "Failed to decode image")?;
+        let mut archive = open_comic_archive(&path)?;
 
-        // Optionally resize to limit memory usage
-        let img = if let Some(max_w) = max_width {
-            let (w, h) = img.dimensions();
-            if w > max_w as u32 {
-                let scale = max_w as f32 / w as f32;
-                let new_h = (h as f32 * scale) as u32;
-                img.resize(max_w as u32, new_h, image::imageops::FilterType::Triangle)
-            } else {
-                img
-            }
+        let key = if PAGE_CACHE_CAPACITY.load(AtomicOrdering::Relaxed) > 0 {
+            archive.entry_checksum(&entry_name).map(|(crc, size)| PageCacheKey {
+                path: path.clone(),
+                crc,
+                size,
+                max_width,
+            })
         } else {
-            img
+            None
         };
+        let cached = key.as_ref().and_then(|key| lock_page_cache().get(key).cloned());
+        if let Some(page) = cached {
+            return Ok(CbzPageData {
+                width: page.width,
+                height: page.height,
+                rgba_bytes: page.rgba_bytes.clone(),
+            });
+        }
 
-        // Convert to RGBA bytes
-        let rgba = img.to_rgba8();
+        // Read the image data directly by name
+        let buffer = archive.read_entry(&entry_name)?;
+        let page = rgba_to_page_data(decode_page(&buffer, max_width)?);
+        if let Some(key) = key {
+            let cached = CbzPageData {
+                width: page.width,
+                height: page.height,
+                rgba_bytes: page.rgba_bytes.clone(),
+            };
+            lock_page_cache().put(key, Arc::new(cached));
+        }
+        Ok(page)
+    })
+}
+
+/// An encoded page image and its pixel size
+#[derive(Debug)]
+pub struct EncodedImage {
+    pub width: i32,
+    pub height: i32,
+    pub bytes: Vec<u8>,
+}
+
+/// Like `get_cbz_page_by_name`, but encoded as PNG, JPEG or WebP instead of
+/// raw RGBA. A JPEG of a photographic scan is a fraction of the RGBA buffer,
+/// so prefer this unless the pixels go straight into a texture.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn get_cbz_page_encoded(
+    path: String,
+    entry_name: String,
+    max_width: Option<i32>,
+    format: CoverFormat,
+) -> Result<EncodedImage> {
+    timed!("get_cbz_page_encoded", {
+        let mut archive = open_comic_archive(&path)?;
+        let buffer = archive.read_entry(&entry_name)?;
+        let rgba = decode_page(&buffer, max_width)?;
         let (width, height) = rgba.dimensions();
+        Ok(EncodedImage {
+            width: width as i32,
+            height: height as i32,
+            bytes: encode_cover(&image::DynamicImage::ImageRgba8(rgba), format)?,
+        })
+    })
+}
+
+/// JPEG quality of page grid thumbnails
+const THUMBNAIL_JPEG_QUALITY: u8 = 80;
 
-        Ok(CbzPageData {
+/// Small JPEG of one page whose longest edge fits in `max_dim`, for page
+/// grids. Resized like book covers, so aspect ratio is preserved and pages
+/// are never upscaled.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn get_cbz_thumbnail(path: String, entry_name: String, max_dim: u32) -> Result<EncodedImage> {
+    timed!("get_cbz_thumbnail", {
+        let mut archive = open_comic_archive(&path)?;
+        let buffer = archive.read_entry(&entry_name)?;
+        let img = image::load_from_memory(&buffer)
+            .with_context(|| "Failed to decode image")?;
+        let thumbnail = resize_to_fit(&img, max_dim.max(1));
+        let (width, height) = thumbnail.dimensions();
+        Ok(EncodedImage {
             width: width as i32,
             height: height as i32,
-            rgba_bytes: rgba.into_raw(),
+            bytes: encode_cover(&thumbnail, CoverFormat::Jpeg { quality: THUMBNAIL_JPEG_QUALITY })?,
         })
     })
 }
 
+/// Size a page ends up at after the optional `max_width` downscale
+/// (pages are never upscaled).
+fn scaled_page_dimensions(width: u32, height: u32, max_width: Option<i32>) -> (u32, u32) {
+    match max_width {
+        Some(max_w) if width > max_w as u32 => {
+            let scale = max_w as f32 / width as f32;
+            (max_w as u32, (height as f32 * scale) as u32)
+        }
+        _ => (width, height),
+    }
+}
+
+/// Decode page image bytes, optionally resized to `max_width` to limit memory usage.
+fn decode_page(buffer: &[u8], max_width: Option<i32>) -> Result<RgbaImage> {
+    let img = image::load_from_memory(buffer)
+        .with_context(|| "Failed to decode image")?;
+
+    let (w, h) = img.dimensions();
+    let (new_w, new_h) = scaled_page_dimensions(w, h, max_width);
+    let img = if new_w != w {
+        img.resize(new_w, new_h, image::imageops::FilterType::Triangle)
+    } else {
+        img
+    };
+
+    Ok(img.to_rgba8())
+}
+
+/// Decode a single page by index, optionally resized to `max_width`.
+fn load_cbz_page_rgba(path: &str, index: i32, max_width: Option<i32>) -> Result<RgbaImage> {
+    let mut archive = open_comic_archive(path)?;
+
+    // Get sorted image entries (O(n))
+    let entries = archive.image_entries()?;
+
+    if index < 0 || index as usize >= entries.len() {
+        return Err(anyhow!("Page index {} out of range (0-{})", index, entries.len() - 1));
+    }
+
+    let entry_name = entries[index as usize].clone();
+
+    let buffer = archive.read_entry(&entry_name)?;
+    decode_page(&buffer, max_width)
+}
+
+fn rgba_to_page_data(rgba: RgbaImage) -> CbzPageData {
+    let (width, height) = rgba.dimensions();
+    CbzPageData {
+        width: width as i32,
+        height: height as i32,
+        rgba_bytes: rgba.into_raw(),
+    }
+}
+
+/// Average color of the page's outer border, packed as 0xAARRGGBB (opaque).
+fn sample_border_color(rgba: &RgbaImage) -> u32 {
+    let (width, height) = rgba.dimensions();
+    if width == 0 || height == 0 {
+        return 0xFF000000;
+    }
+
+    let mut sum = [0u64; 3];
+    let mut count = 0u64;
+    let mut add = |x: u32, y: u32| {
+        let p = rgba.get_pixel(x, y);
+        sum[0] += p[0] as u64;
+        sum[1] += p[1] as u64;
+        sum[2] += p[2] as u64;
+        count += 1;
+    };
+
+    for x in 0..width {
+        add(x, 0);
+        if height > 1 {
+            add(x, height - 1);
+        }
+    }
+    for y in 1..height.saturating_sub(1) {
+        add(0, y);
+        if width > 1 {
+            add(width - 1, y);
+        }
+    }
+
+    let r = (sum[0] / count) as u32;
+    let g = (sum[1] / count) as u32;
+    let b = (sum[2] / count) as u32;
+    0xFF000000 | (r << 16) | (g << 8) | b
+}
+
 /// Extract and optionally resize a single page by index.
 #[flutter_rust_bridge::frb]
 #[hotpath::measure]
@@ -133,53 +620,226 @@ pub fn get_cbz_page(
     max_width: Option<i32>,
 ) -> Result<CbzPageData> {
     timed!("get_cbz_page", {
-        let file = File::open(&path)?;
-        let reader = BufReader::new(file);
-        let mut archive = ZipArchive::new(reader)?;
-        
-        // Get sorted image entries (O(n))
-        let entries = get_image_entries(&mut archive);
-        
-        if index < 0 || index as usize >= entries.len() {
-            return Err(anyhow!("Page index {} out of range (0-{})", index, entries.len() - 1));
+        let rgba = load_cbz_page_rgba(&path, index, max_width)?;
+        Ok(rgba_to_page_data(rgba))
+    })
+}
+
+/// Like `get_cbz_page`, but also return the page's average border color so the
+/// reader can letterbox with a matching fill.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn get_cbz_page_with_background(
+    path: String,
+    index: i32,
+    max_width: Option<i32>,
+) -> Result<CbzPageWithBackground> {
+    timed!("get_cbz_page_with_background", {
+        let rgba = load_cbz_page_rgba(&path, index, max_width)?;
+        let background_color = sample_border_color(&rgba);
+        Ok(CbzPageWithBackground {
+            page: rgba_to_page_data(rgba),
+            background_color,
+        })
+    })
+}
+
+pub enum CbzPageEvent {
+    /// One decoded page; pages arrive in reading order
+    Page { index: i32, page: CbzPageData },
+    /// Every page was handled. `failed` lists pages that couldn't be read or
+    /// decoded and were skipped.
+    Done { page_count: i32, failed: Vec<i32> },
+}
+
+/// Decode every page in reading order, emitting each one as soon as it's
+/// ready so the first page can be shown while the rest are still decoding.
+/// Stops early when the Dart side stops listening.
+#[hotpath::measure]
+pub fn stream_cbz_pages(path: String, max_width: Option<i32>, sink: StreamSink<CbzPageEvent>) -> Result<()> {
+    timed!("stream_cbz_pages", {
+        let mut archive = open_comic_archive(&path)?;
+        let entries = archive.image_entries()?;
+
+        let mut failed = Vec::new();
+        for (index, name) in entries.iter().enumerate() {
+            let index = index as i32;
+            let page = archive.read_entry(name).and_then(|buffer| decode_page(&buffer, max_width));
+            match page {
+                Ok(rgba) => {
+                    let page = rgba_to_page_data(rgba);
+                    if sink.add(CbzPageEvent::Page { index, page }).is_err() {
+                        // Dart side stopped listening
+                        return Ok(());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("⚠️ Warning: failed to decode page {} ({}): {}", index, name, e);
+                    failed.push(index);
+                }
+            }
         }
-        
-        let entry_name = entries[index as usize].clone();
-        
-        let mut entry = archive.by_name(&entry_name)
-            .with_context(|| format!("Failed to read entry: {}", entry_name))?;
-        
-        let mut buffer = Vec::new();
-        entry.read_to_end(&mut buffer)
-            .with_context(|| "Failed to read image data")?;
-        
-        let img = image::load_from_memory(&buffer)
-            .with_context(|| "Failed to decode image")?;
-        
-        let img = if let Some(max_w) = max_width {
-            let (w, h) = img.dimensions();
-            if w > max_w as u32 {
-                let scale = max_w as f32 / w as f32;
-                let new_h = (h as f32 * scale) as u32;
-                img.resize(max_w as u32, new_h, image::imageops::FilterType::Triangle)
-            } else {
-                img
+
+        let _ = sink.add(CbzPageEvent::Done {
+            page_count: entries.len() as i32,
+            failed,
+        });
+        Ok(())
+    })
+}
+
+/// Thorough integrity check: read every image entry through the archive's
+/// CRC-32 check without decoding it. Reads the whole archive, so only call it
+/// when the user asks for a verification (e.g. at import), not on open.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn verify_cbz_integrity(path: String) -> Result<Vec<(String, bool)>> {
+    timed!("verify_cbz_integrity", {
+        let mut archive = open_comic_archive(&path)?;
+
+        let entries = archive.image_entries()?;
+        let mut results = Vec::with_capacity(entries.len());
+        for name in entries {
+            // Both backends validate the CRC once the entry is read to the end
+            let ok = archive.read_entry(&name).is_ok();
+            results.push((name, ok));
+        }
+
+        Ok(results)
+    })
+}
+
+/// Most image formats put their dimensions in the first few KB
+const IMAGE_HEADER_PREFIX: u64 = 64 * 1024;
+
+/// Read an entry's pixel dimensions from its image header without decoding.
+/// Tries a short prefix first and only reads the whole entry if the header
+/// lies further in (e.g. JPEGs with large EXIF blocks).
+fn read_entry_dimensions(archive: &mut dyn ComicArchive, name: &str) -> Result<(u32, u32)> {
+    let dimensions_of = |buffer: Vec<u8>| {
+        image::ImageReader::new(std::io::Cursor::new(buffer))
+            .with_guessed_format()
+            .ok()
+            .and_then(|reader| reader.into_dimensions().ok())
+    };
+
+    let prefix = archive.read_entry_prefix(name, IMAGE_HEADER_PREFIX)?;
+    let complete = (prefix.len() as u64) < IMAGE_HEADER_PREFIX;
+    if let Some(dimensions) = dimensions_of(prefix) {
+        return Ok(dimensions);
+    }
+    if complete {
+        return Err(anyhow!("Failed to read image header: {}", name));
+    }
+
+    let buffer = archive.read_entry(name)?;
+    dimensions_of(buffer).with_context(|| format!("Failed to read image header: {}", name))
+}
+
+/// Common page size if every page has the same dimensions, else `None`.
+/// Only image headers are read, so this is cheap enough to call on open.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn get_cbz_uniform_size(path: String) -> Result<Option<(u32, u32)>> {
+    timed!("get_cbz_uniform_size", {
+        let mut archive = open_comic_archive(&path)?;
+
+        let mut common = None;
+        for name in archive.image_entries()? {
+            let dimensions = read_entry_dimensions(archive.as_mut(), &name)?;
+            match common {
+                None => common = Some(dimensions),
+                Some(size) if size != dimensions => return Ok(None),
+                Some(_) => {}
             }
-        } else {
-            img
+        }
+
+        Ok(common)
+    })
+}
+
+fn parse_comic_info(xml: &str) -> Result<ComicInfo> {
+    let doc = roxmltree::Document::parse(xml.trim_start_matches('\u{feff}'))
+        .with_context(|| "Failed to parse ComicInfo.xml")?;
+
+    let field = |tag: &str| {
+        doc.root_element()
+            .children()
+            .find(|n| n.is_element() && n.tag_name().name() == tag)
+            .and_then(|n| n.text())
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+
+    Ok(ComicInfo {
+        series: field("Series"),
+        number: field("Number"),
+        title: field("Title"),
+        writer: field("Writer"),
+        page_count: field("PageCount").and_then(|count| count.parse().ok()),
+        manga: matches!(field("Manga").as_deref(), Some("Yes" | "YesAndRightToLeft")),
+    })
+}
+
+/// Read `ComicInfo.xml` from the archive root. Returns `Ok(None)` when the
+/// archive has no such file, and an error when it exists but can't be parsed.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn get_cbz_metadata(path: String) -> Result<Option<ComicInfo>> {
+    timed!("get_cbz_metadata", {
+        let mut archive = open_comic_archive(&path)?;
+
+        let Some(name) = archive
+            .file_entries()?
+            .into_iter()
+            .find(|name| name.eq_ignore_ascii_case("ComicInfo.xml"))
+        else {
+            return Ok(None);
         };
-        
-        let rgba = img.to_rgba8();
-        let (width, height) = rgba.dimensions();
-        
-        Ok(CbzPageData {
-            width: width as i32,
-            height: height as i32,
-            rgba_bytes: rgba.into_raw(),
+
+        let buffer = archive.read_entry(&name)?;
+        parse_comic_info(&String::from_utf8_lossy(&buffer)).map(Some)
+    })
+}
+
+/// Dimensions and spread hint for one page, from its image header only, so
+/// it's cheap enough to call for every page.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn get_cbz_page_info(path: String, entry_name: String) -> Result<CbzPageInfo> {
+    timed!("get_cbz_page_info", {
+        let mut archive = open_comic_archive(&path)?;
+
+        let (width, height) = read_entry_dimensions(archive.as_mut(), &entry_name)?;
+        Ok(CbzPageInfo {
+            width,
+            height,
+            is_wide: width > height,
         })
     })
 }
 
+/// Predicted RGBA size in bytes of `get_cbz_page(path, index, max_width)`,
+/// from the image header alone. Lets the caller refuse a page or lower
+/// `max_width` before a large decode.
+#[flutter_rust_bridge::frb]
+#[hotpath::measure]
+pub fn estimate_cbz_page_bytes(path: String, index: i32, max_width: Option<i32>) -> Result<u64> {
+    timed!("estimate_cbz_page_bytes", {
+        let mut archive = open_comic_archive(&path)?;
+
+        let entries = archive.image_entries()?;
+        if index < 0 || index as usize >= entries.len() {
+            return Err(anyhow!("Page index {} out of range (0-{})", index, entries.len() as i64 - 1));
+        }
+
+        let (width, height) = read_entry_dimensions(archive.as_mut(), &entries[index as usize])?;
+        let (width, height) = scaled_page_dimensions(width, height, max_width);
+        Ok(width as u64 * height as u64 * 4)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +852,92 @@ mod tests {
         assert!(!is_image_file("readme.txt"));
         assert!(!is_image_file("folder/"));
     }
+
+    #[test]
+    fn test_natural_sort() {
+        let mut names = vec!["p1.jpg", "p10.jpg", "p2.jpg"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(names, vec!["p1.jpg", "p2.jpg", "p10.jpg"]);
+
+        let mut names = vec!["b.png", "a.png", "cover.jpg"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(names, vec!["a.png", "b.png", "cover.jpg"]);
+    }
+
+    #[test]
+    fn test_sort_page_entries() {
+        let mut names: Vec<String> = [
+            "chapter10/p1.jpg",
+            "chapter2/p10.jpg",
+            "zz.jpg",
+            "chapter2/p2.jpg",
+            "Cover.png",
+            "chapter2/extra/p1.jpg",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        sort_page_entries(&mut names);
+        assert_eq!(
+            names,
+            vec![
+                "Cover.png",
+                "zz.jpg",
+                "chapter2/p2.jpg",
+                "chapter2/p10.jpg",
+                "chapter10/p1.jpg",
+                "chapter2/extra/p1.jpg",
+            ]
+        );
+
+        let mut names: Vec<String> = ["chapter1/p1.jpg", "chapter2/cover.jpg", "chapter2/p1.jpg"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        sort_page_entries(&mut names);
+        assert_eq!(names, vec!["chapter1/p1.jpg", "chapter2/cover.jpg", "chapter2/p1.jpg"]);
+    }
+
+    #[test]
+    fn test_matches_skip_pattern() {
+        let patterns = vec!["credits".to_string(), "00_*.jpg".to_string()];
+        assert!(matches_skip_pattern("ch1/Credits.png", &patterns));
+        assert!(matches_skip_pattern("00_ad.jpg", &patterns));
+        assert!(!matches_skip_pattern("00_ad.png", &patterns));
+        assert!(!matches_skip_pattern("p01.jpg", &patterns));
+        assert!(!matches_skip_pattern("p01.jpg", &[]));
+    }
+
+    #[test]
+    fn test_scaled_page_dimensions() {
+        assert_eq!(scaled_page_dimensions(2000, 3000, Some(1000)), (1000, 1500));
+        assert_eq!(scaled_page_dimensions(800, 1200, Some(1000)), (800, 1200));
+        assert_eq!(scaled_page_dimensions(2000, 3000, None), (2000, 3000));
+    }
+
+    #[test]
+    fn test_parse_comic_info() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<ComicInfo xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <Title>The Beginning</Title>
+  <Series>Example Saga</Series>
+  <Number>1</Number>
+  <Writer>A. Writer</Writer>
+  <PageCount>24</PageCount>
+  <Manga>YesAndRightToLeft</Manga>
+</ComicInfo>"#;
+
+        let info = parse_comic_info(xml).unwrap();
+        assert_eq!(info.series.as_deref(), Some("Example Saga"));
+        assert_eq!(info.number.as_deref(), Some("1"));
+        assert_eq!(info.title.as_deref(), Some("The Beginning"));
+        assert_eq!(info.writer.as_deref(), Some("A. Writer"));
+        assert_eq!(info.page_count, Some(24));
+        assert!(info.manga);
+
+        let info = parse_comic_info("<ComicInfo><Manga>No</Manga></ComicInfo>").unwrap();
+        assert_eq!(info, ComicInfo::default());
+
+        assert!(parse_comic_info("<ComicInfo>").is_err());
+    }
 }
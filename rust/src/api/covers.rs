@@ -1,11 +1,15 @@
 use anyhow::{Context, Result};
 use crate::timed;
-use image::{imageops::FilterType, GenericImageView, ImageFormat};
+use image::{imageops::FilterType, DynamicImage, GenericImageView, ImageFormat};
 use std::fs::File;
 use std::io::{BufReader, Read, Seek, Write};
 use std::path::Path;
 use zip::ZipArchive;
 
+use mobi::headers::ExthRecord;
+use mobi::Mobi;
+
+use crate::api::cbz::open_comic_archive;
 use crate::api::pdf::{load_pdf_document, with_pdfium};
 
 fn percent_decode_to_string(input: &str) -> String {
@@ -32,7 +36,7 @@ fn percent_decode_to_string(input: &str) -> String {
     String::from_utf8_lossy(&out).to_string()
 }
 
-fn normalize_zip_path(path: &str) -> String {
+pub(crate) fn normalize_zip_path(path: &str) -> String {
     let mut parts: Vec<&str> = Vec::new();
     let normalized = path.replace('\\', "/");
     for segment in normalized.split('/') {
@@ -57,7 +61,7 @@ fn strip_fragment_and_query(href: &str) -> &str {
         .unwrap_or(href)
 }
 
-fn resolve_epub_href(base_file: &str, href: &str) -> String {
+pub(crate) fn resolve_epub_href(base_file: &str, href: &str) -> String {
     let cleaned = percent_decode_to_string(strip_fragment_and_query(href).trim());
     if cleaned.starts_with("http://") || cleaned.starts_with("https://") {
         return cleaned;
@@ -87,6 +91,11 @@ fn is_supported_image_path(path: &str) -> bool {
         || name.ends_with(".png")
         || name.ends_with(".webp")
         || name.ends_with(".gif")
+        || name.ends_with(".svg")
+}
+
+fn is_svg(path: &str, media_type: Option<&str>) -> bool {
+    media_type.is_some_and(|mt| mt.eq_ignore_ascii_case("image/svg+xml")) || path.to_lowercase().ends_with(".svg")
 }
 
 fn find_zip_entry_case_insensitive<R: Read + Seek>(
@@ -107,32 +116,96 @@ fn find_zip_entry_case_insensitive<R: Read + Seek>(
     None
 }
 
-fn read_zip_bytes<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>> {
-    if let Ok(mut file) = archive.by_name(name) {
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer)
-            .with_context(|| format!("Failed to read zip entry: {}", file.name()))?;
-        return Ok(buffer);
+fn normalized_basename(path: &str) -> String {
+    let decoded = percent_decode_to_string(path);
+    decoded
+        .replace('\\', "/")
+        .rsplit('/')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase()
+}
+
+/// Last-resort lookup for broken EPUBs whose hrefs don't match the zip
+/// layout: the one entry with the same (decoded, case-folded) file name.
+/// `None` when no entry or several entries share the name.
+fn find_zip_entry_by_basename<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    wanted: &str,
+) -> Option<String> {
+    let wanted_base = normalized_basename(wanted);
+    if wanted_base.is_empty() {
+        return None;
     }
+    let mut matches = (0..archive.len())
+        .filter_map(|i| archive.by_index(i).ok().map(|entry| entry.name().to_string()))
+        .filter(|name| !name.ends_with('/') && normalized_basename(name) == wanted_base);
+    let found = matches.next()?;
+    matches.next().is_none().then_some(found)
+}
 
+fn read_zip_entry<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>> {
+    let mut file = archive
+        .by_name(name)
+        .with_context(|| format!("Failed to open zip entry: {name}"))?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)
+        .with_context(|| format!("Failed to read zip entry: {}", file.name()))?;
+    Ok(buffer)
+}
+
+/// Bytes of the entry `name`, matched exactly or else case-insensitively.
+pub(crate) fn read_zip_bytes<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>> {
+    if archive.index_for_name(name).is_some() {
+        return read_zip_entry(archive, name);
+    }
     if let Some(actual) = find_zip_entry_case_insensitive(archive, name) {
-        let mut file = archive
-            .by_name(&actual)
-            .with_context(|| format!("Failed to open zip entry: {actual}"))?;
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer)
-            .with_context(|| format!("Failed to read zip entry: {}", file.name()))?;
-        return Ok(buffer);
+        return read_zip_entry(archive, &actual);
     }
+    Err(anyhow::anyhow!("Zip entry not found: {}", name))
+}
 
+/// `read_zip_bytes` for cover images, which also accepts the one entry
+/// with the same file name elsewhere in the archive, since broken EPUBs
+/// often point covers at the wrong folder.
+fn read_cover_zip_bytes<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>> {
+    if let Ok(bytes) = read_zip_bytes(archive, name) {
+        return Ok(bytes);
+    }
+    if let Some(actual) = find_zip_entry_by_basename(archive, name) {
+        eprintln!("⚠️ Warning: zip entry {} not found, using {} by file name", name, actual);
+        return read_zip_entry(archive, &actual);
+    }
     Err(anyhow::anyhow!("Zip entry not found: {}", name))
 }
 
-fn read_zip_string<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<String> {
+pub(crate) fn read_zip_string<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<String> {
     let bytes = read_zip_bytes(archive, name)?;
     Ok(String::from_utf8_lossy(&bytes).to_string())
 }
 
+/// Locate the OPF package document via `META-INF/container.xml`.
+pub(crate) fn find_epub_opf_path<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<String> {
+    let container_xml = read_zip_string(archive, "META-INF/container.xml")
+        .context("Missing META-INF/container.xml")?;
+    let container_doc = roxmltree::Document::parse(&container_xml)
+        .context("Failed to parse META-INF/container.xml")?;
+
+    for node in container_doc.descendants().filter(|n| n.is_element()) {
+        if node.tag_name().name() != "rootfile" {
+            continue;
+        }
+        if let Some(full) = node.attribute("full-path") {
+            if !full.trim().is_empty() {
+                return Ok(normalize_zip_path(full.trim()));
+            }
+        }
+    }
+
+    Err(anyhow::anyhow!("No OPF rootfile found in container.xml"))
+}
+
 fn extract_first_image_ref_from_html(html: &str) -> Option<String> {
     let doc = scraper::Html::parse_document(html);
 
@@ -159,20 +232,345 @@ fn extract_first_image_ref_from_html(html: &str) -> Option<String> {
     None
 }
 
+/// Longest edge of archive-sourced cover thumbnails when no size is requested
+const DEFAULT_COVER_MAX_DIM: u32 = 360;
+/// Render width of PDF covers when no size is requested
+const DEFAULT_PDF_COVER_WIDTH: u32 = 300;
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher` its output is fixed, so names and
+/// colors derived from it stay the same across releases.
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+pub(crate) fn book_format(book_path: &str) -> String {
+    let lower = book_path.to_lowercase();
+    if lower.ends_with(".fb2.zip") {
+        return "fb2.zip".to_string();
+    }
+    lower.split('.').next_back().unwrap_or("").to_string()
+}
+
+/// Encoded output format for cover thumbnails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverFormat {
+    Png,
+    Jpeg { quality: u8 },
+    Webp,
+}
+
+/// Size and encoding of an extracted cover. The default (360px PNG) matches
+/// what `extract_cover` writes.
+#[derive(Debug, Clone, Copy)]
+pub struct CoverOptions {
+    pub max_dim: u32,
+    pub format: CoverFormat,
+}
+
+impl Default for CoverOptions {
+    fn default() -> Self {
+        Self {
+            max_dim: DEFAULT_COVER_MAX_DIM,
+            format: CoverFormat::Png,
+        }
+    }
+}
+
 #[hotpath::measure]
 pub fn extract_cover(book_path: String, save_path: String) -> Result<String> {
     timed!("extract_cover", {
-        let format = book_path.split('.').last().unwrap_or("").to_lowercase();
-        match format.as_str() {
-            "pdf" => extract_pdf_cover(&book_path, &save_path),
-            "epub" => extract_epub_cover(&book_path, &save_path),
-            "cbz" | "cbr" => extract_cbz_cover(&book_path, &save_path),
-            _ => Err(anyhow::anyhow!("Unsupported format for cover extraction: {}", format)),
+        let bytes = extract_cover_encoded(&book_path, None, CoverFormat::Png)?;
+        write_cover_file(&save_path, &bytes)?;
+        Ok(save_path)
+    })
+}
+
+/// Like `extract_cover`, but with an explicit thumbnail size and encoding,
+/// e.g. JPEG to keep a large library's cover cache small.
+#[hotpath::measure]
+pub fn extract_cover_with_options(
+    book_path: String,
+    save_path: String,
+    options: CoverOptions,
+) -> Result<String> {
+    timed!("extract_cover_with_options", {
+        let bytes = extract_cover_encoded(&book_path, Some(options.max_dim), options.format)?;
+        write_cover_file(&save_path, &bytes)?;
+        Ok(save_path)
+    })
+}
+
+/// Size of generated placeholder covers (2:3, like most book covers)
+const PLACEHOLDER_COVER_WIDTH: u32 = 600;
+const PLACEHOLDER_COVER_HEIGHT: u32 = 900;
+const PLACEHOLDER_MARGIN: f32 = 48.0;
+/// DejaVu Sans Bold, see `assets/fonts/DejaVu-LICENSE.txt`
+static PLACEHOLDER_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+/// Opaque background color derived from `seed`: any hue, muted enough for
+/// white text to stay readable.
+fn placeholder_color(seed: u64) -> image::Rgba<u8> {
+    let hue = (seed % 360) as f32;
+    let (saturation, lightness) = (0.45f32, 0.35f32);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match (hue / 60.0) as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    image::Rgba([channel(r), channel(g), channel(b), 255])
+}
+
+fn text_width(font: &ab_glyph::FontRef, scale: ab_glyph::PxScale, text: &str) -> f32 {
+    use ab_glyph::{Font, ScaleFont};
+    let scaled = font.as_scaled(scale);
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(previous) = previous {
+            width += scaled.kern(previous, id);
+        }
+        width += scaled.h_advance(id);
+        previous = Some(id);
+    }
+    width
+}
+
+/// Greedy word wrap to `max_width`. Words wider than a line (and runs of
+/// CJK text, which has no spaces) are broken between characters.
+fn wrap_text(font: &ab_glyph::FontRef, scale: ab_glyph::PxScale, text: &str, max_width: f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() { word.to_string() } else { format!("{current} {word}") };
+        if text_width(font, scale, &candidate) <= max_width {
+            current = candidate;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        for c in word.chars() {
+            current.push(c);
+            if current.chars().count() > 1 && text_width(font, scale, &current) > max_width {
+                current.pop();
+                lines.push(std::mem::replace(&mut current, c.to_string()));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// `text` without the characters the placeholder font has no glyph for.
+/// DejaVu Sans covers Latin, Greek and Cyrillic but not CJK, so such titles
+/// are left off the card rather than drawn as boxes.
+fn drawable_text(font: &ab_glyph::FontRef, text: &str) -> String {
+    use ab_glyph::Font;
+    let kept: String = text
+        .chars()
+        .filter(|c| c.is_whitespace() || font.glyph_id(*c).0 != 0)
+        .collect();
+    kept.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Largest title size (64px down to 24px) whose wrapped lines fit
+/// `max_width` and `max_height`, with those lines.
+fn fit_title(
+    font: &ab_glyph::FontRef,
+    title: &str,
+    max_width: f32,
+    max_height: f32,
+) -> (ab_glyph::PxScale, Vec<String>) {
+    use ab_glyph::{Font, PxScale};
+    let mut size = 64.0;
+    loop {
+        let scale = PxScale::from(size);
+        let lines = wrap_text(font, scale, title, max_width);
+        let line_height = font.as_scaled(scale).height() * 1.15;
+        let fits = lines.len() as f32 * line_height <= max_height
+            && lines.iter().all(|line| text_width(font, scale, line) <= max_width);
+        if fits || size <= 24.0 {
+            return (scale, lines);
+        }
+        size -= 4.0;
+    }
+}
+
+/// Draw one line of white text horizontally centered, with its baseline at `baseline`.
+fn draw_centered_line(
+    canvas: &mut image::RgbaImage,
+    font: &ab_glyph::FontRef,
+    scale: ab_glyph::PxScale,
+    text: &str,
+    baseline: f32,
+) {
+    use ab_glyph::{point, Font, ScaleFont};
+    let scaled = font.as_scaled(scale);
+    let mut x = (canvas.width() as f32 - text_width(font, scale, text)) / 2.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(previous) = previous {
+            x += scaled.kern(previous, id);
+        }
+        let glyph = id.with_scale_and_position(scale, point(x, baseline));
+        x += scaled.h_advance(id);
+        previous = Some(id);
+
+        let Some(outlined) = font.outline_glyph(glyph) else { continue };
+        let bounds = outlined.px_bounds();
+        outlined.draw(|gx, gy, coverage| {
+            let px = bounds.min.x as i32 + gx as i32;
+            let py = bounds.min.y as i32 + gy as i32;
+            if px < 0 || py < 0 || px >= canvas.width() as i32 || py >= canvas.height() as i32 {
+                return;
+            }
+            let pixel = canvas.get_pixel_mut(px as u32, py as u32);
+            for channel in 0..3 {
+                let background = pixel[channel] as f32;
+                pixel[channel] = (background + (255.0 - background) * coverage.min(1.0)).round() as u8;
+            }
+        });
+    }
+}
+
+/// Render a plain cover card for books without one: a solid background
+/// derived from the title (or `seed`, when given) with the title and author
+/// in white, saved as PNG to `save_path`. The same title always gets the
+/// same color, across runs and releases. The bundled font has no CJK
+/// glyphs; characters it can't draw are skipped, so a CJK-only title gives
+/// a plain colored card.
+#[hotpath::measure]
+pub fn generate_placeholder_cover(
+    title: String,
+    author: String,
+    save_path: String,
+    seed: Option<u64>,
+) -> Result<String> {
+    timed!("generate_placeholder_cover", {
+        use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+
+        let font = FontRef::try_from_slice(PLACEHOLDER_FONT).context("Failed to load placeholder font")?;
+        let seed = seed.unwrap_or_else(|| fnv1a_64(title.as_bytes()));
+        let mut canvas = image::RgbaImage::from_pixel(
+            PLACEHOLDER_COVER_WIDTH,
+            PLACEHOLDER_COVER_HEIGHT,
+            placeholder_color(seed),
+        );
+        let max_width = PLACEHOLDER_COVER_WIDTH as f32 - 2.0 * PLACEHOLDER_MARGIN;
+
+        // Shrink long titles until they fit in the upper two thirds
+        let title_area = PLACEHOLDER_COVER_HEIGHT as f32 * 2.0 / 3.0 - PLACEHOLDER_MARGIN;
+        let (scale, lines) = fit_title(&font, &drawable_text(&font, &title), max_width, title_area);
+        let scaled = font.as_scaled(scale);
+        let line_height = scaled.height() * 1.15;
+        let mut baseline = PLACEHOLDER_MARGIN * 2.0 + scaled.ascent();
+        for line in &lines {
+            draw_centered_line(&mut canvas, &font, scale, line, baseline);
+            baseline += line_height;
+        }
+
+        let author_scale = PxScale::from(32.0);
+        let author_lines = wrap_text(&font, author_scale, &drawable_text(&font, &author), max_width);
+        let author_line_height = font.as_scaled(author_scale).height() * 1.15;
+        let mut author_baseline = PLACEHOLDER_COVER_HEIGHT as f32
+            - PLACEHOLDER_MARGIN * 2.0
+            - author_line_height * (author_lines.len() as f32 - 1.0).max(0.0);
+        for line in &author_lines {
+            draw_centered_line(&mut canvas, &font, author_scale, line, author_baseline);
+            author_baseline += author_line_height;
         }
+
+        let bytes = encode_png(&DynamicImage::ImageRgba8(canvas))?;
+        write_cover_file(&save_path, &bytes)?;
+        Ok(save_path)
     })
 }
 
-fn extract_pdf_cover(book_path: &str, save_path: &str) -> Result<String> {
+fn write_cover_file(save_path: &str, bytes: &[u8]) -> Result<()> {
+    let mut out_file = File::create(save_path).context("Failed to create cover file")?;
+    out_file.write_all(bytes).context("Failed to save cover")?;
+    Ok(())
+}
+
+/// Extract the cover as encoded PNG bytes without touching the disk. `max_dim`
+/// bounds the longest edge (default 360px, or a 300px-wide render for PDFs).
+/// Embedded covers the image decoder can't read are an error, so the bytes
+/// returned are always in the requested format.
+#[hotpath::measure]
+pub fn extract_cover_bytes(book_path: String, max_dim: Option<u32>) -> Result<Vec<u8>> {
+    timed!("extract_cover_bytes", {
+        extract_cover_encoded(&book_path, max_dim, CoverFormat::Png)
+    })
+}
+
+fn extract_cover_encoded(book_path: &str, max_dim: Option<u32>, format: CoverFormat) -> Result<Vec<u8>> {
+    let book_format = book_format(book_path);
+    let image = if book_format == "pdf" {
+        let image = render_pdf_cover(book_path, max_dim.unwrap_or(DEFAULT_PDF_COVER_WIDTH))?;
+        match max_dim {
+            Some(max_dim) => resize_to_fit(&image, max_dim),
+            None => image,
+        }
+    } else {
+        let bytes = find_cover_source_bytes(book_path, &book_format)?;
+        let max_dim = max_dim.unwrap_or(DEFAULT_COVER_MAX_DIM);
+        resize_to_fit(&decode_cover_image(&bytes, max_dim)?, max_dim)
+    };
+    encode_cover(&image, format)
+}
+
+/// Decode the book's cover once and encode it as PNG at each requested size
+/// (longest edge), sorted ascending by size.
+#[hotpath::measure]
+pub fn extract_cover_multi(book_path: String, sizes: Vec<u32>) -> Result<Vec<(u32, Vec<u8>)>> {
+    timed!("extract_cover_multi", {
+        let mut sizes = sizes;
+        sizes.retain(|size| *size > 0);
+        sizes.sort_unstable();
+        sizes.dedup();
+        let Some(&largest) = sizes.last() else {
+            return Ok(Vec::new());
+        };
+
+        let format = book_format(&book_path);
+        let image = if format == "pdf" {
+            render_pdf_cover(&book_path, largest)?
+        } else {
+            decode_cover_image(&find_cover_source_bytes(&book_path, &format)?, largest)?
+        };
+
+        sizes
+            .into_iter()
+            .map(|size| Ok((size, encode_png(&resize_to_fit(&image, size))?)))
+            .collect()
+    })
+}
+
+/// Raw embedded cover bytes for archive-based formats
+fn find_cover_source_bytes(book_path: &str, format: &str) -> Result<Vec<u8>> {
+    match format {
+        "epub" => find_epub_cover_bytes(book_path),
+        "cbz" | "cbr" => find_cbz_cover_bytes(book_path),
+        "mobi" | "azw" | "azw3" => find_mobi_cover_bytes(book_path),
+        "fb2" | "fb2.zip" => crate::api::fb2::extract_fb2_cover(book_path.to_string()),
+        _ => Err(anyhow::anyhow!("Unsupported format for cover extraction: {}", format)),
+    }
+}
+
+fn render_pdf_cover(book_path: &str, width: u32) -> Result<DynamicImage> {
     with_pdfium(|pdfium| {
         let doc = load_pdf_document(pdfium, book_path)?;
 
@@ -181,7 +579,7 @@ fn extract_pdf_cover(book_path: &str, save_path: &str) -> Result<String> {
             .get(0)
             .map_err(|e| anyhow::anyhow!("Failed to get first page: {:?}", e))?;
 
-        let width = 300;
+        let width = width as i32;
         let scale = width as f32 / page.width().value;
         let height = (page.height().value * scale) as i32;
 
@@ -193,21 +591,17 @@ fn extract_pdf_cover(book_path: &str, save_path: &str) -> Result<String> {
             )
             .map_err(|e| anyhow::anyhow!("Failed to render page: {:?}", e))?;
 
-        let img = bitmap.as_image();
-        img.save_with_format(save_path, ImageFormat::Png)
-            .context("Failed to save PDF cover")?;
-
-        Ok(save_path.to_string())
+        Ok(bitmap.as_image())
     })
 }
 
-fn extract_epub_cover(book_path: &str, save_path: &str) -> Result<String> {
+fn find_epub_cover_bytes(book_path: &str) -> Result<Vec<u8>> {
     let file = File::open(book_path).context("Failed to open EPUB file")?;
     let reader = BufReader::new(file);
     let mut archive = ZipArchive::new(reader).context("Failed to read EPUB archive")?;
 
-    if let Ok(saved) = extract_epub_cover_from_opf(&mut archive, save_path) {
-        return Ok(saved);
+    if let Ok(bytes) = find_epub_cover_bytes_from_opf(&mut archive) {
+        return Ok(bytes);
     }
 
     let possible_cover_paths = [
@@ -242,12 +636,7 @@ fn extract_epub_cover(book_path: &str, save_path: &str) -> Result<String> {
         if let Ok(mut entry) = archive.by_name(cover_path) {
             let mut buffer = Vec::new();
             entry.read_to_end(&mut buffer)?;
-            if let Ok(saved) = save_cover_thumbnail(&buffer, save_path) {
-                return Ok(saved);
-            }
-            let mut out_file = File::create(save_path).context("Failed to create cover file")?;
-            out_file.write_all(&buffer)?;
-            return Ok(save_path.to_string());
+            return Ok(buffer);
         }
     }
 
@@ -264,41 +653,15 @@ fn extract_epub_cover(book_path: &str, save_path: &str) -> Result<String> {
         {
             let mut buffer = Vec::new();
             entry.read_to_end(&mut buffer)?;
-            if let Ok(saved) = save_cover_thumbnail(&buffer, save_path) {
-                return Ok(saved);
-            }
-            let mut out_file = File::create(save_path).context("Failed to create cover file")?;
-            out_file.write_all(&buffer)?;
-            return Ok(save_path.to_string());
+            return Ok(buffer);
         }
     }
 
     Err(anyhow::anyhow!("No cover image found in EPUB"))
 }
 
-fn extract_epub_cover_from_opf<R: Read + Seek>(
-    archive: &mut ZipArchive<R>,
-    save_path: &str,
-) -> Result<String> {
-    let container_xml = read_zip_string(archive, "META-INF/container.xml")
-        .context("Missing META-INF/container.xml")?;
-    let container_doc = roxmltree::Document::parse(&container_xml)
-        .context("Failed to parse META-INF/container.xml")?;
-
-    let mut opf_path: Option<String> = None;
-    for node in container_doc.descendants().filter(|n| n.is_element()) {
-        if node.tag_name().name() != "rootfile" {
-            continue;
-        }
-        if let Some(full) = node.attribute("full-path") {
-            if !full.trim().is_empty() {
-                opf_path = Some(normalize_zip_path(full.trim()));
-                break;
-            }
-        }
-    }
-
-    let opf_path = opf_path.context("No OPF rootfile found in container.xml")?;
+fn find_epub_cover_bytes_from_opf<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<Vec<u8>> {
+    let opf_path = find_epub_opf_path(archive)?;
     let opf_xml = read_zip_string(archive, &opf_path)
         .with_context(|| format!("Failed to read OPF: {opf_path}"))?;
     let opf_doc = roxmltree::Document::parse(&opf_xml).context("Failed to parse OPF")?;
@@ -338,19 +701,27 @@ fn extract_epub_cover_from_opf<R: Read + Seek>(
         is_supported_image_path(&item.href)
     };
 
-    let save_from_href = |archive: &mut ZipArchive<R>, base: &str, href: &str| -> Result<String> {
+    let read_href = |archive: &mut ZipArchive<R>, base: &str, href: &str, media_type: Option<&str>| -> Result<Vec<u8>> {
         let resolved = resolve_epub_href(base, href);
         if resolved.starts_with("http://") || resolved.starts_with("https://") {
             return Err(anyhow::anyhow!("External cover ref not supported: {}", resolved));
         }
-        let bytes = read_zip_bytes(archive, &resolved)
+        let bytes = read_cover_zip_bytes(archive, &resolved)
             .with_context(|| format!("Failed to read cover bytes: {resolved}"))?;
-        save_cover_thumbnail(&bytes, save_path).or_else(|_| {
-            let mut out_file =
-                File::create(save_path).context("Failed to create cover file")?;
-            out_file.write_all(&bytes)?;
-            Ok(save_path.to_string())
-        })
+
+        // SVG covers usually just wrap a raster image; prefer that over
+        // rasterizing, which can't resolve the archive-relative reference
+        if is_svg(&resolved, media_type) {
+            if let Some(img_href) = extract_first_image_ref_from_html(&String::from_utf8_lossy(&bytes)) {
+                let img_path = resolve_epub_href(&resolved, &img_href);
+                if !is_svg(&img_path, None) {
+                    if let Ok(raster) = read_cover_zip_bytes(archive, &img_path) {
+                        return Ok(raster);
+                    }
+                }
+            }
+        }
+        Ok(bytes)
     };
 
     if let Some(item) = manifest.iter().find(|item| {
@@ -362,7 +733,7 @@ fn extract_epub_cover_from_opf<R: Read + Seek>(
                 .split_whitespace()
                 .any(|p| p.eq_ignore_ascii_case("cover-image"))
     }) {
-        return save_from_href(archive, &opf_path, &item.href);
+        return read_href(archive, &opf_path, &item.href, item.media_type.as_deref());
     }
 
     let mut cover_id: Option<String> = None;
@@ -388,7 +759,7 @@ fn extract_epub_cover_from_opf<R: Read + Seek>(
             .iter()
             .find(|item| item.id == cover_id && is_image_item(item))
         {
-            return save_from_href(archive, &opf_path, &item.href);
+            return read_href(archive, &opf_path, &item.href, item.media_type.as_deref());
         }
     }
 
@@ -411,8 +782,8 @@ fn extract_epub_cover_from_opf<R: Read + Seek>(
         }
 
         if is_supported_image_path(&resolved) {
-            if let Ok(saved) = save_from_href(archive, &opf_path, href) {
-                return Ok(saved);
+            if let Ok(bytes) = read_href(archive, &opf_path, href, None) {
+                return Ok(bytes);
             }
         }
 
@@ -426,14 +797,8 @@ fn extract_epub_cover_from_opf<R: Read + Seek>(
             if cover_img_path.starts_with("http://") || cover_img_path.starts_with("https://") {
                 continue;
             }
-            if let Ok(bytes) = read_zip_bytes(archive, &cover_img_path) {
-                if let Ok(saved) = save_cover_thumbnail(&bytes, save_path) {
-                    return Ok(saved);
-                }
-                let mut out_file =
-                    File::create(save_path).context("Failed to create cover file")?;
-                out_file.write_all(&bytes)?;
-                return Ok(save_path.to_string());
+            if let Ok(bytes) = read_cover_zip_bytes(archive, &cover_img_path) {
+                return Ok(bytes);
             }
         }
     }
@@ -446,49 +811,160 @@ fn extract_epub_cover_from_opf<R: Read + Seek>(
         let href = item.href.to_lowercase();
         id.contains("cover") || href.contains("cover") || href.contains("title")
     }) {
-        return save_from_href(archive, &opf_path, &item.href);
+        return read_href(archive, &opf_path, &item.href, item.media_type.as_deref());
     }
 
     Err(anyhow::anyhow!("No cover image found via OPF metadata"))
 }
 
-fn extract_cbz_cover(book_path: &str, save_path: &str) -> Result<String> {
-    let file = File::open(book_path).context("Failed to open CBZ file")?;
-    let reader = BufReader::new(file);
-    let mut archive = ZipArchive::new(reader).context("Failed to read CBZ archive")?;
+fn find_cbz_cover_bytes(book_path: &str) -> Result<Vec<u8>> {
+    let mut archive = open_comic_archive(book_path)?;
+    let image_names = archive.image_entries()?;
 
-    let mut image_names: Vec<String> = Vec::new();
-    for i in 0..archive.len() {
-        let entry = archive.by_index(i)?;
-        let name = entry.name().to_lowercase();
-        if name.ends_with(".jpg") || name.ends_with(".jpeg") || name.ends_with(".png") || name.ends_with(".webp") {
-            image_names.push(entry.name().to_string());
-        }
+    match image_names.first() {
+        Some(first_image) => archive.read_entry(first_image),
+        None => Err(anyhow::anyhow!("No image found in CBZ")),
     }
+}
 
-    image_names.sort();
+/// Cover image of a MOBI/AZW3 file: the EXTH cover (201) or thumbnail (202)
+/// record, which hold offsets from the first image record, falling back to
+/// the first image in the book.
+fn find_mobi_cover_bytes(book_path: &str) -> Result<Vec<u8>> {
+    let mobi = Mobi::from_path(book_path).context("Failed to open MOBI file")?;
+    let records = mobi.raw_records();
+    let first_image = mobi.metadata.mobi.first_image_index as usize;
 
-    if let Some(first_image) = image_names.first() {
-        let mut entry = archive.by_name(first_image)?;
-        let mut buffer = Vec::new();
-        entry.read_to_end(&mut buffer)?;
-        if let Ok(saved) = save_cover_thumbnail(&buffer, save_path) {
-            return Ok(saved);
+    for exth in [ExthRecord::CoverOffset, ExthRecord::ThumbOffset] {
+        let Some(offset) = mobi
+            .metadata
+            .exth_record(exth)
+            .and_then(|values| values.first())
+            .and_then(|value| <[u8; 4]>::try_from(value.as_slice()).ok())
+            .map(u32::from_be_bytes)
+        else {
+            continue;
+        };
+        // 0xFFFFFFFF marks "no cover"
+        if offset == u32::MAX {
+            continue;
+        }
+        if let Some(record) = records.records().get(first_image + offset as usize) {
+            if !record.content.is_empty() {
+                return Ok(record.content.to_vec());
+            }
         }
-        let mut out_file = File::create(save_path).context("Failed to create cover file")?;
-        out_file.write_all(&buffer)?;
-        return Ok(save_path.to_string());
     }
 
-    Err(anyhow::anyhow!("No image found in CBZ"))
+    mobi.image_records()
+        .first()
+        .map(|record| record.content.to_vec())
+        .context("No image found in MOBI")
+}
+
+/// Unwrap a zip that just wraps one document (e.g. `.fb2.zip`): return the
+/// name and bytes of the first file entry ending in `inner_ext`.
+pub(crate) fn read_single_file_zip<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    inner_ext: &str,
+) -> Result<(String, Vec<u8>)> {
+    let inner_ext = inner_ext.to_lowercase();
+    let name = (0..archive.len())
+        .filter_map(|i| archive.by_index(i).ok())
+        .find(|entry| !entry.is_dir() && entry.name().to_lowercase().ends_with(&inner_ext))
+        .map(|entry| entry.name().to_string())
+        .with_context(|| format!("No {inner_ext} entry in archive"))?;
+    let bytes = read_zip_bytes(archive, &name)?;
+    Ok((name, bytes))
+}
+
+/// FB2 covers are base64 `<binary>` elements referenced from
+/// `<coverpage><image l:href="#id"/>`.
+pub(crate) fn find_fb2_cover_bytes(fb2: &[u8]) -> Result<Vec<u8>> {
+    use base64::Engine;
+
+    // Ids and base64 payloads are ASCII, so legacy 8-bit encodings survive this
+    let xml = String::from_utf8_lossy(fb2);
+    let doc = roxmltree::Document::parse(&xml).context("Failed to parse FB2")?;
+
+    let cover_id = doc
+        .descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "coverpage")
+        .and_then(|coverpage| {
+            coverpage
+                .descendants()
+                .find(|n| n.is_element() && n.tag_name().name() == "image")
+        })
+        .and_then(|image| {
+            image
+                .attributes()
+                .find(|attr| attr.name() == "href")
+                .map(|attr| attr.value().trim_start_matches('#').to_string())
+        })
+        .context("No coverpage in FB2")?;
+
+    let binary = doc
+        .descendants()
+        .find(|n| {
+            n.is_element() && n.tag_name().name() == "binary" && n.attribute("id") == Some(cover_id.as_str())
+        })
+        .with_context(|| format!("FB2 cover binary not found: {cover_id}"))?;
+
+    let payload: String = binary
+        .text()
+        .unwrap_or("")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(payload)
+        .context("Failed to decode FB2 cover")
+}
+
+/// Decode a raster cover, or rasterize an SVG one so its longest edge is
+/// `svg_max_dim`.
+fn decode_cover_image(bytes: &[u8], svg_max_dim: u32) -> Result<DynamicImage> {
+    if looks_like_svg(bytes) {
+        return rasterize_svg(bytes, svg_max_dim);
+    }
+    image::load_from_memory(bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to decode cover image: {:?}", e))
+}
+
+fn looks_like_svg(bytes: &[u8]) -> bool {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]).to_lowercase();
+    head.contains("<svg")
 }
 
-fn save_cover_thumbnail(bytes: &[u8], save_path: &str) -> Result<String> {
-    let image = image::load_from_memory(bytes)
-        .map_err(|e| anyhow::anyhow!("Failed to decode cover image: {:?}", e))?;
+fn rasterize_svg(bytes: &[u8], max_dim: u32) -> Result<DynamicImage> {
+    use resvg::{tiny_skia, usvg};
+
+    let tree = usvg::Tree::from_data(bytes, &usvg::Options::default()).context("Failed to parse SVG cover")?;
+    let size = tree.size();
+    let scale = max_dim.max(1) as f32 / size.width().max(size.height());
+    let width = (size.width() * scale).round().max(1.0) as u32;
+    let height = (size.height() * scale).round().max(1.0) as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).context("Invalid SVG cover size")?;
+    resvg::render(&tree, tiny_skia::Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+
+    // tiny-skia stores premultiplied alpha
+    let rgba = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    let image = image::RgbaImage::from_raw(width, height, rgba).context("Failed to rasterize SVG cover")?;
+    Ok(DynamicImage::ImageRgba8(image))
+}
+
+/// Downscale so the longest edge fits in `max_dim`, preserving aspect ratio.
+pub(crate) fn resize_to_fit(image: &DynamicImage, max_dim: u32) -> DynamicImage {
     let (width, height) = image.dimensions();
-    let max_dim = 360u32;
-    let resized = if width > max_dim || height > max_dim {
+    if width > max_dim || height > max_dim {
         let scale = if width >= height {
             max_dim as f32 / width as f32
         } else {
@@ -498,11 +974,106 @@ fn save_cover_thumbnail(bytes: &[u8], save_path: &str) -> Result<String> {
         let new_height = (height as f32 * scale).round().max(1.0) as u32;
         image.resize(new_width, new_height, FilterType::Triangle)
     } else {
-        image
-    };
+        image.clone()
+    }
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>> {
+    encode_cover(image, CoverFormat::Png)
+}
+
+pub(crate) fn encode_cover(image: &DynamicImage, format: CoverFormat) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut cursor = std::io::Cursor::new(&mut bytes);
+    match format {
+        CoverFormat::Png => image.write_to(&mut cursor, ImageFormat::Png),
+        CoverFormat::Webp => DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut cursor, ImageFormat::WebP),
+        CoverFormat::Jpeg { quality } => {
+            // JPEG has no alpha channel
+            let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut cursor, quality.clamp(1, 100));
+            DynamicImage::ImageRgb8(image.to_rgb8()).write_with_encoder(encoder)
+        }
+    }
+    .context("Failed to encode cover")?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const FB2_FIXTURE: &str = r##"<?xml version="1.0" encoding="utf-8"?>
+<FictionBook xmlns="http://www.gribuser.ru/xml/fictionbook/2.0" xmlns:l="http://www.w3.org/1999/xlink">
+  <description><title-info><coverpage><image l:href="#cover.jpg"/></coverpage></title-info></description>
+  <body><section><p>Text</p></section></body>
+  <binary id="cover.jpg" content-type="image/jpeg">
+    AQID
+    BA==
+  </binary>
+</FictionBook>"##;
+
+    #[test]
+    fn test_fb2_cover() {
+        assert_eq!(find_fb2_cover_bytes(FB2_FIXTURE.as_bytes()).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_zipped_fb2_cover() {
+        let mut buffer = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(Cursor::new(&mut buffer));
+            writer
+                .start_file("book.fb2", zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(FB2_FIXTURE.as_bytes()).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let mut archive = ZipArchive::new(Cursor::new(buffer)).unwrap();
+        let (name, fb2) = read_single_file_zip(&mut archive, ".fb2").unwrap();
+        assert_eq!(name, "book.fb2");
+        assert_eq!(find_fb2_cover_bytes(&fb2).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_fnv1a_64() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_placeholder_title_wrap_and_fit() {
+        let font = ab_glyph::FontRef::try_from_slice(PLACEHOLDER_FONT).unwrap();
+        let max_width = PLACEHOLDER_COVER_WIDTH as f32 - 2.0 * PLACEHOLDER_MARGIN;
+        let area = PLACEHOLDER_COVER_HEIGHT as f32 * 2.0 / 3.0 - PLACEHOLDER_MARGIN;
 
-    resized
-        .save_with_format(save_path, ImageFormat::Png)
-        .context("Failed to save cover thumbnail")?;
-    Ok(save_path.to_string())
+        let (scale, lines) = fit_title(&font, "Dune", max_width, area);
+        assert_eq!(scale.y, 64.0);
+        assert_eq!(lines, vec!["Dune"]);
+
+        let long = "The Strange Case of Dr Jekyll and Mr Hyde and Other Tales of Terror";
+        let (scale, lines) = fit_title(&font, long, max_width, area);
+        assert!(lines.len() > 1);
+        assert_eq!(lines.join(" "), long);
+        assert!(lines.iter().all(|line| text_width(&font, scale, line) <= max_width));
+
+        // A single word wider than the card is broken instead of overflowing
+        let word = "Donaudampfschifffahrtsgesellschaftskapitän";
+        let lines = wrap_text(&font, ab_glyph::PxScale::from(64.0), word, max_width);
+        assert!(lines.len() > 1);
+        assert_eq!(lines.concat(), word);
+        assert!(lines
+            .iter()
+            .all(|line| text_width(&font, ab_glyph::PxScale::from(64.0), line) <= max_width));
+    }
+
+    #[test]
+    fn test_placeholder_skips_missing_glyphs() {
+        let font = ab_glyph::FontRef::try_from_slice(PLACEHOLDER_FONT).unwrap();
+        assert_eq!(drawable_text(&font, "  Война и мир "), "Война и мир");
+        assert_eq!(drawable_text(&font, "三体"), "");
+        assert_eq!(drawable_text(&font, "三体 Trilogy"), "Trilogy");
+    }
 }
@@ -2,7 +2,9 @@ use anyhow::Result;
 use image::{GenericImageView, Pixel};
 use pdfium_render::prelude::*;
 
-use crate::api::pdf::{load_pdf_document, with_pdfium};
+use crate::api::format::BookFormat;
+use crate::api::pdf::with_document;
+use crate::timed;
 
 #[derive(Debug, Clone, Copy)]
 pub struct CropMargins {
@@ -12,89 +14,495 @@ pub struct CropMargins {
     pub right: f32,
 }
 
+/// Which end of the brightness range counts as page background when
+/// detecting margins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundKind {
+    Light,
+    Dark,
+    /// Decided per page from its corners
+    Auto,
+}
+
+/// Side of the square patch sampled in each corner for `BackgroundKind::Auto`.
+const BACKGROUND_SAMPLE_SIZE: u32 = 8;
+
+/// Whether the page's corners are mostly dark. Corners are nearly always
+/// margin, so their brightness is the background's.
+fn corners_are_dark(img: &image::DynamicImage) -> bool {
+    let (w, h) = img.dimensions();
+    let size = BACKGROUND_SAMPLE_SIZE.min(w).min(h);
+    if size == 0 {
+        return false;
+    }
+    let mut sum = 0u64;
+    let mut count = 0u64;
+    for (x0, y0) in [(0, 0), (w - size, 0), (0, h - size), (w - size, h - size)] {
+        for y in y0..y0 + size {
+            for x in x0..x0 + size {
+                let ch = img.get_pixel(x, y).to_rgb();
+                sum += (ch[0] as u64 + ch[1] as u64 + ch[2] as u64) / 3;
+                count += 1;
+            }
+        }
+    }
+    sum / count < 128
+}
+
+/// Width pages are rendered at for margin detection.
+const CROP_ANALYSIS_WIDTH: i32 = 500;
+
+fn crop_render_config() -> PdfRenderConfig {
+    PdfRenderConfig::new().set_target_width(CROP_ANALYSIS_WIDTH)
+}
+
+/// Threshold `detect_pdf_whitespace` and `detect_pdf_uniform_crop` detect with.
+const DEFAULT_WHITESPACE_THRESHOLD: u8 = 5;
+
+/// Content box of the page, as margins relative to its size, on a white
+/// background with the default threshold.
 pub fn detect_pdf_whitespace(path: String, page_index: u32) -> Result<CropMargins> {
-    with_pdfium(|pdfium| {
-        let doc = load_pdf_document(pdfium, &path)?;
+    detect_pdf_whitespace_with_options(path, page_index, BackgroundKind::Light, DEFAULT_WHITESPACE_THRESHOLD)
+}
 
+/// `detect_pdf_whitespace` with a chosen background. A pixel is background
+/// when all channels are within `threshold` of white (or of black for dark
+/// backgrounds).
+pub fn detect_pdf_whitespace_with_options(
+    path: String,
+    page_index: u32,
+    background: BackgroundKind,
+    threshold: u8,
+) -> Result<CropMargins> {
+    with_document(&path, |doc| {
         let page = doc
             .pages()
             .get(page_index as u16)
             .map_err(|e| anyhow::anyhow!("Failed to get page: {:?}", e))?;
+        page_margins(&page, &crop_render_config(), background, threshold)
+    })
+}
+
+/// `detect_pdf_whitespace_with_options` for pages `start..end` (clamped to the
+/// document), opening the document once.
+pub fn detect_pdf_whitespace_batch(
+    path: String,
+    start: u32,
+    end: u32,
+    background: BackgroundKind,
+    threshold: u8,
+) -> Result<Vec<CropMargins>> {
+    with_document(&path, |doc| {
+        let pages = doc.pages();
+        let end = end.min(pages.len() as u32);
+        let config = crop_render_config();
+        (start..end)
+            .map(|page_index| {
+                let page = pages
+                    .get(page_index as u16)
+                    .map_err(|e| anyhow::anyhow!("Failed to get page: {:?}", e))?;
+                page_margins(&page, &config, background, threshold)
+            })
+            .collect()
+    })
+}
+
+/// Sampled pages whose margin differs from the median by more than this
+/// share of the page on any edge (e.g. full-bleed images) are left out.
+const UNIFORM_CROP_OUTLIER_TOLERANCE: f32 = 0.1;
 
-        let width = 500;
-        let scale = width as f32 / page.width().value;
-        let height = (page.height().value * scale) as i32;
-
-        let bitmap = page
-            .render_with_config(
-                &PdfRenderConfig::new()
-                    .set_target_width(width)
-                    .set_target_height(height),
-            )
-            .map_err(|e| anyhow::anyhow!("Failed to render page: {:?}", e))?;
-
-        let img = bitmap.as_image();
-        let (w, h) = img.dimensions();
-
-        let mut top = 0;
-        let mut bottom = h - 1;
-        let mut left = 0;
-        let mut right = w - 1;
-        
-        let threshold: u8 = 5;
-        let white_cutoff = 255u8.saturating_sub(threshold);
-        let is_white = |p: image::Rgba<u8>| {
-            let ch = p.channels();
+fn median(values: &mut [f32]) -> f32 {
+    values.sort_by(f32::total_cmp);
+    values[values.len() / 2]
+}
+
+/// One crop box for the whole document: the margins of every
+/// `sample_every`-th page are detected and the smallest inset per edge
+/// taken, so content on any sampled page stays visible. Outlier pages are
+/// dropped first by comparing each edge against its median.
+pub fn detect_pdf_uniform_crop(path: String, sample_every: u32) -> Result<CropMargins> {
+    let step = sample_every.max(1) as usize;
+    let samples: Vec<CropMargins> = with_document(&path, |doc| {
+        let pages = doc.pages();
+        let config = crop_render_config();
+        (0..pages.len() as u32)
+            .step_by(step)
+            .map(|page_index| {
+                let page = pages
+                    .get(page_index as u16)
+                    .map_err(|e| anyhow::anyhow!("Failed to get page: {:?}", e))?;
+                page_margins(&page, &config, BackgroundKind::Auto, DEFAULT_WHITESPACE_THRESHOLD)
+            })
+            .collect()
+    })?;
+    if samples.is_empty() {
+        return Err(anyhow::anyhow!("PDF has no pages: {}", path));
+    }
+
+    let edges = |m: &CropMargins| [m.top, m.bottom, m.left, m.right];
+    let medians: Vec<f32> = (0..4)
+        .map(|edge| median(&mut samples.iter().map(|m| edges(m)[edge]).collect::<Vec<_>>()))
+        .collect();
+    let typical: Vec<&CropMargins> = samples
+        .iter()
+        .filter(|m| {
+            edges(m)
+                .iter()
+                .zip(&medians)
+                .all(|(value, median)| (value - median).abs() <= UNIFORM_CROP_OUTLIER_TOLERANCE)
+        })
+        .collect();
+    let kept = if typical.is_empty() { samples.iter().collect() } else { typical };
+
+    let min_edge = |edge: usize| kept.iter().map(|m| edges(m)[edge]).fold(f32::MAX, f32::min);
+    Ok(CropMargins {
+        top: min_edge(0),
+        bottom: min_edge(1),
+        left: min_edge(2),
+        right: min_edge(3),
+    })
+}
+
+fn page_margins(
+    page: &PdfPage,
+    config: &PdfRenderConfig,
+    background: BackgroundKind,
+    threshold: u8,
+) -> Result<CropMargins> {
+    let bitmap = page
+        .render_with_config(config)
+        .map_err(|e| anyhow::anyhow!("Failed to render page: {:?}", e))?;
+
+    let img = bitmap.as_image();
+    let (w, h) = img.dimensions();
+
+    let mut top = 0;
+    let mut bottom = h - 1;
+    let mut left = 0;
+    let mut right = w - 1;
+    
+    let dark = match background {
+        BackgroundKind::Light => false,
+        BackgroundKind::Dark => true,
+        BackgroundKind::Auto => corners_are_dark(&img),
+    };
+    let white_cutoff = 255u8.saturating_sub(threshold);
+    let is_background = |p: image::Rgba<u8>| {
+        let ch = p.channels();
+        if dark {
+            ch[0] < threshold && ch[1] < threshold && ch[2] < threshold
+        } else {
             ch[0] > white_cutoff && ch[1] > white_cutoff && ch[2] > white_cutoff
-        };
+        }
+    };
 
-        'top_loop: for y in 0..h {
-            for x in 0..w {
-                if !is_white(img.get_pixel(x, y)) {
-                    top = y;
-                    break 'top_loop;
-                }
+    'top_loop: for y in 0..h {
+        for x in 0..w {
+            if !is_background(img.get_pixel(x, y)) {
+                top = y;
+                break 'top_loop;
+            }
+        }
+    }
+
+    'bottom_loop: for y in (0..h).rev() {
+        for x in 0..w {
+            if !is_background(img.get_pixel(x, y)) {
+                bottom = y;
+                break 'bottom_loop;
+            }
+        }
+    }
+
+    'left_loop: for x in 0..w {
+        for y in top..=bottom {
+            if !is_background(img.get_pixel(x, y)) {
+                left = x;
+                break 'left_loop;
             }
         }
+    }
 
-        'bottom_loop: for y in (0..h).rev() {
-            for x in 0..w {
-                if !is_white(img.get_pixel(x, y)) {
-                    bottom = y;
-                    break 'bottom_loop;
+    'right_loop: for x in (0..w).rev() {
+        for y in top..=bottom {
+            if !is_background(img.get_pixel(x, y)) {
+                right = x;
+                break 'right_loop;
+            }
+        }
+    }
+
+    let padding = 5;
+    top = top.saturating_sub(padding);
+    bottom = (bottom + padding).min(h - 1);
+    left = left.saturating_sub(padding);
+    right = (right + padding).min(w - 1);
+
+    Ok(CropMargins {
+        top: top as f32 / h as f32,
+        bottom: 1.0 - (bottom as f32 / h as f32),
+        left: left as f32 / w as f32,
+        right: 1.0 - (right as f32 / w as f32),
+    })
+}
+
+/// Fewest glyphs a page needs before its text angle is trusted.
+const ORIENTATION_MIN_CHARS: usize = 20;
+/// Share of glyphs that must agree on one quarter-turn.
+const ORIENTATION_MIN_AGREEMENT: f32 = 0.6;
+/// Longest edge of the thumbnail used for projection profiles.
+const ORIENTATION_SAMPLE_DIM: u32 = 600;
+/// One profile must be this much "peakier" than the other to call the line direction.
+const ORIENTATION_PROFILE_RATIO: f32 = 1.3;
+/// Fewest text lines needed to tell the aligned margin from the ragged one.
+const ORIENTATION_MIN_LINES: usize = 3;
+/// Ink on one side of the lines' x-height band must exceed the other side's
+/// by this factor before a rotation is suggested. Latin ascenders outweigh
+/// descenders by well over this, while scripts without that asymmetry
+/// (Hebrew, CJK columns) stay below it and are left alone.
+const ORIENTATION_STROKE_RATIO: f32 = 2.0;
+
+/// Clockwise quarter-turn (0, 90, 180 or 270) that makes most glyphs
+/// upright, from pdfium's per-character angles and the page's `/Rotate`.
+/// `None` for pages without (enough) text.
+fn text_orientation(page: &PdfPage) -> Option<u16> {
+    let text = page.text().ok()?;
+    let page_rotation = match page.rotation().ok()? {
+        PdfPageRenderRotation::None => 0,
+        PdfPageRenderRotation::Degrees90 => 90,
+        PdfPageRenderRotation::Degrees180 => 180,
+        PdfPageRenderRotation::Degrees270 => 270,
+    };
+
+    let mut votes = [0usize; 4];
+    for ch in text.chars().iter() {
+        if ch.unicode_char().is_none_or(|c| c.is_whitespace()) {
+            continue;
+        }
+        let Ok(angle) = ch.angle_degrees() else { continue };
+        // Glyph angles are counter-clockwise in page space; /Rotate turns the
+        // page clockwise on screen
+        let on_screen = (angle - page_rotation as f32).rem_euclid(360.0);
+        votes[((on_screen / 90.0).round() as usize) % 4] += 1;
+    }
+
+    let total: usize = votes.iter().sum();
+    let (quarter, count) = votes.iter().copied().enumerate().max_by_key(|&(_, count)| count)?;
+    if total < ORIENTATION_MIN_CHARS || (count as f32) < total as f32 * ORIENTATION_MIN_AGREEMENT {
+        return None;
+    }
+    // Text rotated counter-clockwise by θ is fixed by turning θ clockwise
+    Some(quarter as u16 * 90)
+}
+
+/// Sum of jumps between neighbouring profile entries per unit of ink. Text
+/// lines perpendicular to the profile axis make it alternate sharply.
+fn profile_peakiness(profile: &[u32]) -> f32 {
+    let ink: u64 = profile.iter().map(|&v| v as u64).sum();
+    if ink == 0 {
+        return 0.0;
+    }
+    let jumps: u64 = profile.windows(2).map(|w| w[0].abs_diff(w[1]) as u64).sum();
+    jumps as f32 / ink as f32
+}
+
+fn std_dev(values: &[f32]) -> f32 {
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32).sqrt()
+}
+
+/// Clockwise correction for a scanned page from ink projection profiles:
+/// the peakier profile gives the line direction, the aligned (not ragged)
+/// end of the lines marks where they start, and the side of each line's
+/// x-height band with more ink (ascenders) marks the top of the glyphs.
+/// A rotation is only suggested when the margin and the strokes agree, so
+/// flush-right text (RTL scripts) and vertical CJK columns read as upright.
+/// Returns 0 whenever any step is unclear, including fully justified text.
+fn image_orientation(image: &image::DynamicImage) -> u16 {
+    let gray = image.thumbnail(ORIENTATION_SAMPLE_DIM, ORIENTATION_SAMPLE_DIM).to_luma8();
+    let (w, h) = gray.dimensions();
+    if w == 0 || h == 0 {
+        return 0;
+    }
+    let mean = gray.pixels().map(|p| p[0] as u64).sum::<u64>() as f32 / (w * h) as f32;
+    let threshold = (mean * 0.6) as u8;
+    let is_ink = |x: u32, y: u32| gray.get_pixel(x, y)[0] < threshold;
+
+    let mut rows = vec![0u32; h as usize];
+    let mut cols = vec![0u32; w as usize];
+    for y in 0..h {
+        for x in 0..w {
+            if is_ink(x, y) {
+                rows[y as usize] += 1;
+                cols[x as usize] += 1;
+            }
+        }
+    }
+
+    let row_score = profile_peakiness(&rows);
+    let col_score = profile_peakiness(&cols);
+    let horizontal = if row_score > col_score * ORIENTATION_PROFILE_RATIO {
+        true
+    } else if col_score > row_score * ORIENTATION_PROFILE_RATIO {
+        false
+    } else {
+        return 0;
+    };
+
+    // Bands of the stacking profile are the text lines; find where each
+    // line's ink starts and ends along the reading axis, and how much ink
+    // sits before and after its x-height core across the line
+    let (stack, along) = if horizontal { (&rows, w) } else { (&cols, h) };
+    let floor = stack.iter().copied().max().unwrap_or(0) / 20;
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let (mut leading_strokes, mut trailing_strokes) = (0u64, 0u64);
+    let mut band_start = None;
+    for i in 0..=stack.len() {
+        let inked = i < stack.len() && stack[i] > floor;
+        match (inked, band_start) {
+            (true, None) => band_start = Some(i),
+            (false, Some(first)) => {
+                band_start = None;
+                let band = &stack[first..i];
+                let core_floor = band.iter().copied().max().unwrap_or(0) / 2;
+                let core_start = band.iter().position(|&v| v > core_floor).unwrap_or(0);
+                let core_end = band.iter().rposition(|&v| v > core_floor).unwrap_or(0);
+                leading_strokes += band[..core_start].iter().map(|&v| v as u64).sum::<u64>();
+                trailing_strokes += band[core_end + 1..].iter().map(|&v| v as u64).sum::<u64>();
+
+                let mut first_ink = None;
+                let mut last_ink = None;
+                for pos in 0..along {
+                    let hit = (first..i).any(|line| {
+                        if horizontal { is_ink(pos, line as u32) } else { is_ink(line as u32, pos) }
+                    });
+                    if hit {
+                        first_ink.get_or_insert(pos);
+                        last_ink = Some(pos);
+                    }
+                }
+                if let (Some(start), Some(end)) = (first_ink, last_ink) {
+                    starts.push(start as f32);
+                    ends.push(end as f32);
                 }
             }
+            _ => {}
         }
+    }
+    if starts.len() < ORIENTATION_MIN_LINES {
+        return 0;
+    }
+
+    let start_spread = std_dev(&starts);
+    let end_spread = std_dev(&ends);
+    let starts_aligned = start_spread * ORIENTATION_PROFILE_RATIO < end_spread;
+    let ends_aligned = end_spread * ORIENTATION_PROFILE_RATIO < start_spread;
+    let tops_leading = leading_strokes as f32 > trailing_strokes as f32 * ORIENTATION_STROKE_RATIO;
+    let tops_trailing = trailing_strokes as f32 > leading_strokes as f32 * ORIENTATION_STROKE_RATIO;
+    match (horizontal, starts_aligned, ends_aligned) {
+        // Lines flush right with glyph tops facing down: upside down
+        (true, false, true) if tops_trailing => 180,
+        // Lines run top to bottom with glyph tops facing right: the page
+        // was turned clockwise
+        (false, true, false) if tops_trailing => 270,
+        // Lines run bottom to top with glyph tops facing left: the page
+        // was turned counter-clockwise
+        (false, false, true) if tops_leading => 90,
+        _ => 0,
+    }
+}
 
-        'left_loop: for x in 0..w {
-            for y in top..=bottom {
-                if !is_white(img.get_pixel(x, y)) {
-                    left = x;
-                    break 'left_loop;
+/// Suggested clockwise rotation (0, 90, 180 or 270 degrees) to show a
+/// PDF or comic page upright. PDFs with a text layer are judged by glyph
+/// angles, image pages by projection profiles. 0 means the page looks
+/// upright or the evidence is too weak to say. This is a separate, opt-in
+/// analysis: it renders or decodes the page.
+#[hotpath::measure]
+pub fn detect_page_orientation(path: String, page_index: u32) -> Result<u16> {
+    timed!("detect_page_orientation", {
+        match crate::api::format::detect_book_format(path.clone())? {
+            BookFormat::Pdf => with_document(&path, |doc| {
+                let page = doc
+                    .pages()
+                    .get(page_index as u16)
+                    .map_err(|e| anyhow::anyhow!("Failed to get page: {:?}", e))?;
+                if let Some(rotation) = text_orientation(&page) {
+                    return Ok(rotation);
                 }
+
+                let bitmap = page
+                    .render_with_config(&PdfRenderConfig::new()
+                        .set_target_width(ORIENTATION_SAMPLE_DIM as i32)
+                        .set_maximum_height(ORIENTATION_SAMPLE_DIM as i32))
+                    .map_err(|e| anyhow::anyhow!("Failed to render page: {:?}", e))?;
+                Ok(image_orientation(&bitmap.as_image()))
+            }),
+            BookFormat::Cbz | BookFormat::Cbr => {
+                let mut archive = crate::api::cbz::open_comic_archive(&path)?;
+                let entries = archive.image_entries()?;
+                let name = entries
+                    .get(page_index as usize)
+                    .ok_or_else(|| anyhow::anyhow!("Page index {} out of range", page_index))?;
+                let image = image::load_from_memory(&archive.read_entry(name)?)
+                    .map_err(|e| anyhow::anyhow!("Failed to decode image: {:?}", e))?;
+                Ok(image_orientation(&image))
             }
+            format => Err(crate::api::format::UnpagedFormatError { format }.into()),
         }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::imageops::{rotate180, rotate270, rotate90};
+    use image::{DynamicImage, GrayImage, Luma};
 
-        'right_loop: for x in (0..w).rev() {
-            for y in top..=bottom {
-                if !is_white(img.get_pixel(x, y)) {
-                    right = x;
-                    break 'right_loop;
+    /// A 600x400 page of ten ragged text lines: an 8px x-height band per
+    /// line with word gaps, plus ascender and descender strokes above and
+    /// below it when `strokes` is set (Latin-like) and none otherwise.
+    fn text_page(flush_right: bool, strokes: bool) -> GrayImage {
+        let mut page = GrayImage::from_pixel(600, 400, Luma([255]));
+        for line in 0..10u32 {
+            let top = 20 + line * 36;
+            let length = 300 + (line * 97) % 220;
+            let start = if flush_right { 560 - length } else { 40 };
+            for x in start..start + length {
+                if (x - start + line * 17) % 50 >= 44 {
+                    continue;
+                }
+                let mut ink = (top + 4)..(top + 12);
+                if strokes && (x + line) % 6 == 0 {
+                    ink.start = top;
+                }
+                if strokes && (x + line) % 23 == 0 {
+                    ink.end = top + 16;
+                }
+                for y in ink {
+                    page.put_pixel(x, y, Luma([0]));
                 }
             }
         }
+        page
+    }
 
-        let padding = 5;
-        top = top.saturating_sub(padding);
-        bottom = (bottom + padding).min(h - 1);
-        left = left.saturating_sub(padding);
-        right = (right + padding).min(w - 1);
-
-        Ok(CropMargins {
-            top: top as f32 / h as f32,
-            bottom: 1.0 - (bottom as f32 / h as f32),
-            left: left as f32 / w as f32,
-            right: 1.0 - (right as f32 / w as f32),
-        })
-    })
+    #[test]
+    fn test_image_orientation() {
+        let upright = text_page(false, true);
+        let detect = |page: GrayImage| image_orientation(&DynamicImage::ImageLuma8(page));
+        assert_eq!(detect(upright.clone()), 0);
+        assert_eq!(detect(rotate180(&upright)), 180);
+        assert_eq!(detect(rotate90(&upright)), 270);
+        assert_eq!(detect(rotate270(&upright)), 90);
+    }
+
+    #[test]
+    fn test_image_orientation_without_stroke_evidence() {
+        // Flush-right lines of an RTL script are not an upside-down page
+        assert_eq!(image_orientation(&DynamicImage::ImageLuma8(text_page(true, false))), 0);
+        // Nor are vertical columns of CJK text a page turned on its side
+        let columns = rotate90(&text_page(false, false));
+        assert_eq!(image_orientation(&DynamicImage::ImageLuma8(columns)), 0);
+    }
 }
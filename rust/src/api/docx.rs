@@ -1,10 +1,13 @@
 use anyhow::{Context, Result};
-use docx_rs::{read_docx, Bold, DocumentChild, Italic, Paragraph, ParagraphChild, RunChild, Table, TableChild, TableCellContent, TableRowChild};
+use docx_rs::{read_docx, Bold, DocumentChild, HyperlinkData, Italic, Numberings, Paragraph, ParagraphChild, RunChild, Strike, Table, TableCell, TableChild, TableCellContent, TableRowChild};
 use std::fs::File;
 use std::io::Read;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::fs;
+use std::sync::OnceLock;
+
+use crate::api::covers::{read_zip_string, resolve_epub_href};
 
 fn escape_html(text: &str) -> String {
     text.replace('&', "&amp;")
@@ -58,11 +61,38 @@ fn parse_docx_relationships(path: &str) -> Result<HashMap<String, String>> {
 
 fn parse_paragraph_to_html(
     paragraph: &Paragraph, 
-    media_dir: &str, 
+    images: &ImageTarget,
+    rels_map: &HashMap<String, String>
+) -> String {
+    parse_paragraph_children_to_html(&paragraph.children, images, rels_map)
+}
+
+/// Runs of a paragraph or hyperlink, with hyperlinks wrapped in `<a>`.
+fn parse_paragraph_children_to_html(
+    children: &[ParagraphChild],
+    images: &ImageTarget,
     rels_map: &HashMap<String, String>
 ) -> String {
+    let rid_regex = regex::Regex::new(r#"rId\d+"#).unwrap();
+    let size_regex = regex::Regex::new(r#"size: \((\d+), (\d+)\)"#).unwrap();
     let mut html = String::new();
-    for p_child in &paragraph.children {
+    for p_child in children {
+        if let ParagraphChild::Hyperlink(hyperlink) = p_child {
+            let href = match &hyperlink.link {
+                // The reader leaves `path` empty; the target lives in the rels
+                HyperlinkData::External { rid, path } => {
+                    rels_map.get(rid).cloned().unwrap_or_else(|| path.clone())
+                }
+                HyperlinkData::Anchor { anchor } => format!("#{}", anchor),
+            };
+            let content = parse_paragraph_children_to_html(&hyperlink.children, images, rels_map);
+            if href.is_empty() || href == "#" {
+                html.push_str(&content);
+            } else {
+                html.push_str(&format!("<a href=\"{}\">{}</a>", escape_html(&href), content));
+            }
+            continue;
+        }
         if let ParagraphChild::Run(run) = p_child {
             let mut text_content = String::new();
             
@@ -79,16 +109,24 @@ fn parse_paragraph_to_html(
                     }
                     RunChild::Drawing(drawing) => {
                         let drawing_debug = format!("{:?}", drawing);
-                        let re_rid = regex::Regex::new(r#"rId\d+"#).unwrap();
-                        if let Some(mat) = re_rid.find(&drawing_debug) {
+                        if let Some(mat) = rid_regex.find(&drawing_debug) {
                             let rid = mat.as_str();
                             if let Some(target) = rels_map.get(rid) {
                                 // target in rels is relative to word/ (e.g. "media/image1.png")
-                                let img_path = format!("{}/word/{}", media_dir, target);
-                                text_content.push_str(&format!(
-                                    "<img src=\"file://{}\" style=\"max-width: 100%; display: block; margin: 16px auto;\" />",
-                                    img_path
-                                ));
+                                let archive_path = resolve_epub_href("word/document.xml", target);
+                                if let Some(src) = images.src_for(&archive_path) {
+                                    // Extents are in EMUs, 9525 per CSS pixel
+                                    let size_attrs = size_regex
+                                        .captures(&drawing_debug)
+                                        .and_then(|c| Some((c[1].parse::<u64>().ok()?, c[2].parse::<u64>().ok()?)))
+                                        .filter(|(cx, cy)| *cx > 0 && *cy > 0)
+                                        .map(|(cx, cy)| format!(" width=\"{}\" height=\"{}\"", cx / 9525, cy / 9525))
+                                        .unwrap_or_default();
+                                    text_content.push_str(&format!(
+                                        "<img src=\"{}\"{} style=\"max-width: 100%; height: auto; display: block; margin: 16px auto;\" />",
+                                        src, size_attrs
+                                    ));
+                                }
                             }
                         }
                     }
@@ -120,6 +158,47 @@ fn parse_paragraph_to_html(
                 close_tags.insert_str(0, "</i>");
             }
 
+            // Tags always nest b > i > u > s > sup/sub > span so output is stable
+            if debug_string_val(&format!("{:?}", props.underline)).is_some_and(|val| val != "none") {
+                open_tags.push_str("<u>");
+                close_tags.insert_str(0, "</u>");
+            }
+
+            if props
+                .strike
+                .as_ref()
+                .is_some_and(|strike| strike == &Strike::new())
+            {
+                open_tags.push_str("<s>");
+                close_tags.insert_str(0, "</s>");
+            }
+
+            let vert_align = format!("{:?}", props.vert_align);
+            if vert_align.contains("SuperScript") {
+                open_tags.push_str("<sup>");
+                close_tags.insert_str(0, "</sup>");
+            } else if vert_align.contains("SubScript") {
+                open_tags.push_str("<sub>");
+                close_tags.insert_str(0, "</sub>");
+            }
+
+            // Style-only attributes share one span
+            let mut styles = Vec::new();
+            if let Some(color) = debug_string_val(&format!("{:?}", props.color)) {
+                if color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit()) {
+                    styles.push(format!("color:#{}", color.to_lowercase()));
+                }
+            }
+            if let Some(highlight) = debug_string_val(&format!("{:?}", props.highlight))
+                .and_then(|val| highlight_css_color(&val))
+            {
+                styles.push(format!("background-color:{}", highlight));
+            }
+            if !styles.is_empty() {
+                open_tags.push_str(&format!("<span style=\"{}\">", styles.join(";")));
+                close_tags.insert_str(0, "</span>");
+            }
+
             html.push_str(&open_tags);
             html.push_str(&text_content);
             html.push_str(&close_tags);
@@ -128,29 +207,120 @@ fn parse_paragraph_to_html(
     html
 }
 
+/// `val` of a run property whose fields docx-rs keeps private, read from its
+/// Debug output (e.g. `Some(Color { val: "FF0000" })`).
+fn debug_string_val(debug: &str) -> Option<String> {
+    let start = debug.find("val: \"")? + "val: \"".len();
+    let end = debug[start..].find('"')? + start;
+    Some(debug[start..end].to_string())
+}
+
+/// CSS color for a `w:highlight` name. Word's dark yellow has no CSS name.
+fn highlight_css_color(name: &str) -> Option<String> {
+    match name {
+        "none" | "" => None,
+        "darkYellow" => Some("#808000".to_string()),
+        // The remaining Word names (yellow, darkBlue, lightGray...) are CSS names
+        other => Some(other.to_lowercase()),
+    }
+}
+
+/// Vertical merge state of a table cell (`w:vMerge`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum VerticalMerge {
+    Restart,
+    Continue,
+}
+
+static GRID_SPAN_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+
+fn get_grid_span_regex() -> &'static regex::Regex {
+    GRID_SPAN_REGEX.get_or_init(|| regex::Regex::new(r"GridSpan \{ val: (\d+) \}").unwrap())
+}
+
+/// `(gridSpan, vMerge)` of a cell. docx-rs keeps cell properties private, so
+/// they are read from its Debug output like drawing relationship IDs are.
+fn cell_merge_properties(cell: &TableCell) -> (usize, Option<VerticalMerge>) {
+    let debug = format!("{:?}", cell.property);
+    let grid_span = get_grid_span_regex()
+        .captures(&debug)
+        .and_then(|c| c[1].parse::<usize>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let vertical_merge = if debug.contains("VMerge { val: Restart }") {
+        Some(VerticalMerge::Restart)
+    } else if debug.contains("VMerge { val: Continue }") {
+        Some(VerticalMerge::Continue)
+    } else {
+        None
+    };
+    (grid_span, vertical_merge)
+}
+
 fn parse_table_to_html(
     table: &Table, 
-    media_dir: &str, 
+    images: &ImageTarget,
     rels_map: &HashMap<String, String>
 ) -> String {
+    // Lay cells out on the column grid first so vertical merges can be
+    // matched up by grid column across rows
+    let grid: Vec<Vec<(usize, usize, Option<VerticalMerge>, &TableCell)>> = table
+        .rows
+        .iter()
+        .map(|table_child| {
+            let TableChild::TableRow(row) = table_child;
+            let mut column = 0;
+            row.cells
+                .iter()
+                .map(|cell_child| {
+                    let TableRowChild::TableCell(cell) = cell_child;
+                    let (span, merge) = cell_merge_properties(cell);
+                    let start = column;
+                    column += span;
+                    (start, span, merge, cell)
+                })
+                .collect()
+        })
+        .collect();
+
     let mut html = String::new();
     html.push_str("<table>");
-    
-    for table_child in &table.rows {
-        let TableChild::TableRow(row) = table_child;
+
+    for (row_index, row) in grid.iter().enumerate() {
         html.push_str("<tr>");
-        for cell_child in &row.cells {
-            let TableRowChild::TableCell(cell) = cell_child;
-            html.push_str("<td>");
+        for &(column, span, merge, cell) in row {
+            // Continuation cells are covered by the rowspan of the cell above
+            if merge == Some(VerticalMerge::Continue) {
+                continue;
+            }
+            let row_span = if merge == Some(VerticalMerge::Restart) {
+                1 + grid[row_index + 1..]
+                    .iter()
+                    .take_while(|next| {
+                        next.iter().any(|&(c, _, m, _)| c == column && m == Some(VerticalMerge::Continue))
+                    })
+                    .count()
+            } else {
+                1
+            };
+
+            html.push_str("<td");
+            if span > 1 {
+                html.push_str(&format!(" colspan=\"{}\"", span));
+            }
+            if row_span > 1 {
+                html.push_str(&format!(" rowspan=\"{}\"", row_span));
+            }
+            html.push('>');
             for cell_content in &cell.children {
                 match cell_content {
                     TableCellContent::Paragraph(para) => {
                         html.push_str("<p>");
-                        html.push_str(&parse_paragraph_to_html(para, media_dir, rels_map));
+                        html.push_str(&parse_paragraph_to_html(para, images, rels_map));
                         html.push_str("</p>");
                     }
                     TableCellContent::Table(nested_table) => {
-                        html.push_str(&parse_table_to_html(nested_table, media_dir, rels_map));
+                        html.push_str(&parse_table_to_html(nested_table, images, rels_map));
                     }
                     _ => {}
                 }
@@ -164,77 +334,493 @@ fn parse_table_to_html(
     html
 }
 
-pub fn read_docx_to_html(path: String) -> Result<String> {
-    let mut file = File::open(&path).context("Failed to open DOCX file")?;
-    let mut buffer = Vec::new();
-    file.read_to_end(&mut buffer).context("Failed to read DOCX file")?;
+/// Heading level (1-6) of a paragraph, from its style ID ("Heading1".."Heading6",
+/// "Title") or, failing that, its outline level. `None` for body text.
+fn heading_level(paragraph: &Paragraph) -> Option<usize> {
+    if let Some(style) = &paragraph.property.style {
+        let style_id = style.val.to_lowercase();
+        if style_id == "title" {
+            return Some(1);
+        }
+        if let Some(level) = style_id
+            .strip_prefix("heading")
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|n| (1..=6).contains(n))
+        {
+            return Some(level);
+        }
+    }
+
+    // Outline levels are 0-based; 9 means body text
+    paragraph
+        .property
+        .outline_lvl
+        .as_ref()
+        .map(|lvl| lvl.v + 1)
+        .filter(|level| (1..=6).contains(level))
+}
+
+fn collect_plain_text(children: &[ParagraphChild], text: &mut String) {
+    for p_child in children {
+        match p_child {
+            ParagraphChild::Run(run) => {
+                for run_child in &run.children {
+                    if let RunChild::Text(t) = run_child {
+                        text.push_str(&t.text);
+                    }
+                }
+            }
+            ParagraphChild::Hyperlink(hyperlink) => collect_plain_text(&hyperlink.children, text),
+            _ => {}
+        }
+    }
+}
+
+fn paragraph_plain_text(paragraph: &Paragraph) -> String {
+    let mut text = String::new();
+    collect_plain_text(&paragraph.children, &mut text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Text of every non-empty body paragraph, whitespace-collapsed, one
+/// paragraph per entry.
+pub(crate) fn read_docx_paragraphs(path: &str) -> Result<Vec<String>> {
+    let buffer = fs::read(path).context("Failed to read DOCX file")?;
+    let docx = read_docx(&buffer).map_err(|e| anyhow::anyhow!("Failed to parse DOCX: {:?}", e))?;
+
+    let paragraphs = docx
+        .document
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            DocumentChild::Paragraph(paragraph) => {
+                let text = paragraph_plain_text(paragraph);
+                (!text.is_empty()).then_some(text)
+            }
+            _ => None,
+        })
+        .collect();
+    Ok(paragraphs)
+}
 
+/// `(level, title)` of each non-empty heading, in document order. These are
+/// the h1–h6 elements `read_docx_to_html` emits.
+pub(crate) fn read_docx_headings(path: &str) -> Result<Vec<(usize, String)>> {
+    let buffer = fs::read(path).context("Failed to read DOCX file")?;
     let docx = read_docx(&buffer).map_err(|e| anyhow::anyhow!("Failed to parse DOCX: {:?}", e))?;
 
+    let headings = docx
+        .document
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            DocumentChild::Paragraph(paragraph) => {
+                let level = heading_level(paragraph)?;
+                let title = paragraph_plain_text(paragraph);
+                (!title.is_empty()).then_some((level, title))
+            }
+            _ => None,
+        })
+        .collect();
+    Ok(headings)
+}
+
+/// `(numId, ilvl)` pairs whose numbering format is a counter (decimal,
+/// letters, roman...) rather than a bullet. Everything else renders as `<ul>`.
+fn ordered_list_levels(numberings: &Numberings) -> HashSet<(usize, usize)> {
+    let mut ordered = HashSet::new();
+    for numbering in &numberings.numberings {
+        let Some(abstract_num) = numberings
+            .abstract_nums
+            .iter()
+            .find(|a| a.id == numbering.abstract_num_id)
+        else {
+            continue;
+        };
+        for level in &abstract_num.levels {
+            if !matches!(level.format.val.as_str(), "bullet" | "none") {
+                ordered.insert((numbering.id, level.level));
+            }
+        }
+    }
+    ordered
+}
+
+/// Close open lists (and their open `<li>`) until `depth` remain.
+fn close_lists(html: &mut String, open_lists: &mut Vec<&'static str>, depth: usize) {
+    while open_lists.len() > depth {
+        let tag = open_lists.pop().unwrap_or("ul");
+        html.push_str(&format!("</li></{}>", tag));
+    }
+}
+
+/// How `read_docx_to_html_with_images` emits embedded images
+#[derive(Debug, Clone)]
+pub enum DocxImageMode {
+    /// Inline `data:` URIs; nothing is written to disk
+    DataUri,
+    /// Extract media under `assets_dir` and reference it by relative path
+    /// (e.g. `word/media/image1.png`), for HTML loaded with that base URL
+    AssetsDir { assets_dir: String },
+}
+
+/// Where drawing images end up in the generated HTML
+enum ImageTarget {
+    /// Media bytes by archive path, inlined as `data:` URIs
+    DataUri(HashMap<String, Vec<u8>>),
+    /// Media extracted to disk; `src` is the prefix plus the archive path
+    Files { src_prefix: String },
+}
+
+impl ImageTarget {
+    fn src_for(&self, archive_path: &str) -> Option<String> {
+        match self {
+            ImageTarget::DataUri(media) => {
+                use base64::Engine;
+                let bytes = media.get(archive_path)?;
+                let extension = archive_path.rsplit('.').next().unwrap_or("").to_lowercase();
+                let mime = match extension.as_str() {
+                    "jpg" | "jpeg" => "image/jpeg",
+                    "gif" => "image/gif",
+                    "bmp" => "image/bmp",
+                    "webp" => "image/webp",
+                    "svg" => "image/svg+xml",
+                    "tif" | "tiff" => "image/tiff",
+                    "emf" => "image/emf",
+                    "wmf" => "image/wmf",
+                    _ => "image/png",
+                };
+                Some(format!(
+                    "data:{};base64,{}",
+                    mime,
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                ))
+            }
+            ImageTarget::Files { src_prefix } => Some(format!("{}{}", src_prefix, archive_path)),
+        }
+    }
+}
+
+fn read_docx_media(path: &str) -> Result<HashMap<String, Vec<u8>>> {
+    let file = File::open(path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+    let mut media = HashMap::new();
+
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        if file.is_dir() || !file.name().starts_with("word/media/") {
+            continue;
+        }
+        let name = file.name().to_string();
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        media.insert(name, bytes);
+    }
+    Ok(media)
+}
+
+/// Convert a DOCX to HTML. Images are extracted next to the book (into
+/// `<path>_media`) and referenced by absolute `file://` URLs.
+pub fn read_docx_to_html(path: String) -> Result<String> {
     // Derive media cache directory from resolved DOCX path
     let media_dir = format!("{}_media", path);
     let _ = extract_docx_media(&path, &media_dir);
-    let rels_map = parse_docx_relationships(&path).unwrap_or_default();
+    render_docx_html(&path, ImageTarget::Files { src_prefix: format!("file://{}/", media_dir) })
+}
+
+/// Convert a DOCX to HTML with embedded images as data URIs or as files in
+/// a caller-chosen assets directory.
+pub fn read_docx_to_html_with_images(path: String, image_mode: DocxImageMode) -> Result<String> {
+    let images = match image_mode {
+        DocxImageMode::DataUri => {
+            ImageTarget::DataUri(read_docx_media(&path).context("Failed to read DOCX media")?)
+        }
+        DocxImageMode::AssetsDir { assets_dir } => {
+            extract_docx_media(&path, &assets_dir).context("Failed to extract DOCX media")?;
+            ImageTarget::Files { src_prefix: String::new() }
+        }
+    };
+    render_docx_html(&path, images)
+}
+
+/// Document properties from `docProps/core.xml`; missing ones are empty
+#[derive(Debug, Clone, Default)]
+pub struct DocxMetadata {
+    pub title: String,
+    pub creator: String,
+    pub subject: String,
+    pub description: String,
+    pub last_modified_by: String,
+    /// W3CDTF timestamps as stored, e.g. "2024-03-01T10:00:00Z"
+    pub created: String,
+    pub modified: String,
+}
+
+/// Read the core properties (title, author, dates, ...) of a DOCX. A
+/// document without `docProps/core.xml` gets all-empty metadata.
+pub fn get_docx_metadata(path: String) -> Result<DocxMetadata> {
+    let file = File::open(&path).context("Failed to open DOCX file")?;
+    let mut archive = zip::ZipArchive::new(file).context("Failed to read DOCX archive")?;
+    let mut metadata = DocxMetadata::default();
+    let Ok(core_xml) = read_zip_string(&mut archive, "docProps/core.xml") else {
+        return Ok(metadata);
+    };
+    let doc = roxmltree::Document::parse(&core_xml).context("Failed to parse docProps/core.xml")?;
+
+    // Elements come from the dc, dcterms and cp namespaces; local names
+    // don't collide, so match on those alone
+    for node in doc.root_element().children().filter(|n| n.is_element()) {
+        let value = node.text().unwrap_or("").trim().to_string();
+        let field = match node.tag_name().name() {
+            "title" => &mut metadata.title,
+            "creator" => &mut metadata.creator,
+            "subject" => &mut metadata.subject,
+            "description" => &mut metadata.description,
+            "lastModifiedBy" => &mut metadata.last_modified_by,
+            "created" => &mut metadata.created,
+            "modified" => &mut metadata.modified,
+            _ => continue,
+        };
+        *field = value;
+    }
+    Ok(metadata)
+}
+
+fn render_docx_html(path: &str, images: ImageTarget) -> Result<String> {
+    let mut file = File::open(path).context("Failed to open DOCX file")?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).context("Failed to read DOCX file")?;
+
+    let docx = read_docx(&buffer).map_err(|e| anyhow::anyhow!("Failed to parse DOCX: {:?}", e))?;
+
+    let rels_map = parse_docx_relationships(path).unwrap_or_default();
 
     let mut html_output = String::new();
     html_output.push_str("<div class='docx-content'>");
 
+    let ordered_levels = ordered_list_levels(&docx.numberings);
+    // Tags of the open lists, outermost first. Each one has an open <li>.
+    let mut open_lists: Vec<&'static str> = Vec::new();
+
     for child in docx.document.children {
         match child {
             DocumentChild::Paragraph(paragraph) => {
-                let mut tag = "p";
-                let mut extra_style = String::new();
-                let mut class_attr = String::new();
-                let mut is_list = false;
-
-                // Detect heading styles
-                if let Some(style) = &paragraph.property.style {
-                    let style_name = &style.val;
-                    if style_name.to_lowercase().contains("heading1") {
-                        tag = "h1";
-                    } else if style_name.to_lowercase().contains("heading2") {
-                        tag = "h2";
-                    } else if style_name.to_lowercase().contains("heading3") {
-                        tag = "h3";
-                    } else if style_name.to_lowercase().contains("heading") || style_name.to_lowercase().contains("title") {
-                        tag = "h4";
+                let heading = heading_level(&paragraph);
+                let list_item = paragraph
+                    .property
+                    .numbering_property
+                    .as_ref()
+                    .filter(|_| heading.is_none())
+                    .and_then(|num_prop| {
+                        // numId 0 explicitly removes numbering
+                        let num_id = num_prop.id.as_ref().map(|id| id.id).filter(|id| *id != 0)?;
+                        let level = num_prop.level.as_ref().map(|l| l.val).unwrap_or(0);
+                        Some((num_id, level))
+                    });
+
+                let para_content = parse_paragraph_to_html(&paragraph, &images, &rels_map);
+
+                if let Some((num_id, level)) = list_item {
+                    let list_tag = if ordered_levels.contains(&(num_id, level)) { "ol" } else { "ul" };
+                    // A skipped level nests one step deeper, never leaving an empty <li>
+                    let depth = level.min(open_lists.len());
+                    close_lists(&mut html_output, &mut open_lists, depth + 1);
+                    if open_lists.len() == depth + 1 {
+                        if open_lists[depth] == list_tag {
+                            html_output.push_str("</li>");
+                        } else {
+                            close_lists(&mut html_output, &mut open_lists, depth);
+                        }
+                    }
+                    if open_lists.len() == depth {
+                        html_output.push_str(&format!("<{}>", list_tag));
+                        open_lists.push(list_tag);
                     }
+                    html_output.push_str("<li>");
+                    html_output.push_str(&para_content);
+                    continue;
                 }
 
-                // Detect bullet or numbered list styles
-                if let Some(num_prop) = &paragraph.property.numbering_property {
-                    is_list = true;
-                    class_attr = " class='list-item'".to_string();
-                    let level = num_prop.level.as_ref().map(|l| l.val).unwrap_or(0);
-                    let indent = 24 * (level + 1);
-                    extra_style = format!(
-                        " style='margin-left: {}px; text-indent: -16px; padding-left: 16px; margin-top: 4px; margin-bottom: 4px;'",
-                        indent
-                    );
-                }
+                close_lists(&mut html_output, &mut open_lists, 0);
 
-                let para_content = parse_paragraph_to_html(&paragraph, &media_dir, &rels_map);
-                
                 // Skip empty paragraphs or render as vertical spacing
-                if para_content.trim().is_empty() && !is_list {
+                if para_content.trim().is_empty() {
                     html_output.push_str("<div style='height: 12px;'></div>");
                     continue;
                 }
 
-                html_output.push_str(&format!("<{}{}{}>", tag, class_attr, extra_style));
-                if is_list {
-                    html_output.push_str("• &nbsp;");
-                }
+                let tag = match heading {
+                    Some(level) => format!("h{}", level),
+                    None => "p".to_string(),
+                };
+                html_output.push_str(&format!("<{}>", tag));
                 html_output.push_str(&para_content);
                 html_output.push_str(&format!("</{}>", tag));
             }
             DocumentChild::Table(table) => {
-                html_output.push_str(&parse_table_to_html(&table, &media_dir, &rels_map));
+                close_lists(&mut html_output, &mut open_lists, 0);
+                html_output.push_str(&parse_table_to_html(&table, &images, &rels_map));
             }
             _ => {}
         }
     }
+    close_lists(&mut html_output, &mut open_lists, 0);
 
     html_output.push_str("</div>");
     Ok(html_output)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const W_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+    const R_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+    /// Write a minimal DOCX with `body` as the document body to a temp file
+    /// named after `name`, and return its path.
+    fn write_docx_fixture(name: &str, body: &str) -> String {
+        let content_types = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="xml" ContentType="application/xml"/>
+  <Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+  <Override PartName="/word/numbering.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml"/>
+</Types>"#;
+        let package_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+</Relationships>"#;
+        let document_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering" Target="numbering.xml"/>
+  <Relationship Id="rId10" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="https://example.com/docs" TargetMode="External"/>
+</Relationships>"#;
+        // numId 1 counts (decimal), numId 2 is bulleted
+        let numbering = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:numbering xmlns:w="{W_NS}">
+  <w:abstractNum w:abstractNumId="0"><w:lvl w:ilvl="0"><w:start w:val="1"/><w:numFmt w:val="decimal"/><w:lvlText w:val="%1."/></w:lvl></w:abstractNum>
+  <w:abstractNum w:abstractNumId="1"><w:lvl w:ilvl="1"><w:start w:val="1"/><w:numFmt w:val="bullet"/><w:lvlText w:val="-"/></w:lvl></w:abstractNum>
+  <w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+  <w:num w:numId="2"><w:abstractNumId w:val="1"/></w:num>
+</w:numbering>"#
+        );
+        let document = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="{W_NS}" xmlns:r="{R_NS}"><w:body>{body}</w:body></w:document>"#
+        );
+
+        let path = std::env::temp_dir().join(format!("ferrous_test_{name}.docx"));
+        let mut writer = zip::ZipWriter::new(File::create(&path).unwrap());
+        for (entry, content) in [
+            ("[Content_Types].xml", content_types),
+            ("_rels/.rels", package_rels),
+            ("word/_rels/document.xml.rels", document_rels),
+            ("word/numbering.xml", numbering.as_str()),
+            ("word/document.xml", document.as_str()),
+        ] {
+            writer.start_file(entry, zip::write::SimpleFileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    fn render_fixture(name: &str, body: &str) -> String {
+        let path = write_docx_fixture(name, body);
+        let html = read_docx_to_html_with_images(path.clone(), DocxImageMode::DataUri).unwrap();
+        let _ = std::fs::remove_file(&path);
+        html
+    }
+
+    fn list_item(text: &str, num_id: usize, level: usize) -> String {
+        format!(
+            r#"<w:p><w:pPr><w:numPr><w:ilvl w:val="{level}"/><w:numId w:val="{num_id}"/></w:numPr></w:pPr><w:r><w:t>{text}</w:t></w:r></w:p>"#
+        )
+    }
+
+    #[test]
+    fn test_docx_lists() {
+        let body = [
+            list_item("one", 1, 0),
+            list_item("two", 1, 0),
+            list_item("dot", 2, 1),
+            "<w:p><w:r><w:t>after</w:t></w:r></w:p>".to_string(),
+        ]
+        .concat();
+        assert_eq!(
+            render_fixture("docx_lists", &body),
+            "<div class='docx-content'><ol><li>one</li><li>two<ul><li>dot</li></ul></li></ol><p>after</p></div>"
+        );
+    }
+
+    #[test]
+    fn test_docx_table() {
+        let cell = |text: &str, properties: &str| {
+            format!("<w:tc><w:tcPr>{properties}</w:tcPr><w:p><w:r><w:t>{text}</w:t></w:r></w:p></w:tc>")
+        };
+        let body = format!(
+            "<w:tbl><w:tr>{}</w:tr><w:tr>{}{}</w:tr></w:tbl>",
+            cell("Head", r#"<w:gridSpan w:val="2"/>"#),
+            cell("A", ""),
+            cell("B", ""),
+        );
+        assert!(render_fixture("docx_table", &body).contains(
+            "<table><tr><td colspan=\"2\"><p>Head</p></td></tr><tr><td><p>A</p></td><td><p>B</p></td></tr></table>"
+        ));
+    }
+
+    #[test]
+    fn test_docx_table_vertical_merge() {
+        let cell = |text: &str, properties: &str| {
+            format!("<w:tc><w:tcPr>{properties}</w:tcPr><w:p><w:r><w:t>{text}</w:t></w:r></w:p></w:tc>")
+        };
+        let restart = r#"<w:vMerge w:val="restart"/>"#;
+        // A bare <w:vMerge/> continues the merge above
+        let body = format!(
+            "<w:tbl><w:tr>{}{}</w:tr><w:tr>{}{}</w:tr><w:tr>{}{}</w:tr><w:tr>{}{}</w:tr></w:tbl>",
+            cell("Tall", restart),
+            cell("A", ""),
+            cell("", r#"<w:vMerge w:val="continue"/>"#),
+            cell("B", ""),
+            cell("", "<w:vMerge/>"),
+            cell("C", ""),
+            cell("D", ""),
+            cell("E", ""),
+        );
+        assert!(render_fixture("docx_table_vmerge", &body).contains(
+            "<table><tr><td rowspan=\"3\"><p>Tall</p></td><td><p>A</p></td></tr>\
+             <tr><td><p>B</p></td></tr><tr><td><p>C</p></td></tr>\
+             <tr><td><p>D</p></td><td><p>E</p></td></tr></table>"
+        ));
+    }
+
+    #[test]
+    fn test_docx_hyperlink_and_run_formatting() {
+        let run = |properties: &str, text: &str| format!("<w:r><w:rPr>{properties}</w:rPr><w:t>{text}</w:t></w:r>");
+        let body = format!(
+            r#"<w:p>{}{}{}{}<w:hyperlink r:id="rId10">{}</w:hyperlink></w:p><w:p>{}{}{}{}{}{}</w:p>"#,
+            run("<w:b/>", "bold"),
+            run("<w:i/>", "italic"),
+            run(r#"<w:u w:val="single"/>"#, "under"),
+            run("<w:strike/>", "struck"),
+            run("", "site"),
+            run(r#"<w:vertAlign w:val="superscript"/>"#, "sup"),
+            run(r#"<w:vertAlign w:val="subscript"/>"#, "sub"),
+            run(r#"<w:color w:val="FF0000"/>"#, "red"),
+            run(r#"<w:highlight w:val="darkYellow"/>"#, "olive"),
+            run(r#"<w:b/><w:color w:val="00AA00"/><w:highlight w:val="yellow"/>"#, "both"),
+            run(r#"<w:color w:val="auto"/><w:highlight w:val="none"/>"#, "plain"),
+        );
+        assert_eq!(
+            render_fixture("docx_formatting", &body),
+            "<div class='docx-content'><p><b>bold</b><i>italic</i><u>under</u><s>struck</s>\
+             <a href=\"https://example.com/docs\">site</a></p>\
+             <p><sup>sup</sup><sub>sub</sub><span style=\"color:#ff0000\">red</span>\
+             <span style=\"background-color:#808000\">olive</span>\
+             <b><span style=\"color:#00aa00;background-color:yellow\">both</span></b>plain</p></div>"
+        );
+    }
+}
@@ -0,0 +1,780 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek};
+use std::sync::OnceLock;
+use zip::ZipArchive;
+
+use crate::timed;
+
+use crate::api::covers::{find_epub_opf_path, read_zip_bytes, read_zip_string, resolve_epub_href};
+use crate::api::pdf::{find_text_matches, snippet, SEARCH_SNIPPET_CHARS};
+use crate::api::tts_text::html_to_highlight_data;
+
+/// A manifest entry with its href resolved to an archive path
+#[derive(Debug, Clone)]
+pub(crate) struct EpubManifestItem {
+    pub id: String,
+    pub href: String,
+    pub media_type: String,
+    pub properties: String,
+    pub media_overlay: Option<String>,
+}
+
+/// A spine entry, with its per-item `rendition:layout` override if any
+#[derive(Debug, Clone)]
+pub(crate) struct EpubSpineItem {
+    pub idref: String,
+    pub layout: Option<EpubLayout>,
+}
+
+/// Parsed OPF package document
+#[derive(Debug, Clone)]
+pub(crate) struct EpubPackage {
+    pub manifest: Vec<EpubManifestItem>,
+    pub spine: Vec<EpubSpineItem>,
+    pub layout: EpubLayout,
+    /// Manifest id of the EPUB2 NCX, from `<spine toc="...">`
+    pub toc_id: Option<String>,
+    /// First `dc:title`, empty if missing
+    pub title: String,
+    /// First `dc:creator`, empty if missing
+    pub creator: String,
+}
+
+/// EPUB3 `rendition:layout`: reflowable text or fixed-layout pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpubLayout {
+    Reflowable,
+    PrePaginated,
+}
+
+impl EpubLayout {
+    fn from_property(value: &str) -> Option<Self> {
+        match value.trim() {
+            "pre-paginated" => Some(EpubLayout::PrePaginated),
+            "reflowable" => Some(EpubLayout::Reflowable),
+            _ => None,
+        }
+    }
+}
+
+impl EpubPackage {
+    pub fn item_by_id(&self, id: &str) -> Option<&EpubManifestItem> {
+        self.manifest.iter().find(|item| item.id == id)
+    }
+
+    /// The EPUB3 navigation document, if any
+    pub fn nav_item(&self) -> Option<&EpubManifestItem> {
+        self.manifest
+            .iter()
+            .find(|item| item.properties.split_whitespace().any(|p| p == "nav"))
+    }
+
+    /// The EPUB2 NCX, from the spine `toc` attribute or its media type
+    pub fn ncx_item(&self) -> Option<&EpubManifestItem> {
+        self.toc_id
+            .as_deref()
+            .and_then(|id| self.item_by_id(id))
+            .or_else(|| {
+                self.manifest
+                    .iter()
+                    .find(|item| item.media_type == "application/x-dtbncx+xml")
+            })
+    }
+
+    pub fn item_by_href(&self, href: &str) -> Option<&EpubManifestItem> {
+        let wanted = resolve_epub_href("", href);
+        self.manifest
+            .iter()
+            .find(|item| item.href == wanted)
+            .or_else(|| {
+                let wanted_lower = wanted.to_lowercase();
+                self.manifest
+                    .iter()
+                    .find(|item| item.href.to_lowercase() == wanted_lower)
+            })
+    }
+}
+
+pub(crate) fn open_epub_archive(path: &str) -> Result<ZipArchive<BufReader<File>>> {
+    let file = File::open(path).with_context(|| format!("Failed to open EPUB file: {path}"))?;
+    ZipArchive::new(BufReader::new(file)).context("Failed to read EPUB archive")
+}
+
+pub(crate) fn read_epub_package<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<EpubPackage> {
+    let opf_path = find_epub_opf_path(archive)?;
+    let opf_xml = read_zip_string(archive, &opf_path)
+        .with_context(|| format!("Failed to read OPF: {opf_path}"))?;
+    let opf_doc = roxmltree::Document::parse(&opf_xml).context("Failed to parse OPF")?;
+
+    let mut manifest = Vec::new();
+    let mut spine = Vec::new();
+    let mut layout = EpubLayout::Reflowable;
+    let mut toc_id = None;
+    let mut title = String::new();
+    let mut creator = String::new();
+    for node in opf_doc.descendants().filter(|n| n.is_element()) {
+        match node.tag_name().name() {
+            "item" => {
+                let id = node.attribute("id").unwrap_or("").trim();
+                let href = node.attribute("href").unwrap_or("").trim();
+                if id.is_empty() || href.is_empty() {
+                    continue;
+                }
+                manifest.push(EpubManifestItem {
+                    id: id.to_string(),
+                    href: resolve_epub_href(&opf_path, href),
+                    media_type: node.attribute("media-type").unwrap_or("").trim().to_string(),
+                    properties: node.attribute("properties").unwrap_or("").trim().to_string(),
+                    media_overlay: node.attribute("media-overlay").map(|s| s.trim().to_string()),
+                });
+            }
+            "spine" => {
+                toc_id = node
+                    .attribute("toc")
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty());
+            }
+            "itemref" => {
+                let idref = node.attribute("idref").unwrap_or("").trim();
+                if idref.is_empty() {
+                    continue;
+                }
+                let item_layout = node
+                    .attribute("properties")
+                    .unwrap_or("")
+                    .split_whitespace()
+                    .find_map(|p| p.strip_prefix("rendition:layout-"))
+                    .and_then(EpubLayout::from_property);
+                spine.push(EpubSpineItem {
+                    idref: idref.to_string(),
+                    layout: item_layout,
+                });
+            }
+            "title" if title.is_empty() => {
+                title = node.text().unwrap_or("").trim().to_string();
+            }
+            "creator" if creator.is_empty() => {
+                creator = node.text().unwrap_or("").trim().to_string();
+            }
+            "meta" if node.attribute("property") == Some("rendition:layout") => {
+                if let Some(value) = node.text().and_then(EpubLayout::from_property) {
+                    layout = value;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(EpubPackage {
+        manifest,
+        spine,
+        layout,
+        toc_id,
+        title,
+        creator,
+    })
+}
+
+const DUBLIN_CORE_NS: &str = "http://purl.org/dc/elements/1.1/";
+
+/// Dublin Core metadata of an EPUB; missing single-valued fields are `None`
+#[derive(Debug, Clone, Default)]
+pub struct EpubMetadata {
+    pub title: Option<String>,
+    pub creators: Vec<String>,
+    pub language: Option<String>,
+    pub identifier: Option<String>,
+    pub publisher: Option<String>,
+    pub date: Option<String>,
+    pub description: Option<String>,
+    pub subjects: Vec<String>,
+}
+
+/// Read the `dc:*` children of the OPF `<metadata>` element. Single-valued
+/// fields keep their first non-blank occurrence.
+pub fn get_epub_metadata(path: String) -> Result<EpubMetadata> {
+    let mut archive = open_epub_archive(&path)?;
+    let opf_path = find_epub_opf_path(&mut archive)?;
+    let opf_xml = read_zip_string(&mut archive, &opf_path)
+        .with_context(|| format!("Failed to read OPF: {opf_path}"))?;
+    let opf_doc = roxmltree::Document::parse(&opf_xml).context("Failed to parse OPF")?;
+
+    let mut metadata = EpubMetadata::default();
+    let Some(metadata_node) = opf_doc
+        .descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "metadata")
+    else {
+        return Ok(metadata);
+    };
+
+    for node in metadata_node.children().filter(|n| n.is_element()) {
+        if node.tag_name().namespace() != Some(DUBLIN_CORE_NS) {
+            continue;
+        }
+        let value = node.text().unwrap_or("").split_whitespace().collect::<Vec<_>>().join(" ");
+        if value.is_empty() {
+            continue;
+        }
+        let first = |field: &mut Option<String>, value: String| {
+            field.get_or_insert(value);
+        };
+        match node.tag_name().name() {
+            "title" => first(&mut metadata.title, value),
+            "language" => first(&mut metadata.language, value),
+            "identifier" => first(&mut metadata.identifier, value),
+            "publisher" => first(&mut metadata.publisher, value),
+            "date" => first(&mut metadata.date, value),
+            "description" => first(&mut metadata.description, value),
+            "creator" => metadata.creators.push(value),
+            "subject" => metadata.subjects.push(value),
+            _ => {}
+        }
+    }
+
+    Ok(metadata)
+}
+
+/// Effective layout of one spine item
+#[derive(Debug, Clone)]
+pub struct EpubSpineLayout {
+    pub href: String,
+    pub layout: EpubLayout,
+}
+
+/// Book-wide rendition layout plus the effective layout of every spine item
+#[derive(Debug, Clone)]
+pub struct EpubRendition {
+    pub layout: EpubLayout,
+    pub spine: Vec<EpubSpineLayout>,
+}
+
+/// Detect whether the book (and each spine item) is reflowable or fixed-layout.
+/// Books that don't declare `rendition:layout` are reflowable.
+pub fn get_epub_rendition(path: String) -> Result<EpubRendition> {
+    let mut archive = open_epub_archive(&path)?;
+    let package = read_epub_package(&mut archive)?;
+
+    let spine = package
+        .spine
+        .iter()
+        .filter_map(|spine_item| {
+            let item = package.item_by_id(&spine_item.idref)?;
+            Some(EpubSpineLayout {
+                href: item.href.clone(),
+                layout: spine_item.layout.unwrap_or(package.layout),
+            })
+        })
+        .collect();
+
+    Ok(EpubRendition {
+        layout: package.layout,
+        spine,
+    })
+}
+
+/// One reading-order entry of an EPUB
+#[derive(Debug, Clone)]
+pub struct EpubChapter {
+    pub id: String,
+    pub href: String,
+    pub title: String,
+    pub order: u32,
+}
+
+/// Map of chapter archive path (without fragment) to its first TOC title.
+fn read_epub_toc_titles<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    package: &EpubPackage,
+) -> HashMap<String, String> {
+    let mut titles = HashMap::new();
+
+    // EPUB3 nav document takes precedence over the legacy NCX
+    if let Some(nav) = package.nav_item() {
+        if let Ok(html) = read_zip_string(archive, &nav.href) {
+            collect_toc_titles(&read_nav_toc(&html, &nav.href), &mut titles);
+        }
+    }
+    if let Some(ncx) = package.ncx_item() {
+        if let Ok(xml) = read_zip_string(archive, &ncx.href) {
+            collect_toc_titles(&read_ncx_toc(&xml, &ncx.href), &mut titles);
+        }
+    }
+
+    titles
+}
+
+/// Add the titles of `entries` and their children, in reading order, for
+/// chapters that don't have one yet.
+fn collect_toc_titles(entries: &[TocEntry], titles: &mut HashMap<String, String>) {
+    for entry in entries {
+        let chapter = entry.href.split('#').next().unwrap_or_default();
+        if !chapter.is_empty() && !entry.label.is_empty() {
+            titles.entry(chapter.to_string()).or_insert_with(|| entry.label.clone());
+        }
+        collect_toc_titles(&entry.children, titles);
+    }
+}
+
+/// A table of contents entry. `href` is an archive path, with the fragment
+/// kept when the entry points inside a chapter.
+#[derive(Debug, Clone)]
+pub struct TocEntry {
+    pub label: String,
+    pub href: String,
+    /// Nesting level, 0 for top-level entries
+    pub depth: u32,
+    pub children: Vec<TocEntry>,
+}
+
+fn collapse_label(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Resolve a TOC link against the document it appears in, keeping its fragment.
+fn resolve_toc_href(base: &str, href: &str) -> String {
+    let href = href.trim();
+    if href.is_empty() {
+        return String::new();
+    }
+    let fragment = href.find('#').map(|i| &href[i..]).unwrap_or("");
+    format!("{}{}", resolve_epub_href(base, href), fragment)
+}
+
+/// Entries of a nav `<ol>`: each `<li>` holds an `<a>` (or a bare `<span>`
+/// heading) and optionally a nested `<ol>`.
+fn nav_list_entries(list: scraper::ElementRef, nav_href: &str, depth: u32) -> Vec<TocEntry> {
+    list.child_elements()
+        .filter(|li| li.value().name() == "li")
+        .filter_map(|li| {
+            let label_element = li
+                .child_elements()
+                .find(|child| matches!(child.value().name(), "a" | "span"))?;
+            let children = li
+                .child_elements()
+                .find(|child| child.value().name() == "ol")
+                .map(|ol| nav_list_entries(ol, nav_href, depth + 1))
+                .unwrap_or_default();
+            Some(TocEntry {
+                label: collapse_label(&label_element.text().collect::<String>()),
+                href: resolve_toc_href(nav_href, label_element.value().attr("href").unwrap_or("")),
+                depth,
+                children,
+            })
+        })
+        .collect()
+}
+
+/// The `epub:type="toc"` nav of a nav document, or its first nav when none
+/// is marked. The document may also hold landmarks and page lists.
+fn toc_nav(doc: &scraper::Html) -> Option<scraper::ElementRef<'_>> {
+    let nav_selector = scraper::Selector::parse("nav").ok()?;
+    let navs: Vec<_> = doc.select(&nav_selector).collect();
+    navs.iter()
+        .find(|nav| nav.value().attr("epub:type").is_some_and(|t| t.split_whitespace().any(|t| t == "toc")))
+        .or(navs.first())
+        .copied()
+}
+
+fn read_nav_toc(html: &str, nav_href: &str) -> Vec<TocEntry> {
+    let doc = scraper::Html::parse_document(html);
+    let Some(toc) = toc_nav(&doc) else {
+        return Vec::new();
+    };
+    toc.child_elements()
+        .find(|child| child.value().name() == "ol")
+        .map(|ol| nav_list_entries(ol, nav_href, 0))
+        .unwrap_or_default()
+}
+
+fn ncx_point_entries(parent: roxmltree::Node, ncx_href: &str, depth: u32) -> Vec<TocEntry> {
+    parent
+        .children()
+        .filter(|n| n.is_element() && n.tag_name().name() == "navPoint")
+        .map(|point| {
+            let label = point
+                .children()
+                .find(|n| n.is_element() && n.tag_name().name() == "navLabel")
+                .and_then(|label| label.descendants().find(|n| n.tag_name().name() == "text"))
+                .and_then(|text| text.text())
+                .map(collapse_label)
+                .unwrap_or_default();
+            let src = point
+                .children()
+                .find(|n| n.is_element() && n.tag_name().name() == "content")
+                .and_then(|content| content.attribute("src"))
+                .unwrap_or("");
+            TocEntry {
+                label,
+                href: resolve_toc_href(ncx_href, src),
+                depth,
+                children: ncx_point_entries(point, ncx_href, depth + 1),
+            }
+        })
+        .collect()
+}
+
+fn read_ncx_toc(xml: &str, ncx_href: &str) -> Vec<TocEntry> {
+    let Ok(doc) = roxmltree::Document::parse(xml) else {
+        return Vec::new();
+    };
+    doc.descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "navMap")
+        .map(|nav_map| ncx_point_entries(nav_map, ncx_href, 0))
+        .unwrap_or_default()
+}
+
+/// Hierarchical table of contents from the EPUB3 nav document, falling back
+/// to the EPUB2 NCX when there is no nav document or its TOC is empty.
+#[hotpath::measure]
+pub fn get_epub_toc(path: String) -> Result<Vec<TocEntry>> {
+    timed!("get_epub_toc", {
+        let mut archive = open_epub_archive(&path)?;
+        let package = read_epub_package(&mut archive)?;
+
+        if let Some(nav) = package.nav_item() {
+            if let Ok(html) = read_zip_string(&mut archive, &nav.href) {
+                let toc = read_nav_toc(&html, &nav.href);
+                if !toc.is_empty() {
+                    return Ok(toc);
+                }
+            }
+        }
+        if let Some(ncx) = package.ncx_item() {
+            if let Ok(xml) = read_zip_string(&mut archive, &ncx.href) {
+                return Ok(read_ncx_toc(&xml, &ncx.href));
+            }
+        }
+        Ok(Vec::new())
+    })
+}
+
+/// Reading order of the book from the OPF spine, titled from the nav
+/// document (or NCX). Untitled spine items get an empty title.
+#[hotpath::measure]
+pub fn get_epub_spine(path: String) -> Result<Vec<EpubChapter>> {
+    timed!("get_epub_spine", {
+        let mut archive = open_epub_archive(&path)?;
+        let package = read_epub_package(&mut archive)?;
+        let titles = read_epub_toc_titles(&mut archive, &package);
+
+        let chapters = package
+            .spine
+            .iter()
+            .filter_map(|spine_item| package.item_by_id(&spine_item.idref))
+            .enumerate()
+            .map(|(order, item)| EpubChapter {
+                id: item.id.clone(),
+                href: item.href.clone(),
+                title: titles.get(&item.href).cloned().unwrap_or_default(),
+                order: order as u32,
+            })
+            .collect();
+
+        Ok(chapters)
+    })
+}
+
+// Pre-compiled regex for resource attributes in chapter markup
+static RESOURCE_LINK_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_resource_link_regex() -> &'static Regex {
+    RESOURCE_LINK_REGEX.get_or_init(|| {
+        Regex::new(r#"(?i)(\s(?:src|href|xlink:href|poster)\s*=\s*)(["'])([^"']*)(["'])"#).unwrap()
+    })
+}
+
+/// Rewrite relative `src`/`href` attributes to archive paths so every link in
+/// the chapter can be fed straight back to the archive readers. Fragments are
+/// kept; external, data and same-document links are left alone.
+fn rewrite_chapter_links(chapter_href: &str, html: &str) -> String {
+    get_resource_link_regex()
+        .replace_all(html, |caps: &regex::Captures| {
+            let value = &caps[3];
+            let trimmed = value.trim();
+            let lower = trimmed.to_lowercase();
+            let keep = trimmed.is_empty()
+                || trimmed.starts_with('#')
+                || lower.starts_with("data:")
+                || lower.starts_with("mailto:")
+                || lower.contains("://");
+            if keep {
+                return caps[0].to_string();
+            }
+
+            let fragment = trimmed.find('#').map(|i| &trimmed[i..]).unwrap_or("");
+            format!(
+                "{}{}{}{}{}",
+                &caps[1],
+                &caps[2],
+                resolve_epub_href(chapter_href, trimmed),
+                fragment,
+                &caps[4]
+            )
+        })
+        .into_owned()
+}
+
+/// XHTML of one chapter, with relative resource links rewritten to archive paths.
+#[hotpath::measure]
+pub fn get_epub_chapter_html(path: String, href: String) -> Result<String> {
+    timed!("get_epub_chapter_html", {
+        let mut archive = open_epub_archive(&path)?;
+        let chapter_href = resolve_epub_href("", &href);
+        let html = read_zip_string(&mut archive, &chapter_href)
+            .with_context(|| format!("Failed to read chapter: {chapter_href}"))?;
+        Ok(rewrite_chapter_links(&chapter_href, &html))
+    })
+}
+
+/// One match of an EPUB text search
+#[derive(Debug, Clone)]
+pub struct EpubSearchHit {
+    pub chapter_href: String,
+    /// Char range of the match in the chapter's normalized text, the same
+    /// offsets `insert_html_highlight` takes
+    pub char_start: u32,
+    pub char_end: u32,
+    /// The match with up to `SEARCH_SNIPPET_CHARS` characters either side
+    pub snippet: String,
+}
+
+/// Every match of `query` in the book's text, in spine order. Each
+/// chapter's body is reduced to the normalized text TTS highlighting uses
+/// and matched like `search_pdf` does. Chapters that fail to read or parse
+/// are skipped.
+#[hotpath::measure]
+pub fn search_epub(path: String, query: String, case_sensitive: bool) -> Result<Vec<EpubSearchHit>> {
+    timed!("search_epub", {
+        let chapters = get_epub_spine(path.clone())?;
+        let mut archive = open_epub_archive(&path)?;
+        let mut hits = Vec::new();
+        for chapter in chapters {
+            let chapter_href = resolve_epub_href("", &chapter.href);
+            let Ok(html) = read_zip_string(&mut archive, &chapter_href) else { continue };
+            let Ok(data) = html_to_highlight_data(html) else { continue };
+            let chars: Vec<char> = data.normalized_text.chars().collect();
+            for (start, end) in find_text_matches(&chars, &query, case_sensitive) {
+                let from = start.saturating_sub(SEARCH_SNIPPET_CHARS);
+                let to = (end + SEARCH_SNIPPET_CHARS).min(chars.len());
+                hits.push(EpubSearchHit {
+                    chapter_href: chapter_href.clone(),
+                    char_start: start as u32,
+                    char_end: end as u32,
+                    snippet: snippet(&chars[from..to]),
+                });
+            }
+        }
+        Ok(hits)
+    })
+}
+
+/// Raw bytes of an archive entry and its media type
+#[derive(Debug, Clone)]
+pub struct ResourceBytes {
+    pub bytes: Vec<u8>,
+    pub media_type: String,
+}
+
+fn media_type_from_extension(href: &str) -> &'static str {
+    let extension = href.rsplit('.').next().unwrap_or("").to_lowercase();
+    match extension.as_str() {
+        "xhtml" | "xht" => "application/xhtml+xml",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "js" => "text/javascript",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "mp3" => "audio/mpeg",
+        "m4a" | "mp4" => "audio/mp4",
+        "smil" => "application/smil+xml",
+        "ncx" => "application/x-dtbncx+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Read a file a chapter references, resolving `href` against `base_href`
+/// (the chapter's archive path; empty when `href` is already an archive
+/// path). The media type comes from the OPF manifest, or the extension for
+/// files the manifest doesn't list.
+#[hotpath::measure]
+pub fn read_epub_resource(path: String, href: String, base_href: String) -> Result<ResourceBytes> {
+    timed!("read_epub_resource", {
+        let mut archive = open_epub_archive(&path)?;
+        let resource_href = resolve_epub_href(&base_href, &href);
+        let bytes = read_zip_bytes(&mut archive, &resource_href)
+            .with_context(|| format!("Failed to read resource: {resource_href}"))?;
+
+        let media_type = read_epub_package(&mut archive)
+            .ok()
+            .and_then(|package| package.item_by_href(&resource_href).map(|item| item.media_type.clone()))
+            .filter(|media_type| !media_type.is_empty())
+            .unwrap_or_else(|| media_type_from_extension(&resource_href).to_string());
+
+        Ok(ResourceBytes { bytes, media_type })
+    })
+}
+
+/// One audio clip of an EPUB3 media overlay, synced to a text fragment
+#[derive(Debug, Clone)]
+pub struct MediaClip {
+    pub text_fragment_id: String,
+    pub audio_href: String,
+    pub clip_begin: f64,
+    /// `None` when the SMIL has no `clipEnd`: the clip plays until the next
+    /// clip begins or the audio file ends
+    pub clip_end: Option<f64>,
+}
+
+/// Parse a SMIL clock value ("0:01:02.5", "01:02.5", "12.5s", "1500ms", "2min", "1h") into seconds.
+fn parse_smil_clock(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if value.contains(':') {
+        let mut seconds = 0.0;
+        for part in value.split(':') {
+            seconds = seconds * 60.0 + part.trim().parse::<f64>().ok()?;
+        }
+        return Some(seconds);
+    }
+
+    let (number, scale) = if let Some(n) = value.strip_suffix("ms") {
+        (n, 0.001)
+    } else if let Some(n) = value.strip_suffix("min") {
+        (n, 60.0)
+    } else if let Some(n) = value.strip_suffix('h') {
+        (n, 3600.0)
+    } else if let Some(n) = value.strip_suffix('s') {
+        (n, 1.0)
+    } else {
+        (value, 1.0)
+    };
+    number.trim().parse::<f64>().ok().map(|n| n * scale)
+}
+
+fn parse_smil_clips(smil_path: &str, smil: &str) -> Result<Vec<MediaClip>> {
+    let doc = roxmltree::Document::parse(smil)
+        .with_context(|| format!("Failed to parse SMIL: {smil_path}"))?;
+
+    let mut clips = Vec::new();
+    for par in doc.descendants().filter(|n| n.is_element() && n.tag_name().name() == "par") {
+        let text = par
+            .children()
+            .find(|n| n.is_element() && n.tag_name().name() == "text");
+        let audio = par
+            .children()
+            .find(|n| n.is_element() && n.tag_name().name() == "audio");
+        let (Some(text), Some(audio)) = (text, audio) else {
+            continue;
+        };
+
+        let text_src = text.attribute("src").unwrap_or("");
+        let audio_src = audio.attribute("src").unwrap_or("").trim();
+        if audio_src.is_empty() {
+            continue;
+        }
+
+        let text_fragment_id = text_src
+            .split_once('#')
+            .map(|(_, fragment)| fragment.to_string())
+            .unwrap_or_default();
+        let clip_begin = audio.attribute("clipBegin").and_then(parse_smil_clock).unwrap_or(0.0);
+        let clip_end = audio.attribute("clipEnd").and_then(parse_smil_clock);
+
+        clips.push(MediaClip {
+            text_fragment_id,
+            audio_href: resolve_epub_href(smil_path, audio_src),
+            clip_begin,
+            clip_end,
+        });
+    }
+
+    Ok(clips)
+}
+
+/// Read the media overlay (SMIL) clips for a content document. `href` is the
+/// archive path of the chapter; returns an empty list when it has no overlay.
+pub fn get_epub_media_overlay(path: String, href: String) -> Result<Vec<MediaClip>> {
+    let mut archive = open_epub_archive(&path)?;
+    let package = read_epub_package(&mut archive)?;
+
+    let Some(overlay_id) = package
+        .item_by_href(&href)
+        .and_then(|item| item.media_overlay.clone())
+    else {
+        return Ok(Vec::new());
+    };
+    let Some(smil_item) = package.item_by_id(&overlay_id) else {
+        return Ok(Vec::new());
+    };
+
+    let smil_path = smil_item.href.clone();
+    let smil = read_zip_string(&mut archive, &smil_path)?;
+    parse_smil_clips(&smil_path, &smil)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_nav_toc() {
+        let html = r#"<html><body>
+            <nav epub:type="landmarks"><ol><li><a href="cover.xhtml">Cover</a></li></ol></nav>
+            <nav epub:type="toc"><ol>
+              <li><a href="text/ch1.xhtml">Chapter
+                One</a><ol><li><a href="text/ch1.xhtml#s1">Section</a></li></ol></li>
+              <li><span>Part</span></li>
+            </ol></nav></body></html>"#;
+        let toc = read_nav_toc(html, "OEBPS/nav.xhtml");
+        assert_eq!(toc.len(), 2);
+        assert_eq!(toc[0].label, "Chapter One");
+        assert_eq!(toc[0].href, "OEBPS/text/ch1.xhtml");
+        assert_eq!(toc[0].children[0].href, "OEBPS/text/ch1.xhtml#s1");
+        assert_eq!(toc[0].children[0].depth, 1);
+        assert_eq!(toc[1].label, "Part");
+        assert_eq!(toc[1].href, "");
+
+        // Spine titles: first entry per chapter, landmarks and bare headings ignored
+        let mut titles = HashMap::new();
+        collect_toc_titles(&toc, &mut titles);
+        assert_eq!(titles.len(), 1);
+        assert_eq!(titles["OEBPS/text/ch1.xhtml"], "Chapter One");
+    }
+
+    #[test]
+    fn test_parse_smil_clock() {
+        assert_eq!(parse_smil_clock("0:01:02.5"), Some(62.5));
+        assert_eq!(parse_smil_clock("01:02.5"), Some(62.5));
+        assert_eq!(parse_smil_clock("12.5s"), Some(12.5));
+        assert_eq!(parse_smil_clock("1500ms"), Some(1.5));
+        assert_eq!(parse_smil_clock("2min"), Some(120.0));
+        assert_eq!(parse_smil_clock("3"), Some(3.0));
+        assert_eq!(parse_smil_clock("abc"), None);
+    }
+
+    #[test]
+    fn test_parse_smil_clips_open_end() {
+        let smil = r#"<smil xmlns="http://www.w3.org/ns/SMIL"><body>
+            <par><text src="ch1.xhtml#p1"/><audio src="audio/ch1.mp3" clipBegin="0:00:01.5" clipEnd="3s"/></par>
+            <par><text src="ch1.xhtml#p2"/><audio src="audio/ch1.mp3" clipBegin="3s"/></par>
+            </body></smil>"#;
+        let clips = parse_smil_clips("OEBPS/ch1.smil", smil).unwrap();
+        assert_eq!(clips.len(), 2);
+        assert_eq!(clips[0].audio_href, "OEBPS/audio/ch1.mp3");
+        assert_eq!((clips[0].clip_begin, clips[0].clip_end), (1.5, Some(3.0)));
+        assert_eq!((clips[1].clip_begin, clips[1].clip_end), (3.0, None));
+    }
+}
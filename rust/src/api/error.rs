@@ -0,0 +1,103 @@
+use crate::api::operation::CancelledError;
+use pdfium_render::prelude::PdfiumError;
+use std::fmt;
+
+/// Typed failure of the PDF API, surfaced to Dart as a sealed class so
+/// callers match on variants instead of message prefixes. `Display` keeps
+/// the `CATEGORY::DETAIL:` prefixes the string errors used, for logs.
+/// Converts into `anyhow::Error` like any other error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FerrousError {
+    /// The path of the file, for the message
+    FileMissing(String),
+    Empty(String),
+    BadHeader(String),
+    PasswordRequired(String),
+    Corrupt(String),
+    /// Opening is refused by the document's security handler
+    Security(String),
+    /// pdfium failed on a page while opening the document
+    PageError(String),
+    PageOutOfRange,
+    /// pdfium could not load a page of an already open document
+    PageLoad { page_index: u32, detail: String },
+    /// The document's owner password forbids copying its text; the path
+    PermissionDenied(String),
+    Unsupported(String),
+    /// A request the function can't serve, e.g. a zero tile size
+    InvalidArgument(String),
+    /// Stopped through `cancel_operation`
+    Cancelled,
+    /// Any other pdfium failure: rendering, text, annotations, saving
+    Pdfium(String),
+    /// Encoding a rendered page or an embedded image failed
+    Image(String),
+    Io(String),
+}
+
+impl fmt::Display for FerrousError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FerrousError::FileMissing(path) => {
+                write!(f, "PDF_OPEN_ERROR::FILE: Missing PDF file at {path}")
+            }
+            FerrousError::Empty(path) => write!(f, "PDF_OPEN_ERROR::EMPTY: PDF file is empty at {path}"),
+            FerrousError::BadHeader(path) => {
+                write!(f, "PDF_OPEN_ERROR::HEADER: File does not look like a valid PDF at {path}")
+            }
+            FerrousError::PasswordRequired(path) => {
+                write!(f, "PDF_OPEN_ERROR::PASSWORD: PDF is password-protected at {path}.")
+            }
+            FerrousError::Corrupt(path) => write!(
+                f,
+                "PDF_OPEN_ERROR::FORMAT: PDF format error at {path}. The file may be corrupted or not a PDF."
+            ),
+            FerrousError::Security(path) => {
+                write!(f, "PDF_OPEN_ERROR::SECURITY: PDF security settings prevent opening {path}.")
+            }
+            FerrousError::PageError(path) => {
+                write!(f, "PDF_OPEN_ERROR::PAGE: PDF page error while opening {path}.")
+            }
+            FerrousError::PageOutOfRange => write!(f, "PAGE_OUT_OF_RANGE: Page index is out of range"),
+            FerrousError::PageLoad { page_index, detail } => {
+                write!(f, "PDF_PAGE_ERROR: Failed to load page {page_index}: {detail}")
+            }
+            FerrousError::PermissionDenied(path) => write!(
+                f,
+                "PDF_PERMISSION_DENIED::COPY: Document restrictions forbid text extraction from {path}."
+            ),
+            FerrousError::Unsupported(detail) => write!(f, "UNSUPPORTED: {detail}"),
+            FerrousError::InvalidArgument(detail) => write!(f, "INVALID_ARGUMENT: {detail}"),
+            FerrousError::Cancelled => write!(f, "{CancelledError}"),
+            FerrousError::Pdfium(detail) => write!(f, "PDF_ERROR: {detail}"),
+            FerrousError::Image(detail) => write!(f, "IMAGE_ERROR: {detail}"),
+            FerrousError::Io(detail) => write!(f, "IO_ERROR: {detail}"),
+        }
+    }
+}
+
+impl std::error::Error for FerrousError {}
+
+impl From<std::io::Error> for FerrousError {
+    fn from(error: std::io::Error) -> Self {
+        FerrousError::Io(error.to_string())
+    }
+}
+
+impl From<CancelledError> for FerrousError {
+    fn from(_: CancelledError) -> Self {
+        FerrousError::Cancelled
+    }
+}
+
+impl From<PdfiumError> for FerrousError {
+    fn from(error: PdfiumError) -> Self {
+        FerrousError::Pdfium(format!("{error:?}"))
+    }
+}
+
+impl From<image::ImageError> for FerrousError {
+    fn from(error: image::ImageError) -> Self {
+        FerrousError::Image(error.to_string())
+    }
+}
@@ -0,0 +1,278 @@
+use anyhow::{Context, Result};
+use encoding_rs::{Encoding, UTF_8};
+use regex::Regex;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use zip::ZipArchive;
+
+use crate::timed;
+
+/// Bytes of an FB2 document, unwrapping `.fb2.zip` archives.
+pub(crate) fn read_fb2_bytes(path: &str) -> Result<Vec<u8>> {
+    let mut file = File::open(path).with_context(|| format!("Failed to open FB2 file: {path}"))?;
+    let mut magic = [0u8; 4];
+    let is_zip = file.read_exact(&mut magic).is_ok() && &magic == b"PK\x03\x04";
+    if is_zip {
+        let file = File::open(path).with_context(|| format!("Failed to open FB2 archive: {path}"))?;
+        let mut archive = ZipArchive::new(BufReader::new(file)).context("Failed to read FB2 archive")?;
+        let (_, fb2) = crate::api::covers::read_single_file_zip(&mut archive, ".fb2")?;
+        Ok(fb2)
+    } else {
+        std::fs::read(path).with_context(|| format!("Failed to read FB2 file: {path}"))
+    }
+}
+
+/// `encoding` of the XML declaration at the start of `bytes`, if any
+fn declared_encoding(bytes: &[u8]) -> Option<String> {
+    let head = &bytes[..bytes.len().min(256)];
+    let end = head.windows(2).position(|w| w == b"?>")?;
+    let declaration = std::str::from_utf8(&head[..end]).ok()?;
+    if !declaration.trim_start_matches('\u{feff}').starts_with("<?xml") {
+        return None;
+    }
+    let encoding_re = Regex::new(r#"\bencoding\s*=\s*["']([A-Za-z0-9._:-]+)["']"#).ok()?;
+    Some(encoding_re.captures(declaration)?[1].to_string())
+}
+
+/// FB2 document text, decoded per its byte order mark or the declared
+/// `encoding` (older books are often windows-1251 or koi8-r). Undeclared
+/// and unknown encodings are read as UTF-8.
+pub(crate) fn read_fb2_xml(path: &str) -> Result<String> {
+    let bytes = read_fb2_bytes(path)?;
+    let fallback = declared_encoding(&bytes)
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .unwrap_or(UTF_8);
+    // A byte order mark beats the declaration
+    let (text, _, _) = fallback.decode(&bytes);
+    Ok(text.into_owned())
+}
+
+/// Whether a `<binary>` content type is an image type safe to put in a
+/// data URI (`image/jpeg`, `image/svg+xml`, ...)
+fn is_image_content_type(content_type: &str) -> bool {
+    content_type.strip_prefix("image/").is_some_and(|subtype| {
+        !subtype.is_empty()
+            && subtype.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+    })
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// `l:href` (or plain `href`) of a link or image, whatever the prefix is bound to
+fn href<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.attributes().find(|attr| attr.name() == "href").map(|attr| attr.value())
+}
+
+struct Fb2Html<'a> {
+    /// `<binary>` id -> data URI
+    images: HashMap<&'a str, String>,
+    html: String,
+}
+
+impl Fb2Html<'_> {
+    fn open(&mut self, tag: &str, node: Node, class: Option<&str>) {
+        self.html.push('<');
+        self.html.push_str(tag);
+        if let Some(id) = node.attribute("id") {
+            self.html.push_str(&format!(" id=\"{}\"", escape_html(id)));
+        }
+        if let Some(class) = class {
+            self.html.push_str(&format!(" class=\"{class}\""));
+        }
+        self.html.push('>');
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.html.push_str(&format!("</{tag}>"));
+    }
+
+    fn children(&mut self, node: Node, depth: usize) {
+        for child in node.children() {
+            self.node(child, depth);
+        }
+    }
+
+    fn wrap(&mut self, tag: &str, node: Node, class: Option<&str>, depth: usize) {
+        self.open(tag, node, class);
+        self.children(node, depth);
+        self.close(tag);
+    }
+
+    /// `depth` is the section nesting level: the book title is `<h1>`,
+    /// top-level section titles `<h2>` and so on
+    fn node(&mut self, node: Node, depth: usize) {
+        if node.is_text() {
+            self.html.push_str(&escape_html(node.text().unwrap_or("")));
+            return;
+        }
+        if !node.is_element() {
+            return;
+        }
+
+        match node.tag_name().name() {
+            "section" => self.wrap("section", node, None, depth + 1),
+            "title" => {
+                let tag = format!("h{}", (depth + 1).min(6));
+                self.open(&tag, node, None);
+                // Title paragraphs become lines of one heading
+                let mut first = true;
+                for child in node.children().filter(|c| c.is_element()) {
+                    if !first {
+                        self.html.push_str("<br/>");
+                    }
+                    first = false;
+                    self.children(child, depth);
+                }
+                self.close(&tag);
+            }
+            "subtitle" => self.wrap("p", node, Some("subtitle"), depth),
+            "p" => self.wrap("p", node, None, depth),
+            "v" => self.wrap("p", node, Some("verse"), depth),
+            "text-author" => self.wrap("p", node, Some("text-author"), depth),
+            "emphasis" => self.wrap("em", node, None, depth),
+            "strong" => self.wrap("strong", node, None, depth),
+            "strikethrough" => self.wrap("s", node, None, depth),
+            "sub" => self.wrap("sub", node, None, depth),
+            "sup" => self.wrap("sup", node, None, depth),
+            "code" => self.wrap("code", node, None, depth),
+            "epigraph" => self.wrap("blockquote", node, Some("epigraph"), depth),
+            "cite" => self.wrap("blockquote", node, None, depth),
+            "annotation" => self.wrap("div", node, Some("annotation"), depth),
+            "poem" => self.wrap("div", node, Some("poem"), depth),
+            "stanza" => self.wrap("div", node, Some("stanza"), depth),
+            "table" | "tr" | "td" | "th" => {
+                let tag = node.tag_name().name().to_string();
+                self.wrap(&tag, node, None, depth);
+            }
+            "empty-line" => self.html.push_str("<br/>"),
+            "a" => {
+                let target = href(node).unwrap_or("");
+                self.html.push_str(&format!("<a href=\"{}\">", escape_html(target)));
+                self.children(node, depth);
+                self.html.push_str("</a>");
+            }
+            "image" => {
+                let id = href(node).unwrap_or("").trim_start_matches('#');
+                if let Some(src) = self.images.get(id) {
+                    self.html.push_str(&format!("<img src=\"{src}\"/>"));
+                }
+            }
+            // Unknown inline markup keeps its text
+            _ => self.children(node, depth),
+        }
+    }
+}
+
+/// Convert an FB2 book (plain or `.fb2.zip`) to HTML: sections, headings,
+/// paragraphs and inline emphasis map to their semantic HTML counterparts,
+/// and `<binary>` images are inlined as data URIs. Legacy 8-bit encodings
+/// are decoded as declared (see `read_fb2_xml`).
+#[hotpath::measure]
+pub fn read_fb2_to_html(path: String) -> Result<String> {
+    timed!("read_fb2_to_html", {
+        let xml = read_fb2_xml(&path)?;
+        let doc = Document::parse(&xml).context("Failed to parse FB2")?;
+
+        let images = doc
+            .descendants()
+            .filter(|n| n.is_element() && n.tag_name().name() == "binary")
+            .filter_map(|binary| {
+                let id = binary.attribute("id")?;
+                let content_type = binary.attribute("content-type").unwrap_or("image/jpeg").trim();
+                if !is_image_content_type(content_type) {
+                    return None;
+                }
+                // Only the base64 alphabet can end up inside the attribute
+                let payload: String = binary
+                    .text()
+                    .unwrap_or("")
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '='))
+                    .collect();
+                Some((id, format!("data:{content_type};base64,{payload}")))
+            })
+            .collect();
+
+        let mut out = Fb2Html {
+            images,
+            html: String::from("<div class='fb2-content'>"),
+        };
+        for body in doc
+            .root_element()
+            .children()
+            .filter(|n| n.is_element() && n.tag_name().name() == "body")
+        {
+            let class = match body.attribute("name") {
+                Some("notes") => Some("notes"),
+                _ => None,
+            };
+            out.open("div", body, class);
+            out.children(body, 0);
+            out.close("div");
+        }
+        out.html.push_str("</div>");
+        Ok(out.html)
+    })
+}
+
+/// Cover image bytes of an FB2 book, from its `<coverpage>` reference.
+pub fn extract_fb2_cover(path: String) -> Result<Vec<u8>> {
+    crate::api::covers::find_fb2_cover_bytes(&read_fb2_bytes(&path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_fb2_to_html() {
+        let path = std::env::temp_dir().join("ferrous_test_read_fb2_to_html.fb2");
+        std::fs::write(
+            &path,
+            r##"<?xml version="1.0" encoding="utf-8"?>
+<FictionBook xmlns="http://www.gribuser.ru/xml/fictionbook/2.0" xmlns:l="http://www.w3.org/1999/xlink">
+  <body><title><p>Book</p></title><section id="c1"><title><p>One</p></title><p>A <emphasis>b</emphasis> &amp; <image l:href="#i"/></p></section></body>
+  <binary id="i" content-type="image/png">AQID</binary>
+</FictionBook>"##,
+        )
+        .unwrap();
+
+        let html = read_fb2_to_html(path.to_string_lossy().into_owned()).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            html,
+            "<div class='fb2-content'><div><h1>Book</h1><section id=\"c1\"><h2>One</h2>\
+             <p>A <em>b</em> &amp; <img src=\"data:image/png;base64,AQID\"/></p></section></div></div>"
+        );
+    }
+
+    #[test]
+    fn test_read_fb2_legacy_encoding_and_unsafe_binary() {
+        let path = std::env::temp_dir().join("ferrous_test_read_fb2_legacy_encoding.fb2");
+        let mut fb2 = br#"<?xml version="1.0" encoding="windows-1251"?>
+<FictionBook xmlns:l="http://www.w3.org/1999/xlink"><body><section><p>"#
+            .to_vec();
+        // "Привет" in windows-1251
+        fb2.extend_from_slice(b"\xcf\xf0\xe8\xe2\xe5\xf2");
+        fb2.extend_from_slice(
+            br##"<image l:href="#x"/></p></section></body>
+<binary id="x" content-type="text/html&quot; onerror=&quot;alert(1)">AQID</binary></FictionBook>"##,
+        );
+        std::fs::write(&path, fb2).unwrap();
+
+        let html = read_fb2_to_html(path.to_string_lossy().into_owned()).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            html,
+            "<div class='fb2-content'><div><section><p>\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}</p></section></div></div>"
+        );
+        assert!(is_image_content_type("image/svg+xml"));
+    }
+}
@@ -0,0 +1,486 @@
+use anyhow::{Context, Result};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::api::covers::CoverFormat;
+use crate::timed;
+
+/// Book container formats the reader understands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookFormat {
+    Pdf,
+    Epub,
+    Cbz,
+    Cbr,
+    Docx,
+    Mobi,
+    Fb2,
+    Txt,
+    Unknown,
+}
+
+impl BookFormat {
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn name(&self) -> &'static str {
+        match self {
+            BookFormat::Pdf => "PDF",
+            BookFormat::Epub => "EPUB",
+            BookFormat::Cbz => "CBZ",
+            BookFormat::Cbr => "CBR",
+            BookFormat::Docx => "DOCX",
+            BookFormat::Mobi => "MOBI",
+            BookFormat::Fb2 => "FB2",
+            BookFormat::Txt => "TXT",
+            BookFormat::Unknown => "unknown",
+        }
+    }
+
+    fn from_extension(path: &str) -> Self {
+        let ext = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match ext.as_str() {
+            "pdf" => BookFormat::Pdf,
+            "epub" => BookFormat::Epub,
+            "cbz" => BookFormat::Cbz,
+            "cbr" => BookFormat::Cbr,
+            "docx" => BookFormat::Docx,
+            "mobi" | "azw" | "azw3" => BookFormat::Mobi,
+            "fb2" => BookFormat::Fb2,
+            "txt" => BookFormat::Txt,
+            _ => BookFormat::Unknown,
+        }
+    }
+}
+
+/// Returned (inside `anyhow::Error`) by paged APIs for formats that have no
+/// fixed pages, e.g. DOCX and plain text. Callers can `downcast_ref` on it.
+#[derive(Debug, Clone, Copy)]
+pub struct UnpagedFormatError {
+    pub format: BookFormat,
+}
+
+impl fmt::Display for UnpagedFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UNPAGED_FORMAT::{} documents have no page model", self.format.name())
+    }
+}
+
+impl std::error::Error for UnpagedFormatError {}
+
+/// Header bytes read for sniffing; MOBI keeps its type tag at offset 60.
+const SNIFF_LEN: usize = 1024;
+
+fn sniff_zip_format(path: &str) -> BookFormat {
+    let Ok(file) = File::open(path) else {
+        return BookFormat::Unknown;
+    };
+    let Ok(mut archive) = ZipArchive::new(BufReader::new(file)) else {
+        return BookFormat::Unknown;
+    };
+
+    if let Ok(mut mimetype) = archive.by_name("mimetype") {
+        let mut value = String::new();
+        if mimetype.read_to_string(&mut value).is_ok() && value.trim() == "application/epub+zip" {
+            return BookFormat::Epub;
+        }
+    }
+    if archive.by_name("META-INF/container.xml").is_ok() {
+        return BookFormat::Epub;
+    }
+    if archive.by_name("word/document.xml").is_ok() {
+        return BookFormat::Docx;
+    }
+    // .fb2.zip wraps a single FB2 document
+    if crate::api::covers::read_single_file_zip(&mut archive, ".fb2").is_ok() {
+        return BookFormat::Fb2;
+    }
+    BookFormat::Cbz
+}
+
+/// Identify a book by its content rather than trusting the extension. Falls
+/// back to the extension for formats without a signature (plain text).
+#[hotpath::measure]
+pub fn detect_book_format(path: String) -> Result<BookFormat> {
+    timed!("detect_book_format", {
+        let mut file = File::open(&path)
+            .with_context(|| format!("Failed to open book file: {}", path))?;
+        let mut header = Vec::with_capacity(SNIFF_LEN);
+        file.by_ref().take(SNIFF_LEN as u64).read_to_end(&mut header)?;
+
+        let format = if header.windows(5).any(|w| w == b"%PDF-") {
+            BookFormat::Pdf
+        } else if header.starts_with(b"PK\x03\x04") {
+            sniff_zip_format(&path)
+        } else if header.starts_with(b"Rar!\x1a\x07") {
+            BookFormat::Cbr
+        } else if header.get(60..68) == Some(b"BOOKMOBI") {
+            BookFormat::Mobi
+        } else if header.windows(12).any(|w| w == b"<FictionBook") {
+            BookFormat::Fb2
+        } else {
+            match BookFormat::from_extension(&path) {
+                BookFormat::Txt => BookFormat::Txt,
+                _ => BookFormat::Unknown,
+            }
+        };
+
+        Ok(format)
+    })
+}
+
+/// Number of pages (or reading-order sections) of any paged book: PDF pages,
+/// comic pages, EPUB spine items or MOBI sections. Valid page indices are
+/// `0..count`. Formats without pages fail with `UnpagedFormatError`.
+#[hotpath::measure]
+pub fn get_page_count(path: String) -> Result<u32> {
+    timed!("get_page_count", {
+        match detect_book_format(path.clone())? {
+            BookFormat::Pdf => Ok(crate::api::pdf::get_pdf_page_count(path)?),
+            BookFormat::Cbz | BookFormat::Cbr => {
+                Ok(crate::api::cbz::get_cbz_page_count(path)?.max(0) as u32)
+            }
+            BookFormat::Epub => {
+                let mut archive = crate::api::epub::open_epub_archive(&path)?;
+                let package = crate::api::epub::read_epub_package(&mut archive)?;
+                // Same items get_epub_spine lists
+                let count = package
+                    .spine
+                    .iter()
+                    .filter(|spine_item| package.item_by_id(&spine_item.idref).is_some())
+                    .count();
+                Ok(count as u32)
+            }
+            BookFormat::Mobi => {
+                let mobi = mobi::Mobi::from_path(&path)?;
+                let text = crate::api::mobi::MobiText::read(&mobi);
+                let sections = crate::api::mobi::split_mobi_chapters(&text)?;
+                // get_mobi_chapters always yields at least one chapter
+                Ok(sections.len().max(1) as u32)
+            }
+            format => Err(UnpagedFormatError { format }.into()),
+        }
+    })
+}
+
+/// Where a chapter starts, in the terms of the format's own reader APIs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChapterLocation {
+    /// Page index of a fixed-layout book (PDF, comics)
+    Page { index: u32 },
+    /// Section of a reflowable book (EPUB spine item, MOBI section, 0 for a
+    /// whole DOCX) and a position inside it. For DOCX, `offset` is the
+    /// heading's ordinal among the h1–h6 elements of `read_docx_to_html`;
+    /// elsewhere it is 0, the start of the section.
+    Section { index: u32, offset: u32 },
+}
+
+/// One entry of a book's table of contents
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterEntry {
+    pub title: String,
+    /// Nesting level, 0 for top-level entries
+    pub level: u32,
+    pub location: ChapterLocation,
+}
+
+/// Comic chapters are the folders pages are grouped in. A flat archive (or
+/// a single folder) has no chapters.
+fn comic_chapters_from_page_names(names: &[String]) -> Vec<ChapterEntry> {
+    let folder = |name: &str| name.rsplit_once('/').map(|(dir, _)| dir.to_string());
+
+    let mut chapters: Vec<ChapterEntry> = Vec::new();
+    let mut current = None;
+    for (index, name) in names.iter().enumerate() {
+        let dir = folder(name);
+        if index > 0 && dir == current {
+            continue;
+        }
+        let title = dir
+            .as_deref()
+            .map(|d| d.rsplit('/').next().unwrap_or(d).to_string())
+            .unwrap_or_default();
+        chapters.push(ChapterEntry {
+            title,
+            level: 0,
+            location: ChapterLocation::Page { index: index as u32 },
+        });
+        current = dir;
+    }
+
+    if chapters.len() < 2 {
+        chapters.clear();
+    }
+    chapters
+}
+
+/// Table of contents of any supported book, normalized across formats: the
+/// PDF outline, titled EPUB spine items, MOBI sections, DOCX headings or comic
+/// folders. Formats without chapter structure yield an empty list.
+#[hotpath::measure]
+pub fn get_chapters(path: String) -> Result<Vec<ChapterEntry>> {
+    timed!("get_chapters", {
+        let chapters = match detect_book_format(path.clone())? {
+            BookFormat::Pdf => crate::api::pdf::get_pdf_outline(path)?
+                .into_iter()
+                .map(|item| ChapterEntry {
+                    title: item.title,
+                    level: item.depth,
+                    location: ChapterLocation::Page { index: item.page_index },
+                })
+                .collect(),
+            BookFormat::Epub => crate::api::epub::get_epub_spine(path)?
+                .into_iter()
+                .filter(|chapter| !chapter.title.is_empty())
+                .map(|chapter| ChapterEntry {
+                    title: chapter.title,
+                    level: 0,
+                    location: ChapterLocation::Section { index: chapter.order, offset: 0 },
+                })
+                .collect(),
+            BookFormat::Mobi => crate::api::mobi::get_mobi_chapters(path)?
+                .into_iter()
+                .enumerate()
+                .map(|(index, chapter)| ChapterEntry {
+                    title: chapter.title,
+                    level: 0,
+                    location: ChapterLocation::Section { index: index as u32, offset: 0 },
+                })
+                .collect(),
+            BookFormat::Docx => crate::api::docx::read_docx_headings(&path)?
+                .into_iter()
+                .enumerate()
+                .map(|(ordinal, (level, title))| ChapterEntry {
+                    title,
+                    level: level as u32 - 1,
+                    location: ChapterLocation::Section { index: 0, offset: ordinal as u32 },
+                })
+                .collect(),
+            BookFormat::Cbz | BookFormat::Cbr => {
+                comic_chapters_from_page_names(&crate::api::cbz::get_cbz_page_names(path, Vec::new())?.names)
+            }
+            BookFormat::Fb2 | BookFormat::Txt | BookFormat::Unknown => Vec::new(),
+        };
+        Ok(chapters)
+    })
+}
+
+/// Granularity of `extract_plain_text`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextUnit {
+    Page,
+    Chapter,
+    Whole,
+}
+
+/// Elements whose start begins a new line of plain text
+const BLOCK_ELEMENTS: &[&str] = &[
+    "p", "div", "br", "li", "tr", "h1", "h2", "h3", "h4", "h5", "h6", "blockquote", "pre",
+    "section", "title", "subtitle", "v", "stanza",
+];
+/// Elements whose text is never read out (FB2 `<binary>` holds base64 images)
+const SKIPPED_ELEMENTS: &[&str] = &["script", "style", "head", "binary"];
+
+/// Readable text of an (X)HTML or FB2 document: body text with a line break
+/// at each block element, blank lines dropped.
+fn markup_plain_text(markup: &str) -> String {
+    let document = scraper::Html::parse_document(markup);
+    let mut text = String::new();
+    for node in document.root_element().descendants() {
+        match node.value() {
+            scraper::Node::Element(element) if BLOCK_ELEMENTS.contains(&element.name()) => {
+                text.push('\n');
+            }
+            scraper::Node::Text(content) => {
+                let skipped = node.ancestors().any(|ancestor| {
+                    ancestor
+                        .value()
+                        .as_element()
+                        .is_some_and(|element| SKIPPED_ELEMENTS.contains(&element.name()))
+                });
+                if !skipped {
+                    text.push_str(content);
+                }
+            }
+            _ => {}
+        }
+    }
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Plain text of any book for TTS, split into `unit`s. `Page` and
+/// `Chapter` both give the format's natural unit: PDF pages, EPUB spine
+/// chapters or MOBI sections; DOCX, FB2 and TXT come back whole either way.
+/// `Whole` joins everything with blank lines. Comics have no text.
+#[hotpath::measure]
+pub fn extract_plain_text(path: String, unit: TextUnit) -> Result<Vec<String>> {
+    timed!("extract_plain_text", {
+        let units = match detect_book_format(path.clone())? {
+            BookFormat::Pdf => {
+                let page_count = crate::api::pdf::get_pdf_page_count(path.clone())?;
+                (0..page_count)
+                    .map(|index| crate::api::pdf::extract_pdf_page_text(path.clone(), index))
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            BookFormat::Epub => {
+                let mut archive = crate::api::epub::open_epub_archive(&path)?;
+                let package = crate::api::epub::read_epub_package(&mut archive)?;
+                let mut chapters = Vec::new();
+                for item in package.spine.iter().filter_map(|spine_item| package.item_by_id(&spine_item.idref)) {
+                    let html = crate::api::covers::read_zip_string(&mut archive, &item.href)?;
+                    chapters.push(markup_plain_text(&html));
+                }
+                chapters
+            }
+            BookFormat::Mobi => crate::api::mobi::get_mobi_chapters(path)?
+                .into_iter()
+                .map(|chapter| markup_plain_text(&chapter.html_content))
+                .collect(),
+            BookFormat::Docx => vec![crate::api::docx::read_docx_paragraphs(&path)?.join("\n")],
+            BookFormat::Fb2 => vec![markup_plain_text(&crate::api::fb2::read_fb2_xml(&path)?)],
+            BookFormat::Txt => {
+                let bytes = std::fs::read(&path).with_context(|| format!("Failed to read {path}"))?;
+                vec![String::from_utf8_lossy(&bytes).into_owned()]
+            }
+            BookFormat::Cbz | BookFormat::Cbr => Vec::new(),
+            BookFormat::Unknown => {
+                return Err(anyhow::anyhow!("Unsupported book format: {path}"));
+            }
+        };
+
+        Ok(match unit {
+            TextUnit::Whole => vec![units.join("\n\n")],
+            TextUnit::Page | TextUnit::Chapter => units,
+        })
+    })
+}
+
+/// Encoding and size of a page from `render_page`
+#[derive(Debug, Clone)]
+pub struct RenderedPage {
+    pub bytes: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub format: CoverFormat,
+}
+
+/// Options for `render_page`. Pages are encoded like covers; the default is
+/// JPEG at quality 85.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions {
+    pub format: CoverFormat,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            format: CoverFormat::Jpeg { quality: 85 },
+        }
+    }
+}
+
+/// Image of a pre-paginated EPUB spine item: the first `<img>` or SVG
+/// `<image>` its XHTML references. Reflowable items have no page image.
+fn fixed_layout_epub_page_bytes(path: &str, page_index: u32) -> Result<Vec<u8>> {
+    let mut archive = crate::api::epub::open_epub_archive(path)?;
+    let package = crate::api::epub::read_epub_package(&mut archive)?;
+    let (spine_item, item) = package
+        .spine
+        .iter()
+        .filter_map(|spine_item| Some((spine_item, package.item_by_id(&spine_item.idref)?)))
+        .nth(page_index as usize)
+        .with_context(|| format!("Page index {} out of range", page_index))?;
+
+    if spine_item.layout.unwrap_or(package.layout) != crate::api::epub::EpubLayout::PrePaginated {
+        return Err(anyhow::anyhow!("EPUB page {} is reflowable and has no page image", page_index));
+    }
+
+    let xhtml = crate::api::covers::read_zip_string(&mut archive, &item.href)?;
+    let doc = scraper::Html::parse_document(&xhtml);
+    let selector = scraper::Selector::parse("img[src], image")
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let src = doc
+        .select(&selector)
+        .find_map(|element| {
+            let value = element.value();
+            value.attr("src").or_else(|| value.attr("xlink:href")).or_else(|| value.attr("href"))
+        })
+        .with_context(|| format!("No page image in {}", item.href))?;
+
+    let image_path = crate::api::covers::resolve_epub_href(&item.href, src);
+    crate::api::covers::read_zip_bytes(&mut archive, &image_path)
+}
+
+/// Render one page of any fixed-layout book (PDF page, comic page or
+/// pre-paginated EPUB page) fitted within `width` x `height` and encoded per
+/// `options`. The format-specific renderers stay available for finer control.
+#[hotpath::measure]
+pub fn render_page(
+    path: String,
+    page_index: u32,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<RenderedPage> {
+    timed!("render_page", {
+        let format = detect_book_format(path.clone())?;
+        let image = match format {
+            BookFormat::Pdf => crate::api::pdf::render_pdf_page_image(&path, page_index, width, height)?,
+            BookFormat::Cbz | BookFormat::Cbr => {
+                let mut archive = crate::api::cbz::open_comic_archive(&path)?;
+                let entries = archive.image_entries()?;
+                let name = entries
+                    .get(page_index as usize)
+                    .with_context(|| format!("Page index {} out of range (0-{})", page_index, entries.len() as i64 - 1))?;
+                image::load_from_memory(&archive.read_entry(name)?).context("Failed to decode image")?
+            }
+            BookFormat::Epub => image::load_from_memory(&fixed_layout_epub_page_bytes(&path, page_index)?)
+                .context("Failed to decode image")?,
+            format => return Err(UnpagedFormatError { format }.into()),
+        };
+
+        // Images are only ever scaled down to fit; PDFs are rendered to size
+        let image = if image.width() > width || image.height() > height {
+            image.resize(width.max(1), height.max(1), image::imageops::FilterType::Triangle)
+        } else {
+            image
+        };
+
+        Ok(RenderedPage {
+            bytes: crate::api::covers::encode_cover(&image, options.format)?,
+            width: image.width(),
+            height: image.height(),
+            format: options.format,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comic_chapters_from_folders() {
+        let names: Vec<String> = ["Vol 1/Ch 1/01.jpg", "Vol 1/Ch 1/02.jpg", "Vol 1/Ch 2/01.jpg"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let chapters = comic_chapters_from_page_names(&names);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].title, "Ch 1");
+        assert_eq!(chapters[1].title, "Ch 2");
+        assert_eq!(chapters[1].location, ChapterLocation::Page { index: 2 });
+
+        let flat: Vec<String> = vec!["01.jpg".to_string(), "02.jpg".to_string()];
+        assert!(comic_chapters_from_page_names(&flat).is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+/// Unicode script of a code point, for the scripts the reader picks fonts
+/// for. `None` for punctuation, digits, symbols and anything unlisted.
+fn script_of(c: char) -> Option<&'static str> {
+    let script = match c as u32 {
+        0x0041..=0x005A | 0x0061..=0x007A | 0x00C0..=0x024F | 0x1E00..=0x1EFF => "Latin",
+        0x0370..=0x03FF | 0x1F00..=0x1FFF => "Greek",
+        0x0400..=0x052F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => "Cyrillic",
+        0x0530..=0x058F => "Armenian",
+        0x0590..=0x05FF | 0xFB1D..=0xFB4F => "Hebrew",
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => "Arabic",
+        0x0900..=0x097F => "Devanagari",
+        0x0980..=0x09FF => "Bengali",
+        0x0B80..=0x0BFF => "Tamil",
+        0x0E00..=0x0E7F => "Thai",
+        0x10A0..=0x10FF => "Georgian",
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => "Hangul",
+        0x3040..=0x309F => "Hiragana",
+        0x30A0..=0x30FF | 0x31F0..=0x31FF => "Katakana",
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x2FA1F => "Han",
+        _ if c.is_alphabetic() => "Unknown",
+        _ => return None,
+    };
+    Some(script)
+}
+
+/// Dominant Unicode script of `text` ("Latin", "Cyrillic", "Han", "Arabic",
+/// ...), by counting letters per script. Returns "Common" when the text has
+/// no letters at all and "Unknown" when its letters are in unlisted scripts.
+pub fn detect_script(text: String) -> String {
+    let mut counts: Vec<(&'static str, usize)> = Vec::new();
+    for script in text.chars().filter_map(script_of) {
+        match counts.iter_mut().find(|(name, _)| *name == script) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+
+    // Ties go to the script seen first
+    let mut dominant: Option<(&'static str, usize)> = None;
+    for (name, count) in counts {
+        if dominant.is_none_or(|(_, best)| count > best) {
+            dominant = Some((name, count));
+        }
+    }
+
+    dominant.map(|(name, _)| name).unwrap_or("Common").to_string()
+}
+
+/// Whether a script returned by `detect_script` is written right-to-left.
+pub fn is_rtl_script(script: String) -> bool {
+    matches!(script.as_str(), "Arabic" | "Hebrew")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_script() {
+        assert_eq!(detect_script("The quick brown fox".to_string()), "Latin");
+        assert_eq!(detect_script("Война и мир".to_string()), "Cyrillic");
+        assert_eq!(detect_script("مرحبا بالعالم".to_string()), "Arabic");
+        assert_eq!(detect_script("中文文本".to_string()), "Han");
+        assert_eq!(detect_script("123 -- !?".to_string()), "Common");
+    }
+}
@@ -1,38 +1,743 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::sync::{mpsc, Mutex};
 use walkdir::WalkDir;
 
+use crate::api::covers::{extract_cover, fnv1a_64};
+use crate::api::operation::{cancel_operation, OperationHandle};
+use crate::frb_generated::StreamSink;
+
+const SUPPORTED_EXTENSIONS: [&str; 10] = ["pdf", "epub", "cbz", "cbr", "docx", "txt", "mobi", "azw", "azw3", "fb2"];
+
+/// Number of worker threads used for the non-PDF part of a library import.
+/// PDF covers go through the global pdfium instance, so PDFs are imported
+/// one at a time on a separate scoped thread alongside these workers.
+const IMPORT_WORKERS: usize = 4;
+
+/// `size_bytes`, `modified_epoch` and `format` were added after the first
+/// three fields; existing callers can ignore them.
 pub struct BookMetadata {
     pub title: String,
     pub author: String,
     pub path: String,
+    pub size_bytes: u64,
+    /// Modification time in seconds since the Unix epoch
+    pub modified_epoch: i64,
+    /// Lowercased extension, `fb2.zip` for zipped FB2
+    pub format: String,
+}
+
+pub struct ImportedBook {
+    pub metadata: BookMetadata,
+    pub cover_path: Option<String>,
+}
+
+pub struct ImportFailure {
+    pub path: String,
+    pub reason: String,
+}
+
+pub struct ImportSummary {
+    pub imported: u32,
+    pub failed: Vec<ImportFailure>,
+    pub cancelled: bool,
+}
+
+pub enum ImportEvent {
+    Book(ImportedBook),
+    Finished(ImportSummary),
+}
+
+/// What `scan_library` walks and which files it picks up
+pub struct ScanOptions {
+    /// Book extensions without the dot, matched case-insensitively;
+    /// `fb2` also matches `.fb2.zip`
+    pub extensions: Vec<String>,
+    /// How deep to walk, counted like `WalkDir::max_depth`: 1 picks up only
+    /// the files directly in `root_path`, 2 also those one folder down, and
+    /// so on; `None` for no limit
+    pub max_depth: Option<usize>,
+    /// Whether to descend into symlinked folders. Symlinked book files are
+    /// picked up either way.
+    pub follow_symlinks: bool,
+    /// Whether to pick up dotfiles and descend into dot-folders
+    pub include_hidden: bool,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            extensions: SUPPORTED_EXTENSIONS.iter().map(|ext| ext.to_string()).collect(),
+            max_depth: None,
+            follow_symlinks: false,
+            include_hidden: true,
+        }
+    }
+}
+
+fn is_supported_book(path: &Path, extensions: &[String]) -> bool {
+    let matches = |ext: &str| {
+        extensions
+            .iter()
+            .any(|wanted| wanted.trim_start_matches('.').eq_ignore_ascii_case(ext))
+    };
+    if path.to_string_lossy().to_lowercase().ends_with(".fb2.zip") && matches("fb2") {
+        return true;
+    }
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(matches)
+}
+
+fn is_hidden(entry: &walkdir::DirEntry) -> bool {
+    // The root itself is walked whatever its name
+    entry.depth() > 0 && entry.file_name().to_string_lossy().starts_with('.')
+}
+
+fn book_metadata_from_path(path: &Path, metadata: &std::fs::Metadata) -> BookMetadata {
+    let title = path.file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Unknown Title");
+    // "book.fb2.zip" has the stem "book.fb2"
+    let title = title
+        .strip_suffix(".fb2")
+        .unwrap_or(title)
+        .to_string();
+
+    let path = path.to_string_lossy().to_string();
+    BookMetadata {
+        title,
+        author: "Unknown Author".to_string(),
+        format: crate::api::covers::book_format(&path),
+        path,
+        size_bytes: metadata.len(),
+        modified_epoch: modified_epoch(metadata),
+    }
+}
+
+/// Title and author embedded in the book, each `None` when the format has
+/// no metadata accessor or the field is blank.
+fn read_embedded_metadata(path: &str) -> Result<(Option<String>, Option<String>)> {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    let (title, author) = match ext.as_str() {
+        "pdf" => {
+            let metadata = crate::api::pdf::get_pdf_metadata(path.to_string())?;
+            (metadata.title, metadata.author)
+        }
+        "epub" => {
+            let mut archive = crate::api::epub::open_epub_archive(path)?;
+            let package = crate::api::epub::read_epub_package(&mut archive)?;
+            (package.title, package.creator)
+        }
+        "mobi" | "azw" | "azw3" => {
+            let mobi = mobi::Mobi::from_path(path)?;
+            (mobi.title().to_string(), mobi.author().unwrap_or_default())
+        }
+        _ => (String::new(), String::new()),
+    };
+
+    let non_blank = |value: String| {
+        let value = value.trim().to_string();
+        (!value.is_empty()).then_some(value)
+    };
+    Ok((non_blank(title), non_blank(author)))
+}
+
+/// Replace the file-name title and placeholder author with the ones embedded
+/// in the book, when it has them. A book whose metadata cannot be read keeps
+/// its file-name title and is reported in `skipped`.
+fn apply_embedded_metadata(book: &mut BookMetadata, skipped: &mut Vec<ScanError>) {
+    match read_embedded_metadata(&book.path) {
+        Ok((title, author)) => {
+            if let Some(title) = title {
+                book.title = title;
+            }
+            if let Some(author) = author {
+                book.author = author;
+            }
+        }
+        Err(e) => skipped.push(ScanError {
+            path: book.path.clone(),
+            reason: format!("Failed to read embedded metadata: {}", e),
+        }),
+    }
+}
+
+/// A file or directory the scan could not read
+pub struct ScanError {
+    pub path: String,
+    pub reason: String,
+}
+
+pub struct ScanReport {
+    pub books: Vec<BookMetadata>,
+    pub skipped: Vec<ScanError>,
+}
+
+/// Walk `root_path` for supported books. With `read_metadata`, titles and
+/// authors come from the files themselves (slower on large libraries);
+/// otherwise, or when a file has none, the file name is used.
+///
+/// Unreadable entries (permission denied, broken links, ...) are reported in
+/// `skipped`; only an unreadable `root_path` fails the whole scan.
+///
+/// `options` default to every supported format, unlimited depth, hidden
+/// files included and symlinked folders not descended into, which matches
+/// the scan before `ScanOptions` existed.
+pub fn scan_library(root_path: String, read_metadata: bool, options: Option<ScanOptions>) -> Result<ScanReport> {
+    walk_library(&root_path, read_metadata, &options.unwrap_or_default(), |_, _| true)
 }
 
-pub fn scan_library(root_path: String) -> Vec<BookMetadata> {
-    let supported_extensions = vec!["pdf", "epub", "cbz", "docx", "txt", "mobi", "azw", "azw3"];
-    
-    let mut books = Vec::new();
-    
-    for entry in WalkDir::new(&root_path)
+/// Scan driver shared by the scan entry points. `on_entry` runs with each
+/// walked path and the report so far; returning `false` stops the walk.
+fn walk_library(
+    root_path: &str,
+    read_metadata: bool,
+    options: &ScanOptions,
+    mut on_entry: impl FnMut(&Path, &ScanReport) -> bool,
+) -> Result<ScanReport> {
+    std::fs::metadata(root_path)
+        .with_context(|| format!("Cannot read library folder: {}", root_path))?;
+
+    let mut report = ScanReport {
+        books: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    let mut walker = WalkDir::new(root_path).follow_links(options.follow_symlinks);
+    if let Some(max_depth) = options.max_depth {
+        walker = walker.max_depth(max_depth);
+    }
+    let walker = walker
         .into_iter()
-        .filter_map(|e| e.ok())
-    {
+        .filter_entry(|entry| options.include_hidden || !is_hidden(entry));
+
+    for entry in walker {
+        if let Ok(entry) = &entry {
+            if !on_entry(entry.path(), &report) {
+                break;
+            }
+        }
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let path = e
+                    .path()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|| root_path.to_string());
+                report.skipped.push(ScanError {
+                    path,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+
         let path = entry.path();
-        if path.is_file() {
-            if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                if supported_extensions.contains(&ext.to_lowercase().as_str()) {
-                    let title = path.file_stem()
-                        .and_then(|s| s.to_str())
-                        .unwrap_or("Unknown Title")
-                        .to_string();
-                        
-                    books.push(BookMetadata {
-                        title,
-                        author: "Unknown Author".to_string(),
-                        path: path.to_string_lossy().to_string(),
-                    });
+        if !is_supported_book(path, &options.extensions) {
+            continue;
+        }
+        // Follows symlinks, so a linked book file is picked up even when
+        // symlinked folders are not descended into
+        let metadata = match std::fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => metadata,
+            Ok(_) => continue,
+            Err(e) => {
+                report.skipped.push(ScanError {
+                    path: path.to_string_lossy().to_string(),
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let mut book = book_metadata_from_path(path, &metadata);
+        if read_metadata {
+            apply_embedded_metadata(&mut book, &mut report.skipped);
+        }
+        report.books.push(book);
+    }
+
+    Ok(report)
+}
+
+/// A book from a previous scan, as the app stored it
+pub struct KnownFile {
+    pub path: String,
+    /// Modification time in seconds since the Unix epoch
+    pub modified_epoch: i64,
+    pub size: u64,
+}
+
+pub struct ScanDelta {
+    pub added: Vec<BookMetadata>,
+    pub modified: Vec<BookMetadata>,
+    /// Paths of known books that are no longer there
+    pub removed: Vec<String>,
+    /// Entries the rescan could not read, as in `ScanReport::skipped`
+    pub skipped: Vec<ScanError>,
+}
+
+fn modified_epoch(metadata: &std::fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
+
+/// Rescan `root_path` against the books found last time. Files whose size
+/// and modification time match their `known` entry are left out; embedded
+/// metadata is only read for added and modified books. `options` work as in
+/// `scan_library`; known books they leave out count as removed.
+pub fn scan_library_incremental(
+    root_path: String,
+    known: Vec<KnownFile>,
+    options: Option<ScanOptions>,
+) -> Result<ScanDelta> {
+    let report = scan_library(root_path.clone(), false, options)?;
+    // Books the app knows from other library folders are not this scan's business
+    let mut known: HashMap<String, KnownFile> = known
+        .into_iter()
+        .filter(|file| Path::new(&file.path).starts_with(&root_path))
+        .map(|file| (file.path.clone(), file))
+        .collect();
+
+    let mut delta = ScanDelta {
+        added: Vec::new(),
+        modified: Vec::new(),
+        removed: Vec::new(),
+        skipped: report.skipped,
+    };
+    for mut book in report.books {
+        let previous = known.remove(&book.path);
+        let unchanged = previous
+            .as_ref()
+            .is_some_and(|previous| previous.size == book.size_bytes && previous.modified_epoch == book.modified_epoch);
+        if unchanged {
+            continue;
+        }
+        apply_embedded_metadata(&mut book, &mut delta.skipped);
+        if previous.is_some() {
+            delta.modified.push(book);
+        } else {
+            delta.added.push(book);
+        }
+    }
+    // Books under an unreadable folder were skipped, not removed
+    delta.removed = known
+        .into_keys()
+        .filter(|path| {
+            !delta
+                .skipped
+                .iter()
+                .any(|skipped| Path::new(path).starts_with(&skipped.path))
+        })
+        .collect();
+    delta.removed.sort();
+
+    Ok(delta)
+}
+
+/// Entries walked between two `ScanEvent::Progress` events.
+const SCAN_PROGRESS_INTERVAL: u32 = 50;
+
+pub struct ScanProgress {
+    /// Files and folders walked so far
+    pub scanned: u32,
+    /// Supported books found so far
+    pub found: u32,
+    pub current_path: String,
+}
+
+pub enum ScanEvent {
+    Progress(ScanProgress),
+    Finished(ScanReport),
+}
+
+/// `scan_library` that reports its progress every few dozen entries while
+/// walking, then emits the full report as the final event. Stops early when
+/// the Dart side stops listening.
+pub fn scan_library_with_progress(
+    root_path: String,
+    read_metadata: bool,
+    options: Option<ScanOptions>,
+    sink: StreamSink<ScanEvent>,
+) -> Result<()> {
+    let mut scanned = 0u32;
+    let mut listening = true;
+    let report = walk_library(&root_path, read_metadata, &options.unwrap_or_default(), |path, report| {
+        scanned += 1;
+        if scanned % SCAN_PROGRESS_INTERVAL == 0 {
+            let progress = ScanProgress {
+                scanned,
+                found: report.books.len() as u32,
+                current_path: path.to_string_lossy().to_string(),
+            };
+            listening = sink.add(ScanEvent::Progress(progress)).is_ok();
+        }
+        listening
+    })?;
+
+    if listening {
+        let _ = sink.add(ScanEvent::Finished(report));
+    }
+    Ok(())
+}
+
+/// Stable cover file name for a book, so re-imports overwrite instead of piling up.
+fn cover_file_name(book_path: &str) -> String {
+    format!("{:016x}.png", fnv1a_64(book_path.as_bytes()))
+}
+
+/// Read metadata for a single book and extract its cover into `cover_dir`.
+/// Title and author come from the book when it has them, as with a
+/// `read_metadata` scan. A missing cover or unreadable metadata is not an
+/// error; the book is still imported with what could be read.
+pub fn import_book(path: String, cover_dir: String) -> Result<ImportedBook> {
+    let book_path = Path::new(&path);
+    let file_metadata = match std::fs::metadata(book_path) {
+        Ok(metadata) if metadata.is_file() => metadata,
+        _ => return Err(anyhow::anyhow!("Book file not found: {}", path)),
+    };
+
+    let mut metadata = book_metadata_from_path(book_path, &file_metadata);
+    apply_embedded_metadata(&mut metadata, &mut Vec::new());
+    let save_path = Path::new(&cover_dir).join(cover_file_name(&path));
+    let cover_path = extract_cover(path, save_path.to_string_lossy().to_string()).ok();
+
+    Ok(ImportedBook {
+        metadata,
+        cover_path,
+    })
+}
+
+/// Request cancellation of a running `import_library_stream`. Returns whether
+/// an import with that id was running. Same as `cancel_operation`.
+pub fn cancel_library_import(import_id: u64) -> bool {
+    cancel_operation(import_id)
+}
+
+/// Walk `root_path` and import every supported book, emitting each one as soon
+/// as its metadata and cover are ready, followed by a final summary event.
+/// `import_id` is an operation id (see `create_operation`); cancel with
+/// `cancel_library_import(import_id)` or `cancel_operation`.
+pub fn import_library_stream(
+    root_path: String,
+    cover_dir: String,
+    import_id: u64,
+    sink: StreamSink<ImportEvent>,
+) -> Result<()> {
+    let operation = OperationHandle::new(Some(import_id));
+    let cancelled = operation.flag();
+    let report = scan_library(root_path, false, None)?;
+    let _ = std::fs::create_dir_all(&cover_dir);
+
+    let mut pdf_paths: Vec<String> = Vec::new();
+    let mut other_paths: Vec<String> = Vec::new();
+    for book in report.books {
+        if book.path.to_lowercase().ends_with(".pdf") {
+            pdf_paths.push(book.path);
+        } else {
+            other_paths.push(book.path);
+        }
+    }
+
+    let queue = Mutex::new(other_paths.into_iter());
+    let (tx, rx) = mpsc::channel::<(String, Result<ImportedBook>)>();
+
+    let mut summary = ImportSummary {
+        imported: 0,
+        failed: report
+            .skipped
+            .into_iter()
+            .map(|skipped| ImportFailure {
+                path: skipped.path,
+                reason: skipped.reason,
+            })
+            .collect(),
+        cancelled: false,
+    };
+
+    std::thread::scope(|scope| {
+        for _ in 0..IMPORT_WORKERS {
+            let tx = tx.clone();
+            let queue = &queue;
+            let cover_dir = &cover_dir;
+            scope.spawn(move || loop {
+                if cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+                let next = queue.lock().unwrap_or_else(|p| p.into_inner()).next();
+                let Some(path) = next else { break };
+                let result = import_book(path.clone(), cover_dir.clone());
+                if tx.send((path, result)).is_err() {
+                    break;
                 }
+            });
+        }
+
+        let pdf_tx = tx;
+        let pdf_cancelled = cancelled;
+        let pdf_cover_dir = &cover_dir;
+        scope.spawn(move || {
+            for path in pdf_paths {
+                if pdf_cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+                let result = import_book(path.clone(), pdf_cover_dir.clone());
+                if pdf_tx.send((path, result)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // All senders live in the workers, so this ends once every worker is done
+        for (path, result) in rx {
+            match result {
+                Ok(book) => {
+                    summary.imported += 1;
+                    if sink.add(ImportEvent::Book(book)).is_err() {
+                        // Dart side stopped listening; wind the workers down
+                        cancelled.store(true, Ordering::Relaxed);
+                    }
+                }
+                Err(e) => summary.failed.push(ImportFailure {
+                    path,
+                    reason: e.to_string(),
+                }),
+            }
+        }
+    });
+
+    summary.cancelled = cancelled.load(Ordering::Relaxed);
+    let _ = sink.add(ImportEvent::Finished(summary));
+    Ok(())
+}
+
+/// Paragraphs shorter than this are skipped as headings, dedications, etc.
+const SYNOPSIS_MIN_CHARS: usize = 120;
+/// How far into a book the synopsis search looks before giving up.
+const SYNOPSIS_MAX_SECTIONS: usize = 12;
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Heuristic check that a paragraph reads like body prose rather than front
+/// matter (title pages, copyright notices, tables of contents).
+fn is_synopsis_candidate(paragraph: &str) -> bool {
+    if paragraph.chars().count() < SYNOPSIS_MIN_CHARS || paragraph.split_whitespace().count() < 15 {
+        return false;
+    }
+
+    let letters: Vec<char> = paragraph.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.is_empty() {
+        return false;
+    }
+    let upper = letters.iter().filter(|c| c.is_uppercase()).count();
+    if upper * 10 > letters.len() * 6 {
+        return false;
+    }
+
+    let lower = paragraph.to_lowercase();
+    let front_matter = [
+        "copyright",
+        "©",
+        "all rights reserved",
+        "isbn",
+        "published by",
+        "table of contents",
+        "printed in",
+        "library of congress",
+    ];
+    if front_matter.iter().any(|marker| lower.contains(marker)) {
+        return false;
+    }
+
+    // TOC lines end in page numbers; prose is mostly letters
+    let digits = paragraph.chars().filter(|c| c.is_ascii_digit()).count();
+    digits * 10 < paragraph.chars().count()
+}
+
+fn truncate_synopsis(paragraph: &str, max_chars: usize) -> String {
+    if paragraph.chars().count() <= max_chars {
+        return paragraph.to_string();
+    }
+    let cut: String = paragraph.chars().take(max_chars.saturating_sub(1)).collect();
+    let cut = match cut.rfind(' ') {
+        Some(space) if space > 0 => cut[..space].to_string(),
+        _ => cut,
+    };
+    format!("{}…", cut.trim_end_matches(|c: char| c.is_whitespace() || c == ',' || c == ';'))
+}
+
+fn html_paragraphs(html: &str) -> Vec<String> {
+    let document = scraper::Html::parse_document(html);
+    let Ok(selector) = scraper::Selector::parse("p") else {
+        return Vec::new();
+    };
+    document
+        .select(&selector)
+        .map(|p| collapse_whitespace(&p.text().collect::<String>()))
+        .collect()
+}
+
+fn plain_text_paragraphs(text: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(collapse_whitespace(&current.join(" ")));
+                current.clear();
             }
+            continue;
         }
+        current.push(line);
     }
+    if !current.is_empty() {
+        paragraphs.push(collapse_whitespace(&current.join(" ")));
+    }
+    paragraphs
+}
+
+/// PDF text has no paragraph markup, so a paragraph ends at a blank line or a
+/// noticeably short line that finishes a sentence.
+fn pdf_text_paragraphs(text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().map(str::trim).collect();
+    let longest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
 
-    books
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(collapse_whitespace(&current.join(" ")));
+                current.clear();
+            }
+            continue;
+        }
+        current.push(line);
+        let ends_sentence = line.ends_with(['.', '!', '?', '"', '”']);
+        if ends_sentence && line.chars().count() * 10 < longest * 7 {
+            paragraphs.push(collapse_whitespace(&current.join(" ")));
+            current.clear();
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(collapse_whitespace(&current.join(" ")));
+    }
+    paragraphs
+}
+
+fn synopsis_sections(path: &str, format: &str) -> Result<Vec<Vec<String>>> {
+    let sections = match format {
+        "pdf" => {
+            let page_count = crate::api::pdf::get_pdf_page_count(path.to_string())?;
+            (0..page_count.min(SYNOPSIS_MAX_SECTIONS as u32))
+                .filter_map(|i| crate::api::pdf::extract_pdf_page_text(path.to_string(), i).ok())
+                .map(|text| pdf_text_paragraphs(&text))
+                .collect()
+        }
+        "epub" => {
+            let mut archive = crate::api::epub::open_epub_archive(path)?;
+            let package = crate::api::epub::read_epub_package(&mut archive)?;
+            let hrefs: Vec<String> = package
+                .spine
+                .iter()
+                .filter_map(|spine_item| package.item_by_id(&spine_item.idref))
+                .map(|item| item.href.clone())
+                .take(SYNOPSIS_MAX_SECTIONS)
+                .collect();
+            hrefs
+                .iter()
+                .filter_map(|href| crate::api::covers::read_zip_string(&mut archive, href).ok())
+                .map(|html| html_paragraphs(&html))
+                .collect()
+        }
+        "docx" => vec![html_paragraphs(&crate::api::docx::read_docx_to_html(path.to_string())?)],
+        "mobi" | "azw" | "azw3" => {
+            let mobi = mobi::Mobi::from_path(path)?;
+            vec![html_paragraphs(&crate::api::mobi::MobiText::read(&mobi).html())]
+        }
+        "txt" => vec![plain_text_paragraphs(&std::fs::read_to_string(path)?)],
+        _ => Vec::new(),
+    };
+    Ok(sections)
+}
+
+/// Synthesize a description from the first substantial paragraph of body
+/// text, truncated to `max_chars`. Returns an empty string when nothing
+/// suitable is found rather than front matter or garbage.
+#[hotpath::measure]
+pub fn get_book_synopsis(path: String, max_chars: u32) -> Result<String> {
+    crate::timed!("get_book_synopsis", {
+        let format = Path::new(&path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let synopsis = synopsis_sections(&path, &format)?
+            .into_iter()
+            .flatten()
+            .find(|paragraph| is_synopsis_candidate(paragraph))
+            .map(|paragraph| truncate_synopsis(&paragraph, max_chars as usize))
+            .unwrap_or_default();
+
+        Ok(synopsis)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_library_incremental() {
+        let root = std::env::temp_dir().join("ferrous_test_incremental_scan");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        let book = |name: &str| root.join(name).to_string_lossy().into_owned();
+        for name in ["unchanged.txt", "changed.txt", "removed.txt"] {
+            std::fs::write(book(name), "first").unwrap();
+        }
+        let known: Vec<KnownFile> = scan_library(root.to_string_lossy().into_owned(), false, None)
+            .unwrap()
+            .books
+            .into_iter()
+            .map(|book| KnownFile {
+                path: book.path,
+                modified_epoch: book.modified_epoch,
+                size: book.size_bytes,
+            })
+            .chain([KnownFile {
+                // A sibling folder that merely shares the name prefix
+                path: format!("{}_other/elsewhere.txt", root.to_string_lossy()),
+                modified_epoch: 0,
+                size: 0,
+            }])
+            .collect();
+
+        std::fs::write(book("changed.txt"), "second, longer").unwrap();
+        std::fs::remove_file(book("removed.txt")).unwrap();
+        std::fs::write(book("new.txt"), "new").unwrap();
+        let delta = scan_library_incremental(root.to_string_lossy().into_owned(), known, None);
+        let _ = std::fs::remove_dir_all(&root);
+
+        let delta = delta.unwrap();
+        let paths = |books: &[BookMetadata]| books.iter().map(|book| book.path.clone()).collect::<Vec<_>>();
+        assert_eq!(paths(&delta.added), vec![book("new.txt")]);
+        assert_eq!(paths(&delta.modified), vec![book("changed.txt")]);
+        assert_eq!(delta.removed, vec![book("removed.txt")]);
+        assert!(delta.skipped.is_empty());
+    }
 }
@@ -2,7 +2,9 @@ use std::fs::{self, File};
 use std::io::Write;
 use std::path::Path;
 use anyhow::Result;
+use encoding_rs::WINDOWS_1252;
 use mobi::Mobi;
+use regex::bytes::Regex as BytesRegex;
 use regex::Regex;
 
 #[flutter_rust_bridge::frb]
@@ -11,48 +13,302 @@ pub struct MobiChapter {
     pub html_content: String,
 }
 
-fn prepare_mobi_content(path: &str) -> Result<(String, usize)> {
-    let mobi = Mobi::from_path(path)?;
-    let content = mobi.content_as_string_lossy();
-    
-    // Extract image records
+/// Save the book's image records next to it as `<path>_media/image_<i>.png`
+/// (once) and return how many there are.
+fn write_mobi_images(path: &str, mobi: &Mobi) -> usize {
     let images = mobi.image_records();
-    let img_count = images.len();
-    
-    if img_count > 0 {
-        let media_dir = format!("{}_media", path);
-        let _ = fs::create_dir_all(&media_dir);
-        
-        for (i, image) in images.iter().enumerate() {
-            let img_filename = format!("image_{}.png", i);
-            let img_dest_path = Path::new(&media_dir).join(&img_filename);
-            
-            if !img_dest_path.exists() {
-                if let Ok(mut outfile) = File::create(&img_dest_path) {
-                    let _ = outfile.write_all(image.content);
+    if images.is_empty() {
+        return 0;
+    }
+
+    let media_dir = format!("{}_media", path);
+    let _ = fs::create_dir_all(&media_dir);
+    for (i, image) in images.iter().enumerate() {
+        let img_filename = format!("image_{}.png", i);
+        let img_dest_path = Path::new(&media_dir).join(&img_filename);
+
+        if !img_dest_path.exists() {
+            if let Ok(mut outfile) = File::create(&img_dest_path) {
+                let _ = outfile.write_all(image.content);
+            }
+        }
+    }
+    images.len()
+}
+
+/// Point `recindex` (MOBI6) and `kindle:embed` (KF8) image references at the
+/// files `write_mobi_images` saved.
+fn rewrite_mobi_image_refs(html: &str, path: &str, img_count: usize) -> Result<String> {
+    if img_count == 0 {
+        return Ok(html.to_string());
+    }
+    let media_dir = format!("{}_media", path);
+
+    // Single-pass replacement using regex
+    let re = Regex::new(r#"recindex="([0-9]+)""#)?;
+    let result = re.replace_all(html, |caps: &regex::Captures| {
+        if let Some(idx_match) = caps.get(1) {
+            if let Ok(rec_idx) = idx_match.as_str().parse::<usize>() {
+                if rec_idx > 0 && rec_idx <= img_count {
+                    let i = rec_idx - 1;
+                    let img_filename = format!("image_{}.png", i);
+                    return format!(r#"src="file://{}/{}"#, media_dir, img_filename);
                 }
             }
         }
-        
-        // Single-pass replacement using regex
-        let re = Regex::new(r#"recindex="([0-9]+)""#)?;
-        let result = re.replace_all(&content, |caps: &regex::Captures| {
-            if let Some(idx_match) = caps.get(1) {
-                if let Ok(rec_idx) = idx_match.as_str().parse::<usize>() {
-                    if rec_idx > 0 && rec_idx <= img_count {
-                        let i = rec_idx - 1;
-                        let img_filename = format!("image_{}.png", i);
-                        return format!(r#"src="file://{}/{}"#, media_dir, img_filename);
-                    }
+        caps.get(0).unwrap().as_str().to_string()
+    });
+
+    // KF8 markup embeds resources as `kindle:embed:XXXX`, a 1-based base-32 index
+    let kf8_re = Regex::new(r#"src="kindle:embed:([0-9A-Va-v]+)(\?[^"]*)?""#)?;
+    let result = kf8_re.replace_all(&result, |caps: &regex::Captures| {
+        match u32::from_str_radix(&caps[1], 32) {
+            Ok(rec_idx) if rec_idx > 0 && rec_idx as usize <= img_count => {
+                format!(r#"src="file://{}/image_{}.png""#, media_dir, rec_idx - 1)
+            }
+            _ => caps[0].to_string(),
+        }
+    });
+
+    Ok(result.into_owned())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+}
+
+/// Size of the trailing entries `flags` says are appended to a text record.
+fn trailing_entries_size(data: &[u8], flags: usize) -> usize {
+    let mut size = 0;
+    let mut bits = flags >> 1;
+    while bits != 0 {
+        if bits & 1 != 0 {
+            // Backward-encoded varint: the high bit marks its first byte
+            let mut value = 0;
+            let mut shift = 0;
+            let mut end = data.len().saturating_sub(size);
+            while end > 0 && shift < 28 {
+                let byte = data[end - 1];
+                value |= ((byte & 0x7F) as usize) << shift;
+                shift += 7;
+                end -= 1;
+                if byte & 0x80 != 0 {
+                    break;
                 }
             }
-            caps.get(0).unwrap().as_str().to_string()
-        });
-        
-        return Ok((result.into_owned(), img_count));
+            size += value;
+        }
+        bits >>= 1;
     }
-    
-    Ok((content, 0))
+    // Multibyte character overlap
+    if flags & 1 != 0 {
+        if let Some(&byte) = data.len().checked_sub(size + 1).and_then(|i| data.get(i)) {
+            size += (byte & 0x3) as usize + 1;
+        }
+    }
+    size.min(data.len())
+}
+
+/// PalmDOC LZ77 decompression.
+fn palmdoc_decompress(data: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < data.len() {
+        let c = data[i];
+        i += 1;
+        match c {
+            0x01..=0x08 => {
+                let end = (i + c as usize).min(data.len());
+                out.extend_from_slice(&data[i..end]);
+                i = end;
+            }
+            0x80..=0xBF => {
+                let Some(&next) = data.get(i) else { break };
+                i += 1;
+                let pair = ((c as usize) << 8 | next as usize) & 0x3FFF;
+                let distance = pair >> 3;
+                let length = (pair & 0x7) + 3;
+                if distance == 0 || distance > out.len() {
+                    continue;
+                }
+                // Byte by byte: the copy may overlap its own output
+                for _ in 0..length {
+                    out.push(out[out.len() - distance]);
+                }
+            }
+            0xC0..=0xFF => {
+                out.push(b' ');
+                out.push(c ^ 0x80);
+            }
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Record index of the KF8 header: right after the `BOUNDARY` record of a
+/// joint MOBI6/KF8 file, or record 0 of a KF8-only AZW3.
+fn kf8_header_index(records: &[&[u8]]) -> Option<usize> {
+    if let Some(boundary) = records.iter().position(|r| r.starts_with(b"BOUNDARY")) {
+        return Some(boundary + 1);
+    }
+    // MOBI header version, 20 bytes into the MOBI header
+    (read_u32(records.first()?, 36)? == 8).then_some(0)
+}
+
+/// Decompressed text of the book whose header is `records[base]` (0 for
+/// MOBI6, the KF8 header for AZW3) and whether it is UTF-8 rather than
+/// CP1252, the only other encoding MOBI allows. `None` for text this
+/// reader can't decode (HUFF/CDIC compression, DRM).
+fn read_book_text(records: &[&[u8]], base: usize) -> Option<(Vec<u8>, bool)> {
+    let header = *records.get(base)?;
+    if header.get(16..20)? != b"MOBI" {
+        return None;
+    }
+
+    let compression = read_u16(header, 0)?;
+    let text_length = read_u32(header, 4)?;
+    let text_records = read_u16(header, 8)?;
+    let encrypted = read_u16(header, 12)? != 0;
+    if encrypted || !matches!(compression, 1 | 2) {
+        return None;
+    }
+    let header_length = read_u32(header, 20)?;
+    let extra_flags = if header_length >= 0xE4 { read_u16(header, 16 + 0xE2)? } else { 0 };
+    let utf8 = read_u32(header, 16 + 12)? == 65001;
+
+    let mut text = Vec::with_capacity(text_length);
+    for record in records.get(base + 1..=base + text_records)? {
+        let record = &record[..record.len() - trailing_entries_size(record, extra_flags)];
+        if compression == 2 {
+            palmdoc_decompress(record, &mut text);
+        } else {
+            text.extend_from_slice(record);
+        }
+    }
+    text.truncate(text_length);
+    Some((text, utf8))
+}
+
+/// Reassemble the KF8 part of an AZW3 as one HTML document with its CSS
+/// flows inlined, or `None` for MOBI6-only files and KF8 text this reader
+/// can't decode (HUFF/CDIC compression, DRM).
+///
+/// Fragments are kept in file order after their skeleton instead of being
+/// spliced back in through the SKEL/FRAG indexes, which keeps the reading
+/// order and markup but may drop wrapper elements' styling.
+fn kf8_content(mobi: &Mobi) -> Option<String> {
+    let raw_records = mobi.raw_records();
+    let records: Vec<&[u8]> = raw_records.records().iter().map(|r| r.content).collect();
+    let base = kf8_header_index(&records)?;
+    let header = *records.get(base)?;
+    let (text, _) = read_book_text(&records, base)?;
+
+    // FDST: (start, end) ranges of the flows; flow 0 is the HTML, the rest
+    // are stylesheets and SVG referenced as `kindle:flow:XXXX`
+    let mut flows = Vec::new();
+    let fdst = read_u32(header, 16 + 0xC0)
+        .and_then(|index| records.get(base.checked_add(index)?))
+        .filter(|r| r.starts_with(b"FDST"));
+    if let Some(fdst) = fdst {
+        let count = read_u32(fdst, 8)?;
+        for i in 0..count {
+            let start = read_u32(fdst, 12 + i * 8)?;
+            let end = read_u32(fdst, 16 + i * 8)?.min(text.len());
+            flows.push(String::from_utf8_lossy(text.get(start..end)?).into_owned());
+        }
+    }
+    if flows.is_empty() {
+        flows.push(String::from_utf8_lossy(&text).into_owned());
+    }
+
+    let html = &flows[0];
+    let css_re =
+        Regex::new(r#"(?i)<link[^>]*href="kindle:flow:([0-9A-V]+)\?mime=text/css"[^>]*>"#).ok()?;
+    let mut css_flows: Vec<usize> = css_re
+        .captures_iter(html)
+        .filter_map(|caps| usize::from_str_radix(&caps[1], 32).ok())
+        .filter(|&i| i > 0 && i < flows.len())
+        .collect();
+    css_flows.sort_unstable();
+    css_flows.dedup();
+
+    // Every skeleton repeats the document scaffolding; keep only the body markup
+    let scaffold_re = Regex::new(
+        r"(?is)<\?xml[^>]*\?>|<!DOCTYPE[^>]*>|<head\b.*?</head>|</?html\b[^>]*>|</?body\b[^>]*>",
+    )
+    .ok()?;
+    let body = scaffold_re.replace_all(html, "");
+
+    let mut out = String::from("<html><head>");
+    for i in css_flows {
+        out.push_str("<style>");
+        out.push_str(&flows[i]);
+        out.push_str("</style>");
+    }
+    out.push_str("</head><body>");
+    out.push_str(&body);
+    out.push_str("</body></html>");
+    Some(out)
+}
+
+/// The book text, kept as undecoded bytes so MOBI6 `filepos` offsets (byte
+/// offsets into the raw text) stay valid until it has been split.
+pub(crate) struct MobiText {
+    bytes: Vec<u8>,
+    utf8: bool,
+}
+
+impl MobiText {
+    /// The KF8 part of an AZW3 when it can be decoded, else the MOBI6 text.
+    /// Every reader of the book text starts here, so an AZW3 reads the same
+    /// in chapters, page counts and synopses.
+    pub(crate) fn read(mobi: &Mobi) -> MobiText {
+        if let Some(html) = kf8_content(mobi) {
+            return MobiText { bytes: html.into_bytes(), utf8: true };
+        }
+        let raw_records = mobi.raw_records();
+        let records: Vec<&[u8]> = raw_records.records().iter().map(|r| r.content).collect();
+        match read_book_text(&records, 0) {
+            Some((bytes, utf8)) => MobiText { bytes, utf8 },
+            // Left to the mobi crate; filepos offsets won't line up
+            None => MobiText { bytes: mobi.content_as_string_lossy().into_bytes(), utf8: true },
+        }
+    }
+
+    fn decode(&self, range: std::ops::Range<usize>) -> String {
+        let bytes = &self.bytes[range];
+        if self.utf8 {
+            String::from_utf8_lossy(bytes).into_owned()
+        } else {
+            WINDOWS_1252.decode_without_bom_handling(bytes).0.into_owned()
+        }
+    }
+
+    /// The whole book as HTML.
+    pub(crate) fn html(&self) -> String {
+        self.decode(0..self.bytes.len())
+    }
+
+    /// Round `pos` down to the text, and to a UTF-8 sequence start.
+    fn floor_boundary(&self, pos: usize) -> usize {
+        let mut pos = pos.min(self.bytes.len());
+        while self.utf8 && pos > 0 && pos < self.bytes.len() && self.bytes[pos] & 0xC0 == 0x80 {
+            pos -= 1;
+        }
+        pos
+    }
+}
+
+fn prepare_mobi_content(path: &str) -> Result<(String, usize)> {
+    let mobi = Mobi::from_path(path)?;
+    let content = MobiText::read(&mobi).html();
+    let img_count = write_mobi_images(path, &mobi);
+    Ok((rewrite_mobi_image_refs(&content, path, img_count)?, img_count))
 }
 
 fn split_large_html(html: &str, target_chunk_size: usize) -> Vec<String> {
@@ -107,8 +363,8 @@ fn extract_title(html: &str, default_title: &str) -> String {
             let clean = strip_html_re.replace_all(content_match.as_str(), "");
             let trimmed = clean.trim();
             if !trimmed.is_empty() {
-                if trimmed.len() > 60 {
-                    return format!("{}...", &trimmed[..60]);
+                if trimmed.chars().count() > 60 {
+                    return format!("{}...", trimmed.chars().take(60).collect::<String>());
                 }
                 return trimmed.to_string();
             }
@@ -118,19 +374,11 @@ fn extract_title(html: &str, default_title: &str) -> String {
     default_title.to_string()
 }
 
-#[flutter_rust_bridge::frb]
-pub fn get_mobi_content(path: String) -> Result<String> {
-    let (content, _) = prepare_mobi_content(&path)?;
-    Ok(content)
-}
-
-#[flutter_rust_bridge::frb]
-pub fn get_mobi_chapters(path: String) -> Result<Vec<MobiChapter>> {
-    let (content, _) = prepare_mobi_content(&path)?;
-    
+/// Split the book HTML into sections at page breaks, chunking oversized ones.
+pub(crate) fn split_mobi_sections(content: &str) -> Result<Vec<String>> {
     // Split by pagebreaks first
     let pagebreak_re = Regex::new(r"(?i)<mbp:pagebreak\s*/?>|<pagebreak\s*/?>|<pb\s*/?>")?;
-    let raw_sections: Vec<&str> = pagebreak_re.split(&content).collect();
+    let raw_sections: Vec<&str> = pagebreak_re.split(content).collect();
     
     let mut final_sections = Vec::new();
     for section in raw_sections {
@@ -146,26 +394,150 @@ pub fn get_mobi_chapters(path: String) -> Result<Vec<MobiChapter>> {
         }
     }
     
+    Ok(final_sections)
+}
+
+/// Book HTML with image references pointing at extracted files. AZW3 files
+/// are read from their KF8 part when it can be decoded.
+#[flutter_rust_bridge::frb]
+pub fn get_mobi_content(path: String) -> Result<String> {
+    let (content, _) = prepare_mobi_content(&path)?;
+    Ok(content)
+}
+
+/// Largest byte span after the guide's TOC reference searched for TOC links.
+const MOBI_TOC_MAX_LEN: usize = 200_000;
+
+/// `(offset, title)` of each entry of the book's HTML table of contents: the
+/// page the `<guide>` marks as `type="toc"`, whose `<a filepos=...>` links
+/// hold offsets into the book text.
+fn mobi_toc_entries(text: &MobiText) -> Vec<(usize, String)> {
+    // Byte patterns without Unicode, so CP1252 text matches too
+    let guide_re = BytesRegex::new(r#"(?is-u)<reference\b[^>]*\btype\s*=\s*["']?toc\b[^>]*>"#).unwrap();
+    let filepos_re = BytesRegex::new(r#"(?i-u)\bfilepos\s*=\s*["']?0*([0-9]+)"#).unwrap();
+    let link_re = BytesRegex::new(r#"(?is-u)<a\b([^>]*)>(.*?)</a>"#).unwrap();
+    let pagebreak_re = BytesRegex::new(r"(?i-u)<mbp:pagebreak\s*/?>").unwrap();
+    let strip_html_re = Regex::new(r"<[^>]*>").unwrap();
+    let filepos = |tag: &[u8]| -> Option<usize> {
+        std::str::from_utf8(&filepos_re.captures(tag)?[1]).ok()?.parse().ok()
+    };
+    let content = &text.bytes[..];
+
+    let Some(toc_start) = guide_re
+        .find(content)
+        .and_then(|tag| filepos(tag.as_bytes()))
+        .filter(|&pos| pos < content.len())
+        .map(|pos| text.floor_boundary(pos))
+    else {
+        return Vec::new();
+    };
+
+    // The TOC page runs to the next page break
+    let region_end = text.floor_boundary(toc_start + MOBI_TOC_MAX_LEN);
+    let region = &content[toc_start..region_end];
+    let region = match pagebreak_re.find_iter(region).find(|pagebreak| pagebreak.start() > 0) {
+        Some(pagebreak) => &region[..pagebreak.start()],
+        None => region,
+    };
+
+    link_re
+        .captures_iter(region)
+        .filter_map(|caps| {
+            let offset = filepos(&caps[1])?;
+            let label = caps.get(2)?;
+            let title = text.decode(toc_start + label.start()..toc_start + label.end());
+            let title = strip_html_re.replace_all(&title, "");
+            let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+            (offset < content.len()).then(|| (text.floor_boundary(offset), title))
+        })
+        .collect()
+}
+
+/// Split the book into `(title, html)` chapters: at the TOC entries when
+/// the book has a TOC page, else before each top-level `<h1>`/`<h2>`, else at
+/// page breaks. Offsets refer to the raw book text, so it is split before
+/// each chapter is decoded and before any markup rewriting.
+pub(crate) fn split_mobi_chapters(text: &MobiText) -> Result<Vec<(String, String)>> {
+    let pagebreak_re = Regex::new(r"(?i)<mbp:pagebreak\s*/?>|<pagebreak\s*/?>|<pb\s*/?>")?;
+    let heading_re = BytesRegex::new(r"(?i-u)<h[12][\s>]")?;
+    let tag_re = Regex::new(r"<[^>]*>")?;
+    let has_text = |html: &str| !tag_re.replace_all(html, "").trim().is_empty();
+
+    let mut toc = mobi_toc_entries(text);
+    toc.sort_by_key(|(offset, _)| *offset);
+    toc.dedup_by_key(|(offset, _)| *offset);
+    let splits: Vec<(usize, Option<String>)> = if toc.len() >= 2 {
+        toc.into_iter().map(|(offset, title)| (offset, Some(title).filter(|t| !t.is_empty()))).collect()
+    } else {
+        heading_re.find_iter(&text.bytes).map(|m| (m.start(), None)).collect()
+    };
+
+    if splits.is_empty() {
+        let sections = split_mobi_sections(&text.html())?;
+        return Ok(sections
+            .into_iter()
+            .enumerate()
+            .map(|(i, section)| (extract_title(&section, &format!("Section {}", i + 1)), section))
+            .collect());
+    }
+
     let mut chapters = Vec::new();
-    for (i, section_content) in final_sections.into_iter().enumerate() {
-        let default_title = format!("Section {}", i + 1);
-        let title = extract_title(&section_content, &default_title);
+    let prologue = text.decode(0..splits[0].0);
+    if has_text(&prologue) {
+        chapters.push(("Beginning".to_string(), pagebreak_re.replace_all(&prologue, "").trim().to_string()));
+    }
+    for (i, (start, title)) in splits.iter().enumerate() {
+        let end = splits.get(i + 1).map_or(text.bytes.len(), |(next, _)| *next);
+        let html = pagebreak_re.replace_all(&text.decode(*start..end), "").trim().to_string();
+        if !has_text(&html) {
+            continue;
+        }
+        let default_title = format!("Chapter {}", chapters.len() + 1);
+        let title = title.clone().unwrap_or_else(|| extract_title(&html, &default_title));
+        chapters.push((title, html));
+    }
+    Ok(chapters)
+}
+
+/// Chapters of the book, split via its TOC page, headings or page breaks
+/// (see `split_mobi_chapters`), with image references resolved.
+#[flutter_rust_bridge::frb]
+pub fn get_mobi_chapters(path: String) -> Result<Vec<MobiChapter>> {
+    let mobi = Mobi::from_path(&path)?;
+    let text = MobiText::read(&mobi);
+    let img_count = write_mobi_images(&path, &mobi);
+
+    let mut chapters = Vec::new();
+    for (title, html) in split_mobi_chapters(&text)? {
         chapters.push(MobiChapter {
             title,
-            html_content: section_content,
+            html_content: rewrite_mobi_image_refs(&html, &path, img_count)?,
         });
     }
-    
+
     if chapters.is_empty() {
         chapters.push(MobiChapter {
             title: "Beginning".to_string(),
-            html_content: content,
+            html_content: rewrite_mobi_image_refs(&text.html(), &path, img_count)?,
         });
     }
-    
+
     Ok(chapters)
 }
 
+/// Raw bytes of the image an `<img recindex="N">` in the book text refers
+/// to. `recindex` is 1-based from the first image record, as in the markup.
+#[flutter_rust_bridge::frb]
+pub fn get_mobi_image(path: String, recindex: u32) -> Result<Vec<u8>> {
+    let mobi = Mobi::from_path(&path)?;
+    let images = mobi.image_records();
+    recindex
+        .checked_sub(1)
+        .and_then(|i| images.get(i as usize))
+        .map(|record| record.content.to_vec())
+        .ok_or_else(|| anyhow::anyhow!("No image record {} in {}", recindex, path))
+}
+
 #[flutter_rust_bridge::frb]
 pub fn get_mobi_title(path: String) -> Result<String> {
     let mobi = Mobi::from_path(&path)?;
@@ -181,3 +553,120 @@ pub fn get_mobi_author(path: String) -> Result<String> {
     Ok(author)
 }
 
+
+/// Catalog metadata from the MOBI header and its EXTH records
+#[derive(Debug, Clone, Default)]
+pub struct MobiMetadata {
+    pub title: String,
+    pub author: Option<String>,
+    pub publisher: Option<String>,
+    /// BCP-47 code ("en", "ru", ...) of the header locale
+    pub language: Option<String>,
+    pub isbn: Option<String>,
+    pub published: Option<String>,
+    pub description: Option<String>,
+}
+
+/// ISO 639-1 codes for the header locales the `mobi` crate names, keyed by
+/// the variant name. Sami and Sorbian have no two-letter code.
+const MOBI_LANGUAGE_CODES: &[(&str, &str)] = &[
+    ("Afrikaans", "af"), ("Albanian", "sq"), ("Arabic", "ar"), ("Armenian", "hy"),
+    ("Assamese", "as"), ("Azeri", "az"), ("Basque", "eu"), ("Belarusian", "be"),
+    ("Bengali", "bn"), ("Bulgarian", "bg"), ("Catalan", "ca"), ("Chinese", "zh"),
+    ("Czech", "cs"), ("Danish", "da"), ("Dutch", "nl"), ("English", "en"),
+    ("Estonian", "et"), ("Faeroese", "fo"), ("Farsi", "fa"), ("Finnish", "fi"),
+    ("French", "fr"), ("Georgian", "ka"), ("German", "de"), ("Greek", "el"),
+    ("Gujarati", "gu"), ("Hebrew", "he"), ("Hindi", "hi"), ("Hungarian", "hu"),
+    ("Icelandic", "is"), ("Indonesian", "id"), ("Italian", "it"), ("Japanese", "ja"),
+    ("Kannada", "kn"), ("Kazak", "kk"), ("Korean", "ko"), ("Latvian", "lv"),
+    ("Lithuanian", "lt"), ("Macedonian", "mk"), ("Malay", "ms"), ("Malayalam", "ml"),
+    ("Maltese", "mt"), ("Marathi", "mr"), ("Nepali", "ne"), ("Norwegian", "no"),
+    ("Oriya", "or"), ("Polish", "pl"), ("Portuguese", "pt"), ("Punjabi", "pa"),
+    ("Rhaetoromanic", "rm"), ("Romanian", "ro"), ("Russian", "ru"), ("Sanskrit", "sa"),
+    ("Serbian", "sr"), ("Slovak", "sk"), ("Slovenian", "sl"), ("Spanish", "es"),
+    ("Sutu", "st"), ("Swahili", "sw"), ("Swedish", "sv"), ("Tamil", "ta"),
+    ("Tatar", "tt"), ("Telugu", "te"), ("Thai", "th"), ("Tsonga", "ts"),
+    ("Tswana", "tn"), ("Turkish", "tr"), ("Ukrainian", "uk"), ("Urdu", "ur"),
+    ("Uzbek", "uz"), ("Vietnamese", "vi"), ("Xhosa", "xh"), ("Zulu", "zu"),
+];
+
+/// BCP-47 code of a header locale, `None` for "Neutral" (none set) and
+/// locales without a code.
+fn mobi_language_code(name: &str) -> Option<String> {
+    MOBI_LANGUAGE_CODES
+        .iter()
+        .find(|(language, _)| *language == name)
+        .map(|&(_, code)| code.to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_mobi_metadata(path: String) -> Result<MobiMetadata> {
+    let mobi = Mobi::from_path(&path)?;
+    let non_blank = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let language = mobi_language_code(&format!("{:?}", mobi.language()));
+
+    Ok(MobiMetadata {
+        title: mobi.title().trim().to_string(),
+        author: non_blank(mobi.author()),
+        publisher: non_blank(mobi.publisher()),
+        language,
+        isbn: non_blank(mobi.isbn()),
+        published: non_blank(mobi.publish_date()),
+        description: non_blank(mobi.description()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_mobi_chapters_cp1252() {
+        // "\xe9" is one byte in CP1252 and two once decoded, so splitting
+        // the decoded text would shift every filepos after it
+        let build = |toc: usize, one: usize, two: usize| -> Vec<u8> {
+            [
+                format!("<guide><reference type=\"toc\" filepos={toc:010} /></guide><mbp:pagebreak/>").as_bytes(),
+                format!("<p><a filepos={one:010}>Caf").as_bytes(),
+                &b"\xe9"[..],
+                format!("</a></p><p><a filepos={two:010}>Two</a></p><mbp:pagebreak/>").as_bytes(),
+                &b"<p>Caf\xe9 au lait</p><mbp:pagebreak/><p>Second</p>"[..],
+            ]
+            .concat()
+        };
+        let layout = build(0, 0, 0);
+        let find = |needle: &[u8]| layout.windows(needle.len()).position(|w| w == needle).unwrap();
+        let text = MobiText {
+            bytes: build(find(b"<p><a"), find(b"<p>Caf\xe9"), find(b"<p>Second")),
+            utf8: false,
+        };
+
+        let chapters = split_mobi_chapters(&text).unwrap();
+        assert_eq!(
+            chapters[1..],
+            [
+                ("Caf\u{e9}".to_string(), "<p>Caf\u{e9} au lait</p>".to_string()),
+                ("Two".to_string(), "<p>Second</p>".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mobi_language_code() {
+        assert_eq!(mobi_language_code("English").as_deref(), Some("en"));
+        assert_eq!(mobi_language_code("Chinese").as_deref(), Some("zh"));
+        assert_eq!(mobi_language_code("Neutral"), None);
+    }
+
+    #[test]
+    fn test_extract_title_truncates_by_char() {
+        let long = "\u{e9}".repeat(61);
+        let title = extract_title(&format!("<h1>{long}</h1>"), "Untitled");
+        assert_eq!(title, format!("{}...", "\u{e9}".repeat(60)));
+        assert_eq!(extract_title("<p>No heading</p>", "Untitled"), "Untitled");
+    }
+}
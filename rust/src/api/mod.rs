@@ -8,6 +8,13 @@ pub mod mobi;
 pub mod cbz;
 pub mod tts_text;
 pub mod txt;
+pub mod epub;
+pub mod format;
+pub mod language;
+pub mod error;
+pub mod operation;
+pub mod fb2;
+pub mod profiling;
 
 pub use library::*;
 pub use pdf::*;
@@ -18,6 +25,13 @@ pub use mobi::*;
 pub use cbz::*;
 pub use tts_text::*;
 pub use txt::*;
+pub use epub::*;
+pub use format::*;
+pub use language::*;
+pub use error::*;
+pub use operation::*;
+pub use fb2::*;
+pub use profiling::*;
 
 pub fn hello_world() -> String {
     "Hello from Rust!".to_string()
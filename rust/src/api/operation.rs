@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Returned by operations stopped through `cancel_operation`, inside
+/// `anyhow::Error` (callers can `downcast_ref` on it) or as
+/// `FerrousError::Cancelled`.
+#[derive(Debug, Clone, Copy)]
+pub struct CancelledError;
+
+impl std::fmt::Display for CancelledError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CANCELLED::Operation was cancelled")
+    }
+}
+
+impl std::error::Error for CancelledError {}
+
+#[derive(Default)]
+struct Operation {
+    cancelled: Arc<AtomicBool>,
+    /// Live `OperationHandle`s; the entry goes away with the last one
+    handles: usize,
+    /// When `cancel_operation` found no call running under the id
+    tombstoned_at: Option<Instant>,
+}
+
+/// How long a cancellation waits for its call to start. `cancel_operation`
+/// can't tell an id that hasn't started from one that already finished, so
+/// the tombstones it leaves are dropped after this.
+const TOMBSTONE_TTL: Duration = Duration::from_secs(60);
+
+// Operations that are running (or were cancelled before they started), keyed by id
+static OPERATIONS: OnceLock<Mutex<HashMap<u64, Operation>>> = OnceLock::new();
+static NEXT_OPERATION_ID: AtomicU64 = AtomicU64::new(1);
+
+fn lock_operations() -> MutexGuard<'static, HashMap<u64, Operation>> {
+    let operations = OPERATIONS.get_or_init(|| Mutex::new(HashMap::new()));
+    match operations.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Cancellation flag of one running call. Calls sharing an id share the
+/// flag, and the id stays registered until the last of them finishes.
+pub(crate) struct OperationHandle {
+    id: Option<u64>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl OperationHandle {
+    pub(crate) fn new(id: Option<u64>) -> Self {
+        let cancelled = match id {
+            Some(id) => {
+                let mut operations = lock_operations();
+                let operation = operations.entry(id).or_default();
+                operation.handles += 1;
+                operation.cancelled.clone()
+            }
+            None => Arc::default(),
+        };
+        Self { id, cancelled }
+    }
+
+    pub(crate) fn flag(&self) -> &AtomicBool {
+        &self.cancelled
+    }
+}
+
+impl Drop for OperationHandle {
+    fn drop(&mut self) {
+        let Some(id) = self.id else { return };
+        let mut operations = lock_operations();
+        if let Some(operation) = operations.get_mut(&id) {
+            operation.handles = operation.handles.saturating_sub(1);
+            if operation.handles == 0 {
+                operations.remove(&id);
+            }
+        }
+    }
+}
+
+pub(crate) fn check_cancelled(cancelled: &AtomicBool) -> Result<(), CancelledError> {
+    if cancelled.load(Ordering::Relaxed) {
+        Err(CancelledError)
+    } else {
+        Ok(())
+    }
+}
+
+/// A fresh id to pass as `operation_id` to cancellable calls. Nothing is
+/// registered until a call starts with it, so unused ids cost nothing.
+pub fn create_operation() -> u64 {
+    lock_operations().retain(|_, operation| {
+        operation.handles > 0 || operation.tombstoned_at.is_some_and(|at| at.elapsed() < TOMBSTONE_TTL)
+    });
+    NEXT_OPERATION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Ask the calls running under `id` to stop; they fail with
+/// `CancelledError` at their next check. Returns whether one was running.
+/// An id from `create_operation` whose call hasn't started yet is still
+/// cancelled, so the call stops as soon as it begins (within `TOMBSTONE_TTL`).
+pub fn cancel_operation(id: u64) -> bool {
+    let mut operations = lock_operations();
+    match operations.get(&id) {
+        Some(operation) => {
+            operation.cancelled.store(true, Ordering::Relaxed);
+            operation.handles > 0
+        }
+        None => {
+            if id > 0 && id < NEXT_OPERATION_ID.load(Ordering::Relaxed) {
+                let operation = Operation {
+                    tombstoned_at: Some(Instant::now()),
+                    ..Operation::default()
+                };
+                operation.cancelled.store(true, Ordering::Relaxed);
+                operations.insert(id, operation);
+            }
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_shared_until_last_handle() {
+        let id = create_operation();
+        let first = OperationHandle::new(Some(id));
+        let second = OperationHandle::new(Some(id));
+        drop(first);
+
+        assert!(cancel_operation(id));
+        assert!(check_cancelled(second.flag()).is_err());
+        drop(second);
+        assert!(!lock_operations().contains_key(&id));
+    }
+
+    #[test]
+    fn test_cancel_before_start() {
+        let unused = create_operation();
+        assert!(!lock_operations().contains_key(&unused));
+
+        let id = create_operation();
+        assert!(!cancel_operation(id));
+        let handle = OperationHandle::new(Some(id));
+        assert!(check_cancelled(handle.flag()).is_err());
+        drop(handle);
+        assert!(!lock_operations().contains_key(&id));
+    }
+
+    #[test]
+    fn test_cancel_after_finish_expires() {
+        let id = create_operation();
+        drop(OperationHandle::new(Some(id)));
+        assert!(!cancel_operation(id));
+        assert!(lock_operations().contains_key(&id));
+
+        // Age the tombstone past its TTL; the next sweep drops it
+        if let Some(operation) = lock_operations().get_mut(&id) {
+            operation.tombstoned_at = Instant::now().checked_sub(TOMBSTONE_TTL);
+        }
+        create_operation();
+        assert!(!lock_operations().contains_key(&id));
+    }
+}
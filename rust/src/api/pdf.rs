@@ -1,97 +1,194 @@
-use anyhow::{anyhow, Context, Result};
 use pdfium_render::prelude::*;
+use crate::api::error::FerrousError;
+use crate::api::operation::{check_cancelled, OperationHandle};
+use crate::frb_generated::StreamSink;
 use crate::timed;
 use std::fs::File;
 use std::io::Read;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex, OnceLock};
 use std::num::NonZeroUsize;
+use std::os::raw::c_ulong;
+use std::path::Path;
 use lru::LruCache;
 
 
-static PDFIUM: OnceLock<Pdfium> = OnceLock::new();
-static PDFIUM_PATH: OnceLock<String> = OnceLock::new();
+// The bind error is kept so a missing library fails every call cleanly
+static PDFIUM: OnceLock<Result<Pdfium, String>> = OnceLock::new();
 
-pub fn init_pdfium(path: String) -> Result<()> {
-    let _ = PDFIUM_PATH.set(path);
-    Ok(())
+/// Bind to `library_path` (the library file, or the folder holding
+/// `libpdfium.so`/`pdfium.dll`/`libpdfium.dylib`), falling back to the
+/// platform library name on the system search path (with a warning, so a
+/// wrong explicit path shows up in the logs).
+fn bind_pdfium(library_path: Option<&str>) -> Result<Pdfium, String> {
+    let bindings = match library_path {
+        Some(path) => {
+            let explicit = if Path::new(path).is_dir() {
+                Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path(path))
+            } else {
+                Pdfium::bind_to_library(path)
+            };
+            explicit.or_else(|e| {
+                eprintln!(
+                    "⚠️ Warning: failed to bind to pdfium at {path}: {e:?}, falling back to the system library"
+                );
+                Pdfium::bind_to_system_library()
+            })
+        }
+        None => Pdfium::bind_to_system_library(),
+    };
+    match bindings {
+        Ok(bindings) => Ok(Pdfium::new(bindings)),
+        Err(e) => {
+            eprintln!("⚠️ Warning: failed to bind to pdfium: {e:?}");
+            Err(format!("PDF support unavailable, pdfium could not be loaded: {e:?}"))
+        }
+    }
 }
 
-fn get_pdfium() -> &'static Pdfium {
-    PDFIUM.get_or_init(|| {
-        let bindings = if let Some(custom_path) = PDFIUM_PATH.get() {
-            let full_path = format!("{}/libpdfium.so", custom_path);
-            Pdfium::bind_to_library(&full_path)
-                .or_else(|_| Pdfium::bind_to_library("libpdfium.so"))
-        } else {
-            Pdfium::bind_to_library("libpdfium.so")
-        }
-        .or_else(|_| Pdfium::bind_to_system_library())
-        .expect("Failed to bind to pdfium library. Make sure libpdfium.so is in jniLibs.");
-        Pdfium::new(bindings)
-    })
+/// Load pdfium from `library_path` (see `bind_pdfium`) before the first PDF
+/// call; without it the library is looked up by its platform name. Returns
+/// `false` without rebinding when pdfium was already initialized, and
+/// `FerrousError::Unsupported` when it can't be loaded.
+pub fn init_pdfium(library_path: Option<String>) -> Result<bool, FerrousError> {
+    let mut bound_now = false;
+    let pdfium = PDFIUM.get_or_init(|| {
+        bound_now = true;
+        bind_pdfium(library_path.as_deref())
+    });
+    if let Err(detail) = pdfium {
+        return Err(FerrousError::Unsupported(detail.clone()));
+    }
+    Ok(bound_now)
 }
 
-const PDF_OPEN_ERROR_PREFIX: &str = "PDF_OPEN_ERROR";
+fn get_pdfium() -> Result<&'static Pdfium, FerrousError> {
+    PDFIUM
+        .get_or_init(|| bind_pdfium(None))
+        .as_ref()
+        .map_err(|detail| FerrousError::Unsupported(detail.clone()))
+}
+
+/// Whether the pdfium library could be loaded. When it can't, every PDF
+/// function fails with `FerrousError::Unsupported` instead of crashing, and
+/// the app can hide its PDF features.
+pub fn is_pdf_supported() -> bool {
+    get_pdfium().is_ok()
+}
 
-fn ensure_pdf_header(path: &str) -> Result<()> {
-    let metadata = std::fs::metadata(path)
-        .with_context(|| format!("{PDF_OPEN_ERROR_PREFIX}::FILE: Missing PDF file at {path}"))?;
+fn ensure_pdf_header(path: &str) -> Result<(), FerrousError> {
+    let metadata = std::fs::metadata(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => FerrousError::FileMissing(path.to_string()),
+        _ => FerrousError::Io(format!("Unable to access PDF at {path}: {e}")),
+    })?;
     if metadata.len() == 0 {
-        return Err(anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::EMPTY: PDF file is empty at {path}"
-        ));
+        return Err(FerrousError::Empty(path.to_string()));
     }
 
     let mut file = File::open(path)
-        .with_context(|| format!("{PDF_OPEN_ERROR_PREFIX}::FILE: Unable to open PDF at {path}"))?;
+        .map_err(|e| FerrousError::Io(format!("Unable to open PDF at {path}: {e}")))?;
     let mut buf = [0u8; 1024];
     let read = file
         .read(&mut buf)
-        .with_context(|| format!("{PDF_OPEN_ERROR_PREFIX}::FILE: Unable to read PDF at {path}"))?;
+        .map_err(|e| FerrousError::Io(format!("Unable to read PDF at {path}: {e}")))?;
     if read == 0 {
-        return Err(anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::EMPTY: PDF file is empty at {path}"
-        ));
+        return Err(FerrousError::Empty(path.to_string()));
     }
 
     let header_found = buf[..read].windows(5).any(|window| window == b"%PDF-");
     if !header_found {
-        return Err(anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::HEADER: File does not look like a valid PDF at {path}"
-        ));
+        return Err(FerrousError::BadHeader(path.to_string()));
     }
 
     Ok(())
 }
 
-fn map_pdfium_load_error(path: &str, error: PdfiumError) -> anyhow::Error {
+fn map_pdfium_load_error(path: &str, error: PdfiumError) -> FerrousError {
     match error {
-        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::FormatError) => anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::FORMAT: PDF format error at {path}. The file may be corrupted or not a PDF."
-        ),
-        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::PasswordError) => anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::PASSWORD: PDF is password-protected at {path}."
-        ),
-        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::FileError) => anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::FILE: Unable to read PDF file at {path}."
-        ),
-        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::SecurityError) => anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::SECURITY: PDF security settings prevent opening {path}."
-        ),
-        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::PageError) => anyhow!(
-            "{PDF_OPEN_ERROR_PREFIX}::PAGE: PDF page error while opening {path}."
-        ),
-        other => anyhow!("Failed to load PDF at {path}: {other:?}"),
-    }
-}
-
-pub(crate) fn load_pdf_document<'a>(pdfium: &'a Pdfium, path: &str) -> Result<PdfDocument<'a>> {
+        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::FormatError) => {
+            FerrousError::Corrupt(path.to_string())
+        }
+        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::PageError) => {
+            FerrousError::PageError(path.to_string())
+        }
+        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::PasswordError) => {
+            FerrousError::PasswordRequired(path.to_string())
+        }
+        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::FileError) => {
+            FerrousError::Io(format!("Unable to read PDF file at {path}."))
+        }
+        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::SecurityError) => {
+            FerrousError::Security(path.to_string())
+        }
+        other => FerrousError::Io(format!("Failed to load PDF at {path}: {other:?}")),
+    }
+}
+
+/// Page `page_index` of `document`, or `FerrousError::PageOutOfRange`.
+pub(crate) fn get_pdf_page<'a>(document: &'a PdfDocument, page_index: u32) -> Result<PdfPage<'a>, FerrousError> {
+    let pages = document.pages();
+    if page_index >= pages.len() as u32 {
+        return Err(FerrousError::PageOutOfRange);
+    }
+    pages
+        .get(page_index as u16)
+        .map_err(|e| FerrousError::PageLoad { page_index, detail: format!("{e:?}") })
+}
+
+// Passwords supplied through `unlock_pdf`, keyed by path
+static PDF_PASSWORDS: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+fn pdf_password(path: &str) -> Option<String> {
+    let passwords = PDF_PASSWORDS.get()?;
+    let passwords = match passwords.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    passwords.get(path).cloned()
+}
+
+pub(crate) fn load_pdf_document<'a>(pdfium: &'a Pdfium, path: &str) -> Result<PdfDocument<'a>, FerrousError> {
     ensure_pdf_header(path)?;
+    let password = pdf_password(path);
     pdfium
-        .load_pdf_from_file(path, None)
+        .load_pdf_from_file(path, password.as_deref())
         .map_err(|e| map_pdfium_load_error(path, e))
 }
 
+/// Open a password-protected PDF with `password` and remember it for `path`,
+/// so every other PDF function opens the file transparently afterwards. A
+/// wrong password fails with `FerrousError::PasswordRequired` and
+/// is not stored.
+pub fn unlock_pdf(path: String, password: String) -> Result<(), FerrousError> {
+    ensure_pdf_header(&path)?;
+    let document = get_pdfium()?
+        .load_pdf_from_file(&path, Some(&password))
+        .map_err(|e| map_pdfium_load_error(&path, e))?;
+
+    let passwords = PDF_PASSWORDS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut passwords = match passwords.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    passwords.insert(path.clone(), password);
+    drop(passwords);
+    lock_pool().put(path, Arc::new(document));
+    Ok(())
+}
+
+/// Forget the password stored for `path` and drop its pooled document.
+pub fn lock_pdf(path: String) {
+    if let Some(passwords) = PDF_PASSWORDS.get() {
+        let mut passwords = match passwords.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        passwords.remove(&path);
+    }
+    lock_pool().pop(&path);
+}
+
 // Global LRU cache for PDF documents (R3)
 static DOCUMENT_POOL: OnceLock<Mutex<LruCache<String, Arc<PdfDocument<'static>>>>> = OnceLock::new();
 
@@ -101,10 +198,12 @@ fn get_pool() -> &'static Mutex<LruCache<String, Arc<PdfDocument<'static>>>> {
     })
 }
 
-/// Execute a function with a pooled PDF document
-pub fn with_document<F, R>(path: &str, f: F) -> Result<R>
+/// Execute a function with a pooled PDF document. `f` may fail with any
+/// error a `FerrousError` converts into, so `anyhow` code shares the pool.
+pub fn with_document<F, R, E>(path: &str, f: F) -> Result<R, E>
 where
-    F: FnOnce(&PdfDocument) -> Result<R>,
+    F: FnOnce(&PdfDocument) -> Result<R, E>,
+    E: From<FerrousError>,
 {
     let pool = get_pool();
     
@@ -124,7 +223,7 @@ where
     let doc = match doc {
         Some(doc) => doc,
         None => {
-            let loaded_doc = Arc::new(load_pdf_document(get_pdfium(), path)?);
+            let loaded_doc = Arc::new(load_pdf_document(get_pdfium()?, path)?);
             // Lock again to insert into cache (holding lock briefly)
             let mut cache = match pool.lock() {
                 Ok(guard) => guard,
@@ -139,6 +238,25 @@ where
     f(&doc)
 }
 
+fn lock_pool() -> std::sync::MutexGuard<'static, LruCache<String, Arc<PdfDocument<'static>>>> {
+    match get_pool().lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Drop the pooled document for `path` so the next call reloads it from
+/// disk. Call after the file was replaced or deleted. Returns whether a
+/// document was cached.
+pub fn evict_pdf_document(path: String) -> Result<bool, FerrousError> {
+    Ok(lock_pool().pop(&path).is_some())
+}
+
+/// Drop every pooled PDF document.
+pub fn clear_pdf_cache() {
+    lock_pool().clear();
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PdfTextRect {
     pub left: f32,
@@ -155,274 +273,1940 @@ pub struct PdfPageRenderResult {
 }
 
 /// Execute a function with the global Pdfium instance
-pub fn with_pdfium<F, R>(f: F) -> Result<R>
+pub fn with_pdfium<F, R, E>(f: F) -> Result<R, E>
 where
-    F: FnOnce(&Pdfium) -> Result<R>,
+    F: FnOnce(&Pdfium) -> Result<R, E>,
+    E: From<FerrousError>,
 {
-    let pdfium = get_pdfium();
+    let pdfium = get_pdfium()?;
     f(pdfium)
 }
 
 /// Get the page count of a PDF file
-pub fn get_pdf_page_count(path: String) -> Result<u32> {
+pub fn get_pdf_page_count(path: String) -> Result<u32, FerrousError> {
     with_document(&path, |document| {
         Ok(document.pages().len() as u32)
     })
 }
 
-/// Render a specific page of a PDF to PNG bytes with actual dimensions.
-#[hotpath::measure]
-pub fn render_pdf_page(path: String, page_index: u32, width: u32, height: u32) -> Result<PdfPageRenderResult> {
-    timed!("render_pdf_page", {
-        with_document(&path, |document| {
-            let page = document.pages().get(page_index as u16)?;
-            
-            let bitmap = page
-                .render_with_config(&PdfRenderConfig::new()
-                    .set_target_width(width as i32)
-                    .set_maximum_height(height as i32)
-                    .use_lcd_text_rendering(true)
-                    .use_print_quality(true)
-                    .set_text_smoothing(true)
-                    .set_image_smoothing(true)
-                    .set_path_smoothing(true)
-                    .render_form_data(true))?;
-            
+/// Encoding of a rendered page. `RawRgba` skips encoding entirely and
+/// returns `width * height * 4` bytes, like the CBZ page loaders.
+#[derive(Debug, Clone, Copy)]
+pub enum PdfImageFormat {
+    Png,
+    Jpeg { quality: u8 },
+    RawRgba,
+}
 
-            let dynamic_image = bitmap.as_image();
-            let actual_width = dynamic_image.width();
-            let actual_height = dynamic_image.height();
-            
-            // Convert RGBA to RGB for JPEG compatibility (as JPEG doesn't support alpha channel)
-            let rgb_image = dynamic_image.into_rgb8();
-            let mut jpeg_bytes = Vec::new();
-            rgb_image.write_to(
-                &mut std::io::Cursor::new(&mut jpeg_bytes),
-                image::ImageFormat::Jpeg,
-            )?;
-            
-            Ok(PdfPageRenderResult {
-                data: jpeg_bytes,
-                width: actual_width,
-                height: actual_height,
-            })
-        })
-    })
+/// Quality `image` uses for JPEG when none is given.
+const DEFAULT_JPEG_QUALITY: u8 = 75;
+
+/// Page size in PDF points (1/72 inch), as displayed.
+#[derive(Debug, Clone, Copy)]
+pub struct PageSize {
+    pub width_pts: f32,
+    pub height_pts: f32,
+    /// The page's `/Rotate` (0, 90, 180 or 270, clockwise), already applied
+    /// to the width and height above.
+    pub rotation_degrees: u16,
 }
 
-/// Extract the text of a specific page of a PDF file.
+/// Sizes of every page, so layouts can be reserved before rendering.
 #[hotpath::measure]
-pub fn extract_pdf_page_text(path: String, page_index: u32) -> Result<String> {
-    timed!("extract_pdf_page_text", {
+pub fn get_pdf_page_sizes(path: String) -> Result<Vec<PageSize>, FerrousError> {
+    timed!("get_pdf_page_sizes", {
         with_document(&path, |document| {
-            let page = document.pages().get(page_index as u16)?;
-            let text = page.text()?;
-            Ok(text.all())
+            Ok(document
+                .pages()
+                .iter()
+                // Unlike page_size(), these follow /Rotate, matching the render
+                .map(|page| PageSize {
+                    width_pts: page.width().value,
+                    height_pts: page.height().value,
+                    rotation_degrees: match page.rotation() {
+                        Ok(PdfPageRenderRotation::Degrees90) => 90,
+                        Ok(PdfPageRenderRotation::Degrees180) => 180,
+                        Ok(PdfPageRenderRotation::Degrees270) => 270,
+                        _ => 0,
+                    },
+                })
+                .collect())
         })
     })
 }
 
-/// Extract page text starting near a normalized point on the rendered page.
-pub fn extract_pdf_page_text_from_point(
+/// Render a specific page of a PDF to JPEG bytes with actual dimensions.
+#[hotpath::measure]
+pub fn render_pdf_page(path: String, page_index: u32, width: u32, height: u32) -> Result<PdfPageRenderResult, FerrousError> {
+    render_pdf_page_fmt(
+        path,
+        page_index,
+        width,
+        height,
+        PdfImageFormat::Jpeg { quality: DEFAULT_JPEG_QUALITY },
+        None,
+        None,
+    )
+}
+
+/// Render a specific page of a PDF in the given output format.
+/// `rotation_override` turns the result clockwise by that many degrees
+/// (rounded to a quarter turn) on top of the page's own `/Rotate`; the
+/// rotated image still fits `width` x `height`. A cancelled `operation_id`
+/// stops it before rendering.
+#[hotpath::measure]
+pub fn render_pdf_page_fmt(
     path: String,
     page_index: u32,
-    x_norm: f64,
-    y_norm: f64,
-) -> Result<String> {
-    timed!("extract_pdf_page_text_from_point", {
-        with_document(&path, |document| {
-            let page = document.pages().get(page_index as u16)?;
+    width: u32,
+    height: u32,
+    format: PdfImageFormat,
+    rotation_override: Option<i32>,
+    operation_id: Option<u64>,
+) -> Result<PdfPageRenderResult, FerrousError> {
+    timed!("render_pdf_page", {
+        let operation = OperationHandle::new(operation_id);
+        check_cancelled(operation.flag())?;
+        let quarter_turns = rotation_override
+            .map(|degrees| ((degrees as f32 / 90.0).round() as i32).rem_euclid(4))
+            .unwrap_or(0);
+        let image = if quarter_turns % 2 == 1 {
+            render_pdf_page_image(&path, page_index, height, width)?
+        } else {
+            render_pdf_page_image(&path, page_index, width, height)?
+        };
+        let image = match quarter_turns {
+            1 => image.rotate90(),
+            2 => image.rotate180(),
+            3 => image.rotate270(),
+            _ => image,
+        };
+        let (width, height) = (image.width(), image.height());
+        let data = encode_page_image(image, format)?;
+        Ok(PdfPageRenderResult { data, width, height })
+    })
+}
 
-            let page_rect = page.page_size();
-            let width = page_rect.width().value as f64;
-            let height = page_rect.height().value as f64;
+fn encode_page_image(image: image::DynamicImage, format: PdfImageFormat) -> Result<Vec<u8>, FerrousError> {
+    let data = match format {
+        PdfImageFormat::Png => {
+            let mut bytes = Vec::new();
+            image.write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)?;
+            bytes
+        }
+        PdfImageFormat::Jpeg { quality } => {
+            let mut bytes = Vec::new();
+            image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality.clamp(1, 100))
+                .encode_image(&image.into_rgb8())?;
+            bytes
+        }
+        PdfImageFormat::RawRgba => image.into_rgba8().into_raw(),
+    };
+    Ok(data)
+}
 
-            let x_norm = x_norm.clamp(0.0, 1.0);
-            let y_norm = y_norm.clamp(0.0, 1.0);
+/// Alpha-blend `color` (0xAARRGGBB) over each rect, given in normalized
+/// top-left coordinates like the text bounds.
+fn blend_highlight_rects(image: &mut image::RgbaImage, rects: &[PdfTextRect], color: u32) {
+    let (width, height) = image.dimensions();
+    let alpha = ((color >> 24) & 0xFF) as f32 / 255.0;
+    let rgb = [((color >> 16) & 0xFF) as f32, ((color >> 8) & 0xFF) as f32, (color & 0xFF) as f32];
+    let to_pixels = |value: f32, size: u32| ((value.clamp(0.0, 1.0) * size as f32).round() as u32).min(size);
+    for rect in rects {
+        let (left, right) = (to_pixels(rect.left.min(rect.right), width), to_pixels(rect.left.max(rect.right), width));
+        let (top, bottom) = (to_pixels(rect.top.min(rect.bottom), height), to_pixels(rect.top.max(rect.bottom), height));
+        for y in top..bottom {
+            for x in left..right {
+                let pixel = image.get_pixel_mut(x, y);
+                for (channel, &value) in pixel.0.iter_mut().zip(&rgb) {
+                    *channel = (*channel as f32 * (1.0 - alpha) + value * alpha).round() as u8;
+                }
+            }
+        }
+    }
+}
 
-            // Convert from top-left normalized coordinates to Pdfium user space coordinates
-            let x_points = (page_rect.left().value as f64 + (width * x_norm)) as f32;
-            let y_points = (page_rect.top().value as f64 - (height * y_norm)) as f32;
+/// `render_pdf_page` with translucent highlight rectangles baked into the
+/// JPEG, e.g. search hits on a result thumbnail. `rects` are normalized
+/// top-left coordinates (as from the text bounds APIs) and `color` is
+/// 0xAARRGGBB, its alpha setting how strongly the page shows through.
+#[hotpath::measure]
+pub fn render_pdf_page_with_highlights(
+    path: String,
+    page_index: u32,
+    width: u32,
+    height: u32,
+    rects: Vec<PdfTextRect>,
+    color: u32,
+) -> Result<PdfPageRenderResult, FerrousError> {
+    timed!("render_pdf_page_with_highlights", {
+        let mut image = render_pdf_page_image(&path, page_index, width, height)?.into_rgba8();
+        blend_highlight_rects(&mut image, &rects, color);
+        let (width, height) = image.dimensions();
+        let data = encode_page_image(
+            image::DynamicImage::ImageRgba8(image),
+            PdfImageFormat::Jpeg { quality: DEFAULT_JPEG_QUALITY },
+        )?;
+        Ok(PdfPageRenderResult { data, width, height })
+    })
+}
 
-            let text = page.text()?;
-            let chars = text.chars();
+/// Rasterize a page to fit `width`, capped at `height`.
+pub(crate) fn render_pdf_page_image(
+    path: &str,
+    page_index: u32,
+    width: u32,
+    height: u32,
+) -> Result<image::DynamicImage, FerrousError> {
+    with_document(path, |document| {
+        let page = get_pdf_page(document, page_index)?;
+        
+        let bitmap = page
+            .render_with_config(&PdfRenderConfig::new()
+                .set_target_width(width as i32)
+                .set_maximum_height(height as i32)
+                .use_lcd_text_rendering(true)
+                .use_print_quality(true)
+                .set_text_smoothing(true)
+                .set_image_smoothing(true)
+                .set_path_smoothing(true)
+                .render_form_data(true))?;
 
-            // Try a few tolerance levels
-            let mut tolerance = PdfPoints::new(6.0);
-            let mut picked = None;
+        Ok(bitmap.as_image())
+    })
+}
 
-            for _ in 0..4 {
-                picked = chars.get_char_near_point(
-                    PdfPoints::new(x_points),
-                    tolerance,
-                    PdfPoints::new(y_points),
-                    tolerance,
-                );
-                if picked.is_some() {
-                    break;
-                }
-                tolerance = tolerance * 2.0;
-            }
+/// JPEG thumbnails of every page (or of `page_range`, start inclusive, end
+/// exclusive) at `max_width` pixels wide, rendered from one open document.
+/// Cancelling `operation_id` stops it between pages.
+#[hotpath::measure]
+pub fn render_pdf_thumbnails(
+    path: String,
+    max_width: u32,
+    page_range: Option<(u32, u32)>,
+    operation_id: Option<u64>,
+) -> Result<Vec<PdfPageRenderResult>, FerrousError> {
+    timed!("render_pdf_thumbnails", {
+        let operation = OperationHandle::new(operation_id);
+        with_document(&path, |document| {
+            let pages = document.pages();
+            let page_count = pages.len() as u32;
+            let (start, end) = page_range.unwrap_or((0, page_count));
+            let end = end.min(page_count);
 
-            let Some(picked_char) = picked else {
-                return Ok(String::new());
-            };
+            let config = PdfRenderConfig::new()
+                .set_target_width(max_width.max(1) as i32)
+                .set_text_smoothing(true)
+                .set_image_smoothing(true)
+                .set_path_smoothing(true);
 
-            let total = text.len().max(0) as usize;
-            if total == 0 {
-                return Ok(String::new());
+            let mut thumbnails = Vec::with_capacity(end.saturating_sub(start) as usize);
+            for page_index in start..end {
+                check_cancelled(operation.flag())?;
+                let page = get_pdf_page(document, page_index)?;
+                let bitmap = page.render_with_config(&config)?;
+                thumbnails.push(encode_page_jpeg(bitmap.as_image())?);
             }
+            Ok(thumbnails)
+        })
+    })
+}
 
-            // Snap back to a word boundary
-            let mut start_index = picked_char.index().min(total.saturating_sub(1));
-            for _ in 0..32 {
-                if start_index == 0 {
-                    break;
-                }
+fn encode_page_jpeg(dynamic_image: image::DynamicImage) -> Result<PdfPageRenderResult, FerrousError> {
+    let actual_width = dynamic_image.width();
+    let actual_height = dynamic_image.height();
 
-                let prev = chars.get(start_index - 1);
-                let Ok(prev_char) = prev else { break };
+    // Convert RGBA to RGB for JPEG compatibility (as JPEG doesn't support alpha channel)
+    let rgb_image = dynamic_image.into_rgb8();
+    let mut jpeg_bytes = Vec::new();
+    rgb_image.write_to(
+        &mut std::io::Cursor::new(&mut jpeg_bytes),
+        image::ImageFormat::Jpeg,
+    )?;
 
-                let Some(c) = prev_char.unicode_char() else { break };
-                if c.is_whitespace() {
-                    break;
-                }
+    Ok(PdfPageRenderResult {
+        data: jpeg_bytes,
+        width: actual_width,
+        height: actual_height,
+    })
+}
 
-                start_index -= 1;
-            }
+/// An optional content group ("layer") and whether a render should show it.
+#[derive(Debug, Clone)]
+pub struct PdfLayer {
+    pub name: String,
+    pub visible: bool,
+}
 
-            let mut out = String::new();
-            for i in start_index..total {
-                let Ok(ch) = chars.get(i) else { continue };
-                if let Some(c) = ch.unicode_char() {
-                    out.push(c);
-                }
-            }
+// pdfium render flags (fpdfview.h)
+const FPDF_ANNOT: i32 = 0x01;
+const FPDF_LCD_TEXT: i32 = 0x02;
+const FPDF_PRINTING: i32 = 0x800;
 
-            Ok(out)
-        })
-    })
+/// Read a UTF-16LE string through pdfium's two-call buffer convention:
+/// `read(buffer, buffer_len_bytes, out_len_bytes)`.
+fn read_wide_string(
+    read: impl Fn(*mut FPDF_WCHAR, c_ulong, *mut c_ulong) -> FPDF_BOOL,
+) -> Option<String> {
+    let mut byte_len: c_ulong = 0;
+    if read(std::ptr::null_mut(), 0, &mut byte_len) == 0 || byte_len < 2 {
+        return None;
+    }
+    let mut buffer: Vec<FPDF_WCHAR> = vec![0; byte_len as usize / 2];
+    if read(buffer.as_mut_ptr(), byte_len, &mut byte_len) == 0 {
+        return None;
+    }
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    Some(String::from_utf16_lossy(&buffer[..end]))
 }
 
-/// Extract normalized character bounding boxes for a text range on the page.
-pub fn extract_pdf_page_text_bounds(
-    path: String,
-    page_index: u32,
-    start_index: u32,
-    end_index: u32,
-) -> Result<Vec<PdfTextRect>> {
-    timed!("extract_pdf_page_text_bounds", {
-        with_document(&path, |document| {
-            let page = document.pages().get(page_index as u16)?;
-            let text = page.text()?;
-            let chars = text.chars();
+/// The `/OC` content mark of a page object and the name of its layer.
+/// pdfium resolves the mark's properties to the group dictionary, so the
+/// layer name is its `/Name` entry.
+fn optional_content_mark(
+    bindings: &dyn PdfiumLibraryBindings,
+    object: FPDF_PAGEOBJECT,
+) -> Option<(FPDF_PAGEOBJECTMARK, String)> {
+    for index in 0..bindings.FPDFPageObj_CountMarks(object).max(0) {
+        let mark = bindings.FPDFPageObj_GetMark(object, index as c_ulong);
+        if mark.is_null() {
+            continue;
+        }
+        let name = read_wide_string(|buffer, len, out| {
+            bindings.FPDFPageObjMark_GetName(mark, buffer as _, len, out)
+        });
+        if name.as_deref() != Some("OC") {
+            continue;
+        }
+        let layer = read_wide_string(|buffer, len, out| {
+            bindings.FPDFPageObjMark_GetParamStringValue(mark, "Name", buffer as _, len, out)
+        });
+        return Some((mark, layer.unwrap_or_default()));
+    }
+    None
+}
 
-            let total = text.len().max(0) as usize;
-            if total == 0 {
-                return Ok(Vec::new());
-            }
+// pdfium error codes (fpdfview.h)
+const FPDF_ERR_FILE: c_ulong = 2;
+const FPDF_ERR_FORMAT: c_ulong = 3;
+const FPDF_ERR_PASSWORD: c_ulong = 4;
+const FPDF_ERR_SECURITY: c_ulong = 5;
+const FPDF_ERR_PAGE: c_ulong = 6;
 
-            let start = start_index as usize;
-            let end = end_index as usize;
-            if start >= end || start >= total {
-                return Ok(Vec::new());
-            }
+/// Why the last raw `FPDF_LoadDocument` failed, as the error pdfium-render
+/// reports for its own loaders.
+fn last_load_error(bindings: &dyn PdfiumLibraryBindings) -> PdfiumError {
+    let internal = match bindings.FPDF_GetLastError() {
+        FPDF_ERR_FILE => PdfiumInternalError::FileError,
+        FPDF_ERR_FORMAT => PdfiumInternalError::FormatError,
+        FPDF_ERR_PASSWORD => PdfiumInternalError::PasswordError,
+        FPDF_ERR_SECURITY => PdfiumInternalError::SecurityError,
+        FPDF_ERR_PAGE => PdfiumInternalError::PageError,
+        _ => PdfiumInternalError::Unknown,
+    };
+    PdfiumError::PdfiumLibraryInternalError(internal)
+}
 
-            let end = end.min(total);
-            let page_rect = page.page_size();
-            let page_left = page_rect.left().value;
-            let page_bottom = page_rect.bottom().value;
-            let width = page_rect.width().value;
-            let height = page_rect.height().value;
+/// Open `path` as a raw pdfium document outside the pool, so page edits made
+/// for a layer override never leak into other renders.
+fn with_raw_document<F, R>(path: &str, f: F) -> Result<R, FerrousError>
+where
+    F: FnOnce(&dyn PdfiumLibraryBindings, FPDF_DOCUMENT) -> Result<R, FerrousError>,
+{
+    ensure_pdf_header(path)?;
+    let bindings = get_pdfium()?.bindings();
+    let password = pdf_password(path);
+    let document = bindings.FPDF_LoadDocument(path, password.as_deref());
+    if document.is_null() {
+        return Err(map_pdfium_load_error(path, last_load_error(bindings)));
+    }
+    let result = f(bindings, document);
+    bindings.FPDF_CloseDocument(document);
+    result
+}
 
-            if width <= 0.0 || height <= 0.0 {
-                return Ok(Vec::new());
-            }
+/// A page or a form XObject, whose objects the layer code walks alike.
+#[derive(Clone, Copy)]
+enum ObjectContainer {
+    Page(FPDF_PAGE),
+    Form(FPDF_PAGEOBJECT),
+}
 
-            let mut rects = Vec::new();
-            for i in start..end {
-                let ch = match chars.get(i) {
-                    Ok(ch) => ch,
-                    Err(_) => continue,
-                };
+impl ObjectContainer {
+    fn objects(self, bindings: &dyn PdfiumLibraryBindings) -> Vec<FPDF_PAGEOBJECT> {
+        let objects: Vec<FPDF_PAGEOBJECT> = match self {
+            ObjectContainer::Page(page) => (0..bindings.FPDFPage_CountObjects(page))
+                .map(|index| bindings.FPDFPage_GetObject(page, index))
+                .collect(),
+            ObjectContainer::Form(form) => (0..bindings.FPDFFormObj_CountObjects(form).max(0))
+                .map(|index| bindings.FPDFFormObj_GetObject(form, index as c_ulong))
+                .collect(),
+        };
+        objects.into_iter().filter(|object| !object.is_null()).collect()
+    }
 
-                let Some(c) = ch.unicode_char() else {
-                    continue;
-                };
+    /// Take `object` out of the container; the caller then owns it.
+    fn remove(self, bindings: &dyn PdfiumLibraryBindings, object: FPDF_PAGEOBJECT) -> bool {
+        match self {
+            ObjectContainer::Page(page) => bindings.FPDFPage_RemoveObject(page, object) != 0,
+            ObjectContainer::Form(form) => bindings.FPDFFormObj_RemoveObject(form, object) != 0,
+        }
+    }
+}
 
-                if c.is_whitespace() {
-                    continue;
-                }
+fn is_form_object(bindings: &dyn PdfiumLibraryBindings, object: FPDF_PAGEOBJECT) -> bool {
+    bindings.FPDFPageObj_GetType(object) as u32 == FPDF_PAGEOBJ_FORM
+}
 
-                let bounds = ch.loose_bounds().or_else(|_| ch.tight_bounds());
-                let Ok(bounds) = bounds else { continue };
-                let mut left = (bounds.left().value - page_left) / width;
-                let mut right = (bounds.right().value - page_left) / width;
-                let mut top = 1.0 - ((bounds.top().value - page_bottom) / height);
-                let mut bottom = 1.0 - ((bounds.bottom().value - page_bottom) / height);
+/// Add the layer names used in `container`, form XObjects included, to
+/// `names` in first-use order.
+fn collect_layer_names(bindings: &dyn PdfiumLibraryBindings, container: ObjectContainer, names: &mut Vec<String>) {
+    for object in container.objects(bindings) {
+        if let Some((_, name)) = optional_content_mark(bindings, object) {
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        if is_form_object(bindings, object) {
+            collect_layer_names(bindings, ObjectContainer::Form(object), names);
+        }
+    }
+}
 
-                if left > right {
-                    std::mem::swap(&mut left, &mut right);
+/// Apply `layers` to `container` and the form XObjects in it. Hidden layers
+/// lose their objects; shown layers lose the /OC mark so pdfium's
+/// default-state check no longer applies to them.
+fn apply_layer_overrides(bindings: &dyn PdfiumLibraryBindings, container: ObjectContainer, layers: &[PdfLayer]) {
+    for object in container.objects(bindings) {
+        if let Some((mark, name)) = optional_content_mark(bindings, object) {
+            match layers.iter().find(|layer| layer.name == name) {
+                Some(layer) if layer.visible => {
+                    bindings.FPDFPageObj_RemoveMark(object, mark);
                 }
-                if top > bottom {
-                    std::mem::swap(&mut top, &mut bottom);
+                Some(_) => {
+                    if container.remove(bindings, object) {
+                        bindings.FPDFPageObj_Destroy(object);
+                    }
+                    continue;
                 }
-
-                rects.push(PdfTextRect {
-                    left: left.clamp(0.0, 1.0),
-                    top: top.clamp(0.0, 1.0),
-                    right: right.clamp(0.0, 1.0),
-                    bottom: bottom.clamp(0.0, 1.0),
-                });
+                None => {}
             }
+        }
+        if is_form_object(bindings, object) {
+            apply_layer_overrides(bindings, ObjectContainer::Form(object), layers);
+        }
+    }
+}
 
-            Ok(rects)
-        })
-    })
+/// The parts of a PDF object the optional content configuration needs.
+#[derive(Debug, Clone, PartialEq)]
+enum RawPdfObject {
+    Integer(i64),
+    Name(String),
+    String(Vec<u8>),
+    Reference(u32),
+    Array(Vec<RawPdfObject>),
+    Dictionary(Vec<(String, RawPdfObject)>),
+    Other,
 }
 
-/// Pre-compute ALL character bounds for a page.
-#[hotpath::measure]
-pub fn extract_all_page_character_bounds(
-    path: String,
-    page_index: u32,
-) -> Result<Vec<PdfTextRect>> {
-    timed!("extract_all_page_character_bounds", {
-        with_document(&path, |document| {
-            let page = document.pages().get(page_index as u16)?;
-            let text = page.text()?;
-            let chars = text.chars();
+impl RawPdfObject {
+    fn get(&self, key: &str) -> Option<&RawPdfObject> {
+        match self {
+            RawPdfObject::Dictionary(entries) => entries.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
 
-            let total = text.len().max(0) as usize;
-            if total == 0 {
-                return Ok(Vec::new());
-            }
+/// Nesting deeper than this is treated as a malformed object.
+const MAX_RAW_OBJECT_DEPTH: usize = 32;
 
-            let page_rect = page.page_size();
-            let page_left = page_rect.left().value;
-            let page_bottom = page_rect.bottom().value;
-            let width = page_rect.width().value;
-            let height = page_rect.height().value;
+fn is_pdf_delimiter(b: u8) -> bool {
+    matches!(b, b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%')
+}
 
-            if width <= 0.0 || height <= 0.0 {
-                return Ok(Vec::new());
-            }
+fn is_pdf_regular(b: u8) -> bool {
+    !b.is_ascii_whitespace() && b != 0 && !is_pdf_delimiter(b)
+}
 
-            let mut rects = Vec::with_capacity(total);
-            
-            for i in 0..total {
-                let ch = match chars.get(i) {
-                    Ok(ch) => ch,
-                    Err(_) => {
-                        rects.push(PdfTextRect { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 });
-                        continue;
-                    }
-                };
+/// Reads one PDF object (without stream data) at a time from raw bytes.
+struct RawPdfParser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
 
-                let Some(c) = ch.unicode_char() else {
-                    rects.push(PdfTextRect { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 });
-                    continue;
-                };
+impl<'a> RawPdfParser<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        Self { data, pos }
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        self.data.get(self.pos..).unwrap_or(&[])
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&b) = self.data.get(self.pos) {
+            if b == b'%' {
+                while self.data.get(self.pos).is_some_and(|&b| b != b'\n' && b != b'\r') {
+                    self.pos += 1;
+                }
+            } else if b.is_ascii_whitespace() || b == 0 {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn regular_token(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while self.data.get(self.pos).is_some_and(|&b| is_pdf_regular(b)) {
+            self.pos += 1;
+        }
+        &self.data[start..self.pos]
+    }
+
+    fn parse(&mut self, depth: usize) -> Option<RawPdfObject> {
+        self.skip_whitespace();
+        if depth > MAX_RAW_OBJECT_DEPTH {
+            return None;
+        }
+        let rest = self.rest();
+        match *rest.first()? {
+            b'<' if rest.get(1) == Some(&b'<') => {
+                self.pos += 2;
+                let mut entries = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.rest().starts_with(b">>") {
+                        self.pos += 2;
+                        return Some(RawPdfObject::Dictionary(entries));
+                    }
+                    let RawPdfObject::Name(key) = self.parse(depth + 1)? else { return None };
+                    let value = self.parse(depth + 1)?;
+                    entries.push((key, value));
+                }
+            }
+            b'<' => {
+                self.pos += 1;
+                let end = self.rest().iter().position(|&b| b == b'>')?;
+                let mut digits: Vec<u8> = self.rest()[..end]
+                    .iter()
+                    .filter_map(|&b| (b as char).to_digit(16).map(|d| d as u8))
+                    .collect();
+                self.pos += end + 1;
+                if digits.len() % 2 == 1 {
+                    digits.push(0);
+                }
+                Some(RawPdfObject::String(digits.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect()))
+            }
+            b'(' => {
+                self.pos += 1;
+                self.literal_string().map(RawPdfObject::String)
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.rest().first() == Some(&b']') {
+                        self.pos += 1;
+                        return Some(RawPdfObject::Array(items));
+                    }
+                    items.push(self.parse(depth + 1)?);
+                }
+            }
+            b'/' => {
+                self.pos += 1;
+                let token = self.regular_token();
+                let mut name = Vec::with_capacity(token.len());
+                let mut i = 0;
+                while i < token.len() {
+                    let escaped = (token[i] == b'#')
+                        .then(|| token.get(i + 1..i + 3))
+                        .flatten()
+                        .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+                    match escaped {
+                        Some(b) => {
+                            name.push(b);
+                            i += 3;
+                        }
+                        None => {
+                            name.push(token[i]);
+                            i += 1;
+                        }
+                    }
+                }
+                Some(RawPdfObject::Name(String::from_utf8_lossy(&name).into_owned()))
+            }
+            b if is_pdf_regular(b) => {
+                let token = self.regular_token();
+                let Some(number) = std::str::from_utf8(token).ok().and_then(|t| t.parse::<i64>().ok()) else {
+                    return Some(RawPdfObject::Other);
+                };
+                // `N G R` is a reference; anything else leaves the number alone
+                let after_number = self.pos;
+                self.skip_whitespace();
+                let generation = self.regular_token();
+                self.skip_whitespace();
+                let is_reference = !generation.is_empty()
+                    && generation.iter().all(u8::is_ascii_digit)
+                    && self.rest().first() == Some(&b'R')
+                    && !self.rest().get(1).is_some_and(|&b| is_pdf_regular(b));
+                if is_reference && (0..=u32::MAX as i64).contains(&number) {
+                    self.pos += 1;
+                    return Some(RawPdfObject::Reference(number as u32));
+                }
+                self.pos = after_number;
+                Some(RawPdfObject::Integer(number))
+            }
+            _ => None,
+        }
+    }
+
+    /// Body of a `(...)` string after the opening parenthesis.
+    fn literal_string(&mut self) -> Option<Vec<u8>> {
+        let mut bytes = Vec::new();
+        let mut open = 1;
+        loop {
+            let b = *self.data.get(self.pos)?;
+            self.pos += 1;
+            match b {
+                b'(' => open += 1,
+                b')' => {
+                    open -= 1;
+                    if open == 0 {
+                        return Some(bytes);
+                    }
+                }
+                b'\\' => {
+                    let escaped = *self.data.get(self.pos)?;
+                    self.pos += 1;
+                    match escaped {
+                        b'n' => bytes.push(b'\n'),
+                        b'r' => bytes.push(b'\r'),
+                        b't' => bytes.push(b'\t'),
+                        b'b' => bytes.push(0x08),
+                        b'f' => bytes.push(0x0C),
+                        b'0'..=b'7' => {
+                            let mut value = (escaped - b'0') as u32;
+                            for _ in 0..2 {
+                                match self.data.get(self.pos) {
+                                    Some(&digit @ b'0'..=b'7') => {
+                                        value = value * 8 + (digit - b'0') as u32;
+                                        self.pos += 1;
+                                    }
+                                    _ => break,
+                                }
+                            }
+                            bytes.push(value as u8);
+                        }
+                        // A backslash before a line break continues the line
+                        b'\r' => {
+                            if self.data.get(self.pos) == Some(&b'\n') {
+                                self.pos += 1;
+                            }
+                        }
+                        b'\n' => {}
+                        other => bytes.push(other),
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            bytes.push(b);
+        }
+    }
+}
+
+/// Text of a PDF string: UTF-16BE with a byte order mark, UTF-8 with one,
+/// otherwise PDFDocEncoding, read as Latin-1 like pdfium does for names.
+fn decode_pdf_text(bytes: &[u8]) -> String {
+    match bytes {
+        [0xFE, 0xFF, rest @ ..] => {
+            let units: Vec<u16> = rest.chunks_exact(2).map(|pair| u16::from_be_bytes([pair[0], pair[1]])).collect();
+            String::from_utf16_lossy(&units)
+        }
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+        _ => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+/// Decompressed object streams are capped at this size.
+const MAX_OBJECT_STREAM_BYTES: u64 = 64 * 1024 * 1024;
+
+static OBJECT_HEADER_REGEX: OnceLock<regex::bytes::Regex> = OnceLock::new();
+
+fn get_object_header_regex() -> &'static regex::bytes::Regex {
+    OBJECT_HEADER_REGEX.get_or_init(|| regex::bytes::Regex::new(r"(\d+)\s+\d+\s+obj\b").unwrap())
+}
+
+static ROOT_REFERENCE_REGEX: OnceLock<regex::bytes::Regex> = OnceLock::new();
+
+fn get_root_reference_regex() -> &'static regex::bytes::Regex {
+    ROOT_REFERENCE_REGEX.get_or_init(|| regex::bytes::Regex::new(r"/Root\s+(\d+)\s+\d+\s+R").unwrap())
+}
+
+/// A PDF file read just far enough to look up objects by number, for the
+/// catalog entries pdfium doesn't expose.
+struct RawPdfDocument {
+    data: Vec<u8>,
+    /// Offset just past `N G obj`, by object number; later definitions
+    /// (incremental updates) replace earlier ones
+    offsets: HashMap<u32, usize>,
+    object_streams: Vec<Vec<u8>>,
+    /// Stream index and offset of objects stored in object streams
+    compressed: HashMap<u32, (usize, usize)>,
+}
+
+impl RawPdfDocument {
+    fn open(path: &str) -> Option<Self> {
+        let data = std::fs::read(path).ok()?;
+        let mut offsets = HashMap::new();
+        for captures in get_object_header_regex().captures_iter(&data) {
+            let Some(number) = std::str::from_utf8(&captures[1]).ok().and_then(|n| n.parse().ok()) else {
+                continue;
+            };
+            offsets.insert(number, captures.get(0)?.end());
+        }
+        let mut document = Self {
+            data,
+            offsets,
+            object_streams: Vec::new(),
+            compressed: HashMap::new(),
+        };
+        document.read_object_streams();
+        Some(document)
+    }
+
+    fn read_object_streams(&mut self) {
+        let mut object_streams = Vec::new();
+        let mut compressed = HashMap::new();
+        for &offset in self.offsets.values() {
+            let mut parser = RawPdfParser::new(&self.data, offset);
+            let Some(dictionary) = parser.parse(0) else { continue };
+            if dictionary.get("Type") != Some(&RawPdfObject::Name("ObjStm".to_string())) {
+                continue;
+            }
+            let Some(stream) = self.stream_data(&dictionary, parser.pos) else { continue };
+            let (Some(&RawPdfObject::Integer(count)), Some(&RawPdfObject::Integer(first))) =
+                (dictionary.get("N"), dictionary.get("First"))
+            else {
+                continue;
+            };
+
+            // The header is `count` pairs of object number and offset from `first`
+            let mut header = RawPdfParser::new(&stream, 0);
+            for _ in 0..count.max(0) {
+                let (Some(RawPdfObject::Integer(number)), Some(RawPdfObject::Integer(relative))) =
+                    (header.parse(0), header.parse(0))
+                else {
+                    break;
+                };
+                if let (Ok(number), Ok(position)) = (u32::try_from(number), usize::try_from(first + relative)) {
+                    compressed.entry(number).or_insert((object_streams.len(), position));
+                }
+            }
+            object_streams.push(stream);
+        }
+        self.object_streams = object_streams;
+        self.compressed = compressed;
+    }
+
+    /// Decoded data of the stream whose dictionary ends at `after_dictionary`.
+    /// Only unfiltered and Flate streams without predictors are read.
+    fn stream_data(&self, dictionary: &RawPdfObject, after_dictionary: usize) -> Option<Vec<u8>> {
+        let mut parser = RawPdfParser::new(&self.data, after_dictionary);
+        parser.skip_whitespace();
+        if !parser.rest().starts_with(b"stream") {
+            return None;
+        }
+        let mut start = parser.pos + b"stream".len();
+        if self.data.get(start) == Some(&b'\r') {
+            start += 1;
+        }
+        if self.data.get(start) == Some(&b'\n') {
+            start += 1;
+        }
+        let length = self.data[start..].windows(b"endstream".len()).position(|w| w == b"endstream")?;
+        let raw = &self.data[start..start + length];
+
+        let filter = match dictionary.get("Filter") {
+            Some(RawPdfObject::Array(filters)) if filters.len() == 1 => filters.first(),
+            Some(RawPdfObject::Array(filters)) if filters.is_empty() => None,
+            other => other,
+        };
+        if dictionary.get("DecodeParms").is_some() {
+            return None;
+        }
+        match filter {
+            None => Some(raw.to_vec()),
+            Some(RawPdfObject::Name(name)) if name == "FlateDecode" => {
+                let mut decoded = Vec::new();
+                flate2::read::ZlibDecoder::new(raw)
+                    .take(MAX_OBJECT_STREAM_BYTES)
+                    .read_to_end(&mut decoded)
+                    .ok()?;
+                Some(decoded)
+            }
+            Some(_) => None,
+        }
+    }
+
+    fn object(&self, number: u32) -> Option<RawPdfObject> {
+        if let Some(&offset) = self.offsets.get(&number) {
+            return RawPdfParser::new(&self.data, offset).parse(0);
+        }
+        let &(stream, offset) = self.compressed.get(&number)?;
+        RawPdfParser::new(self.object_streams.get(stream)?, offset).parse(0)
+    }
+
+    /// `object` with references followed.
+    fn resolve(&self, object: &RawPdfObject) -> Option<RawPdfObject> {
+        let mut object = object.clone();
+        for _ in 0..MAX_RAW_OBJECT_DEPTH {
+            match object {
+                RawPdfObject::Reference(number) => object = self.object(number)?,
+                resolved => return Some(resolved),
+            }
+        }
+        None
+    }
+
+    /// The document catalog, from the last `/Root` entry in the file (the
+    /// newest trailer or cross-reference stream).
+    fn catalog(&self) -> Option<RawPdfObject> {
+        let captures = get_root_reference_regex().captures_iter(&self.data).last()?;
+        let number = std::str::from_utf8(&captures[1]).ok()?.parse().ok()?;
+        self.object(number)
+    }
+
+    /// `/Name` of each optional content group in the array at `groups`.
+    fn group_names(&self, groups: Option<&RawPdfObject>) -> Vec<String> {
+        let Some(RawPdfObject::Array(groups)) = groups.and_then(|groups| self.resolve(groups)) else {
+            return Vec::new();
+        };
+        groups
+            .iter()
+            .filter_map(|group| match self.resolve(group)?.get("Name")? {
+                RawPdfObject::String(name) => Some(decode_pdf_text(name)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Names of the optional content groups the document's default
+/// configuration (`/OCProperties /D`) hides: those in `/OFF`, or with
+/// `/BaseState /OFF` every group not in `/ON`. Empty when the configuration
+/// can't be read (e.g. encrypted strings), so layers then count as visible.
+fn default_hidden_layers(path: &str) -> Vec<String> {
+    let hidden = || -> Option<Vec<String>> {
+        let document = RawPdfDocument::open(path)?;
+        let properties = document.resolve(document.catalog()?.get("OCProperties")?)?;
+        let config = document.resolve(properties.get("D")?)?;
+        if config.get("BaseState") == Some(&RawPdfObject::Name("OFF".to_string())) {
+            let on = document.group_names(config.get("ON"));
+            let all = document.group_names(properties.get("OCGs"));
+            Some(all.into_iter().filter(|name| !on.contains(name)).collect())
+        } else {
+            Some(document.group_names(config.get("OFF")))
+        }
+    };
+    hidden().unwrap_or_default()
+}
+
+/// Optional content groups ("layers") used on the document's pages,
+/// including inside form XObjects, in first-use order. `visible` is the
+/// state from the document's default configuration, which plain renders
+/// follow until `render_pdf_page_with_layers` overrides it. Documents
+/// without layers yield an empty list.
+#[hotpath::measure]
+pub fn list_pdf_layers(path: String) -> Result<Vec<PdfLayer>, FerrousError> {
+    timed!("list_pdf_layers", {
+        let names = with_raw_document(&path, |bindings, document| {
+            let mut names: Vec<String> = Vec::new();
+            for page_index in 0..bindings.FPDF_GetPageCount(document) {
+                let page = bindings.FPDF_LoadPage(document, page_index);
+                if page.is_null() {
+                    continue;
+                }
+                collect_layer_names(bindings, ObjectContainer::Page(page), &mut names);
+                bindings.FPDF_ClosePage(page);
+            }
+            Ok(names)
+        })?;
+        let hidden = default_hidden_layers(&path);
+        Ok(names
+            .into_iter()
+            .map(|name| PdfLayer {
+                visible: !hidden.contains(&name),
+                name,
+            })
+            .collect())
+    })
+}
+
+/// Like `render_pdf_page`, but with layer visibility overridden by name.
+/// Layers not listed in `layers` keep the document's default state.
+#[hotpath::measure]
+pub fn render_pdf_page_with_layers(
+    path: String,
+    page_index: u32,
+    width: u32,
+    height: u32,
+    layers: Vec<PdfLayer>,
+) -> Result<PdfPageRenderResult, FerrousError> {
+    timed!("render_pdf_page_with_layers", {
+        if layers.is_empty() {
+            return render_pdf_page(path, page_index, width, height);
+        }
+
+        with_raw_document(&path, |bindings, document| {
+            if page_index >= bindings.FPDF_GetPageCount(document).max(0) as u32 {
+                return Err(FerrousError::PageOutOfRange);
+            }
+            let page = bindings.FPDF_LoadPage(document, page_index as i32);
+            if page.is_null() {
+                return Err(FerrousError::PageLoad {
+                    page_index,
+                    detail: format!("pdfium could not load the page of {path}"),
+                });
+            }
+
+            apply_layer_overrides(bindings, ObjectContainer::Page(page), &layers);
+
+            // Same sizing as render_pdf_page: fit the width, cap the height
+            let page_width = bindings.FPDF_GetPageWidthF(page).max(1.0);
+            let page_height = bindings.FPDF_GetPageHeightF(page).max(1.0);
+            let scale = (width as f32 / page_width).min(height as f32 / page_height);
+            let target_width = (page_width * scale).round().max(1.0) as i32;
+            let target_height = (page_height * scale).round().max(1.0) as i32;
+
+            let bitmap = bindings.FPDFBitmap_Create(target_width, target_height, 0);
+            if bitmap.is_null() {
+                bindings.FPDF_ClosePage(page);
+                return Err(FerrousError::Pdfium(format!(
+                    "Failed to allocate {target_width}x{target_height} bitmap"
+                )));
+            }
+            bindings.FPDFBitmap_FillRect(bitmap, 0, 0, target_width, target_height, 0xFFFFFFFF);
+            bindings.FPDF_RenderPageBitmap(
+                bitmap,
+                page,
+                0,
+                0,
+                target_width,
+                target_height,
+                0,
+                FPDF_ANNOT | FPDF_LCD_TEXT | FPDF_PRINTING,
+            );
+
+            // pdfium bitmaps without alpha are BGRx rows padded to the stride
+            let stride = bindings.FPDFBitmap_GetStride(bitmap) as usize;
+            let buffer = bindings.FPDFBitmap_GetBuffer_as_vec(bitmap);
+            bindings.FPDFBitmap_Destroy(bitmap);
+            bindings.FPDF_ClosePage(page);
+
+            let image = image::RgbImage::from_fn(target_width as u32, target_height as u32, |x, y| {
+                let offset = y as usize * stride + x as usize * 4;
+                image::Rgb([buffer[offset + 2], buffer[offset + 1], buffer[offset]])
+            });
+            encode_page_jpeg(image::DynamicImage::ImageRgb8(image))
+        })
+    })
+}
+
+/// One JPEG tile of a zoomed page render. `x`/`y` are the tile's pixel
+/// offset in the full page render; edge tiles may be smaller than the tile size.
+#[derive(Debug, Clone)]
+pub struct PdfTile {
+    pub row: u32,
+    pub col: u32,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+/// Upper bound on tiles returned by a single `render_pdf_page_tiles` call.
+const MAX_TILES_PER_CALL: u32 = 64;
+/// Longest edge of the full page at the requested zoom; larger scales are clamped.
+const MAX_TILED_PAGE_DIM: f32 = 16384.0;
+
+/// Render the page at `scale` (pixels per PDF point) and return the
+/// `tile_size`-square tiles intersecting `viewport`, a normalized top-left
+/// rect of the page. Only the area covered by those tiles is rasterized.
+/// At most `MAX_TILES_PER_CALL` tiles are returned, row by row from the
+/// viewport's top-left corner.
+#[hotpath::measure]
+pub fn render_pdf_page_tiles(
+    path: String,
+    page_index: u32,
+    scale: f32,
+    tile_size: u32,
+    viewport: PdfTextRect,
+) -> Result<Vec<PdfTile>, FerrousError> {
+    timed!("render_pdf_page_tiles", {
+        if tile_size == 0 || scale <= 0.0 {
+            return Err(FerrousError::InvalidArgument(format!(
+                "Invalid tile request: scale {scale}, tile size {tile_size}"
+            )));
+        }
+
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let page_width = page.width().value;
+            let page_height = page.height().value;
+            let scale = scale.min(MAX_TILED_PAGE_DIM / page_width.max(page_height).max(1.0));
+            let full_width = (page_width * scale).round().max(1.0) as u32;
+            let full_height = (page_height * scale).round().max(1.0) as u32;
+
+            let columns = full_width.div_ceil(tile_size);
+            let rows = full_height.div_ceil(tile_size);
+            let to_tile = |norm: f32, full: u32, count: u32| {
+                ((norm.clamp(0.0, 1.0) * full as f32) as u32 / tile_size).min(count - 1)
+            };
+            let first_col = to_tile(viewport.left.min(viewport.right), full_width, columns);
+            let last_col = to_tile(viewport.left.max(viewport.right), full_width, columns);
+            let first_row = to_tile(viewport.top.min(viewport.bottom), full_height, rows);
+            let last_row = to_tile(viewport.top.max(viewport.bottom), full_height, rows);
+
+            let mut wanted = Vec::new();
+            'rows: for row in first_row..=last_row {
+                for col in first_col..=last_col {
+                    if wanted.len() as u32 >= MAX_TILES_PER_CALL {
+                        break 'rows;
+                    }
+                    wanted.push((row, col));
+                }
+            }
+            // Each tile is rendered on its own bitmap, so memory stays
+            // proportional to the tile size however far the page is zoomed
+            let mut tiles = Vec::with_capacity(wanted.len());
+            for (row, col) in wanted {
+                let x = col * tile_size;
+                let y = row * tile_size;
+                let width = tile_size.min(full_width.saturating_sub(x));
+                let height = tile_size.min(full_height.saturating_sub(y));
+                if width == 0 || height == 0 {
+                    continue;
+                }
+
+                let region = PdfTextRect {
+                    left: x as f32 / full_width as f32,
+                    top: y as f32 / full_height as f32,
+                    right: (x + width) as f32 / full_width as f32,
+                    bottom: (y + height) as f32 / full_height as f32,
+                };
+                let tile = render_page_region(&page, &region, width, height)?.into_rgb8();
+                let mut bytes = Vec::new();
+                tile.write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Jpeg)?;
+                tiles.push(PdfTile {
+                    row,
+                    col,
+                    x,
+                    y,
+                    width,
+                    height,
+                    bytes,
+                });
+            }
+
+            Ok(tiles)
+        })
+    })
+}
+
+/// Rasterize only `region` (a normalized top-left rect of the page) into an
+/// `output_width` x `output_height` JPEG. The page is translated and scaled
+/// so the region fills the bitmap, so pdfium never draws the rest of the
+/// page and memory stays proportional to the output size.
+#[hotpath::measure]
+pub fn render_pdf_page_region(
+    path: String,
+    page_index: u32,
+    region: PdfTextRect,
+    output_width: u32,
+    output_height: u32,
+) -> Result<PdfPageRenderResult, FerrousError> {
+    timed!("render_pdf_page_region", {
+        let left = region.left.min(region.right).clamp(0.0, 1.0);
+        let right = region.left.max(region.right).clamp(0.0, 1.0);
+        let top = region.top.min(region.bottom).clamp(0.0, 1.0);
+        let bottom = region.top.max(region.bottom).clamp(0.0, 1.0);
+        if output_width == 0 || output_height == 0 || right <= left || bottom <= top {
+            return Err(FerrousError::InvalidArgument(format!(
+                "Invalid region request: {output_width}x{output_height} for {left},{top},{right},{bottom}"
+            )));
+        }
+
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let region = PdfTextRect { left, top, right, bottom };
+            encode_page_jpeg(render_page_region(&page, &region, output_width, output_height)?)
+        })
+    })
+}
+
+/// Rasterize `region`, a normalized top-left rect already ordered and
+/// clamped to the page, onto an `output_width` x `output_height` bitmap.
+fn render_page_region(
+    page: &PdfPage,
+    region: &PdfTextRect,
+    output_width: u32,
+    output_height: u32,
+) -> Result<image::DynamicImage, FerrousError> {
+    let page_width = page.width().value;
+    let page_height = page.height().value;
+
+    // The config's own scale maps the whole page onto the target size;
+    // shift the region's corner to the origin and enlarge it first
+    let bitmap = page.render_with_config(
+        &PdfRenderConfig::new()
+            .set_target_size(output_width as i32, output_height as i32)
+            .translate(PdfPoints::new(-region.left * page_width), PdfPoints::new(-region.top * page_height))?
+            .scale(1.0 / (region.right - region.left), 1.0 / (region.bottom - region.top))?
+            .clip(0, 0, output_width as i32, output_height as i32)
+            .use_lcd_text_rendering(true)
+            .set_text_smoothing(true)
+            .set_image_smoothing(true)
+            .set_path_smoothing(true),
+    )?;
+    Ok(bitmap.as_image())
+}
+
+/// Owner-password restrictions of a PDF. Unprotected documents allow everything.
+#[derive(Debug, Clone, Copy)]
+pub struct PdfPermissions {
+    pub can_print: bool,
+    pub can_copy: bool,
+    pub can_modify: bool,
+    pub can_annotate: bool,
+    pub can_fill_forms: bool,
+}
+
+/// Read the permission bits of a PDF. Bits pdfium can't interpret (unknown
+/// security handler revisions) are reported as allowed.
+#[hotpath::measure]
+pub fn get_pdf_permissions(path: String) -> Result<PdfPermissions, FerrousError> {
+    timed!("get_pdf_permissions", {
+        with_document(&path, |document| {
+            let permissions = document.permissions();
+            Ok(PdfPermissions {
+                can_print: permissions.can_print_high_quality().unwrap_or(true)
+                    || permissions.can_print_only_low_quality().unwrap_or(false),
+                can_copy: permissions.can_extract_text_and_graphics().unwrap_or(true),
+                can_modify: permissions.can_modify_document_content().unwrap_or(true),
+                can_annotate: permissions.can_add_or_modify_text_annotations().unwrap_or(true),
+                can_fill_forms: permissions.can_fill_existing_interactive_form_fields().unwrap_or(true),
+            })
+        })
+    })
+}
+
+/// Whether text extraction honors a document's "no copy" restriction. Off by
+/// default; rendering is never restricted.
+static ENFORCE_PDF_PERMISSIONS: AtomicBool = AtomicBool::new(false);
+
+/// Choose whether text-extraction APIs refuse documents whose owner password
+/// forbids copying. When enforced they fail with
+/// `FerrousError::PermissionDenied` instead of returning text.
+pub fn set_enforce_pdf_permissions(enforce: bool) {
+    ENFORCE_PDF_PERMISSIONS.store(enforce, Ordering::Relaxed);
+}
+
+fn ensure_text_extraction_allowed(document: &PdfDocument, path: &str) -> Result<(), FerrousError> {
+    if !ENFORCE_PDF_PERMISSIONS.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    let can_copy = document
+        .permissions()
+        .can_extract_text_and_graphics()
+        .unwrap_or(true);
+    if can_copy {
+        Ok(())
+    } else {
+        Err(FerrousError::PermissionDenied(path.to_string()))
+    }
+}
+
+/// Extract the text of a specific page of a PDF file.
+#[hotpath::measure]
+pub fn extract_pdf_page_text(path: String, page_index: u32) -> Result<String, FerrousError> {
+    timed!("extract_pdf_page_text", {
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+            Ok(text.all())
+        })
+    })
+}
+
+/// Words that form hyphenated compounds ("self-aware", "well-known") and
+/// so keep their hyphen when a line break falls right after it. Only
+/// prefixes that are almost never the first syllable of a plain word: "pro",
+/// "pre", "ex", "so" and the like split "production", "present", "example"
+/// and "social" far more often than they start a compound.
+const COMPOUND_PREFIXES: &[&str] = &["half", "non", "quasi", "self", "well"];
+
+/// Rejoin words hyphenated across a line break: a "-" at the end of a
+/// line followed by a lowercase letter on the next one. The hyphen and
+/// break are dropped ("informa-\ntion" becomes "information") unless the
+/// word looks like a genuine compound, i.e. it already has a hyphen or
+/// starts with a common compound prefix, where only the break is dropped
+/// ("self-\naware" becomes "self-aware"). Soft hyphens always join.
+pub(crate) fn dehyphenate_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut word_start = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let after_letter = i > 0 && chars[i - 1].is_alphabetic();
+        if (c == '-' || c == '\u{AD}') && after_letter {
+            // Skip to the next line, then check it continues the word
+            let mut j = i + 1;
+            while j < chars.len() && matches!(chars[j], ' ' | '\t' | '\r') {
+                j += 1;
+            }
+            if chars.get(j) == Some(&'\n') {
+                j += 1;
+                while j < chars.len() && matches!(chars[j], ' ' | '\t') {
+                    j += 1;
+                }
+                if chars.get(j).is_some_and(|c| c.is_lowercase()) {
+                    let word: String = chars[word_start..i].iter().collect();
+                    let is_compound = c == '-'
+                        && (word.contains('-') || COMPOUND_PREFIXES.contains(&word.to_lowercase().as_str()));
+                    if is_compound {
+                        out.push('-');
+                    }
+                    i = j;
+                    continue;
+                }
+            }
+        }
+        if !(c.is_alphabetic() || c == '-') {
+            word_start = i + 1;
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// `extract_pdf_page_text` with words hyphenated across line breaks
+/// rejoined (see `dehyphenate_text`), for TTS and search.
+#[hotpath::measure]
+pub fn extract_pdf_page_text_dehyphenated(path: String, page_index: u32) -> Result<String, FerrousError> {
+    timed!("extract_pdf_page_text_dehyphenated", {
+        let text = extract_pdf_page_text(path, page_index)?;
+        Ok(dehyphenate_text(&text))
+    })
+}
+
+/// Fraction of the page height at the top and bottom where running
+/// headers/footers are looked for.
+const RUNNING_HEAD_EDGE: f32 = 0.12;
+/// Lines longer than this are treated as body text, never as a running head.
+const RUNNING_HEAD_MAX_CHARS: usize = 100;
+/// How many pages on each side of the target page are compared.
+const RUNNING_HEAD_SAMPLE_RADIUS: i64 = 2;
+
+struct PageLine {
+    text: String,
+    /// Vertical center of the line, normalized top-left (0 = top of page)
+    y_norm: f32,
+    /// Extent of the line's glyphs, normalized top-left. All zero when
+    /// pdfium reported no glyph bounds.
+    bounds: PdfTextRect,
+}
+
+fn collect_page_lines(page: &PdfPage) -> Result<Vec<PageLine>, FerrousError> {
+    let text = page.text()?;
+    let page_rect = page.page_size();
+    let page_left = page_rect.left().value;
+    let page_bottom = page_rect.bottom().value;
+    let width = page_rect.width().value;
+    let height = page_rect.height().value;
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut y_sum = 0.0f32;
+    let mut y_count = 0u32;
+    // Glyph extent in page points: left, bottom, right, top
+    let mut extent: Option<(f32, f32, f32, f32)> = None;
+
+    let mut flush = |current: &mut String,
+                     y_sum: &mut f32,
+                     y_count: &mut u32,
+                     extent: &mut Option<(f32, f32, f32, f32)>| {
+        if !current.trim().is_empty() {
+            let y_norm = if *y_count > 0 && height > 0.0 {
+                1.0 - ((*y_sum / *y_count as f32 - page_bottom) / height)
+            } else {
+                0.5
+            };
+            let bounds = match *extent {
+                Some((left, bottom, right, top)) if width > 0.0 && height > 0.0 => PdfTextRect {
+                    left: (left - page_left) / width,
+                    top: 1.0 - (top - page_bottom) / height,
+                    right: (right - page_left) / width,
+                    bottom: 1.0 - (bottom - page_bottom) / height,
+                },
+                _ => PdfTextRect { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 },
+            };
+            lines.push(PageLine {
+                text: std::mem::take(current),
+                y_norm,
+                bounds,
+            });
+        }
+        current.clear();
+        *y_sum = 0.0;
+        *y_count = 0;
+        *extent = None;
+    };
+
+    for ch in text.chars().iter() {
+        let Some(c) = ch.unicode_char() else { continue };
+        if c == '\n' || c == '\r' {
+            flush(&mut current, &mut y_sum, &mut y_count, &mut extent);
+            continue;
+        }
+        current.push(c);
+        if let Ok(bounds) = ch.loose_bounds() {
+            let (left, bottom, right, top) =
+                (bounds.left().value, bounds.bottom().value, bounds.right().value, bounds.top().value);
+            y_sum += (top + bottom) / 2.0;
+            y_count += 1;
+            extent = Some(match extent {
+                Some((l, b, r, t)) => (l.min(left), b.min(bottom), r.max(right), t.max(top)),
+                None => (left, bottom, right, top),
+            });
+        }
+    }
+    flush(&mut current, &mut y_sum, &mut y_count, &mut extent);
+
+    Ok(lines)
+}
+
+/// Comparison key for a running head: case-folded, whitespace-collapsed, with
+/// digit runs masked so "Chapter 3 · 41" matches "Chapter 3 · 42".
+fn running_head_key(line: &str) -> String {
+    let mut key = String::with_capacity(line.len());
+    let mut last_was_digit = false;
+    for c in line.split_whitespace().collect::<Vec<_>>().join(" ").chars() {
+        if c.is_ascii_digit() {
+            if !last_was_digit {
+                key.push('#');
+            }
+            last_was_digit = true;
+        } else {
+            key.extend(c.to_lowercase());
+            last_was_digit = false;
+        }
+    }
+    key
+}
+
+fn edge_line_keys(lines: &[PageLine]) -> Vec<String> {
+    lines
+        .iter()
+        .filter(|line| line.y_norm < RUNNING_HEAD_EDGE || line.y_norm > 1.0 - RUNNING_HEAD_EDGE)
+        .filter(|line| line.text.trim().chars().count() <= RUNNING_HEAD_MAX_CHARS)
+        .map(|line| running_head_key(&line.text))
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+/// Extract page text with running headers/footers removed. A short line near
+/// the top or bottom edge is only dropped when the same line (ignoring page
+/// numbers) sits at an edge of at least two neighbouring pages, so body text
+/// is kept whenever in doubt.
+#[hotpath::measure]
+pub fn extract_pdf_page_body_text(path: String, page_index: u32) -> Result<String, FerrousError> {
+    timed!("extract_pdf_page_body_text", {
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let pages = document.pages();
+            let page = get_pdf_page(document, page_index)?;
+            let lines = collect_page_lines(&page)?;
+
+            let page_count = pages.len() as i64;
+            let mut neighbour_keys: Vec<Vec<String>> = Vec::new();
+            for offset in -RUNNING_HEAD_SAMPLE_RADIUS..=RUNNING_HEAD_SAMPLE_RADIUS {
+                let index = page_index as i64 + offset;
+                if offset == 0 || index < 0 || index >= page_count {
+                    continue;
+                }
+                let Ok(neighbour) = pages.get(index as u16) else { continue };
+                let Ok(neighbour_lines) = collect_page_lines(&neighbour) else { continue };
+                neighbour_keys.push(edge_line_keys(&neighbour_lines));
+            }
+
+            let required = neighbour_keys.len().min(2);
+            let mut body = Vec::with_capacity(lines.len());
+            for line in &lines {
+                let at_edge = line.y_norm < RUNNING_HEAD_EDGE || line.y_norm > 1.0 - RUNNING_HEAD_EDGE;
+                let short = line.text.trim().chars().count() <= RUNNING_HEAD_MAX_CHARS;
+                if required > 0 && at_edge && short {
+                    let key = running_head_key(&line.text);
+                    let repeats = neighbour_keys
+                        .iter()
+                        .filter(|keys| keys.contains(&key))
+                        .count();
+                    if repeats >= required {
+                        continue;
+                    }
+                }
+                body.push(line.text.as_str());
+            }
+
+            Ok(body.join("\n"))
+        })
+    })
+}
+
+/// Lowest share of lines allowed to cross a column gutter (full-width
+/// titles do), and the fewest lines each column must have.
+const COLUMN_GUTTER_MAX_CROSSING: f32 = 0.1;
+const COLUMN_MIN_LINES: usize = 3;
+/// A vertical gap larger than this many median line heights starts a new paragraph.
+const PARAGRAPH_GAP_LINE_HEIGHTS: f32 = 0.8;
+
+/// Split lines into reading-order columns at the clearest vertical gutter in
+/// the middle of the page. Lines crossing the gutter (titles, full-width
+/// figures) stay with the left column. Single-column pages come back whole.
+fn split_page_columns(lines: Vec<PageLine>) -> Vec<Vec<PageLine>> {
+    let placed = lines.iter().filter(|l| l.bounds.right > l.bounds.left).count();
+    let mut best: Option<(usize, f32)> = None;
+    for step in 30..=70 {
+        let gutter = step as f32 / 100.0;
+        let crossing = lines
+            .iter()
+            .filter(|l| l.bounds.left < gutter && l.bounds.right > gutter)
+            .count();
+        let left = lines.iter().filter(|l| l.bounds.right > l.bounds.left && l.bounds.right <= gutter).count();
+        let right = lines.iter().filter(|l| l.bounds.right > l.bounds.left && l.bounds.left >= gutter).count();
+        if left < COLUMN_MIN_LINES
+            || right < COLUMN_MIN_LINES
+            || crossing as f32 > placed as f32 * COLUMN_GUTTER_MAX_CROSSING
+        {
+            continue;
+        }
+        if best.is_none_or(|(fewest, _)| crossing < fewest) {
+            best = Some((crossing, gutter));
+        }
+    }
+
+    let Some((_, gutter)) = best else {
+        return vec![lines];
+    };
+    let (right, left): (Vec<_>, Vec<_>) = lines.into_iter().partition(|l| l.bounds.left >= gutter);
+    vec![left, right]
+}
+
+/// Group one column's lines into paragraphs on vertical gaps, joining
+/// hyphenated line breaks.
+fn column_paragraphs(mut lines: Vec<PageLine>) -> Vec<String> {
+    lines.sort_by(|a, b| a.y_norm.total_cmp(&b.y_norm));
+
+    let mut heights: Vec<f32> = lines
+        .iter()
+        .map(|l| l.bounds.bottom - l.bounds.top)
+        .filter(|h| *h > 0.0)
+        .collect();
+    heights.sort_by(|a, b| a.total_cmp(b));
+    let line_height = heights.get(heights.len() / 2).copied().unwrap_or(0.0);
+
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    let mut previous_bottom: Option<f32> = None;
+    for line in lines {
+        let text = line.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        let gap = previous_bottom.map(|bottom| line.bounds.top - bottom).unwrap_or(0.0);
+        if !current.is_empty() && line_height > 0.0 && gap > line_height * PARAGRAPH_GAP_LINE_HEIGHTS {
+            paragraphs.push(std::mem::take(&mut current));
+        }
+
+        if current.ends_with('-') && text.starts_with(|c: char| c.is_lowercase()) {
+            current.pop();
+        } else if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&text);
+        previous_bottom = Some(line.bounds.bottom);
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+/// Page text reflowed into `<p>` paragraphs for a resizable text view. Lines
+/// come from the text layer (including OCR layers of scanned PDFs), columns
+/// are read left to right, and paragraphs break on vertical gaps.
+#[hotpath::measure]
+pub fn reflow_pdf_page(path: String, page_index: u32) -> Result<String, FerrousError> {
+    timed!("reflow_pdf_page", {
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let page = get_pdf_page(document, page_index)?;
+            let lines = collect_page_lines(&page)?;
+
+            let mut html = String::new();
+            for column in split_page_columns(lines) {
+                for paragraph in column_paragraphs(column) {
+                    html.push_str("<p>");
+                    html.push_str(&escape_reflow_text(&paragraph));
+                    html.push_str("</p>\n");
+                }
+            }
+            Ok(html)
+        })
+    })
+}
+
+fn escape_reflow_text(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Find the character index of the start of the word nearest a normalized
+/// top-left point, widening the search tolerance before giving up.
+fn pick_word_start_near_point(
+    page: &PdfPage,
+    text: &PdfPageText,
+    x_norm: f64,
+    y_norm: f64,
+) -> Option<usize> {
+    let page_rect = page.page_size();
+    let width = page_rect.width().value as f64;
+    let height = page_rect.height().value as f64;
+
+    let x_norm = x_norm.clamp(0.0, 1.0);
+    let y_norm = y_norm.clamp(0.0, 1.0);
+
+    // Convert from top-left normalized coordinates to Pdfium user space coordinates
+    let x_points = (page_rect.left().value as f64 + (width * x_norm)) as f32;
+    let y_points = (page_rect.top().value as f64 - (height * y_norm)) as f32;
+
+    let chars = text.chars();
+
+    // Try a few tolerance levels
+    let mut tolerance = PdfPoints::new(6.0);
+    let mut picked = None;
+
+    for _ in 0..4 {
+        picked = chars.get_char_near_point(
+            PdfPoints::new(x_points),
+            tolerance,
+            PdfPoints::new(y_points),
+            tolerance,
+        );
+        if picked.is_some() {
+            break;
+        }
+        tolerance = tolerance * 2.0;
+    }
+
+    let picked_char = picked?;
+
+    let total = text.len().max(0) as usize;
+    if total == 0 {
+        return None;
+    }
+
+    // Snap back to a word boundary
+    let mut start_index = picked_char.index().min(total.saturating_sub(1));
+    for _ in 0..32 {
+        if start_index == 0 {
+            break;
+        }
+
+        let prev = chars.get(start_index - 1);
+        let Ok(prev_char) = prev else { break };
+
+        let Some(c) = prev_char.unicode_char() else { break };
+        if c.is_whitespace() {
+            break;
+        }
+
+        start_index -= 1;
+    }
+
+    Some(start_index)
+}
+
+/// Extract page text starting near a normalized point on the rendered page.
+pub fn extract_pdf_page_text_from_point(
+    path: String,
+    page_index: u32,
+    x_norm: f64,
+    y_norm: f64,
+) -> Result<String, FerrousError> {
+    timed!("extract_pdf_page_text_from_point", {
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+
+            let Some(start_index) = pick_word_start_near_point(&page, &text, x_norm, y_norm) else {
+                return Ok(String::new());
+            };
+
+            let chars = text.chars();
+            let total = text.len().max(0) as usize;
+            let mut out = String::new();
+            for i in start_index..total {
+                let Ok(ch) = chars.get(i) else { continue };
+                if let Some(c) = ch.unicode_char() {
+                    out.push(c);
+                }
+            }
+
+            Ok(out)
+        })
+    })
+}
+
+/// Text of the characters whose box center lies inside a normalized top-left
+/// rect, in text-layer order. Skipped text between selected characters
+/// collapses to a newline when it spans a line break and to a space
+/// otherwise, so a dragged column reads line by line.
+#[hotpath::measure]
+pub fn extract_pdf_text_in_rect(
+    path: String,
+    page_index: u32,
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+) -> Result<String, FerrousError> {
+    timed!("extract_pdf_text_in_rect", {
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+
+            let page_rect = page.page_size();
+            let page_left = page_rect.left().value;
+            let page_bottom = page_rect.bottom().value;
+            let width = page_rect.width().value;
+            let height = page_rect.height().value;
+
+            // Region in pdfium user space (bottom-left origin)
+            let min_x = page_left + left.min(right) * width;
+            let max_x = page_left + left.max(right) * width;
+            let min_y = page_bottom + (1.0 - top.max(bottom)) * height;
+            let max_y = page_bottom + (1.0 - top.min(bottom)) * height;
+
+            let mut out = String::new();
+            // Separator owed before the next selected character, if any
+            let mut gap: Option<char> = None;
+            for ch in text.chars().iter() {
+                let Some(c) = ch.unicode_char() else { continue };
+                if c.is_whitespace() {
+                    if !out.is_empty() {
+                        let sep = if c == '\n' || c == '\r' { '\n' } else { ' ' };
+                        gap = Some(if gap == Some('\n') { '\n' } else { sep });
+                    }
+                    continue;
+                }
+
+                let Ok(bounds) = ch.loose_bounds().or_else(|_| ch.tight_bounds()) else { continue };
+                let cx = (bounds.left().value + bounds.right().value) / 2.0;
+                let cy = (bounds.top().value + bounds.bottom().value) / 2.0;
+                let inside = (min_x..=max_x).contains(&cx) && (min_y..=max_y).contains(&cy);
+                if !inside {
+                    if !out.is_empty() {
+                        gap.get_or_insert(' ');
+                    }
+                    continue;
+                }
+
+                if let Some(sep) = gap.take() {
+                    out.push(sep);
+                }
+                out.push(c);
+            }
+
+            Ok(out)
+        })
+    })
+}
+
+/// Like `extract_pdf_page_text_from_point`, but return the character index of
+/// the word start so TTS can begin from it. `None` when the page has no text
+/// near the point.
+pub fn get_pdf_text_index_from_point(
+    path: String,
+    page_index: u32,
+    x_norm: f64,
+    y_norm: f64,
+) -> Result<Option<u32>, FerrousError> {
+    timed!("get_pdf_text_index_from_point", {
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+            Ok(pick_word_start_near_point(&page, &text, x_norm, y_norm).map(|i| i as u32))
+        })
+    })
+}
+
+fn is_empty_text_rect(rect: &PdfTextRect) -> bool {
+    rect.right <= rect.left || rect.bottom <= rect.top
+}
+
+/// Walk back from `index` to the first character of its word. Whitespace and
+/// unresolvable characters carry empty rects in the per-character bounds.
+fn word_start_in_rects(rects: &[PdfTextRect], index: usize) -> usize {
+    let mut start = index;
+    while start > 0 && !is_empty_text_rect(&rects[start - 1]) {
+        start -= 1;
+    }
+    start
+}
+
+/// A whitespace-delimited word of the text layer and the union of its
+/// character boxes, normalized top-left.
+#[derive(Debug, Clone)]
+pub struct PdfWordRect {
+    pub text: String,
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+/// Word-level counterpart of `extract_all_page_character_bounds`: one rect
+/// per word, in text order. Words are split on whitespace like
+/// `find_word_index_at_point` does; characters without bounds still count
+/// towards the word's text.
+#[hotpath::measure]
+pub fn extract_all_page_word_bounds(path: String, page_index: u32) -> Result<Vec<PdfWordRect>, FerrousError> {
+    timed!("extract_all_page_word_bounds", {
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+
+            let page_rect = page.page_size();
+            let page_left = page_rect.left().value;
+            let page_bottom = page_rect.bottom().value;
+            let width = page_rect.width().value;
+            let height = page_rect.height().value;
+            if width <= 0.0 || height <= 0.0 {
+                return Ok(Vec::new());
+            }
+
+            let mut words = Vec::new();
+            let mut current: Option<PdfWordRect> = None;
+            let mut flush = |current: &mut Option<PdfWordRect>| {
+                if let Some(word) = current.take() {
+                    // Words without any bounds can't be highlighted
+                    if word.right > word.left || word.bottom > word.top {
+                        words.push(word);
+                    }
+                }
+            };
+
+            for ch in text.chars().iter() {
+                let Some(c) = ch.unicode_char().filter(|c| !c.is_whitespace()) else {
+                    flush(&mut current);
+                    continue;
+                };
+                let word = current.get_or_insert_with(|| PdfWordRect {
+                    text: String::new(),
+                    left: f32::MAX,
+                    top: f32::MAX,
+                    right: f32::MIN,
+                    bottom: f32::MIN,
+                });
+                word.text.push(c);
+
+                let Ok(bounds) = ch.loose_bounds().or_else(|_| ch.tight_bounds()) else { continue };
+                let left = ((bounds.left().value - page_left) / width).clamp(0.0, 1.0);
+                let right = ((bounds.right().value - page_left) / width).clamp(0.0, 1.0);
+                let top = (1.0 - ((bounds.top().value - page_bottom) / height)).clamp(0.0, 1.0);
+                let bottom = (1.0 - ((bounds.bottom().value - page_bottom) / height)).clamp(0.0, 1.0);
+                word.left = word.left.min(left.min(right));
+                word.right = word.right.max(left.max(right));
+                word.top = word.top.min(top.min(bottom));
+                word.bottom = word.bottom.max(top.max(bottom));
+            }
+            flush(&mut current);
+
+            Ok(words)
+        })
+    })
+}
+
+/// Character index of the word under a normalized point, using the rects from
+/// `extract_all_page_character_bounds`. Falls back to the nearest word when
+/// nothing is directly under the point.
+pub fn find_word_index_at_point(rects: Vec<PdfTextRect>, x_norm: f32, y_norm: f32) -> Option<u32> {
+    let mut best: Option<(usize, f32)> = None;
+    for (i, rect) in rects.iter().enumerate() {
+        if is_empty_text_rect(rect) {
+            continue;
+        }
+        let dx = (rect.left - x_norm).max(x_norm - rect.right).max(0.0);
+        let dy = (rect.top - y_norm).max(y_norm - rect.bottom).max(0.0);
+        let distance = dx * dx + dy * dy;
+        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+            best = Some((i, distance));
+            if distance == 0.0 {
+                break;
+            }
+        }
+    }
+
+    best.map(|(i, _)| word_start_in_rects(&rects, i) as u32)
+}
+
+/// Character index of the first word whose line starts at or below a
+/// normalized scroll offset, using the rects from
+/// `extract_all_page_character_bounds`. Falls back to the last word on the page.
+pub fn find_first_visible_word_index(rects: Vec<PdfTextRect>, y_norm: f32) -> Option<u32> {
+    let visible = rects
+        .iter()
+        .position(|rect| !is_empty_text_rect(rect) && rect.top >= y_norm)
+        .or_else(|| rects.iter().rposition(|rect| !is_empty_text_rect(rect)))?;
+
+    Some(word_start_in_rects(&rects, visible) as u32)
+}
+
+/// Extract normalized character bounding boxes for a text range on the page.
+pub fn extract_pdf_page_text_bounds(
+    path: String,
+    page_index: u32,
+    start_index: u32,
+    end_index: u32,
+) -> Result<Vec<PdfTextRect>, FerrousError> {
+    timed!("extract_pdf_page_text_bounds", {
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+            Ok(char_range_rects(&page, &text, start_index as usize, end_index as usize))
+        })
+    })
+}
+
+/// Normalized top-left rects of the non-whitespace characters in
+/// `start..end` (clamped to the page's text).
+fn char_range_rects(page: &PdfPage, text: &PdfPageText, start: usize, end: usize) -> Vec<PdfTextRect> {
+    let chars = text.chars();
+
+    let total = text.len().max(0) as usize;
+    if total == 0 {
+        return Vec::new();
+    }
+
+    if start >= end || start >= total {
+        return Vec::new();
+    }
+
+    let end = end.min(total);
+    let page_rect = page.page_size();
+    let page_left = page_rect.left().value;
+    let page_bottom = page_rect.bottom().value;
+    let width = page_rect.width().value;
+    let height = page_rect.height().value;
+
+    if width <= 0.0 || height <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut rects = Vec::new();
+    for i in start..end {
+        let ch = match chars.get(i) {
+            Ok(ch) => ch,
+            Err(_) => continue,
+        };
+
+        let Some(c) = ch.unicode_char() else {
+            continue;
+        };
+
+        if c.is_whitespace() {
+            continue;
+        }
+
+        let bounds = ch.loose_bounds().or_else(|_| ch.tight_bounds());
+        let Ok(bounds) = bounds else { continue };
+        let mut left = (bounds.left().value - page_left) / width;
+        let mut right = (bounds.right().value - page_left) / width;
+        let mut top = 1.0 - ((bounds.top().value - page_bottom) / height);
+        let mut bottom = 1.0 - ((bounds.bottom().value - page_bottom) / height);
+
+        if left > right {
+            std::mem::swap(&mut left, &mut right);
+        }
+        if top > bottom {
+            std::mem::swap(&mut top, &mut bottom);
+        }
+
+        rects.push(PdfTextRect {
+            left: left.clamp(0.0, 1.0),
+            top: top.clamp(0.0, 1.0),
+            right: right.clamp(0.0, 1.0),
+            bottom: bottom.clamp(0.0, 1.0),
+        });
+    }
+
+    rects
+}
+
+/// Pre-compute ALL character bounds for a page.
+#[hotpath::measure]
+pub fn extract_all_page_character_bounds(
+    path: String,
+    page_index: u32,
+) -> Result<Vec<PdfTextRect>, FerrousError> {
+    timed!("extract_all_page_character_bounds", {
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let text = page.text()?;
+            let chars = text.chars();
+
+            let total = text.len().max(0) as usize;
+            if total == 0 {
+                return Ok(Vec::new());
+            }
+
+            let page_rect = page.page_size();
+            let page_left = page_rect.left().value;
+            let page_bottom = page_rect.bottom().value;
+            let width = page_rect.width().value;
+            let height = page_rect.height().value;
+
+            if width <= 0.0 || height <= 0.0 {
+                return Ok(Vec::new());
+            }
+
+            let mut rects = Vec::with_capacity(total);
+            
+            for i in 0..total {
+                let ch = match chars.get(i) {
+                    Ok(ch) => ch,
+                    Err(_) => {
+                        rects.push(PdfTextRect { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 });
+                        continue;
+                    }
+                };
+
+                let Some(c) = ch.unicode_char() else {
+                    rects.push(PdfTextRect { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 });
+                    continue;
+                };
 
                 if c.is_whitespace() {
                     rects.push(PdfTextRect { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 });
@@ -456,6 +2240,957 @@ pub fn extract_all_page_character_bounds(
     })
 }
 
+#[derive(Debug, Clone)]
+pub struct PdfFontInfo {
+    pub name: String,
+    pub font_type: String,
+    pub is_embedded: bool,
+}
+
+/// Guess the font program type from the leading bytes of embedded font data.
+fn sniff_font_type(data: &[u8]) -> &'static str {
+    match data {
+        [0x00, 0x01, 0x00, 0x00, ..] | [b't', b'r', b'u', b'e', ..] => "TrueType",
+        [b'O', b'T', b'T', b'O', ..] => "OpenType",
+        [b'%', b'!', ..] | [0x80, 0x01, ..] => "Type1",
+        [0x01, 0x00, ..] if is_cid_keyed_cff(data) => "Type0",
+        [0x01, 0x00, ..] => "CFF",
+        _ => "Unknown",
+    }
+}
+
+/// First item of a CFF INDEX and the bytes after the INDEX.
+fn cff_index_first(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let count = u16::from_be_bytes([*data.first()?, *data.get(1)?]) as usize;
+    if count == 0 {
+        return Some((&[], data.get(2..)?));
+    }
+    let off_size = *data.get(2)? as usize;
+    let offset = |i: usize| -> Option<usize> {
+        let start = 3 + i * off_size;
+        let bytes = data.get(start..start + off_size)?;
+        Some(bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize))
+    };
+    // Offsets are 1-based from the byte before the object data
+    let data_start = 3 + (count + 1) * off_size - 1;
+    let first = data.get(data_start + offset(0)?..data_start + offset(1)?)?;
+    let rest = data.get(data_start + offset(count)?..)?;
+    Some((first, rest))
+}
+
+/// Whether bare CFF data is CID-keyed (the descendant of a Type0 font): its
+/// Top DICT then starts with the ROS operator.
+fn is_cid_keyed_cff(data: &[u8]) -> bool {
+    let Some(&header_size) = data.get(2) else { return false };
+    let Some((_, after_names)) = data.get(header_size as usize..).and_then(cff_index_first) else {
+        return false;
+    };
+    let Some((top_dict, _)) = cff_index_first(after_names) else { return false };
+
+    // Skip operands up to the first operator
+    let mut i = 0;
+    while let Some(&b) = top_dict.get(i) {
+        i += match b {
+            12 => return top_dict.get(i + 1) == Some(&30),
+            0..=21 => return false,
+            28 => 3,
+            29 => 5,
+            // Real number: nibbles up to an 0xF terminator
+            30 => {
+                let end = top_dict[i + 1..].iter().position(|&n| n >> 4 == 0xF || n & 0xF == 0xF);
+                match end {
+                    Some(end) => end + 2,
+                    None => return false,
+                }
+            }
+            247..=254 => 2,
+            _ => 1,
+        };
+    }
+    false
+}
+
+/// The PDF standard 14 fonts, which are Type1 whether or not they are embedded
+const STANDARD_FONT_NAMES: &[&str] = &["Times", "Helvetica", "Courier", "Symbol", "ZapfDingbats"];
+
+/// Font type implied by a base font name, for fonts whose program can't be
+/// inspected. Type0 names end in their CMap ("-Identity-H"), TrueType
+/// fonts with a style are named "Family,Style" and the standard 14 fonts
+/// are Type1. `None` when the name says nothing.
+fn font_type_from_name(name: &str) -> Option<&'static str> {
+    // Drop a subset tag such as "ABCDEF+"
+    let name = match name.split_once('+') {
+        Some((tag, rest)) if tag.len() == 6 && tag.chars().all(|c| c.is_ascii_uppercase()) => rest,
+        _ => name,
+    };
+    let cmap_suffix = ["-Identity-H", "-Identity-V", "-UCS2-H", "-UCS2-V", "-UTF16-H", "-UTF16-V"];
+    if cmap_suffix.iter().any(|suffix| name.ends_with(suffix)) {
+        Some("Type0")
+    } else if name.contains(',') {
+        Some("TrueType")
+    } else if STANDARD_FONT_NAMES.iter().any(|standard| name.starts_with(standard)) {
+        Some("Type1")
+    } else {
+        None
+    }
+}
+
+fn pdf_font_name(font: &PdfFont) -> String {
+    let name = font.name();
+    if name.is_empty() {
+        font.family()
+    } else {
+        name
+    }
+}
+
+/// Describe `font`, known by `name`. Embedded fonts have their program read
+/// and sniffed, so callers should do this once per distinct font.
+fn pdf_font_info(font: &PdfFont, name: String, is_embedded: bool) -> PdfFontInfo {
+    let from_name = font_type_from_name(&name);
+    let font_type = if font.is_built_in() {
+        "Type1"
+    } else if is_embedded {
+        // A CID font embedded as TrueType is still a Type0 font
+        match font.data().map(|data| sniff_font_type(&data)).unwrap_or("Unknown") {
+            "TrueType" | "Unknown" if from_name == Some("Type0") => "Type0",
+            "Unknown" => from_name.unwrap_or("Unknown"),
+            sniffed => sniffed,
+        }
+    } else {
+        // pdfium hands back a substitute font's data here, so only the
+        // name tells anything
+        from_name.unwrap_or("Unknown")
+    };
+
+    PdfFontInfo {
+        name,
+        font_type: font_type.to_string(),
+        is_embedded,
+    }
+}
+
+/// List the fonts used by text across the whole document, de-duplicated by name.
+#[hotpath::measure]
+pub fn list_pdf_fonts(path: String) -> Result<Vec<PdfFontInfo>, FerrousError> {
+    timed!("list_pdf_fonts", {
+        with_document(&path, |document| {
+            let mut fonts: Vec<PdfFontInfo> = Vec::new();
+            for page in document.pages().iter() {
+                for object in page.objects().iter() {
+                    let Some(text_object) = object.as_text_object() else {
+                        continue;
+                    };
+                    let font = text_object.font();
+                    let name = pdf_font_name(&font);
+                    let is_embedded = font.is_embedded().unwrap_or(false);
+                    let existing = fonts.iter().position(|f| f.name == name);
+                    // Only a font's first use, or its first embedded use,
+                    // can add anything; skip the rest before reading font data
+                    if existing.is_some_and(|index| fonts[index].is_embedded || !is_embedded) {
+                        continue;
+                    }
+
+                    let info = pdf_font_info(&font, name, is_embedded);
+                    if let Some(index) = existing {
+                        let existing = &mut fonts[index];
+                        // A font counts as embedded if any usage of it carries font
+                        // data, and the type read from that data beats a guess
+                        let better_type = existing.font_type == "Unknown"
+                            || (info.is_embedded && !existing.is_embedded);
+                        if better_type && info.font_type != "Unknown" {
+                            existing.font_type = info.font_type;
+                        }
+                        existing.is_embedded |= info.is_embedded;
+                        continue;
+                    }
+                    fonts.push(info);
+                }
+            }
+            Ok(fonts)
+        })
+    })
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PdfMetadata {
+    pub title: String,
+    pub author: String,
+    pub subject: String,
+    pub keywords: String,
+    pub creator: String,
+    pub producer: String,
+    pub page_count: u32,
+}
+
+/// Read the document information dictionary. Missing entries come back as
+/// empty strings.
+#[hotpath::measure]
+pub fn get_pdf_metadata(path: String) -> Result<PdfMetadata, FerrousError> {
+    timed!("get_pdf_metadata", {
+        with_document(&path, |document| {
+            let metadata = document.metadata();
+            let tag = |tag_type: PdfDocumentMetadataTagType| {
+                metadata
+                    .get(tag_type)
+                    .map(|tag| tag.value().trim().to_string())
+                    .unwrap_or_default()
+            };
+
+            Ok(PdfMetadata {
+                title: tag(PdfDocumentMetadataTagType::Title),
+                author: tag(PdfDocumentMetadataTagType::Author),
+                subject: tag(PdfDocumentMetadataTagType::Subject),
+                keywords: tag(PdfDocumentMetadataTagType::Keywords),
+                creator: tag(PdfDocumentMetadataTagType::Creator),
+                producer: tag(PdfDocumentMetadataTagType::Producer),
+                page_count: document.pages().len() as u32,
+            })
+        })
+    })
+}
+
+/// An image embedded in a page
+#[derive(Debug, Clone)]
+pub struct PdfImage {
+    pub bytes: Vec<u8>,
+    /// `jpeg` when the stream is a plain JPEG passed through as stored,
+    /// otherwise `png`
+    pub format: String,
+    /// Placement on the page, normalized top-left like the text rects
+    pub rect: PdfTextRect,
+}
+
+fn embedded_image_bytes(image: &PdfPageImageObject) -> Result<(Vec<u8>, String), FerrousError> {
+    let filters: Vec<String> = image.filters().iter().map(|f| f.name().to_string()).collect();
+    if filters.len() == 1 && filters[0] == "DCTDecode" {
+        let bytes = image.get_raw_image_data()?;
+        if !bytes.is_empty() {
+            return Ok((bytes, "jpeg".to_string()));
+        }
+    }
+
+    let mut bytes = Vec::new();
+    image
+        .get_raw_image()?
+        .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)?;
+    Ok((bytes, "png".to_string()))
+}
+
+/// Images placed directly on the page at their native resolution (masks and
+/// transforms are not applied), with where each one is drawn. Images inside
+/// form XObjects are not listed.
+#[hotpath::measure]
+pub fn extract_pdf_page_images(path: String, page_index: u32) -> Result<Vec<PdfImage>, FerrousError> {
+    timed!("extract_pdf_page_images", {
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let mut images = Vec::new();
+            for object in page.objects().iter() {
+                let Some(image) = object.as_image_object() else { continue };
+                let Some(rect) = object.bounds().ok().and_then(|bounds| normalized_page_rect(&page, &bounds.to_rect())) else {
+                    continue;
+                };
+                let (bytes, format) = match embedded_image_bytes(image) {
+                    Ok(encoded) => encoded,
+                    Err(e) => {
+                        eprintln!("⚠️ Warning: failed to extract image on page {}: {}", page_index, e);
+                        continue;
+                    }
+                };
+                images.push(PdfImage { bytes, format, rect });
+            }
+            Ok(images)
+        })
+    })
+}
+
+/// Annotation subtypes surfaced by `get_pdf_annotations`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PdfAnnotationKind {
+    Highlight,
+    Underline,
+    Squiggly,
+    StrikeOut,
+    /// Sticky note
+    Text,
+    FreeText,
+    Ink,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+pub struct PdfAnnotation {
+    pub kind: PdfAnnotationKind,
+    /// Normalized top-left like the text rects
+    pub rect: PdfTextRect,
+    pub contents: String,
+    /// 0xAARRGGBB
+    pub color: Option<u32>,
+}
+
+/// Normalize a user-space rect to the page's top-left unit square.
+fn normalized_page_rect(page: &PdfPage, rect: &PdfRect) -> Option<PdfTextRect> {
+    let page_rect = page.page_size();
+    let width = page_rect.width().value;
+    let height = page_rect.height().value;
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+    let page_left = page_rect.left().value;
+    let page_bottom = page_rect.bottom().value;
+    Some(PdfTextRect {
+        left: ((rect.left().value - page_left) / width).clamp(0.0, 1.0),
+        top: (1.0 - (rect.top().value - page_bottom) / height).clamp(0.0, 1.0),
+        right: ((rect.right().value - page_left) / width).clamp(0.0, 1.0),
+        bottom: (1.0 - (rect.bottom().value - page_bottom) / height).clamp(0.0, 1.0),
+    })
+}
+
+fn annotation_kind(annotation_type: PdfPageAnnotationType) -> Option<PdfAnnotationKind> {
+    match annotation_type {
+        PdfPageAnnotationType::Highlight => Some(PdfAnnotationKind::Highlight),
+        PdfPageAnnotationType::Underline => Some(PdfAnnotationKind::Underline),
+        PdfPageAnnotationType::Squiggly => Some(PdfAnnotationKind::Squiggly),
+        PdfPageAnnotationType::Strikeout => Some(PdfAnnotationKind::StrikeOut),
+        PdfPageAnnotationType::Text => Some(PdfAnnotationKind::Text),
+        PdfPageAnnotationType::FreeText => Some(PdfAnnotationKind::FreeText),
+        PdfPageAnnotationType::Ink => Some(PdfAnnotationKind::Ink),
+        // Links, form widgets and popups have their own APIs or no content
+        PdfPageAnnotationType::Link | PdfPageAnnotationType::Widget | PdfPageAnnotationType::Popup => None,
+        _ => Some(PdfAnnotationKind::Other),
+    }
+}
+
+fn pack_color(color: PdfColor) -> u32 {
+    (color.alpha() as u32) << 24 | (color.red() as u32) << 16 | (color.green() as u32) << 8 | color.blue() as u32
+}
+
+/// Markup annotations (highlights, notes, ...) of a page in document order.
+/// Read-only: the annotations stay in the file untouched.
+#[hotpath::measure]
+pub fn get_pdf_annotations(path: String, page_index: u32) -> Result<Vec<PdfAnnotation>, FerrousError> {
+    timed!("get_pdf_annotations", {
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let mut annotations = Vec::new();
+            for annotation in page.annotations().iter() {
+                let Some(kind) = annotation_kind(annotation.annotation_type()) else { continue };
+                let Some(rect) = annotation.bounds().ok().and_then(|bounds| normalized_page_rect(&page, &bounds)) else {
+                    continue;
+                };
+                // Markup colors live in /C (stroke); fall back to the interior color
+                let color = annotation
+                    .stroke_color()
+                    .or_else(|_| annotation.fill_color())
+                    .ok()
+                    .map(pack_color);
+                annotations.push(PdfAnnotation {
+                    kind,
+                    rect,
+                    contents: annotation.contents().unwrap_or_default(),
+                    color,
+                });
+            }
+            Ok(annotations)
+        })
+    })
+}
+
+/// Inverse of `normalized_page_rect`: a normalized top-left rect in the
+/// user space of a page whose crop box is `page_rect`.
+fn user_space_rect(rect: &PdfTextRect, page_rect: &PdfRect) -> PdfRect {
+    let page_left = page_rect.left().value;
+    let page_bottom = page_rect.bottom().value;
+    let width = page_rect.width().value;
+    let height = page_rect.height().value;
+    PdfRect::new_from_values(
+        page_bottom + (1.0 - rect.bottom.max(rect.top)) * height,
+        page_left + rect.left.min(rect.right) * width,
+        page_bottom + (1.0 - rect.top.min(rect.bottom)) * height,
+        page_left + rect.right.max(rect.left) * width,
+    )
+}
+
+/// Add one highlight annotation covering `rects` (normalized top-left, e.g.
+/// the rects of a text selection) in `color` (0xAARRGGBB) and save the file.
+/// The pooled copy of the document is evicted so later reads see the
+/// highlight.
+#[hotpath::measure]
+pub fn add_pdf_highlight(path: String, page_index: u32, rects: Vec<PdfTextRect>, color: u32) -> Result<(), FerrousError> {
+    timed!("add_pdf_highlight", {
+        if rects.is_empty() {
+            return Ok(());
+        }
+
+        // Opened outside the pool: pooled documents are shared read-only
+        let document = load_pdf_document(get_pdfium()?, &path)?;
+        {
+            let mut page = get_pdf_page(&document, page_index)?;
+            let page_rect = page.page_size();
+            let user_rects: Vec<PdfRect> = rects.iter().map(|rect| user_space_rect(rect, &page_rect)).collect();
+            let bounds = user_rects.iter().skip(1).fold(user_rects[0], |union, rect| {
+                PdfRect::new_from_values(
+                    union.bottom().value.min(rect.bottom().value),
+                    union.left().value.min(rect.left().value),
+                    union.top().value.max(rect.top().value),
+                    union.right().value.max(rect.right().value),
+                )
+            });
+
+            let mut annotation = page.annotations_mut().create_highlight_annotation()?;
+            annotation.set_bounds(bounds)?;
+            for rect in &user_rects {
+                annotation
+                    .attachment_points_mut()
+                    .create_attachment_point_at_end(PdfQuadPoints::from_rect(rect))?;
+            }
+            annotation.set_stroke_color(PdfColor::new(
+                (color >> 16) as u8,
+                (color >> 8) as u8,
+                color as u8,
+                (color >> 24) as u8,
+            ))?;
+        }
+
+        // pdfium reads the file lazily, so write a copy and swap it in
+        let temp_path = format!("{path}.saving");
+        let saved = document.save_to_file(&temp_path).map_err(FerrousError::from);
+        drop(document);
+        let swapped = saved.and_then(|_| {
+            evict_pdf_document(path.clone())?;
+            std::fs::rename(&temp_path, &path)?;
+            Ok(())
+        });
+        if swapped.is_err() {
+            // Don't leave a half-written copy next to the book
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        swapped
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PdfFormFieldKind {
+    Text,
+    Checkbox,
+    Radio,
+    /// Combo or list box
+    Choice,
+}
+
+/// One widget of a form field. Radio groups list each button separately,
+/// under the group's name.
+#[derive(Debug, Clone)]
+pub struct PdfFormField {
+    pub name: String,
+    pub kind: PdfFormFieldKind,
+    /// Text and choice fields: the current value (empty when unset).
+    /// Checkboxes and radio buttons: `"true"` when checked, else `"false"`.
+    pub value: String,
+    pub page_index: u32,
+    /// Normalized top-left like the text rects
+    pub rect: PdfTextRect,
+}
+
+/// Current values of the document's fillable form fields, page by page.
+/// Push buttons and signatures carry no value and are left out.
+#[hotpath::measure]
+pub fn get_pdf_form_fields(path: String) -> Result<Vec<PdfFormField>, FerrousError> {
+    timed!("get_pdf_form_fields", {
+        with_document(&path, |document| {
+            let mut fields = Vec::new();
+            for (page_index, page) in document.pages().iter().enumerate() {
+                for annotation in page.annotations().iter() {
+                    let Some(field) = annotation.as_form_field() else { continue };
+                    let checked = |checked: Result<bool, PdfiumError>| checked.unwrap_or(false).to_string();
+                    let (kind, value) = if let Some(text) = field.as_text_field() {
+                        (PdfFormFieldKind::Text, text.value())
+                    } else if let Some(checkbox) = field.as_checkbox_field() {
+                        (PdfFormFieldKind::Checkbox, Some(checked(checkbox.is_checked())))
+                    } else if let Some(radio) = field.as_radio_button_field() {
+                        (PdfFormFieldKind::Radio, Some(checked(radio.is_checked())))
+                    } else if let Some(combo) = field.as_combo_box_field() {
+                        (PdfFormFieldKind::Choice, combo.value())
+                    } else if let Some(list) = field.as_list_box_field() {
+                        (PdfFormFieldKind::Choice, list.value())
+                    } else {
+                        continue;
+                    };
+                    let Some(rect) = annotation.bounds().ok().and_then(|bounds| normalized_page_rect(&page, &bounds)) else {
+                        continue;
+                    };
+                    fields.push(PdfFormField {
+                        name: field.name().unwrap_or_default(),
+                        kind,
+                        value: value.unwrap_or_default(),
+                        page_index: page_index as u32,
+                        rect,
+                    });
+                }
+            }
+            Ok(fields)
+        })
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PdfOutlineItem {
+    pub title: String,
+    pub page_index: u32,
+    /// Nesting level, 0 for top-level entries
+    pub depth: u32,
+}
+
+/// Malformed outlines can loop back on themselves; stop after visiting
+/// this many bookmarks, listed or not.
+const MAX_OUTLINE_NODES: usize = 10_000;
+
+fn bookmark_page_index(bookmark: &PdfBookmark) -> Option<u32> {
+    if let Some(destination) = bookmark.destination() {
+        return destination.page_index().ok().map(|i| i as u32);
+    }
+    let action = bookmark.action()?;
+    let local = action.as_local_destination_action()?;
+    local.destination().ok()?.page_index().ok().map(|i| i as u32)
+}
+
+/// Walk the bookmark tree depth-first with an explicit stack, so deep
+/// outlines can't overflow the call stack.
+fn flatten_bookmarks(first: Option<PdfBookmark>) -> Vec<PdfOutlineItem> {
+    let mut items = Vec::new();
+    let mut visited = 0;
+    // Next bookmark to visit at each open level, with its depth
+    let mut stack = vec![(first, 0u32)];
+    while let Some((current, depth)) = stack.pop() {
+        let Some(bookmark) = current else { continue };
+        visited += 1;
+        if visited > MAX_OUTLINE_NODES {
+            break;
+        }
+        // Entries without a resolvable page (e.g. URI actions) are skipped,
+        // but their children are still listed
+        if let Some(page_index) = bookmark_page_index(&bookmark) {
+            items.push(PdfOutlineItem {
+                title: bookmark.title().unwrap_or_default().trim().to_string(),
+                page_index,
+                depth,
+            });
+        }
+        // Children before siblings keeps document order
+        stack.push((bookmark.next_sibling(), depth));
+        stack.push((bookmark.first_child(), depth + 1));
+    }
+    items
+}
+
+/// Flattened bookmark tree in document order. PDFs without an outline yield
+/// an empty list.
+#[hotpath::measure]
+pub fn get_pdf_outline(path: String) -> Result<Vec<PdfOutlineItem>, FerrousError> {
+    timed!("get_pdf_outline", {
+        with_document(&path, |document| {
+            Ok(flatten_bookmarks(document.bookmarks().root()))
+        })
+    })
+}
+
+/// One search match. `char_start..char_end` are pdfium character indices
+/// on the page, as taken by `extract_pdf_page_text_bounds`.
+#[derive(Debug, Clone)]
+pub struct PdfSearchHit {
+    pub page_index: u32,
+    pub char_start: u32,
+    pub char_end: u32,
+    /// Normalized top-left rects of the matched characters
+    pub rects: Vec<PdfTextRect>,
+}
+
+/// Events of `stream_pdf_search`
+#[derive(Debug, Clone)]
+pub enum PdfSearchEvent {
+    Hit {
+        hit: PdfSearchHit,
+        /// Up to `SEARCH_SNIPPET_CHARS` characters either side of the match
+        context_before: String,
+        context_after: String,
+        /// Matches found so far in this scan, including this one
+        total_matches: u32,
+    },
+    /// Every page has been scanned
+    Finished { total_matches: u32 },
+}
+
+/// Characters of context kept on each side of a search match.
+pub(crate) const SEARCH_SNIPPET_CHARS: usize = 40;
+
+/// Every character of a page's text layer by pdfium index. Characters
+/// pdfium can't map to Unicode become U+FFFD so indices stay aligned.
+fn page_chars(text: &PdfPageText) -> Vec<char> {
+    text.chars()
+        .iter()
+        .map(|ch| ch.unicode_char().unwrap_or('\u{FFFD}'))
+        .collect()
+}
+
+/// Non-overlapping `start..end` index ranges of `query` in `chars`.
+/// Case-insensitive matching compares simple (single-char) lowercase forms.
+pub(crate) fn find_text_matches(chars: &[char], query: &str, case_sensitive: bool) -> Vec<(usize, usize)> {
+    let fold = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        }
+    };
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() || needle.len() > chars.len() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= chars.len() {
+        if chars[i..i + needle.len()].iter().zip(&needle).all(|(&c, &n)| fold(c) == n) {
+            matches.push((i, i + needle.len()));
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+pub(crate) fn snippet(chars: &[char]) -> String {
+    chars
+        .iter()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Every match of `query` in the document's text layer, in page order.
+/// Matching is done on the page text by character index (pdfium's own
+/// search only reports rects), so hits line up with
+/// `extract_pdf_page_text_bounds` and `stream_pdf_search`. Cancelling
+/// `operation_id` stops it between pages.
+#[hotpath::measure]
+pub fn search_pdf(
+    path: String,
+    query: String,
+    case_sensitive: bool,
+    operation_id: Option<u64>,
+) -> Result<Vec<PdfSearchHit>, FerrousError> {
+    timed!("search_pdf", {
+        let operation = OperationHandle::new(operation_id);
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let mut hits = Vec::new();
+            for (page_index, page) in document.pages().iter().enumerate() {
+                check_cancelled(operation.flag())?;
+                let Ok(text) = page.text() else { continue };
+                let chars = page_chars(&text);
+                for (start, end) in find_text_matches(&chars, &query, case_sensitive) {
+                    hits.push(PdfSearchHit {
+                        page_index: page_index as u32,
+                        char_start: start as u32,
+                        char_end: end as u32,
+                        rects: char_range_rects(&page, &text, start, end),
+                    });
+                }
+            }
+            Ok(hits)
+        })
+    })
+}
+
+/// Search the text layer page by page, streaming each hit with its context
+/// as soon as its page is scanned. The scan starts at `start_page` and wraps
+/// around to the pages before it, so "find next" after navigating continues
+/// from the current page instead of restarting at page 0. Stops early when
+/// the listener goes away, or with `FerrousError::Cancelled` when
+/// `operation_id` is cancelled.
+#[hotpath::measure]
+pub fn stream_pdf_search(
+    path: String,
+    query: String,
+    case_sensitive: bool,
+    start_page: u32,
+    operation_id: Option<u64>,
+    sink: StreamSink<PdfSearchEvent>,
+) -> Result<(), FerrousError> {
+    timed!("stream_pdf_search", {
+        let operation = OperationHandle::new(operation_id);
+        with_document(&path, |document| {
+            ensure_text_extraction_allowed(document, &path)?;
+            let pages = document.pages();
+            let page_count = pages.len() as u32;
+            let mut total_matches = 0u32;
+
+            for offset in 0..page_count {
+                check_cancelled(operation.flag())?;
+                let page_index = (start_page.min(page_count.saturating_sub(1)) + offset) % page_count;
+                let Ok(page) = pages.get(page_index as u16) else { continue };
+                let Ok(text) = page.text() else { continue };
+                let chars = page_chars(&text);
+
+                for (start, end) in find_text_matches(&chars, &query, case_sensitive) {
+                    total_matches += 1;
+                    let event = PdfSearchEvent::Hit {
+                        hit: PdfSearchHit {
+                            page_index,
+                            char_start: start as u32,
+                            char_end: end as u32,
+                            rects: char_range_rects(&page, &text, start, end),
+                        },
+                        context_before: snippet(&chars[start.saturating_sub(SEARCH_SNIPPET_CHARS)..start]),
+                        context_after: snippet(&chars[end..(end + SEARCH_SNIPPET_CHARS).min(chars.len())]),
+                        total_matches,
+                    };
+                    if sink.add(event).is_err() {
+                        // Dart side stopped listening
+                        return Ok(());
+                    }
+                }
+            }
+
+            let _ = sink.add(PdfSearchEvent::Finished { total_matches });
+            Ok(())
+        })
+    })
+}
+
+/// Where a link points
+#[derive(Debug, Clone)]
+pub enum PdfLinkKind {
+    Uri(String),
+    Page(u32),
+}
+
+/// A clickable area of a page, normalized top-left like the text rects.
+#[derive(Debug, Clone)]
+pub struct PdfLink {
+    pub rect: PdfTextRect,
+    pub kind: PdfLinkKind,
+}
+
+fn link_kind(link: &pdfium_render::prelude::PdfLink) -> Option<PdfLinkKind> {
+    if let Some(destination) = link.destination() {
+        return destination.page_index().ok().map(|i| PdfLinkKind::Page(i as u32));
+    }
+    let action = link.action()?;
+    if let Some(uri) = action.as_uri_action() {
+        return uri.uri().ok().filter(|u| !u.is_empty()).map(PdfLinkKind::Uri);
+    }
+    let local = action.as_local_destination_action()?;
+    local.destination().ok()?.page_index().ok().map(|i| PdfLinkKind::Page(i as u32))
+}
+
+/// Link annotations of a page. Links whose target can't be resolved (e.g.
+/// launch or JavaScript actions) are skipped.
+#[hotpath::measure]
+pub fn get_pdf_page_links(path: String, page_index: u32) -> Result<Vec<PdfLink>, FerrousError> {
+    timed!("get_pdf_page_links", {
+        with_document(&path, |document| {
+            let page = get_pdf_page(document, page_index)?;
+            let page_rect = page.page_size();
+            let page_left = page_rect.left().value;
+            let page_bottom = page_rect.bottom().value;
+            let width = page_rect.width().value;
+            let height = page_rect.height().value;
+            if width <= 0.0 || height <= 0.0 {
+                return Ok(Vec::new());
+            }
+
+            let mut links = Vec::new();
+            for link in page.links().iter() {
+                let Some(kind) = link_kind(&link) else { continue };
+                let Ok(bounds) = link.rect() else { continue };
+                let left = (bounds.left().value - page_left) / width;
+                let right = (bounds.right().value - page_left) / width;
+                let top = 1.0 - ((bounds.top().value - page_bottom) / height);
+                let bottom = 1.0 - ((bounds.bottom().value - page_bottom) / height);
+                links.push(PdfLink {
+                    rect: PdfTextRect {
+                        left: left.min(right).clamp(0.0, 1.0),
+                        top: top.min(bottom).clamp(0.0, 1.0),
+                        right: left.max(right).clamp(0.0, 1.0),
+                        bottom: top.max(bottom).clamp(0.0, 1.0),
+                    },
+                    kind,
+                });
+            }
+            Ok(links)
+        })
+    })
+}
+
 pub fn test_pdf_module() -> String {
     "PDF module loaded successfully".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_space_rect_round_trip() {
+        let page_rect = PdfRect::new_from_values(0.0, 0.0, 800.0, 600.0);
+        let rect = PdfTextRect { left: 0.25, top: 0.1, right: 0.5, bottom: 0.2 };
+        let user = user_space_rect(&rect, &page_rect);
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+        assert!(close(user.left().value, 150.0));
+        assert!(close(user.right().value, 300.0));
+        assert!(close(user.top().value, 720.0));
+        assert!(close(user.bottom().value, 640.0));
+    }
+
+    #[test]
+    fn test_pdf_open_error_messages() {
+        let path = std::env::temp_dir().join("ferrous_test_missing.pdf");
+        let path = path.to_string_lossy();
+        let missing = ensure_pdf_header(&path).unwrap_err();
+        assert_eq!(missing, FerrousError::FileMissing(path.to_string()));
+        assert!(missing.to_string().starts_with("PDF_OPEN_ERROR::FILE:"));
+        assert!(missing.to_string().contains(&*path));
+
+        let page = FerrousError::PageError("book.pdf".to_string()).to_string();
+        assert!(page.starts_with("PDF_OPEN_ERROR::PAGE:") && page.contains("book.pdf"));
+        let security = FerrousError::Security("book.pdf".to_string()).to_string();
+        assert!(security.starts_with("PDF_OPEN_ERROR::SECURITY:") && security.contains("book.pdf"));
+        // A page failing in an open document is not an open error
+        let page_load = FerrousError::PageLoad { page_index: 3, detail: "Unknown".to_string() }.to_string();
+        assert!(page_load.starts_with("PDF_PAGE_ERROR:") && page_load.contains("page 3"));
+    }
+
+    #[test]
+    fn test_add_pdf_highlight_round_trip() {
+        // Needs the pdfium library; nothing to check where it can't be bound
+        let Ok(pdfium) = get_pdfium() else { return };
+        let path = std::env::temp_dir()
+            .join("ferrous_test_add_pdf_highlight.pdf")
+            .to_string_lossy()
+            .into_owned();
+        {
+            let mut document = pdfium.create_new_pdf().unwrap();
+            document.pages_mut().create_page_at_end(PdfPagePaperSize::a4()).unwrap();
+            document.save_to_file(&path).unwrap();
+        }
+
+        let rect = PdfTextRect { left: 0.1, top: 0.2, right: 0.5, bottom: 0.25 };
+        let highlighted = add_pdf_highlight(path.clone(), 0, vec![rect], 0x80FF0000);
+        let annotations = get_pdf_annotations(path.clone(), 0);
+        let _ = evict_pdf_document(path.clone());
+        let _ = std::fs::remove_file(&path);
+
+        highlighted.unwrap();
+        assert!(!Path::new(&format!("{path}.saving")).exists());
+        let annotations = annotations.unwrap();
+        assert_eq!(annotations.len(), 1);
+        let annotation = &annotations[0];
+        assert_eq!(annotation.kind, PdfAnnotationKind::Highlight);
+        assert_eq!(annotation.color.map(|color| color & 0xFFFFFF), Some(0xFF0000));
+        let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+        assert!(close(annotation.rect.left, rect.left));
+        assert!(close(annotation.rect.top, rect.top));
+        assert!(close(annotation.rect.right, rect.right));
+        assert!(close(annotation.rect.bottom, rect.bottom));
+    }
+
+    #[test]
+    fn test_find_text_matches() {
+        let chars: Vec<char> = "Rust and rust, RUST".chars().collect();
+        assert_eq!(find_text_matches(&chars, "rust", false), vec![(0, 4), (9, 13), (15, 19)]);
+        assert_eq!(find_text_matches(&chars, "rust", true), vec![(9, 13)]);
+        assert!(find_text_matches(&chars, "", false).is_empty());
+    }
+
+    #[test]
+    fn test_dehyphenate_text() {
+        assert_eq!(dehyphenate_text("informa-\ntion"), "information");
+        assert_eq!(dehyphenate_text("self-\naware"), "self-aware");
+        assert_eq!(dehyphenate_text("state-of-the-\r\n  art and see-\nsaw"), "state-of-the-art and seesaw");
+        assert_eq!(dehyphenate_text("1990-\n2000 and Anglo-\nSaxon"), "1990-\n2000 and Anglo-\nSaxon");
+        for (split, joined) in [
+            ("pro-\nduction", "production"),
+            ("so-\nlution", "solution"),
+            ("ex-\nample", "example"),
+            ("pre-\nsent", "present"),
+            ("so-\ncial", "social"),
+        ] {
+            assert_eq!(dehyphenate_text(split), joined);
+        }
+        assert_eq!(dehyphenate_text("well-\nknown"), "well-known");
+    }
+
+    #[test]
+    fn test_blend_highlight_rects() {
+        let mut image = image::RgbaImage::from_pixel(4, 2, image::Rgba([255, 255, 255, 255]));
+        let rect = PdfTextRect { left: 0.5, top: 0.0, right: 1.0, bottom: 0.5 };
+        blend_highlight_rects(&mut image, &[rect], 0x80FF0000);
+        assert_eq!(image.get_pixel(1, 0).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(2, 0).0, [255, 127, 127, 255]);
+        assert_eq!(image.get_pixel(3, 1).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_font_type_detection() {
+        assert_eq!(font_type_from_name("ABCDEF+MSMincho-Identity-H"), Some("Type0"));
+        assert_eq!(font_type_from_name("Arial,BoldItalic"), Some("TrueType"));
+        assert_eq!(font_type_from_name("Helvetica-Bold"), Some("Type1"));
+        assert_eq!(font_type_from_name("ABCDEF+Minion-Regular"), None);
+
+        // Header, Name INDEX with "A", Top DICT INDEX starting with ROS
+        let cid_cff = [1, 0, 4, 1, 0, 1, 1, 1, 2, b'A', 0, 1, 1, 1, 6, 139, 139, 139, 12, 30];
+        assert!(is_cid_keyed_cff(&cid_cff));
+        let plain_cff = [1, 0, 4, 1, 0, 1, 1, 1, 2, b'A', 0, 1, 1, 1, 3, 139, 0];
+        assert!(!is_cid_keyed_cff(&plain_cff));
+        assert_eq!(sniff_font_type(&cid_cff), "Type0");
+        assert_eq!(sniff_font_type(&plain_cff), "CFF");
+    }
+
+    #[test]
+    fn test_default_hidden_layers() {
+        use std::io::Write;
+
+        let dir = std::env::temp_dir();
+        // /OFF names the hidden groups; a later revision of object 1 wins
+        let plain = dir.join("ferrous_test_layers_off.pdf");
+        std::fs::write(
+            &plain,
+            b"%PDF-1.5\n\
+              1 0 obj << /Type /Catalog >> endobj\n\
+              1 0 obj << /Type /Catalog /OCProperties << /OCGs [2 0 R 3 0 R] /D << /OFF [3 0 R] >> >> >> endobj\n\
+              2 0 obj << /Type /OCG /Name (Text) >> endobj\n\
+              3 0 obj << /Type /OCG /Name <FEFF004E006F007400650073> >> endobj\n\
+              trailer << /Root 1 0 R >>\n%%EOF\n",
+        )
+        .unwrap();
+        let hidden = default_hidden_layers(&plain.to_string_lossy());
+        let _ = std::fs::remove_file(&plain);
+        assert_eq!(hidden, vec!["Notes".to_string()]);
+
+        // /BaseState /OFF hides everything not in /ON, here from an object stream
+        let objects = b"2 0 3 31 << /Type /OCG /Name (Text) >> << /Type /OCG /Name (Ink\\051) >>";
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(objects).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut data = b"%PDF-1.5\n\
+            1 0 obj << /Type /Catalog /OCProperties << /OCGs [2 0 R 3 0 R] /D << /BaseState /OFF /ON [2 0 R] >> >> >> endobj\n"
+            .to_vec();
+        data.extend_from_slice(
+            format!("4 0 obj << /Type /ObjStm /N 2 /First 8 /Filter /FlateDecode /Length {} >>\nstream\n", compressed.len())
+                .as_bytes(),
+        );
+        data.extend_from_slice(&compressed);
+        data.extend_from_slice(b"\nendstream\nendobj\ntrailer << /Root 1 0 R >>\n%%EOF\n");
+        let streamed = dir.join("ferrous_test_layers_base_off.pdf");
+        std::fs::write(&streamed, data).unwrap();
+        let hidden = default_hidden_layers(&streamed.to_string_lossy());
+        let _ = std::fs::remove_file(&streamed);
+        assert_eq!(hidden, vec!["Ink)".to_string()]);
+
+        assert!(default_hidden_layers(&dir.join("ferrous_test_layers_missing.pdf").to_string_lossy()).is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Oldest samples are dropped past this many, so a forgotten profiler can't
+/// grow without bound.
+const MAX_PROFILING_SAMPLES: usize = 10_000;
+
+/// `timed!` logs blocks slower than this many milliseconds
+static TIMED_THRESHOLD_MS: AtomicU64 = AtomicU64::new(10);
+
+static PROFILING_ENABLED: AtomicBool = AtomicBool::new(false);
+static PROFILING_SAMPLES: Mutex<VecDeque<TimedSample>> = Mutex::new(VecDeque::new());
+
+/// Duration of one `timed!` block
+#[derive(Debug, Clone)]
+pub struct TimedSample {
+    pub name: String,
+    pub millis: f64,
+}
+
+/// Set how slow (in ms) a `timed!` block must be to get logged; 10 by default.
+pub fn set_timed_threshold_ms(threshold_ms: u64) {
+    TIMED_THRESHOLD_MS.store(threshold_ms, Ordering::Relaxed);
+}
+
+pub(crate) fn timed_threshold_ms() -> u64 {
+    TIMED_THRESHOLD_MS.load(Ordering::Relaxed)
+}
+
+/// Start or stop recording every `timed!` block. Off by default; turning it
+/// off keeps the samples recorded so far.
+pub fn set_profiling_enabled(enabled: bool) {
+    PROFILING_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Samples recorded since the last call, oldest first.
+pub fn take_profiling_samples() -> Vec<TimedSample> {
+    let mut samples = PROFILING_SAMPLES.lock().unwrap_or_else(|p| p.into_inner());
+    std::mem::take(&mut *samples).into()
+}
+
+/// Called by `timed!`; a no-op unless profiling is enabled.
+pub(crate) fn record_timed_sample(name: &str, elapsed: Duration) {
+    if !PROFILING_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut samples = PROFILING_SAMPLES.lock().unwrap_or_else(|p| p.into_inner());
+    if samples.len() >= MAX_PROFILING_SAMPLES {
+        samples.pop_front();
+    }
+    samples.push_back(TimedSample {
+        name: name.to_string(),
+        millis: elapsed.as_secs_f64() * 1000.0,
+    });
+}
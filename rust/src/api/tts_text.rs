@@ -1,6 +1,9 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use ego_tree::{NodeId, Tree};
 use regex::Regex;
-use scraper::{Html, Selector};
+use scraper::node::Text;
+use scraper::{Html, Node, Selector, StrTendril};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::OnceLock;
 use unicode_segmentation::UnicodeSegmentation;
 use crate::timed;
@@ -20,40 +23,122 @@ pub struct SentenceSpan {
     pub end: u32,
 }
 
+/// A paragraph span with character offsets into the normalized text
+#[derive(Debug, Clone)]
+pub struct ParagraphSpan {
+    pub start: u32,
+    pub end: u32,
+}
+
 /// Pre-computed text highlight data for fast TTS highlighting
 #[derive(Debug, Clone)]
 pub struct TextHighlightData {
     pub words: Vec<WordSpan>,
     pub sentences: Vec<SentenceSpan>,
+    /// Paragraphs as found in the source text before whitespace collapsing
+    pub paragraphs: Vec<ParagraphSpan>,
     pub normalized_text: String,
+    /// Char offset in the source text of each char of `normalized_text`
+    pub normalized_to_raw: Vec<u32>,
+}
+
+/// Normalize text for TTS (trim, collapse whitespace runs to one space,
+/// drop zero-width spaces) and record where each normalized char came from
+/// in `text`, as a char offset. A collapsed run maps to its first char.
+fn normalize_text_with_offsets(text: &str) -> (String, Vec<u32>) {
+    let mut normalized = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+    let mut started = false;
+    let mut pending_space: Option<u32> = None;
+    for (raw, c) in text.chars().enumerate() {
+        let raw = raw as u32;
+        if c.is_whitespace() {
+            if started && pending_space.is_none() {
+                pending_space = Some(raw);
+            }
+            continue;
+        }
+        // A zero-width space is removed only after whitespace collapsing,
+        // so it still splits a run and stops trimming
+        started = true;
+        if let Some(space) = pending_space.take() {
+            normalized.push(' ');
+            offsets.push(space);
+        }
+        if c != '\u{200B}' {
+            normalized.push(c);
+            offsets.push(raw);
+        }
+    }
+    (normalized, offsets)
 }
 
-// Pre-compiled regex for whitespace normalization
-static WHITESPACE_REGEX: OnceLock<Regex> = OnceLock::new();
+// Blank lines in plain text; `</p>` or two `<br>`s in HTML
+static PARAGRAPH_BREAK_REGEX: OnceLock<Regex> = OnceLock::new();
 
-fn get_whitespace_regex() -> &'static Regex {
-    WHITESPACE_REGEX.get_or_init(|| Regex::new(r"\s+").unwrap())
+fn get_paragraph_break_regex() -> &'static Regex {
+    PARAGRAPH_BREAK_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)\n[^\S\n]*\n|</p\s*>|<br\s*/?>\s*<br\s*/?>").unwrap()
+    })
 }
 
-/// Normalize text for TTS (collapse whitespace, trim)
-fn normalize_text(text: &str) -> String {
-    let regex = get_whitespace_regex();
-    regex
-        .replace_all(text.trim(), " ")
-        .replace('\u{00A0}', " ")
-        .replace('\u{200B}', "")
-        .to_string()
+/// Paragraph spans in normalized offsets, split at the paragraph breaks of
+/// the raw `text` and trimmed of the spaces left around them.
+fn paragraph_spans(text: &str, normalized: &str, normalized_to_raw: &[u32]) -> Vec<ParagraphSpan> {
+    // Raw char ranges between breaks
+    let mut segments = Vec::new();
+    let mut segment_start = 0u32;
+    let mut chars_before = 0u32;
+    let mut last_byte = 0usize;
+    for found in get_paragraph_break_regex().find_iter(text) {
+        chars_before += text[last_byte..found.start()].chars().count() as u32;
+        segments.push((segment_start, chars_before));
+        chars_before += found.as_str().chars().count() as u32;
+        segment_start = chars_before;
+        last_byte = found.end();
+    }
+    segments.push((segment_start, u32::MAX));
+
+    let is_space: Vec<bool> = normalized.chars().map(|c| c == ' ').collect();
+    let mut paragraphs = Vec::new();
+    for (raw_start, raw_end) in segments {
+        let from = normalized_to_raw.partition_point(|&raw| raw < raw_start);
+        let to = normalized_to_raw.partition_point(|&raw| raw < raw_end);
+        let Some(first) = (from..to).find(|&i| !is_space[i]) else { continue };
+        let last = (from..to).rev().find(|&i| !is_space[i]).unwrap_or(first);
+        paragraphs.push(ParagraphSpan {
+            start: first as u32,
+            end: last as u32 + 1,
+        });
+    }
+    paragraphs
+}
+
+/// Translate a normalized char range `[start, end)` of `data` into the
+/// matching char range of the text it was computed from.
+pub fn normalized_range_to_raw(data: &TextHighlightData, start: u32, end: u32) -> (u32, u32) {
+    let map = &data.normalized_to_raw;
+    let raw_len = map.last().map_or(0, |last| last + 1);
+    let start = start as usize;
+    let end = (end as usize).min(map.len());
+    let raw_start = map.get(start).copied().unwrap_or(raw_len);
+    if end <= start {
+        return (raw_start, raw_start);
+    }
+    (raw_start, map[end - 1] + 1)
 }
 
 pub fn precompute_text_highlights(text: String) -> TextHighlightData {
     timed!("precompute_text_highlights", {
-        let normalized = normalize_text(&text);
+        let (normalized, normalized_to_raw) = normalize_text_with_offsets(&text);
         
         if normalized.is_empty() {
             return TextHighlightData {
                 words: Vec::new(),
                 sentences: Vec::new(),
+                paragraphs: Vec::new(),
                 normalized_text: normalized,
+                normalized_to_raw,
             };
         }
         
@@ -99,11 +184,90 @@ pub fn precompute_text_highlights(text: String) -> TextHighlightData {
             char_offset += sentence_len;
         }
         
+        let paragraphs = paragraph_spans(&text, &normalized, &normalized_to_raw);
+
         TextHighlightData {
             words,
             sentences,
+            paragraphs,
             normalized_text: normalized,
+            normalized_to_raw,
+        }
+    })
+}
+
+/// Estimated reading time of a text
+#[derive(Debug, Clone, Copy)]
+pub struct ReadingTime {
+    pub word_count: u32,
+    pub minutes: u32,
+}
+
+/// Reading speed used when the caller passes 0 words per minute.
+const DEFAULT_WORDS_PER_MINUTE: u32 = 230;
+/// Slower speeds are clamped to this.
+const MIN_WORDS_PER_MINUTE: u32 = 50;
+
+/// Word count and reading time, rounded up to whole minutes, at
+/// `words_per_minute` (0 for the default speed). Words are the
+/// word-boundary segments `precompute_text_highlights` yields, minus
+/// punctuation-only ones.
+pub fn estimate_reading_time(text: String, words_per_minute: u32) -> ReadingTime {
+    timed!("estimate_reading_time", {
+        let words_per_minute = match words_per_minute {
+            0 => DEFAULT_WORDS_PER_MINUTE,
+            wpm => wpm.max(MIN_WORDS_PER_MINUTE),
+        };
+        let word_count = text
+            .split_word_bounds()
+            .filter(|word| word.chars().any(char::is_alphanumeric))
+            .count() as u32;
+        ReadingTime {
+            word_count,
+            minutes: word_count.div_ceil(words_per_minute),
+        }
+    })
+}
+
+/// Below this whatlang confidence `detect_text_language` gives no answer
+const LANGUAGE_CONFIDENCE_THRESHOLD: f64 = 0.5;
+
+/// ISO 639-3 codes whatlang reports that have a two-letter ISO 639-1 form,
+/// which BCP-47 prefers
+const ISO_639_1_CODES: &[(&str, &str)] = &[
+    ("afr", "af"), ("aka", "ak"), ("amh", "am"), ("ara", "ar"), ("aze", "az"),
+    ("bel", "be"), ("ben", "bn"), ("bul", "bg"), ("cat", "ca"), ("ces", "cs"),
+    ("cmn", "zh"), ("dan", "da"), ("deu", "de"), ("ell", "el"), ("eng", "en"),
+    ("epo", "eo"), ("est", "et"), ("fin", "fi"), ("fra", "fr"), ("guj", "gu"),
+    ("heb", "he"), ("hin", "hi"), ("hrv", "hr"), ("hun", "hu"), ("hye", "hy"),
+    ("ind", "id"), ("ita", "it"), ("jav", "jv"), ("jpn", "ja"), ("kan", "kn"),
+    ("kat", "ka"), ("khm", "km"), ("kor", "ko"), ("lat", "la"), ("lav", "lv"),
+    ("lit", "lt"), ("mal", "ml"), ("mar", "mr"), ("mkd", "mk"), ("mya", "my"),
+    ("nep", "ne"), ("nld", "nl"), ("nob", "nb"), ("ori", "or"), ("pan", "pa"),
+    ("pes", "fa"), ("pol", "pl"), ("por", "pt"), ("ron", "ro"), ("rus", "ru"),
+    ("sin", "si"), ("slk", "sk"), ("slv", "sl"), ("sna", "sn"), ("spa", "es"),
+    ("srp", "sr"), ("swe", "sv"), ("tam", "ta"), ("tel", "te"), ("tgl", "tl"),
+    ("tha", "th"), ("tuk", "tk"), ("tur", "tr"), ("ukr", "uk"), ("urd", "ur"),
+    ("uzb", "uz"), ("vie", "vi"), ("yid", "yi"), ("zul", "zu"),
+];
+
+/// BCP-47 code ("en", "ru", "zh", ...) of the language `text` is written
+/// in, detected with whatlang over the normalized text. `None` when the
+/// text is too short or mixed to tell with reasonable confidence, so the
+/// caller can fall back to the user's default voice.
+pub fn detect_text_language(text: String) -> Option<String> {
+    timed!("detect_text_language", {
+        let (normalized, _) = normalize_text_with_offsets(&text);
+        let info = whatlang::detect(&normalized)?;
+        if info.confidence() < LANGUAGE_CONFIDENCE_THRESHOLD {
+            return None;
         }
+        let code = info.lang().code();
+        let code = ISO_639_1_CODES
+            .iter()
+            .find(|(iso3, _)| *iso3 == code)
+            .map_or(code, |&(_, iso1)| iso1);
+        Some(code.to_string())
     })
 }
 
@@ -119,74 +283,567 @@ pub fn find_sentence_for_offset(
     sentences.last().cloned()
 }
 
+fn escape_ssml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// SSML for the normalized text: one `<s>` per sentence and a
+/// `<mark name="w{index}"/>` before each word, `index` being the word's
+/// position in `data.words`, so engines report word timings back.
+pub fn to_ssml(data: &TextHighlightData, voice: Option<String>) -> String {
+    let mut ssml = String::with_capacity(data.normalized_text.len() * 2 + 64);
+    ssml.push_str("<speak>");
+    if let Some(voice) = &voice {
+        ssml.push_str(&format!("<voice name=\"{}\">", escape_ssml(voice)));
+    }
+
+    let mut words = data.words.iter().enumerate().peekable();
+    let mut sentences = data.sentences.iter().peekable();
+    let mut open_sentence: Option<u32> = None;
+    let mut buf = [0u8; 4];
+    for (offset, c) in data.normalized_text.chars().enumerate() {
+        let offset = offset as u32;
+        if open_sentence.is_none() {
+            while sentences.next_if(|s| s.end <= offset).is_some() {}
+            if let Some(sentence) = sentences.next_if(|s| s.start <= offset) {
+                ssml.push_str("<s>");
+                open_sentence = Some(sentence.end);
+            }
+        }
+        while let Some((index, _)) = words.next_if(|(_, w)| w.start <= offset) {
+            ssml.push_str(&format!("<mark name=\"w{index}\"/>"));
+        }
+        ssml.push_str(&escape_ssml(c.encode_utf8(&mut buf)));
+        if open_sentence == Some(offset + 1) {
+            ssml.push_str("</s>");
+            open_sentence = None;
+        }
+    }
+    if open_sentence.is_some() {
+        ssml.push_str("</s>");
+    }
+
+    if voice.is_some() {
+        ssml.push_str("</voice>");
+    }
+    ssml.push_str("</speak>");
+    ssml
+}
+
+/// A normalized char range `[start, end)` to wrap in `<tag_name>`
+#[derive(Debug, Clone)]
+pub struct HighlightRange {
+    pub start: u32,
+    pub end: u32,
+    pub tag_name: String,
+}
+
+/// Wrap the characters `[highlight_start, highlight_end)` of the normalized
+/// text (the offsets `precompute_text_highlights` produces) in `<tag_name>`.
+/// The body's text nodes are normalized together and the range mapped back
+/// through `normalize_text_with_offsets`; every text node it touches is
+/// split and its part wrapped separately, so ranges crossing
+/// element boundaries still give well-formed HTML. Input without
+/// `<html>`/`<body>` is treated as a fragment and returned as one.
 pub fn insert_html_highlight(
     html: String,
     highlight_start: u32,
     highlight_end: u32,
     tag_name: String,
 ) -> Result<String> {
-    if highlight_start >= highlight_end {
+    insert_html_highlights(
+        html,
+        vec![HighlightRange {
+            start: highlight_start,
+            end: highlight_end,
+            tag_name,
+        }],
+    )
+}
+
+/// A body text node: its id, text, and whether highlights may wrap it
+/// (text of `<script>`, `<style>`, `<textarea>` and `<title>` may not)
+type BodyTextNode = (NodeId, String, bool);
+
+/// Parse `html` as a document when it has `<html>`/`<body>`, else as a
+/// fragment, and list the text nodes of its body in document order.
+fn parse_body_text_nodes(html: &str) -> Result<(Html, bool, Vec<BodyTextNode>)> {
+    let lower = html.to_ascii_lowercase();
+    let is_document = lower.contains("<html") || lower.contains("<body");
+    let document = if is_document {
+        Html::parse_document(html)
+    } else {
+        Html::parse_fragment(html)
+    };
+
+    // Body text only, like the text the reader hands to TTS
+    let scope = if is_document {
+        let body = Selector::parse("body").map_err(|e| anyhow!("{e:?}"))?;
+        document.select(&body).next().unwrap_or_else(|| document.root_element())
+    } else {
+        document.root_element()
+    };
+    let text_nodes = scope
+        .descendants()
+        .filter_map(|node| {
+            let text = node.value().as_text()?;
+            let wrappable = node
+                .parent()
+                .and_then(|parent| parent.value().as_element().map(|e| e.name().to_string()))
+                .is_none_or(|name| !matches!(name.as_str(), "script" | "style" | "textarea" | "title"));
+            Some((node.id(), text.to_string(), wrappable))
+        })
+        .collect();
+    Ok((document, is_document, text_nodes))
+}
+
+/// The text nodes' text joined, and the char offset each node starts at
+fn join_text_nodes(text_nodes: &[BodyTextNode]) -> (String, Vec<u32>) {
+    let mut node_starts = Vec::with_capacity(text_nodes.len());
+    let mut joined = String::new();
+    let mut joined_len = 0u32;
+    for (_, text, _) in text_nodes {
+        node_starts.push(joined_len);
+        joined.push_str(text);
+        joined_len += text.chars().count() as u32;
+    }
+    (joined, node_starts)
+}
+
+/// Index of the text node holding joined char `raw`, and the char offset
+/// of `raw` within it
+fn text_node_at(node_starts: &[u32], raw: u32) -> (usize, u32) {
+    let node = node_starts.partition_point(|&start| start <= raw).saturating_sub(1);
+    (node, raw - node_starts.get(node).copied().unwrap_or(0))
+}
+
+/// Node `node` goes to: appended to the innermost open wrapper, or before
+/// the text node being split when none is open
+fn place_node(tree: &mut Tree<Node>, anchor: NodeId, open: &[(NodeId, usize)], node: Node) -> Option<NodeId> {
+    let id = match open.last() {
+        Some(&(wrapper, _)) => tree.get_mut(wrapper)?.append(node).id(),
+        None => tree.get_mut(anchor)?.insert_before(node).id(),
+    };
+    Some(id)
+}
+
+/// Like `insert_html_highlight` with several ranges at once, e.g. the
+/// current word inside the current sentence. Ranges are applied in one
+/// pass: a range inside another is wrapped inside its element (an equal
+/// range nests in the one listed first), while ranges that cross each
+/// other are rejected since their elements could not nest.
+pub fn insert_html_highlights(html: String, ranges: Vec<HighlightRange>) -> Result<String> {
+    let mut ranges: Vec<HighlightRange> = ranges.into_iter().filter(|r| r.start < r.end).collect();
+    if ranges.is_empty() {
         return Ok(html);
     }
-    
-    let _document = Html::parse_document(&html);
-    
-    let _body_selector = Selector::parse("body").unwrap_or_else(|_| Selector::parse("*").unwrap());
-    let text_content = extract_text_from_html(&html);
-    let normalized = normalize_text(&text_content);
-    
+    for range in &ranges {
+        let tag_name = &range.tag_name;
+        let valid_tag = tag_name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && tag_name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !valid_tag {
+            return Err(anyhow!("Invalid highlight tag name: {tag_name:?}"));
+        }
+    }
+    // Outer ranges first, so each one opens before those it contains
+    ranges.sort_by_key(|r| (r.start, std::cmp::Reverse(r.end)));
+    let mut open_ends: Vec<u32> = Vec::new();
+    for range in &ranges {
+        while open_ends.last().is_some_and(|&end| end <= range.start) {
+            open_ends.pop();
+        }
+        if let Some(&end) = open_ends.last() {
+            if range.end > end {
+                return Err(anyhow!(
+                    "Highlight range {}..{} overlaps another range ending at {end}",
+                    range.start,
+                    range.end
+                ));
+            }
+        }
+        open_ends.push(range.end);
+    }
+
+    let (mut document, is_document, text_nodes) = parse_body_text_nodes(&html)?;
+    let (joined, node_starts) = join_text_nodes(&text_nodes);
+    let (normalized, normalized_to_raw) = normalize_text_with_offsets(&joined);
     if normalized.is_empty() {
         return Ok(html);
     }
-    
-    // Build mapping from normalized offset to raw offset
-    let _text_regex = get_whitespace_regex();
-    let _raw_text = extract_text_from_html(&html);
-    let start = highlight_start as usize;
-    let end = highlight_end.min(normalized.len() as u32) as usize;
-    
-    if start >= normalized.len() || end <= start {
+
+    // Per text node, the raw char span `(from, to, range)` each range covers
+    let mut spans: Vec<Vec<(usize, usize, usize)>> = vec![Vec::new(); text_nodes.len()];
+    for (index, range) in ranges.iter().enumerate() {
+        let end = (range.end as usize).min(normalized_to_raw.len());
+        for &raw in normalized_to_raw.get(range.start as usize..end).unwrap_or_default() {
+            let (node, raw) = text_node_at(&node_starts, raw);
+            let raw = raw as usize;
+            match spans[node].last_mut() {
+                Some(span) if span.2 == index => span.1 = raw + 1,
+                _ => spans[node].push((raw, raw + 1, index)),
+            }
+        }
+    }
+    if spans.iter().all(Vec::is_empty) {
         return Ok(html);
     }
-    
-    let highlight_text = &normalized[start..end];
-    
-    if let Some(pos) = html.find(highlight_text) {
-        let mut result = String::with_capacity(html.len() + tag_name.len() * 2 + 10);
-        result.push_str(&html[..pos]);
-        result.push('<');
-        result.push_str(&tag_name);
-        result.push('>');
-        result.push_str(highlight_text);
-        result.push_str("</");
-        result.push_str(&tag_name);
-        result.push('>');
-        result.push_str(&html[pos + highlight_text.len()..]);
-        return Ok(result);
-    }
-    
-    Ok(html)
-}
-
-fn extract_text_from_html(html: &str) -> String {
-    let document = Html::parse_document(html);
-    let selector = Selector::parse("body").ok();
-    
-    let mut text = String::new();
-    
-    if let Some(sel) = selector {
-        for element in document.select(&sel) {
-            text.push_str(&element.text().collect::<String>());
+
+    let mut wrappers = Vec::with_capacity(ranges.len());
+    for range in &ranges {
+        let tag_name = &range.tag_name;
+        let template = Html::parse_fragment(&format!("<{tag_name}></{tag_name}>"));
+        let wrapper = template
+            .root_element()
+            .children()
+            .find(|node| node.value().as_element().is_some_and(|e| e.name() == tag_name.to_ascii_lowercase()))
+            .map(|node| node.value().clone())
+            .ok_or_else(|| anyhow!("Failed to build <{tag_name}> element"))?;
+        wrappers.push(wrapper);
+    }
+    let text_node = |chars: &[char]| {
+        let text: String = chars.iter().collect();
+        Node::Text(Text { text: StrTendril::from(text.as_str()) })
+    };
+
+    for ((id, text, wrappable), mut node_spans) in text_nodes.into_iter().zip(spans) {
+        if !wrappable || node_spans.is_empty() {
+            continue;
         }
+        // Stable, so of two equal spans the earlier range stays outside
+        node_spans.sort_by_key(|&(from, to, _)| (from, std::cmp::Reverse(to)));
+        let chars: Vec<char> = text.chars().collect();
+        let tree = &mut document.tree;
+        // Wrappers still open, innermost last, with the raw end they close at
+        let mut open: Vec<(NodeId, usize)> = Vec::new();
+        let mut pos = 0;
+        for (from, to, index) in node_spans {
+            while let Some(&(_, end)) = open.last().filter(|&&(_, end)| end <= from) {
+                if pos < end {
+                    place_node(tree, id, &open, text_node(&chars[pos..end]));
+                    pos = end;
+                }
+                open.pop();
+            }
+            if pos < from {
+                place_node(tree, id, &open, text_node(&chars[pos..from]));
+                pos = from;
+            }
+            if let Some(wrapper) = place_node(tree, id, &open, wrappers[index].clone()) {
+                open.push((wrapper, to));
+            }
+        }
+        while let Some(&(_, end)) = open.last() {
+            if pos < end {
+                place_node(tree, id, &open, text_node(&chars[pos..end]));
+                pos = end;
+            }
+            open.pop();
+        }
+        if pos < chars.len() {
+            place_node(tree, id, &open, text_node(&chars[pos..]));
+        }
+        if let Some(mut node) = tree.get_mut(id) {
+            node.detach();
+        }
+    }
+
+    Ok(if is_document {
+        document.html()
     } else {
-        for node in document.root_element().descendants() {
-            if let Some(text_node) = node.value().as_text() {
-                text.push_str(text_node);
+        document.root_element().inner_html()
+    })
+}
+
+/// A word of `HtmlHighlightData`, located both in the normalized text and
+/// in the HTML it came from
+#[derive(Debug, Clone)]
+pub struct HtmlWordSpan {
+    pub start: u32,
+    pub end: u32,
+    pub text: String,
+    /// Index of the text node the word starts in, counting the body's text
+    /// nodes in document order
+    pub node_index: u32,
+    /// Char offset of the word's first char within that text node
+    pub node_offset: u32,
+}
+
+/// `TextHighlightData` for the body text of an HTML chapter, with each
+/// word mapped back to its text node
+#[derive(Debug, Clone)]
+pub struct HtmlHighlightData {
+    pub words: Vec<HtmlWordSpan>,
+    pub sentences: Vec<SentenceSpan>,
+    pub paragraphs: Vec<ParagraphSpan>,
+    pub normalized_text: String,
+    /// Number of body text nodes `node_index` counts through
+    pub text_node_count: u32,
+}
+
+/// Strip `html` to the normalized text of its body, the same text and
+/// offsets `insert_html_highlight` works on, and locate every word in the
+/// DOM by text node index and offset. Text nodes are counted in document
+/// order, including whitespace-only ones, so a renderer walking the same
+/// DOM can find a word without searching the HTML for it.
+pub fn html_to_highlight_data(html: String) -> Result<HtmlHighlightData> {
+    timed!("html_to_highlight_data", {
+        let (_, _, text_nodes) = parse_body_text_nodes(&html)?;
+        let (joined, node_starts) = join_text_nodes(&text_nodes);
+        let data = precompute_text_highlights(joined);
+
+        let words = data
+            .words
+            .into_iter()
+            .map(|word| {
+                let raw = data.normalized_to_raw.get(word.start as usize).copied().unwrap_or(0);
+                let (node, offset) = text_node_at(&node_starts, raw);
+                HtmlWordSpan {
+                    start: word.start,
+                    end: word.end,
+                    text: word.text,
+                    node_index: node as u32,
+                    node_offset: offset,
+                }
+            })
+            .collect();
+
+        Ok(HtmlHighlightData {
+            words,
+            sentences: data.sentences,
+            paragraphs: data.paragraphs,
+            normalized_text: data.normalized_text,
+            text_node_count: text_nodes.len() as u32,
+        })
+    })
+}
+
+/// Largest number `expand_for_speech` spells out; bigger ones stay digits
+static SPELLED_NUMBER_MAX: AtomicU64 = AtomicU64::new(999_999);
+/// `number_words` names scales up to trillions
+const SPELLABLE_MAX: u64 = 999_999_999_999_999;
+
+/// Set the largest number `expand_for_speech` spells out in words.
+pub fn set_spelled_number_max(max: u64) {
+    SPELLED_NUMBER_MAX.store(max, Ordering::Relaxed);
+}
+
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const SCALES: [(u64, &str); 4] = [
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+fn push_below_thousand(n: u64, words: &mut Vec<String>) {
+    if n >= 100 {
+        words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        let tens = TENS[(rest / 10) as usize];
+        words.push(match rest % 10 {
+            0 => tens.to_string(),
+            ones => format!("{tens}-{}", ONES[ones as usize]),
+        });
+    } else if rest > 0 {
+        words.push(ONES[rest as usize].to_string());
+    }
+}
+
+/// English cardinal of `n` ("two hundred twenty-one"), up to `SPELLABLE_MAX`
+fn number_words(n: u64) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut words = Vec::new();
+    let mut rest = n;
+    for (scale, name) in SCALES {
+        if rest >= scale {
+            push_below_thousand(rest / scale, &mut words);
+            words.push(name.to_string());
+            rest %= scale;
+        }
+    }
+    push_below_thousand(rest, &mut words);
+    words.join(" ")
+}
+
+/// Years read in pairs: 1984 is "nineteen eighty-four", 1905 "nineteen oh five"
+fn year_words(n: u64) -> String {
+    let (century, rest) = (n / 100, n % 100);
+    match rest {
+        0 => format!("{} hundred", number_words(century)),
+        1..=9 => format!("{} oh {}", number_words(century), ONES[rest as usize]),
+        _ => format!("{} {}", number_words(century), number_words(rest)),
+    }
+}
+
+/// English ordinal of `n` ("twenty-first")
+fn ordinal_words(n: u64) -> String {
+    let words = number_words(n);
+    let split = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        word if word.ends_with('y') => format!("{}ieth", &word[..word.len() - 1]),
+        word => format!("{word}th"),
+    };
+    format!("{head}{last}")
+}
+
+static ABBREVIATION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_abbreviation_regex() -> &'static Regex {
+    ABBREVIATION_REGEX.get_or_init(|| {
+        Regex::new(r"\b(?:Mrs|Mr|Ms|Dr|St|Prof|Jr|Sr|Mt|Capt|Gen|Lt|Col|Sgt|vs|etc|approx|No|e\.g|i\.e)\.").unwrap()
+    })
+}
+
+static NUMBER_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_number_regex() -> &'static Regex {
+    NUMBER_REGEX.get_or_init(|| Regex::new(r"[0-9]+(?:st|nd|rd|th)?").unwrap())
+}
+
+/// Whether `text[start..end]` is a number on its own rather than part of
+/// a word, a decimal, a time or a date
+fn is_standalone_number(text: &str, start: usize, end: usize) -> bool {
+    let mut before = text[..start].chars().rev();
+    match before.next() {
+        Some(c) if c.is_alphanumeric() || c == '_' => return false,
+        Some('.' | ',' | ':' | '/') if before.next().is_some_and(|c| c.is_ascii_digit()) => return false,
+        _ => {}
+    }
+    let mut after = text[end..].chars();
+    match after.next() {
+        Some(c) => {
+            if c.is_alphanumeric() || c == '_' {
+                false
+            } else if matches!(c, '.' | ',' | ':' | '/') {
+                !after.next().is_some_and(|c| c.is_ascii_digit())
+            } else {
+                true
             }
         }
+        None => true,
     }
-    
-    text
+}
+
+/// Whether the word just before an abbreviation, at the end of `before`,
+/// is a street name ("Main St.", "5th Ave"): capitalized or a number, and
+/// not merely the capitalized first word of a sentence ("Then Dr. Jones")
+fn follows_street_name(before: &str) -> bool {
+    let before = before.trim_end();
+    let word_start = before.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let is_name = before[word_start..]
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_uppercase() || c.is_ascii_digit());
+    let preceding = before[..word_start].trim_end();
+    is_name && !preceding.is_empty() && !preceding.ends_with(['.', '!', '?'])
+}
+
+/// Rewrite `text` the way it should be spoken, for the synthesizer only:
+/// common abbreviations are expanded ("Dr. Smith" to "Doctor Smith", "Elm
+/// St." to "Elm Street"), standalone numbers up to the limit set with
+/// `set_spelled_number_max` are spelled out, four-digit numbers from 1100
+/// to 1999 read as years and "21st" as "twenty-first". Decimals, times and
+/// numbers inside words are left alone. Offsets change, so highlighting
+/// keeps using `precompute_text_highlights` on the on-screen text. Only
+/// English (`locale` "en", "en-US", ... or empty) is expanded; text in
+/// other locales is returned unchanged.
+pub fn expand_for_speech(text: String, locale: String) -> String {
+    timed!("expand_for_speech", {
+        let language = locale.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        if !language.is_empty() && language != "en" {
+            return text;
+        }
+
+        let expanded = get_abbreviation_regex().replace_all(&text, |caps: &regex::Captures| {
+            let Some(found) = caps.get(0) else { return String::new() };
+            let next = text[found.end()..].trim_start().chars().next();
+            let before_capital = next.is_some_and(char::is_uppercase);
+            let after_street_name = follows_street_name(&text[..found.start()]);
+            // Titles always lead into a name, so a capital after them says
+            // nothing; the rest end the sentence when a capital or the end
+            // of the text follows, and that period is kept
+            let (expansion, can_end_sentence) = match found.as_str().trim_end_matches('.') {
+                "Mr" => ("Mister", false),
+                "Mrs" => ("Missus", false),
+                "Ms" => ("Miz", false),
+                "Dr" if before_capital && !after_street_name => ("Doctor", false),
+                "Dr" => ("Drive", true),
+                "St" if before_capital && !after_street_name => ("Saint", false),
+                "St" => ("Street", true),
+                "Prof" => ("Professor", false),
+                "Jr" => ("Junior", true),
+                "Sr" => ("Senior", true),
+                "Mt" => ("Mount", false),
+                "Capt" => ("Captain", false),
+                "Gen" => ("General", false),
+                "Lt" => ("Lieutenant", false),
+                "Col" => ("Colonel", false),
+                "Sgt" => ("Sergeant", false),
+                "vs" => ("versus", false),
+                "etc" => ("et cetera", true),
+                "approx" => ("approximately", true),
+                "No" if next.is_some_and(|c| c.is_ascii_digit()) => ("number", false),
+                "e.g" => ("for example", false),
+                "i.e" => ("that is", false),
+                _ => return found.as_str().to_string(),
+            };
+            if can_end_sentence && (next.is_none() || before_capital) {
+                format!("{expansion}.")
+            } else {
+                expansion.to_string()
+            }
+        });
+
+        let max = SPELLED_NUMBER_MAX.load(Ordering::Relaxed).min(SPELLABLE_MAX);
+        let mut spoken = String::with_capacity(expanded.len() * 2);
+        let mut last = 0;
+        for found in get_number_regex().find_iter(&expanded) {
+            if !is_standalone_number(&expanded, found.start(), found.end()) {
+                continue;
+            }
+            let digits = found.as_str().trim_end_matches(|c: char| c.is_ascii_alphabetic());
+            let is_ordinal = digits.len() < found.as_str().len();
+            // Leading zeros are codes ("007"), not quantities
+            if digits.len() > 1 && digits.starts_with('0') {
+                continue;
+            }
+            let Some(n) = digits.parse::<u64>().ok().filter(|&n| n <= max) else { continue };
+            let words = if is_ordinal {
+                ordinal_words(n)
+            } else if digits.len() == 4 && (1100..=1999).contains(&n) {
+                year_words(n)
+            } else {
+                number_words(n)
+            };
+            spoken.push_str(&expanded[last..found.start()]);
+            spoken.push_str(&words);
+            last = found.end();
+        }
+        spoken.push_str(&expanded[last..]);
+        spoken
+    })
 }
 
 /// Test function for TTS text module
@@ -200,3 +857,123 @@ pub fn test_tts_text_module() -> String {
         data.normalized_text
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_range_to_raw() {
+        let data = precompute_text_highlights("  Hello \n\n  big\u{200B}world ".to_string());
+        assert_eq!(data.normalized_text, "Hello bigworld");
+        // "bigworld" sits after the collapsed run
+        assert_eq!(normalized_range_to_raw(&data, 6, 14), (12, 21));
+        // The space maps to the first char of the run
+        assert_eq!(normalized_range_to_raw(&data, 5, 6), (7, 8));
+    }
+
+    #[test]
+    fn test_paragraph_spans() {
+        let data = precompute_text_highlights("One.\nStill one.\n \nTwo.<br/><br>Three".to_string());
+        let paragraphs: Vec<&str> = data
+            .paragraphs
+            .iter()
+            .map(|p| &data.normalized_text[p.start as usize..p.end as usize])
+            .collect();
+        assert_eq!(paragraphs, ["One. Still one.", "Two.", "Three"]);
+    }
+
+    #[test]
+    fn test_to_ssml() {
+        let data = precompute_text_highlights("Salt & pepper. Done".to_string());
+        assert_eq!(
+            to_ssml(&data, None),
+            "<speak><s><mark name=\"w0\"/>Salt <mark name=\"w1\"/>&amp; <mark name=\"w2\"/>pepper\
+             <mark name=\"w3\"/>.</s> <s><mark name=\"w4\"/>Done</s></speak>"
+        );
+    }
+
+    #[test]
+    fn test_insert_html_highlight_second_occurrence() {
+        let html = "<p>the cat saw the dog</p>".to_string();
+        // "the" at normalized offset 12
+        let out = insert_html_highlight(html, 12, 15, "mark".to_string()).unwrap();
+        assert_eq!(out, "<p>the cat saw <mark>the</mark> dog</p>");
+    }
+
+    #[test]
+    fn test_insert_html_highlight_across_elements() {
+        let html = "<p>one <b>two</b>\n   three</p>".to_string();
+        // "two three"
+        let out = insert_html_highlight(html, 4, 13, "mark".to_string()).unwrap();
+        assert_eq!(
+            out,
+            "<p>one <b><mark>two</mark></b><mark>\n   three</mark></p>"
+        );
+    }
+
+    #[test]
+    fn test_insert_html_highlights_nested() {
+        let html = "<p>Hi there. <i>Next</i> one.</p>".to_string();
+        let ranges = vec![
+            HighlightRange { start: 3, end: 8, tag_name: "mark".to_string() },
+            HighlightRange { start: 10, end: 18, tag_name: "span".to_string() },
+            HighlightRange { start: 0, end: 9, tag_name: "span".to_string() },
+        ];
+        let out = insert_html_highlights(html, ranges).unwrap();
+        assert_eq!(
+            out,
+            "<p><span>Hi <mark>there</mark>.</span> <i><span>Next</span></i><span> one</span>.</p>"
+        );
+
+        let crossing = vec![
+            HighlightRange { start: 0, end: 5, tag_name: "mark".to_string() },
+            HighlightRange { start: 3, end: 8, tag_name: "span".to_string() },
+        ];
+        assert!(insert_html_highlights("<p>Hi there.</p>".to_string(), crossing).is_err());
+    }
+
+    #[test]
+    fn test_detect_text_language() {
+        let english = "The old man walked slowly along the river, thinking about the long winter ahead of him.";
+        assert_eq!(detect_text_language(english.to_string()).as_deref(), Some("en"));
+        let russian = "Старик медленно шёл вдоль реки и думал о долгой зиме, которая была впереди.";
+        assert_eq!(detect_text_language(russian.to_string()).as_deref(), Some("ru"));
+        assert_eq!(detect_text_language("  ".to_string()), None);
+    }
+
+    #[test]
+    fn test_html_to_highlight_data() {
+        let data = html_to_highlight_data("<p>One <b>two</b>\n  three</p>".to_string()).unwrap();
+        assert_eq!(data.normalized_text, "One two three");
+        assert_eq!(data.text_node_count, 3);
+        let located: Vec<(&str, u32, u32)> = data
+            .words
+            .iter()
+            .map(|w| (w.text.as_str(), w.node_index, w.node_offset))
+            .collect();
+        assert_eq!(located, [("One", 0, 0), ("two", 1, 0), ("three", 2, 3)]);
+    }
+
+    #[test]
+    fn test_expand_for_speech() {
+        let text = "Dr. Jones moved to 221st St. in 1984, paying $3.50 for 12 cats at 10:30.";
+        assert_eq!(
+            expand_for_speech(text.to_string(), "en-US".to_string()),
+            "Doctor Jones moved to two hundred twenty-first Street in nineteen eighty-four, \
+             paying $3.50 for twelve cats at 10:30."
+        );
+        assert_eq!(number_words(1_002_015), "one million two thousand fifteen");
+        assert_eq!(ordinal_words(40), "fortieth");
+        assert_eq!(expand_for_speech("Dr. 5".to_string(), "fr".to_string()), "Dr. 5");
+        assert_eq!(
+            expand_for_speech("Apples, pears, etc. Then Dr. Who came.".to_string(), "en".to_string()),
+            "Apples, pears, et cetera. Then Doctor Who came."
+        );
+        assert_eq!(
+            expand_for_speech("He lived on Main St. The house was near St. Paul's.".to_string(), "en".to_string()),
+            "He lived on Main Street. The house was near Saint Paul's."
+        );
+        assert_eq!(expand_for_speech("and so on, etc.".to_string(), String::new()), "and so on, et cetera.");
+    }
+}
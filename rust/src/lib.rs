@@ -7,11 +7,13 @@ macro_rules! timed {
     ($name:expr, $body:expr) => {{
         let start = std::time::Instant::now();
         let result = $body;
-        let elapsed = start.elapsed().as_millis();
-        if elapsed > 10 { // Log if > 10ms for profiling
+        let duration = start.elapsed();
+        let elapsed = duration.as_millis();
+        if elapsed > $crate::api::profiling::timed_threshold_ms() as u128 { // Log slow blocks for profiling
             // Using eprintln to show in console during debug
             eprintln!("⏱️  Rust: {} took {}ms", $name, elapsed);
         }
+        $crate::api::profiling::record_timed_sample($name, duration);
         result
     }};
 }